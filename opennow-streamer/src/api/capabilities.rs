@@ -0,0 +1,137 @@
+//! Provider Capabilities
+//!
+//! Records which GFN services answered for the signed-in provider. Alliance partners
+//! run their own endpoints and don't all support every call, so the UI lists what
+//! works for them instead of a blanket "experimental" warning.
+
+use log::debug;
+use parking_lot::Mutex;
+
+/// Services checked per provider
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProviderFeature {
+    Catalog,
+    Library,
+    Subscription,
+    ServerRegions,
+    ActiveSessions,
+    Sessions,
+}
+
+impl ProviderFeature {
+    /// Get display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ProviderFeature::Catalog => "Game catalog",
+            ProviderFeature::Library => "Library",
+            ProviderFeature::Subscription => "Membership & hours",
+            ProviderFeature::ServerRegions => "Server list",
+            ProviderFeature::ActiveSessions => "Session resume",
+            ProviderFeature::Sessions => "Launching games",
+        }
+    }
+
+    /// What the user loses when the service isn't supported
+    pub fn fallback(&self) -> &'static str {
+        match self {
+            ProviderFeature::Catalog => "Only the public game list is shown",
+            ProviderFeature::Library => "Your library stays empty",
+            ProviderFeature::Subscription => "Tier, hours and resolution limits are unknown",
+            ProviderFeature::ServerRegions => "The built-in NVIDIA server list is used",
+            ProviderFeature::ActiveSessions => "Running sessions can't be detected or resumed",
+            ProviderFeature::Sessions => "Games can't be started",
+        }
+    }
+
+    /// Get all features
+    pub fn all() -> &'static [ProviderFeature] {
+        &[
+            ProviderFeature::Catalog,
+            ProviderFeature::Library,
+            ProviderFeature::Subscription,
+            ProviderFeature::ServerRegions,
+            ProviderFeature::ActiveSessions,
+            ProviderFeature::Sessions,
+        ]
+    }
+}
+
+/// Outcome of the most recent call for a feature
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeatureStatus {
+    /// No call got a clear answer yet this login
+    Untested,
+    Available,
+    /// The provider refused the last call (error message)
+    Unsupported(String),
+}
+
+/// HTTP statuses meaning the provider doesn't offer a service at all
+const REFUSAL_STATUSES: &[&str] = &[
+    "404 Not Found",
+    "405 Method Not Allowed",
+    "501 Not Implemented",
+];
+
+/// Latest outcome per feature (features not listed are untested)
+static FEATURE_STATUS: Mutex<Vec<(ProviderFeature, FeatureStatus)>> = Mutex::new(Vec::new());
+
+/// Record the outcome of a call to one of the provider's services. Only an explicit
+/// refusal marks it unsupported; network and auth failures say nothing about the
+/// provider, so they leave the last outcome as it was
+pub fn record_feature<T, E: std::fmt::Display>(feature: ProviderFeature, result: &Result<T, E>) {
+    let status = match result {
+        Ok(_) => FeatureStatus::Available,
+        Err(e) => {
+            // Alternate formatting includes the whole anyhow context chain
+            let error = format!("{:#}", e);
+            if !is_refusal(&error) {
+                debug!("{} check inconclusive: {}", feature.display_name(), error);
+                return;
+            }
+            FeatureStatus::Unsupported(error)
+        }
+    };
+    let mut statuses = FEATURE_STATUS.lock();
+    match statuses.iter_mut().find(|(f, _)| *f == feature) {
+        Some(entry) => entry.1 = status,
+        None => statuses.push((feature, status)),
+    }
+}
+
+pub fn feature_status(feature: ProviderFeature) -> FeatureStatus {
+    FEATURE_STATUS
+        .lock()
+        .iter()
+        .find(|(f, _)| *f == feature)
+        .map_or(FeatureStatus::Untested, |(_, status)| status.clone())
+}
+
+/// Forget all outcomes (on login/logout, when the provider may change)
+pub fn reset_feature_status() {
+    FEATURE_STATUS.lock().clear();
+}
+
+fn is_refusal(error: &str) -> bool {
+    REFUSAL_STATUSES.iter().any(|status| error.contains(status))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_refusals_are_unsupported() {
+        assert!(is_refusal("App details failed: 404 Not Found - "));
+        assert!(is_refusal(
+            "Subscription API failed with status 501 Not Implemented: {}"
+        ));
+        assert!(!is_refusal(
+            "Panel fetch failed: 401 Unauthorized - expired"
+        ));
+        assert!(!is_refusal(
+            "Panel fetch request failed: error sending request: connection refused"
+        ));
+        assert!(!is_refusal("no regions returned"));
+    }
+}
@@ -23,6 +23,81 @@ fn cloudmatch_zone_url(zone: &str) -> String {
     format!("https://{}.cloudmatchbeta.nvidiagrid.net", zone)
 }
 
+/// Env var that enables full (redacted) session request/response logging
+const VERBOSE_SESSION_LOG_ENV: &str = "OPENNOW_VERBOSE_SESSION_LOG";
+
+/// JSON keys whose values are replaced before verbose logging (matched case-insensitively)
+const REDACTED_KEYS: &[&str] = &[
+    "token",
+    "password",
+    "credential",
+    "secret",
+    "authorization",
+    "email",
+    "username",
+    "userid",
+    "devicehashid",
+    "clientip",
+    "publicip",
+];
+
+/// Whether verbose session logging is enabled (checked once per process)
+fn verbose_session_logging() -> bool {
+    static ENABLED: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+    *ENABLED.get_or_init(|| {
+        let enabled = std::env::var(VERBOSE_SESSION_LOG_ENV)
+            .map(|v| !v.is_empty() && v != "0")
+            .unwrap_or(false);
+        if enabled {
+            warn!(
+                "{} is set - logging full session request/response bodies (redacted)",
+                VERBOSE_SESSION_LOG_ENV
+            );
+        }
+        enabled
+    })
+}
+
+/// Recursively replace sensitive values in a JSON document
+fn redact_json(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, v) in map.iter_mut() {
+                let key = key.to_ascii_lowercase();
+                if REDACTED_KEYS.iter().any(|k| key.contains(k)) {
+                    *v = serde_json::Value::String("<redacted>".to_string());
+                } else {
+                    redact_json(v);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_json),
+        _ => {}
+    }
+}
+
+/// Log a full session request/response body when verbose session logging is enabled
+fn log_session_body(label: &str, body: &str) {
+    if !verbose_session_logging() {
+        return;
+    }
+
+    match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(mut json) => {
+            redact_json(&mut json);
+            let pretty = serde_json::to_string_pretty(&json).unwrap_or_default();
+            info!("[session] {}:\n{}", label, pretty);
+        }
+        // Non-JSON bodies (HTML error pages, plain text) are logged as-is
+        Err(_) => info!(
+            "[session] {} (non-JSON, {} bytes): {}",
+            label,
+            body.len(),
+            body
+        ),
+    }
+}
+
 impl GfnApiClient {
     /// Request a new streaming session using browser-compatible format
     pub async fn create_session(
@@ -33,7 +108,11 @@ impl GfnApiClient {
         zone: &str,
         account_linked: bool,
     ) -> Result<SessionInfo> {
-        let token = self.token().context("No access token")?;
+        if super::mock::is_mock_mode() {
+            return Ok(super::mock::create_session(zone));
+        }
+
+        self.token().context("No access token")?;
 
         let device_id = generate_uuid();
         let client_id = generate_uuid();
@@ -179,28 +258,34 @@ impl GfnApiClient {
 
         info!("Creating session at: {}", url);
         debug!("App ID: {}, Title: {}", app_id, game_title);
+        if verbose_session_logging() {
+            if let Ok(body) = serde_json::to_string(&request) {
+                log_session_body("Create request", &body);
+            }
+        }
 
         let response = self
-            .client
-            .post(&url)
-            .header("User-Agent", GFN_USER_AGENT)
-            .header("Authorization", format!("GFNJWT {}", token))
-            .header("Content-Type", "application/json")
-            .header("Origin", "https://play.geforcenow.com")
-            .header("Referer", "https://play.geforcenow.com/")
-            // NV-* headers
-            .header("nv-browser-type", "CHROME")
-            .header("nv-client-id", &client_id)
-            .header("nv-client-streamer", "NVIDIA-CLASSIC")
-            .header("nv-client-type", "NATIVE")
-            .header("nv-client-version", GFN_CLIENT_VERSION)
-            .header("nv-device-make", "UNKNOWN")
-            .header("nv-device-model", "UNKNOWN")
-            .header("nv-device-os", "WINDOWS")
-            .header("nv-device-type", "DESKTOP")
-            .header("x-device-id", &device_id)
-            .json(&request)
-            .send()
+            .send_authorized(|token| {
+                self.client
+                    .post(&url)
+                    .header("User-Agent", GFN_USER_AGENT)
+                    .header("Authorization", format!("GFNJWT {}", token))
+                    .header("Content-Type", "application/json")
+                    .header("Origin", "https://play.geforcenow.com")
+                    .header("Referer", "https://play.geforcenow.com/")
+                    // NV-* headers
+                    .header("nv-browser-type", "CHROME")
+                    .header("nv-client-id", &client_id)
+                    .header("nv-client-streamer", "NVIDIA-CLASSIC")
+                    .header("nv-client-type", "NATIVE")
+                    .header("nv-client-version", GFN_CLIENT_VERSION)
+                    .header("nv-device-make", "UNKNOWN")
+                    .header("nv-device-model", "UNKNOWN")
+                    .header("nv-device-os", "WINDOWS")
+                    .header("nv-device-type", "DESKTOP")
+                    .header("x-device-id", &device_id)
+                    .json(&request)
+            })
             .await
             .context("Session request failed")?;
 
@@ -212,6 +297,10 @@ impl GfnApiClient {
             response_text.len(),
             &response_text[..response_text.len().min(500)]
         );
+        log_session_body(
+            &format!("Create response (HTTP {})", status),
+            &response_text,
+        );
 
         if !status.is_success() {
             // Parse error response for user-friendly message
@@ -224,11 +313,8 @@ impl GfnApiClient {
                 session_error.unified_error_code
             );
 
-            return Err(anyhow::anyhow!(
-                "{}: {}",
-                session_error.title,
-                session_error.description
-            ));
+            // Kept as the error type so callers can act on the specific rejection
+            return Err(session_error.into());
         }
 
         let api_response: CloudMatchResponse =
@@ -245,11 +331,7 @@ impl GfnApiClient {
                 api_response.request_status.unified_error_code
             );
 
-            return Err(anyhow::anyhow!(
-                "{}: {}",
-                session_error.title,
-                session_error.description
-            ));
+            return Err(session_error.into());
         }
 
         let session_data = api_response.session;
@@ -386,7 +468,11 @@ impl GfnApiClient {
         zone: &str,
         server_ip: Option<&str>,
     ) -> Result<SessionInfo> {
-        let token = self.token().context("No access token")?;
+        if super::mock::is_mock_mode() {
+            return Ok(super::mock::poll_session(zone));
+        }
+
+        self.token().context("No access token")?;
 
         let device_id = generate_uuid();
         let client_id = generate_uuid();
@@ -409,26 +495,28 @@ impl GfnApiClient {
         debug!("Polling session at: {}", url);
 
         let response = self
-            .client
-            .get(&url)
-            .header("User-Agent", GFN_USER_AGENT)
-            .header("Authorization", format!("GFNJWT {}", token))
-            .header("Content-Type", "application/json")
-            // NV-* headers
-            .header("nv-client-id", &client_id)
-            .header("nv-client-streamer", "NVIDIA-CLASSIC")
-            .header("nv-client-type", "NATIVE")
-            .header("nv-client-version", GFN_CLIENT_VERSION)
-            .header("nv-device-os", "WINDOWS")
-            .header("nv-device-type", "DESKTOP")
-            .header("x-device-id", &device_id)
-            .send()
+            .send_authorized(|token| {
+                self.client
+                    .get(&url)
+                    .header("User-Agent", GFN_USER_AGENT)
+                    .header("Authorization", format!("GFNJWT {}", token))
+                    .header("Content-Type", "application/json")
+                    // NV-* headers
+                    .header("nv-client-id", &client_id)
+                    .header("nv-client-streamer", "NVIDIA-CLASSIC")
+                    .header("nv-client-type", "NATIVE")
+                    .header("nv-client-version", GFN_CLIENT_VERSION)
+                    .header("nv-device-os", "WINDOWS")
+                    .header("nv-device-type", "DESKTOP")
+                    .header("x-device-id", &device_id)
+            })
             .await
             .context("Poll request failed")?;
 
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
+            log_session_body(&format!("Poll response (HTTP {})", status), &body);
             return Err(anyhow::anyhow!("Poll failed: {} - {}", status, body));
         }
 
@@ -436,6 +524,7 @@ impl GfnApiClient {
             .text()
             .await
             .context("Failed to read poll response")?;
+        log_session_body("Poll response", &response_text);
 
         let poll_response: CloudMatchResponse =
             serde_json::from_str(&response_text).context("Failed to parse poll response")?;
@@ -543,7 +632,11 @@ impl GfnApiClient {
         zone: &str,
         server_ip: Option<&str>,
     ) -> Result<()> {
-        let token = self.token().context("No access token")?;
+        if super::mock::is_mock_mode() {
+            return Ok(());
+        }
+
+        self.token().context("No access token")?;
 
         let device_id = generate_uuid();
 
@@ -565,13 +658,14 @@ impl GfnApiClient {
         info!("Stopping session at: {}", url);
 
         let response = self
-            .client
-            .delete(&url)
-            .header("User-Agent", GFN_USER_AGENT)
-            .header("Authorization", format!("GFNJWT {}", token))
-            .header("Content-Type", "application/json")
-            .header("x-device-id", &device_id)
-            .send()
+            .send_authorized(|token| {
+                self.client
+                    .delete(&url)
+                    .header("User-Agent", GFN_USER_AGENT)
+                    .header("Authorization", format!("GFNJWT {}", token))
+                    .header("Content-Type", "application/json")
+                    .header("x-device-id", &device_id)
+            })
             .await
             .context("Stop session request failed")?;
 
@@ -638,7 +732,11 @@ impl GfnApiClient {
     /// Get active sessions
     /// Returns list of sessions with status 2 (Ready) or 3 (Streaming)
     pub async fn get_active_sessions(&self) -> Result<Vec<ActiveSessionInfo>> {
-        let token = self.token().context("No access token")?;
+        if super::mock::is_mock_mode() {
+            return Ok(super::mock::active_sessions());
+        }
+
+        self.token().context("No access token")?;
 
         let device_id = generate_uuid();
         let client_id = generate_uuid();
@@ -650,19 +748,20 @@ impl GfnApiClient {
         info!("Checking for active sessions at: {}", session_url);
 
         let response = self
-            .client
-            .get(&session_url)
-            .header("User-Agent", GFN_USER_AGENT)
-            .header("Authorization", format!("GFNJWT {}", token))
-            .header("Content-Type", "application/json")
-            .header("nv-client-id", &client_id)
-            .header("nv-client-streamer", "NVIDIA-CLASSIC")
-            .header("nv-client-type", "NATIVE")
-            .header("nv-client-version", GFN_CLIENT_VERSION)
-            .header("nv-device-os", "WINDOWS")
-            .header("nv-device-type", "DESKTOP")
-            .header("x-device-id", &device_id)
-            .send()
+            .send_authorized(|token| {
+                self.client
+                    .get(&session_url)
+                    .header("User-Agent", GFN_USER_AGENT)
+                    .header("Authorization", format!("GFNJWT {}", token))
+                    .header("Content-Type", "application/json")
+                    .header("nv-client-id", &client_id)
+                    .header("nv-client-streamer", "NVIDIA-CLASSIC")
+                    .header("nv-client-type", "NATIVE")
+                    .header("nv-client-version", GFN_CLIENT_VERSION)
+                    .header("nv-device-os", "WINDOWS")
+                    .header("nv-device-type", "DESKTOP")
+                    .header("x-device-id", &device_id)
+            })
             .await
             .context("Failed to get sessions")?;
 
@@ -774,7 +873,11 @@ impl GfnApiClient {
         app_id: &str,
         settings: &Settings,
     ) -> Result<SessionInfo> {
-        let token = self.token().context("No access token")?;
+        if super::mock::is_mock_mode() {
+            return Ok(super::mock::claim_session());
+        }
+
+        self.token().context("No access token")?;
 
         let device_id = generate_uuid();
         let client_id = generate_uuid();
@@ -881,23 +984,28 @@ impl GfnApiClient {
             "metaData": []
         });
 
+        if verbose_session_logging() {
+            log_session_body("Claim request", &resume_payload.to_string());
+        }
+
         let response = self
-            .client
-            .put(&claim_url)
-            .header("User-Agent", GFN_USER_AGENT)
-            .header("Authorization", format!("GFNJWT {}", token))
-            .header("Content-Type", "application/json")
-            .header("Origin", "https://play.geforcenow.com")
-            .header("Referer", "https://play.geforcenow.com/")
-            .header("nv-client-id", &client_id)
-            .header("nv-client-streamer", "NVIDIA-CLASSIC")
-            .header("nv-client-type", "NATIVE")
-            .header("nv-client-version", GFN_CLIENT_VERSION)
-            .header("nv-device-os", "WINDOWS")
-            .header("nv-device-type", "DESKTOP")
-            .header("x-device-id", &device_id)
-            .json(&resume_payload)
-            .send()
+            .send_authorized(|token| {
+                self.client
+                    .put(&claim_url)
+                    .header("User-Agent", GFN_USER_AGENT)
+                    .header("Authorization", format!("GFNJWT {}", token))
+                    .header("Content-Type", "application/json")
+                    .header("Origin", "https://play.geforcenow.com")
+                    .header("Referer", "https://play.geforcenow.com/")
+                    .header("nv-client-id", &client_id)
+                    .header("nv-client-streamer", "NVIDIA-CLASSIC")
+                    .header("nv-client-type", "NATIVE")
+                    .header("nv-client-version", GFN_CLIENT_VERSION)
+                    .header("nv-device-os", "WINDOWS")
+                    .header("nv-device-type", "DESKTOP")
+                    .header("x-device-id", &device_id)
+                    .json(&resume_payload)
+            })
             .await
             .context("Claim session request failed")?;
 
@@ -906,6 +1014,7 @@ impl GfnApiClient {
             .text()
             .await
             .context("Failed to read claim response")?;
+        log_session_body(&format!("Claim response (HTTP {})", status), &response_text);
 
         if !status.is_success() {
             error!("Claim session failed response: {}", response_text);
@@ -945,19 +1054,20 @@ impl GfnApiClient {
             }
 
             let poll_response = self
-                .client
-                .get(&get_url)
-                .header("User-Agent", GFN_USER_AGENT)
-                .header("Authorization", format!("GFNJWT {}", token))
-                .header("Content-Type", "application/json")
-                .header("nv-client-id", &client_id)
-                .header("nv-client-streamer", "NVIDIA-CLASSIC")
-                .header("nv-client-type", "NATIVE")
-                .header("nv-client-version", GFN_CLIENT_VERSION)
-                .header("nv-device-os", "WINDOWS")
-                .header("nv-device-type", "DESKTOP")
-                .header("x-device-id", &device_id)
-                .send()
+                .send_authorized(|token| {
+                    self.client
+                        .get(&get_url)
+                        .header("User-Agent", GFN_USER_AGENT)
+                        .header("Authorization", format!("GFNJWT {}", token))
+                        .header("Content-Type", "application/json")
+                        .header("nv-client-id", &client_id)
+                        .header("nv-client-streamer", "NVIDIA-CLASSIC")
+                        .header("nv-client-type", "NATIVE")
+                        .header("nv-client-version", GFN_CLIENT_VERSION)
+                        .header("nv-device-os", "WINDOWS")
+                        .header("nv-device-type", "DESKTOP")
+                        .header("x-device-id", &device_id)
+                })
                 .await
                 .context("Poll claim request failed")?;
 
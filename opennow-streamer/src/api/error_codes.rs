@@ -545,6 +545,17 @@ static ERROR_MESSAGES: Lazy<HashMap<i64, (&'static str, &'static str)>> = Lazy::
     m
 });
 
+/// Part of the requested stream that the server refused
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamSettingRejection {
+    /// Resolution / frame rate combination
+    Mode,
+    /// Video codec
+    Codec,
+    /// HDR
+    Hdr,
+}
+
 /// Parsed error information from CloudMatch response
 #[derive(Debug, Clone)]
 pub struct SessionError {
@@ -731,6 +742,37 @@ impl SessionError {
             .unwrap_or(false)
     }
 
+    /// Which requested stream setting the server refused, if that's what this error is
+    pub fn stream_setting_rejection(&self) -> Option<StreamSettingRejection> {
+        let desc = self
+            .status_description
+            .as_deref()
+            .unwrap_or_default()
+            .to_uppercase();
+        if ["CODEC", "AV1", "HEVC", "H265"]
+            .iter()
+            .any(|k| desc.contains(k))
+        {
+            return Some(StreamSettingRejection::Codec);
+        }
+        if desc.contains("HDR") {
+            return Some(StreamSettingRejection::Hdr);
+        }
+        if ["RESOLUTION", "FPS", "FRAME_RATE", "FRAMERATE", "MONITOR"]
+            .iter()
+            .any(|k| desc.contains(k))
+        {
+            return Some(StreamSettingRejection::Mode);
+        }
+        // Rejections for the requested rig class come without a description
+        matches!(
+            self.gfn_error_code,
+            3237093693 | // RequiredSeatInstanceTypeNotSupported
+            3237093700 // EntitlementFailureForResource
+        )
+        .then_some(StreamSettingRejection::Mode)
+    }
+
     /// Check if this is a temporary error that might resolve with retry
     pub fn is_retryable(&self) -> bool {
         matches!(
@@ -764,6 +806,14 @@ impl SessionError {
     }
 }
 
+impl std::fmt::Display for SessionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.title, self.description)
+    }
+}
+
+impl std::error::Error for SessionError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -790,4 +840,28 @@ mod tests {
         assert_eq!(error.gfn_error_code, 3237093643); // 3237093632 + 11
         assert!(error.is_session_conflict());
     }
+
+    #[test]
+    fn test_stream_setting_rejection() {
+        let response = r#"{"requestStatus":{"statusCode":68,"statusDescription":"ENTITLEMENT_FAILURE_FOR_RESOURCE MONITOR_SETTINGS 3840x2160@120"}}"#;
+        let error = SessionError::from_response(403, response);
+        assert_eq!(
+            error.stream_setting_rejection(),
+            Some(StreamSettingRejection::Mode)
+        );
+
+        let response = r#"{"requestStatus":{"statusCode":5,"statusDescription":"INVALID_REQUEST AV1 NOT SUPPORTED"}}"#;
+        let error = SessionError::from_response(400, response);
+        assert_eq!(
+            error.stream_setting_rejection(),
+            Some(StreamSettingRejection::Codec)
+        );
+
+        let response =
+            r#"{"requestStatus":{"statusCode":11,"statusDescription":"SESSION_LIMIT_EXCEEDED"}}"#;
+        assert_eq!(
+            SessionError::from_response(403, response).stream_setting_rejection(),
+            None
+        );
+    }
 }
@@ -145,6 +145,8 @@ struct VariantGfnStatus {
     status: Option<String>,
     #[serde(default)]
     library: Option<VariantLibraryStatus>,
+    #[serde(default)]
+    features: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -166,6 +168,9 @@ struct AppGfnStatus {
     minimum_membership_tier_label: Option<String>,
     #[serde(default)]
     catalog_sku_strings: Option<CatalogSkuStrings>,
+    /// Streaming features (shape varies between catalog versions, so kept untyped)
+    #[serde(default)]
+    features: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -232,7 +237,7 @@ impl GfnApiClient {
     /// Fetch panels using persisted query (GET request)
     /// This is the correct way to fetch from GFN API
     async fn fetch_panels(&self, panel_names: &[&str], vpc_id: &str) -> Result<Vec<Panel>> {
-        let token = self.token()
+        self.token()
             .context("No access token for panel fetch")?;
 
         let variables = serde_json::json!({
@@ -273,25 +278,27 @@ impl GfnApiClient {
 
         debug!("Fetching panels from: {}", url);
 
-        let response = self.client
-            .get(&url)
-            .header("User-Agent", GFN_USER_AGENT)
-            .header("Accept", "application/json, text/plain, */*")
-            .header("Content-Type", "application/graphql")
-            .header("Origin", "https://play.geforcenow.com")
-            .header("Referer", "https://play.geforcenow.com/")
-            .header("Authorization", format!("GFNJWT {}", token))
-            // GFN client headers (native client)
-            .header("nv-client-id", LCARS_CLIENT_ID)
-            .header("nv-client-type", "NATIVE")
-            .header("nv-client-version", GFN_CLIENT_VERSION)
-            .header("nv-client-streamer", "NVIDIA-CLASSIC")
-            .header("nv-device-os", "WINDOWS")
-            .header("nv-device-type", "DESKTOP")
-            .header("nv-device-make", "UNKNOWN")
-            .header("nv-device-model", "UNKNOWN")
-            .header("nv-browser-type", "CHROME")
-            .send()
+        let response = self
+            .send_authorized(|token| {
+                self.client
+                    .get(&url)
+                    .header("User-Agent", GFN_USER_AGENT)
+                    .header("Accept", "application/json, text/plain, */*")
+                    .header("Content-Type", "application/graphql")
+                    .header("Origin", "https://play.geforcenow.com")
+                    .header("Referer", "https://play.geforcenow.com/")
+                    .header("Authorization", format!("GFNJWT {}", token))
+                    // GFN client headers (native client)
+                    .header("nv-client-id", LCARS_CLIENT_ID)
+                    .header("nv-client-type", "NATIVE")
+                    .header("nv-client-version", GFN_CLIENT_VERSION)
+                    .header("nv-client-streamer", "NVIDIA-CLASSIC")
+                    .header("nv-device-os", "WINDOWS")
+                    .header("nv-device-type", "DESKTOP")
+                    .header("nv-device-make", "UNKNOWN")
+                    .header("nv-device-model", "UNKNOWN")
+                    .header("nv-browser-type", "CHROME")
+            })
             .await
             .context("Panel fetch request failed")?;
 
@@ -363,6 +370,22 @@ impl GfnApiClient {
             .and_then(|i| i.game_box_art.as_ref().or(i.tv_banner.as_ref()).or(i.hero_image.as_ref()))
             .map(|url| optimize_image_url(url, 272));
 
+        // Streaming features from the app and the selected variant
+        let feature_values: Vec<&serde_json::Value> = app.gfn.as_ref()
+            .and_then(|g| g.features.as_ref())
+            .into_iter()
+            .chain(app.variants.as_ref()
+                .and_then(|vars| vars.get(selected_variant_index))
+                .and_then(|v| v.gfn.as_ref())
+                .and_then(|g| g.features.as_ref()))
+            .collect();
+        let mut labels = FeatureLabels::default();
+        for value in feature_values {
+            collect_feature_labels(value, &mut labels);
+        }
+        let features = (!labels.supported.is_empty()).then_some(labels.supported);
+        let unsupported_features = labels.unsupported;
+
         // Check if playType is INSTALL_TO_PLAY
         let is_install_to_play = app.gfn.as_ref()
             .and_then(|g| g.play_type.as_deref())
@@ -384,17 +407,23 @@ impl GfnApiClient {
             description: app.description.or(app.long_description),
             variants,
             selected_variant_index,
+            features,
+            unsupported_features,
         }
     }
 
     /// Fetch games from MAIN panel (GraphQL with images)
     pub async fn fetch_main_games(&self, vpc_id: Option<&str>) -> Result<Vec<GameInfo>> {
+        if super::mock::is_mock_mode() {
+            return Ok(super::mock::games());
+        }
+
         // Use provided VPC ID or fetch dynamically from serverInfo
         let vpc = match vpc_id {
             Some(v) => v.to_string(),
             None => {
-                let token = self.token().map(|s| s.as_str());
-                super::get_vpc_id(&self.client, token).await
+                let token = self.token();
+                super::get_vpc_id(&self.client, token.as_deref()).await
             }
         };
 
@@ -424,12 +453,16 @@ impl GfnApiClient {
     /// Fetch games organized by section (Home view)
     /// Returns sections with titles like "Trending", "Free to Play", etc.
     pub async fn fetch_sectioned_games(&self, vpc_id: Option<&str>) -> Result<Vec<GameSection>> {
+        if super::mock::is_mock_mode() {
+            return Ok(super::mock::sections());
+        }
+
         // Use provided VPC ID or fetch dynamically from serverInfo
         let vpc = match vpc_id {
             Some(v) => v.to_string(),
             None => {
-                let token = self.token().map(|s| s.as_str());
-                super::get_vpc_id(&self.client, token).await
+                let token = self.token();
+                super::get_vpc_id(&self.client, token.as_deref()).await
             }
         };
 
@@ -473,12 +506,16 @@ impl GfnApiClient {
 
     /// Fetch user's library (GraphQL)
     pub async fn fetch_library(&self, vpc_id: Option<&str>) -> Result<Vec<GameInfo>> {
+        if super::mock::is_mock_mode() {
+            return Ok(super::mock::library());
+        }
+
         // Use provided VPC ID or fetch dynamically from serverInfo
         let vpc = match vpc_id {
             Some(v) => v.to_string(),
             None => {
-                let token = self.token().map(|s| s.as_str());
-                super::get_vpc_id(&self.client, token).await
+                let token = self.token();
+                super::get_vpc_id(&self.client, token.as_deref()).await
             }
         };
 
@@ -513,6 +550,10 @@ impl GfnApiClient {
     /// Fetch public games list (static JSON, no auth required)
     /// Uses Steam CDN for game images when available
     pub async fn fetch_public_games(&self) -> Result<Vec<GameInfo>> {
+        if super::mock::is_mock_mode() {
+            return Ok(super::mock::games());
+        }
+
         let url = "https://static.nvidiagrid.net/supported-public-game-list/locales/gfnpc-en-US.json";
 
         info!("Fetching public games from: {}", url);
@@ -581,6 +622,8 @@ impl GfnApiClient {
                     description: None,
                     variants: Vec::new(),
                     selected_variant_index: 0,
+                    features: None,
+                    unsupported_features: Vec::new(),
                 })
             })
             .collect();
@@ -599,11 +642,15 @@ impl GfnApiClient {
 
     /// Fetch full details for a specific app (including playType)
     pub async fn fetch_app_details(&self, app_id: &str) -> Result<Option<GameInfo>> {
+        if super::mock::is_mock_mode() {
+            return Ok(super::mock::app_details(app_id));
+        }
+
         let token = self.token()
             .context("No access token for app details")?;
 
         // Get VPC ID
-        let vpc_id = super::get_vpc_id(&self.client, Some(token)).await;
+        let vpc_id = super::get_vpc_id(&self.client, Some(&token)).await;
 
         let variables = serde_json::json!({
             "vpcId": vpc_id,
@@ -632,14 +679,16 @@ impl GfnApiClient {
         debug!("Fetching app details from: {}", url);
         info!("Fetching app details for ID: {} (Variables: {})", app_id, variables_str);
 
-        let response = self.client
-            .get(&url)
-            .header("User-Agent", GFN_USER_AGENT)
-            .header("Accept", "application/json")
-            .header("Content-Type", "application/graphql") 
-            .header("Authorization", format!("GFNJWT {}", token))
-            .header("nv-client-id", LCARS_CLIENT_ID)
-            .send()
+        let response = self
+            .send_authorized(|token| {
+                self.client
+                    .get(&url)
+                    .header("User-Agent", GFN_USER_AGENT)
+                    .header("Accept", "application/json")
+                    .header("Content-Type", "application/graphql") 
+                    .header("Authorization", format!("GFNJWT {}", token))
+                    .header("nv-client-id", LCARS_CLIENT_ID)
+            })
             .await
             .context("App details request failed")?;
 
@@ -666,6 +715,70 @@ impl GfnApiClient {
 
 
 
+/// Catalog feature keys we show, by display label (matched exactly, ignoring case and separators)
+/// Listed without separators: "highFrameRate", "HIGH_FRAME_RATE" and "high-frame-rate" all match
+const FEATURE_KEYS: &[(&str, &[&str])] = &[
+    ("HDR", &["HDR", "HDRSUPPORTED", "SUPPORTSHDR"]),
+    ("RTX", &["RTX", "RTXON", "RAYTRACING"]),
+    ("DLSS", &["DLSS", "NVIDIADLSS"]),
+    ("Reflex", &["REFLEX", "NVIDIAREFLEX"]),
+    ("High FPS", &["HIGHFPS", "HIGHFRAMERATE", "FPS120", "120FPS", "FPS240", "240FPS"]),
+];
+
+/// Display label for a catalog feature key, if it's one we show
+fn feature_label(key: &str) -> Option<&'static str> {
+    let key: String = key
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    FEATURE_KEYS
+        .iter()
+        .find(|(_, keys)| keys.contains(&key.as_str()))
+        .map(|(label, _)| *label)
+}
+
+/// Feature labels the catalog flags on and off
+#[derive(Default)]
+struct FeatureLabels {
+    supported: Vec<String>,
+    unsupported: Vec<String>,
+}
+
+/// Collect feature labels from catalog data (strings are supported, object keys set to true/false)
+fn collect_feature_labels(value: &serde_json::Value, labels: &mut FeatureLabels) {
+    let add = |key: &str, list: &mut Vec<String>| {
+        if let Some(label) = feature_label(key) {
+            if !list.iter().any(|l| l == label) {
+                list.push(label.to_string());
+            }
+        }
+    };
+
+    match value {
+        serde_json::Value::String(key) => add(key, &mut labels.supported),
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_feature_labels(item, labels);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            for (key, item) in map {
+                match item {
+                    serde_json::Value::Bool(true) => add(key, &mut labels.supported),
+                    serde_json::Value::Bool(false) => add(key, &mut labels.unsupported),
+                    serde_json::Value::Null => {}
+                    _ => collect_feature_labels(item, labels),
+                }
+            }
+        }
+        _ => {}
+    }
+    // A flag listed as supported anywhere (app or variant) wins
+    let supported = &labels.supported;
+    labels.unsupported.retain(|label| !supported.contains(label));
+}
+
 /// Fetch server info to get VPC ID for current provider
 pub async fn fetch_server_info(access_token: Option<&str>) -> Result<ServerInfo> {
     let base_url = auth::get_streaming_base_url();
@@ -677,22 +790,19 @@ pub async fn fetch_server_info(access_token: Option<&str>) -> Result<ServerInfo>
         .user_agent(GFN_USER_AGENT)
         .build()?;
 
-    let mut request = client
-        .get(&url)
-        .header("Accept", "application/json")
-        .header("nv-client-id", LCARS_CLIENT_ID)
-        .header("nv-client-type", "BROWSER")
-        .header("nv-client-version", GFN_CLIENT_VERSION)
-        .header("nv-client-streamer", "WEBRTC")
-        .header("nv-device-os", "WINDOWS")
-        .header("nv-device-type", "DESKTOP");
-
-    if let Some(token) = access_token {
-        request = request.header("Authorization", format!("GFNJWT {}", token));
-    }
-
-    let response = request.send().await
-        .context("Server info request failed")?;
+    let response = super::send_with_token(access_token, || {
+        client
+            .get(&url)
+            .header("Accept", "application/json")
+            .header("nv-client-id", LCARS_CLIENT_ID)
+            .header("nv-client-type", "BROWSER")
+            .header("nv-client-version", GFN_CLIENT_VERSION)
+            .header("nv-client-streamer", "WEBRTC")
+            .header("nv-device-os", "WINDOWS")
+            .header("nv-device-type", "DESKTOP")
+    })
+    .await
+    .context("Server info request failed")?;
 
     if !response.status().is_success() {
         return Err(anyhow::anyhow!("Server info failed: {}", response.status()));
@@ -744,3 +854,24 @@ pub struct ServerInfo {
     pub vpc_id: String,
     pub regions: Vec<(String, String)>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feature_labels_exact_and_tri_state() {
+        let mut labels = FeatureLabels::default();
+        let catalog = serde_json::json!({
+            "HDR": true,
+            "highFrameRate": false,
+            "RESOLUTION_1200P": true,
+            "maxFps": 240,
+            "tags": ["nvidia-reflex", "SDR"]
+        });
+        collect_feature_labels(&catalog, &mut labels);
+
+        assert_eq!(labels.supported, vec!["HDR", "Reflex"]);
+        assert_eq!(labels.unsupported, vec!["High FPS"]);
+    }
+}
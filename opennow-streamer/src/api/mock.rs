@@ -0,0 +1,250 @@
+//! Mock API
+//!
+//! Canned GFN responses for `--mock`, so the UI and session flow can be worked on
+//! without an account or network access. The catalog, subscription and regions come
+//! from the bundled `mock_data.json`; sessions walk through the queue and launch
+//! states and then point at an unreachable signaling server.
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use super::DynamicServerRegion;
+use crate::app::types::EntitledResolution;
+use crate::app::{
+    ActiveSessionInfo, GameInfo, GameSection, SessionInfo, SessionState, SubscriptionInfo,
+};
+use crate::auth::AuthTokens;
+
+/// Whether API calls are answered from the bundled mock data
+static MOCK_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Polls left before the mock session leaves the queue
+static MOCK_POLLS_LEFT: AtomicU32 = AtomicU32::new(0);
+
+/// Queue position a mock session starts at (one poll per position)
+const MOCK_QUEUE_POSITION: u32 = 3;
+
+/// Mock session ID reported by create/poll
+const MOCK_SESSION_ID: &str = "mock-session";
+
+/// Signaling URL nothing listens on, so streaming fails fast with a normal error
+const MOCK_SIGNALING_URL: &str = "wss://127.0.0.1:1/nvst/";
+
+const MOCK_DATA: &str = include_str!("mock_data.json");
+
+#[derive(Deserialize)]
+struct MockData {
+    sections: Vec<MockSection>,
+    #[serde(default)]
+    library: Vec<String>,
+    subscription: MockSubscription,
+    #[serde(default)]
+    regions: Vec<MockRegion>,
+}
+
+#[derive(Deserialize)]
+struct MockSection {
+    id: Option<String>,
+    title: String,
+    games: Vec<GameInfo>,
+}
+
+#[derive(Deserialize)]
+struct MockSubscription {
+    membership_tier: String,
+    remaining_hours: f32,
+    total_hours: f32,
+    has_persistent_storage: bool,
+    storage_size_gb: Option<u32>,
+    is_unlimited: bool,
+    entitled_resolutions: Vec<EntitledResolution>,
+}
+
+#[derive(Deserialize)]
+struct MockRegion {
+    name: String,
+    url: String,
+}
+
+/// Enable mock mode (set once at startup from `--mock`)
+pub fn set_mock_mode(enabled: bool) {
+    MOCK_MODE.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether API calls are answered from the bundled mock data
+pub fn is_mock_mode() -> bool {
+    MOCK_MODE.load(Ordering::Relaxed)
+}
+
+fn data() -> &'static MockData {
+    static DATA: OnceLock<MockData> = OnceLock::new();
+    DATA.get_or_init(|| serde_json::from_str(MOCK_DATA).expect("bundled mock_data.json is invalid"))
+}
+
+/// Home view sections
+pub(super) fn sections() -> Vec<GameSection> {
+    data()
+        .sections
+        .iter()
+        .map(|section| GameSection {
+            id: section.id.clone(),
+            title: section.title.clone(),
+            games: section.games.clone(),
+        })
+        .collect()
+}
+
+/// Every game across all sections, without duplicates
+pub(super) fn games() -> Vec<GameInfo> {
+    let mut seen = HashSet::new();
+    data()
+        .sections
+        .iter()
+        .flat_map(|section| section.games.iter())
+        .filter(|game| seen.insert(game.id.clone()))
+        .cloned()
+        .collect()
+}
+
+/// Games listed in the mock library
+pub(super) fn library() -> Vec<GameInfo> {
+    games()
+        .into_iter()
+        .filter(|game| data().library.contains(&game.id))
+        .collect()
+}
+
+/// Details for a game by ID (any variant ID matches)
+pub(super) fn app_details(app_id: &str) -> Option<GameInfo> {
+    games()
+        .into_iter()
+        .find(|game| game.id == app_id || game.variants.iter().any(|variant| variant.id == app_id))
+}
+
+pub(super) fn subscription() -> SubscriptionInfo {
+    let sub = &data().subscription;
+    SubscriptionInfo {
+        membership_tier: sub.membership_tier.clone(),
+        remaining_hours: sub.remaining_hours,
+        total_hours: sub.total_hours,
+        has_persistent_storage: sub.has_persistent_storage,
+        storage_size_gb: sub.storage_size_gb,
+        is_unlimited: sub.is_unlimited,
+        entitled_resolutions: sub.entitled_resolutions.clone(),
+        addons: Vec::new(),
+        fetched_at: chrono::Utc::now().timestamp(),
+    }
+}
+
+pub(super) fn regions() -> Vec<DynamicServerRegion> {
+    data()
+        .regions
+        .iter()
+        .map(|region| DynamicServerRegion {
+            name: region.name.clone(),
+            url: region.url.clone(),
+        })
+        .collect()
+}
+
+/// Non-expiring tokens so the app starts logged in
+pub fn tokens() -> AuthTokens {
+    AuthTokens {
+        access_token: "mock-token".to_string(),
+        refresh_token: None,
+        id_token: None,
+        expires_at: i64::MAX,
+    }
+}
+
+fn session(zone: &str, state: SessionState) -> SessionInfo {
+    let ready = state == SessionState::Ready;
+    SessionInfo {
+        session_id: MOCK_SESSION_ID.to_string(),
+        server_ip: if ready {
+            "127.0.0.1".to_string()
+        } else {
+            String::new()
+        },
+        gpu_type: ready.then(|| "MOCK-GPU".to_string()),
+        signaling_url: ready.then(|| MOCK_SIGNALING_URL.to_string()),
+        state,
+        ..SessionInfo::new_requesting(zone)
+    }
+}
+
+/// New session, placed in the queue
+pub(super) fn create_session(zone: &str) -> SessionInfo {
+    MOCK_POLLS_LEFT.store(MOCK_QUEUE_POSITION, Ordering::Relaxed);
+    session(
+        zone,
+        SessionState::InQueue {
+            position: MOCK_QUEUE_POSITION,
+            eta_secs: MOCK_QUEUE_POSITION * 10,
+        },
+    )
+}
+
+/// Next state of the mock session: queue positions, then launching, then ready
+pub(super) fn poll_session(zone: &str) -> SessionInfo {
+    let left = MOCK_POLLS_LEFT.load(Ordering::Relaxed);
+    MOCK_POLLS_LEFT.store(left.saturating_sub(1), Ordering::Relaxed);
+    let state = match left {
+        0 => SessionState::Ready,
+        1 => SessionState::Launching,
+        position => SessionState::InQueue {
+            position: position - 1,
+            eta_secs: (position - 1) * 10,
+        },
+    };
+    session(zone, state)
+}
+
+/// Active sessions (never any, so launches skip the conflict dialog)
+pub(super) fn active_sessions() -> Vec<ActiveSessionInfo> {
+    Vec::new()
+}
+
+/// Claimed session, ready immediately
+pub(super) fn claim_session() -> SessionInfo {
+    session("", SessionState::Ready)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_data_parses() {
+        assert!(!sections().is_empty());
+        assert!(!library().is_empty());
+        assert!(games().iter().any(|game| game.is_install_to_play));
+        assert!(app_details("100002").is_some());
+        assert!(!subscription().entitled_resolutions.is_empty());
+    }
+
+    #[test]
+    fn test_session_walks_queue_to_ready() {
+        create_session("mock");
+        let mut states = Vec::new();
+        loop {
+            let session = poll_session("mock");
+            states.push(session.state.clone());
+            if session.is_ready() {
+                assert!(session.signaling_url.is_some());
+                break;
+            }
+        }
+        assert_eq!(
+            states.first(),
+            Some(&SessionState::InQueue {
+                position: 2,
+                eta_secs: 20
+            })
+        );
+        assert_eq!(states[states.len() - 2], SessionState::Launching);
+    }
+}
@@ -2,10 +2,13 @@
 //!
 //! HTTP API interactions with GeForce NOW services.
 
+pub mod capabilities;
 mod cloudmatch;
 mod games;
 pub mod error_codes;
+pub mod mock;
 pub mod queue;
+pub mod releases;
 
 #[allow(unused_imports)]
 pub use cloudmatch::*;
@@ -15,6 +18,7 @@ pub use queue::{QueueServerInfo, fetch_queue_servers, format_queue_eta, calculat
 
 use reqwest::Client;
 use parking_lot::RwLock;
+use std::sync::atomic::{AtomicBool, Ordering};
 use log::{info, debug, warn};
 use serde::Deserialize;
 
@@ -76,21 +80,19 @@ async fn fetch_vpc_id_from_server_info(client: &Client, token: Option<&str>) ->
 
     info!("Fetching VPC ID from serverInfo: {}", url);
 
-    let mut request = client
-        .get(url)
-        .header("Accept", "application/json")
-        .header("nv-client-id", "ec7e38d4-03af-4b58-b131-cfb0495903ab")
-        .header("nv-client-type", "NATIVE")
-        .header("nv-client-version", "2.0.80.173")
-        .header("nv-client-streamer", "NVIDIA-CLASSIC")
-        .header("nv-device-os", "WINDOWS")
-        .header("nv-device-type", "DESKTOP");
-
-    if let Some(t) = token {
-        request = request.header("Authorization", format!("GFNJWT {}", t));
-    }
+    let request = || {
+        client
+            .get(url)
+            .header("Accept", "application/json")
+            .header("nv-client-id", "ec7e38d4-03af-4b58-b131-cfb0495903ab")
+            .header("nv-client-type", "NATIVE")
+            .header("nv-client-version", "2.0.80.173")
+            .header("nv-client-streamer", "NVIDIA-CLASSIC")
+            .header("nv-device-os", "WINDOWS")
+            .header("nv-device-type", "DESKTOP")
+    };
 
-    let response = match request.send().await {
+    let response = match send_with_token(token, request).await {
         Ok(r) => r,
         Err(e) => {
             warn!("Failed to fetch serverInfo: {}", e);
@@ -139,27 +141,29 @@ pub fn clear_vpc_cache() {
 pub async fn fetch_dynamic_regions(client: &Client, token: Option<&str>) -> Vec<DynamicServerRegion> {
     use crate::auth;
 
+    if mock::is_mock_mode() {
+        return mock::regions();
+    }
+
     // Get the base URL from the selected provider (Alliance partners have different URLs)
     let base_url = auth::get_streaming_base_url();
     let url = format!("{}v2/serverInfo", base_url);
 
     info!("[serverInfo] Fetching dynamic regions from: {}", url);
 
-    let mut request = client
-        .get(&url)
-        .header("Accept", "application/json")
-        .header("nv-client-id", "ec7e38d4-03af-4b58-b131-cfb0495903ab")
-        .header("nv-client-type", "BROWSER")
-        .header("nv-client-version", "2.0.80.173")
-        .header("nv-client-streamer", "WEBRTC")
-        .header("nv-device-os", "WINDOWS")
-        .header("nv-device-type", "DESKTOP");
-
-    if let Some(t) = token {
-        request = request.header("Authorization", format!("GFNJWT {}", t));
-    }
+    let request = || {
+        client
+            .get(&url)
+            .header("Accept", "application/json")
+            .header("nv-client-id", "ec7e38d4-03af-4b58-b131-cfb0495903ab")
+            .header("nv-client-type", "BROWSER")
+            .header("nv-client-version", "2.0.80.173")
+            .header("nv-client-streamer", "WEBRTC")
+            .header("nv-device-os", "WINDOWS")
+            .header("nv-device-type", "DESKTOP")
+    };
 
-    let response = match request.send().await {
+    let response = match send_with_token(token, request).await {
         Ok(r) => r,
         Err(e) => {
             warn!("[serverInfo] Failed to fetch: {}", e);
@@ -221,10 +225,71 @@ pub async fn fetch_dynamic_regions(client: &Client, token: Option<&str>) -> Vec<
     regions
 }
 
+/// Set when the API client refreshed tokens inline after a 401 (App reloads them from cache)
+static TOKENS_REFRESHED: AtomicBool = AtomicBool::new(false);
+
+/// Serializes inline token refreshes so concurrent 401s only trigger one refresh
+static TOKEN_REFRESH_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// Returns true (once) if tokens were refreshed inline since the last call
+pub fn take_tokens_refreshed() -> bool {
+    TOKENS_REFRESHED.swap(false, Ordering::AcqRel)
+}
+
+/// Send a request with `token` as GFNJWT, refreshing the stored tokens and retrying once on
+/// HTTP 401. For calls made outside a `GfnApiClient`; without a token it is sent as-is.
+pub async fn send_with_token<F>(token: Option<&str>, build: F) -> reqwest::Result<reqwest::Response>
+where
+    F: Fn() -> reqwest::RequestBuilder,
+{
+    let Some(token) = token else {
+        return build().send().await;
+    };
+    let authorized = |jwt: &str| build().header("Authorization", format!("GFNJWT {}", jwt));
+    let response = authorized(token).send().await?;
+
+    if response.status() != reqwest::StatusCode::UNAUTHORIZED {
+        return Ok(response);
+    }
+
+    warn!("API request returned 401, attempting inline token refresh");
+    match refresh_after_unauthorized(token).await {
+        Some(new_token) => authorized(&new_token).send().await,
+        None => Ok(response),
+    }
+}
+
+/// Refresh tokens after a 401 using the stored refresh token
+/// Returns the new JWT, or None if no refresh was possible
+async fn refresh_after_unauthorized(failed_token: &str) -> Option<String> {
+    let _guard = TOKEN_REFRESH_LOCK.lock().await;
+
+    // Another request may have refreshed while we waited for the lock
+    let stored = crate::app::cache::load_stored_tokens()?;
+    if stored.jwt() != failed_token && !stored.is_expired() {
+        debug!("Using tokens refreshed by a concurrent request");
+        return Some(stored.jwt().to_string());
+    }
+
+    let refresh_token = stored.refresh_token.as_ref()?;
+    match crate::auth::refresh_token(refresh_token).await {
+        Ok(new_tokens) => {
+            info!("Inline token refresh successful");
+            crate::app::cache::save_tokens(&new_tokens);
+            TOKENS_REFRESHED.store(true, Ordering::Release);
+            Some(new_tokens.jwt().to_string())
+        }
+        Err(e) => {
+            warn!("Inline token refresh failed: {}", e);
+            None
+        }
+    }
+}
+
 /// HTTP client wrapper for GFN APIs
 pub struct GfnApiClient {
     client: Client,
-    access_token: Option<String>,
+    access_token: RwLock<Option<String>>,
 }
 
 impl GfnApiClient {
@@ -238,13 +303,13 @@ impl GfnApiClient {
 
         Self {
             client,
-            access_token: None,
+            access_token: RwLock::new(None),
         }
     }
 
     /// Set the access token for authenticated requests
     pub fn set_access_token(&mut self, token: String) {
-        self.access_token = Some(token);
+        *self.access_token.write() = Some(token);
     }
 
     /// Get the HTTP client
@@ -253,8 +318,31 @@ impl GfnApiClient {
     }
 
     /// Get the access token
-    pub fn token(&self) -> Option<&String> {
-        self.access_token.as_ref()
+    pub fn token(&self) -> Option<String> {
+        self.access_token.read().clone()
+    }
+
+    /// Send an authenticated request, refreshing the token and retrying once on HTTP 401.
+    /// `build` receives the current JWT and must build a fresh request on each call.
+    pub async fn send_authorized<F>(&self, build: F) -> reqwest::Result<reqwest::Response>
+    where
+        F: Fn(&str) -> reqwest::RequestBuilder,
+    {
+        let token = self.token().unwrap_or_default();
+        let response = build(&token).send().await?;
+
+        if response.status() != reqwest::StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+
+        warn!("API request returned 401, attempting inline token refresh");
+        match refresh_after_unauthorized(&token).await {
+            Some(new_token) => {
+                *self.access_token.write() = Some(new_token.clone());
+                build(&new_token).send().await
+            }
+            None => Ok(response),
+        }
     }
 }
 
@@ -341,6 +429,10 @@ struct AddonAttribute {
 /// Fetch subscription info from MES API
 pub async fn fetch_subscription(token: &str, user_id: &str) -> Result<crate::app::SubscriptionInfo, String> {
     use crate::auth;
+
+    if mock::is_mock_mode() {
+        return Ok(mock::subscription());
+    }
     
     let client = Client::builder()
         .gzip(true)
@@ -383,19 +475,19 @@ pub async fn fetch_subscription(token: &str, user_id: &str) -> Result<crate::app
 
     info!("Fetching subscription from: {}", url);
 
-    let response = client
-        .get(&url)
-        .header("Authorization", format!("GFNJWT {}", token))
-        .header("Accept", "application/json")
-        .header("nv-client-id", LCARS_CLIENT_ID)
-        .header("nv-client-type", "NATIVE")
-        .header("nv-client-version", GFN_CLIENT_VERSION)
-        .header("nv-client-streamer", "NVIDIA-CLASSIC")
-        .header("nv-device-os", "WINDOWS")
-        .header("nv-device-type", "DESKTOP")
-        .send()
-        .await
-        .map_err(|e| format!("Failed to fetch subscription: {}", e))?;
+    let response = send_with_token(Some(token), || {
+        client
+            .get(&url)
+            .header("Accept", "application/json")
+            .header("nv-client-id", LCARS_CLIENT_ID)
+            .header("nv-client-type", "NATIVE")
+            .header("nv-client-version", GFN_CLIENT_VERSION)
+            .header("nv-client-streamer", "NVIDIA-CLASSIC")
+            .header("nv-device-os", "WINDOWS")
+            .header("nv-device-type", "DESKTOP")
+    })
+    .await
+    .map_err(|e| format!("Failed to fetch subscription: {}", e))?;
 
     if !response.status().is_success() {
         let status = response.status();
@@ -422,8 +514,17 @@ pub async fn fetch_subscription(token: &str, user_id: &str) -> Result<crate::app
     // Check for persistent storage addon
     let mut has_persistent_storage = false;
     let mut storage_size_gb: Option<u32> = None;
+    let mut addons = Vec::new();
 
     for addon in &sub.addons {
+        if let Some(addon_type) = addon.addon_type.as_ref() {
+            addons.push(crate::app::types::SubscriptionAddon {
+                addon_type: addon_type.clone(),
+                sub_type: addon.sub_type.clone(),
+                status: addon.status.clone(),
+            });
+        }
+
         // Check for storage addon - API returns type="STORAGE", subType="PERMANENT_STORAGE", status="OK"
         if addon.addon_type.as_deref() == Some("STORAGE")
             && addon.sub_type.as_deref() == Some("PERMANENT_STORAGE")
@@ -475,5 +576,7 @@ pub async fn fetch_subscription(token: &str, user_id: &str) -> Result<crate::app
         storage_size_gb,
         is_unlimited,
         entitled_resolutions,
+        addons,
+        fetched_at: chrono::Utc::now().timestamp(),
     })
 }
@@ -3,6 +3,7 @@
 //! Fetches queue time information from PrintedWaste API for GeForce NOW servers.
 
 use log::{info, warn, debug};
+use parking_lot::RwLock;
 use reqwest::Client;
 use serde::Deserialize;
 use std::collections::HashMap;
@@ -82,6 +83,8 @@ pub struct QueueServerInfo {
     pub server_id: String,
     pub display_name: String,
     pub region: String,
+    /// Detailed region from the mapping (e.g., "US Central"), matches serverInfo zone names
+    pub zone: String,
     pub ping_ms: Option<u32>,
     pub queue_position: i32,
     pub eta_seconds: Option<i64>,
@@ -90,21 +93,44 @@ pub struct QueueServerInfo {
     pub last_updated: i64,
 }
 
+#[cfg(test)]
+impl QueueServerInfo {
+    /// Queue entry for a location, for tests
+    pub(crate) fn for_test(region: &str, location: &str, eta_seconds: Option<i64>) -> Self {
+        Self {
+            server_id: "NP-TEST-01".to_string(),
+            display_name: location.to_string(),
+            region: region.to_string(),
+            zone: String::new(),
+            ping_ms: None,
+            queue_position: 0,
+            eta_seconds,
+            is_4080_server: false,
+            is_5080_server: false,
+            last_updated: 0,
+        }
+    }
+}
+
 /// App version for User-Agent header
 const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
 
-/// PrintedWaste API endpoints
-const QUEUE_API_URL: &str = "https://api.printedwaste.com/gfn/queue/";
-const MAPPING_API_URL: &str = "https://remote.printedwaste.com/config/GFN_SERVERID_TO_REGION_MAPPING";
+/// PrintedWaste API endpoints (both can be replaced by mirrors in settings)
+pub const DEFAULT_QUEUE_DATA_URL: &str = "https://api.printedwaste.com/gfn/queue/";
+pub const DEFAULT_QUEUE_MAPPING_URL: &str = "https://remote.printedwaste.com/config/GFN_SERVERID_TO_REGION_MAPPING";
+
+/// Last server mapping fetched, used when the mapping endpoint is unreachable
+/// (rigs rarely change, so queue data from a mirror stays usable; also saved to disk)
+static LAST_MAPPING: RwLock<Option<HashMap<String, ServerMapping>>> = RwLock::new(None);
 
-/// Fetch server mapping from PrintedWaste
-pub async fn fetch_server_mapping(client: &Client) -> Result<HashMap<String, ServerMapping>, String> {
+/// Fetch server mapping from PrintedWaste (or a mirror serving the same format)
+pub async fn fetch_server_mapping(client: &Client, mapping_url: &str) -> Result<HashMap<String, ServerMapping>, String> {
     let user_agent = format!("OpenNOW/{}", APP_VERSION);
 
-    debug!("Fetching server mapping from PrintedWaste...");
+    debug!("Fetching server mapping from {}...", mapping_url);
 
     let response = client
-        .get(MAPPING_API_URL)
+        .get(mapping_url)
         .header("User-Agent", &user_agent)
         .send()
         .await
@@ -117,7 +143,15 @@ pub async fn fetch_server_mapping(client: &Client) -> Result<HashMap<String, Ser
     let body = response.text().await
         .map_err(|e| format!("Failed to read server mapping response: {}", e))?;
 
-    let raw: RawMappingResponse = serde_json::from_str(&body)
+    let mappings = parse_server_mapping(&body)?;
+    crate::app::cache::save_queue_mapping(&body);
+
+    info!("Fetched {} server mappings from {}", mappings.len(), mapping_url);
+    Ok(mappings)
+}
+
+fn parse_server_mapping(body: &str) -> Result<HashMap<String, ServerMapping>, String> {
+    let raw: RawMappingResponse = serde_json::from_str(body)
         .map_err(|e| format!("Failed to parse server mapping: {}", e))?;
 
     if !raw.errors.is_empty() {
@@ -125,23 +159,30 @@ pub async fn fetch_server_mapping(client: &Client) -> Result<HashMap<String, Ser
     }
 
     // Convert raw mappings to our struct
-    let mappings: HashMap<String, ServerMapping> = raw.data
+    Ok(raw.data
         .into_iter()
         .map(|(k, v)| (k, ServerMapping::from_raw(v)))
-        .collect();
+        .collect())
+}
 
-    info!("Fetched {} server mappings from PrintedWaste", mappings.len());
-    Ok(mappings)
+/// Last good server mapping: this run's, else the one saved by a previous run
+fn last_mapping() -> Option<HashMap<String, ServerMapping>> {
+    if let Some(mapping) = LAST_MAPPING.read().clone() {
+        return Some(mapping);
+    }
+    let mapping = parse_server_mapping(&crate::app::cache::load_queue_mapping()?).ok()?;
+    *LAST_MAPPING.write() = Some(mapping.clone());
+    Some(mapping)
 }
 
-/// Fetch queue data from PrintedWaste
-pub async fn fetch_queue_data(client: &Client) -> Result<QueueResponse, String> {
+/// Fetch queue data from PrintedWaste (or a mirror serving the same format)
+pub async fn fetch_queue_data(client: &Client, queue_url: &str) -> Result<QueueResponse, String> {
     let user_agent = format!("OpenNOW/{}", APP_VERSION);
 
-    debug!("Fetching queue data from PrintedWaste...");
+    debug!("Fetching queue data from {}...", queue_url);
 
     let response = client
-        .get(QUEUE_API_URL)
+        .get(queue_url)
         .header("User-Agent", &user_agent)
         .send()
         .await
@@ -166,14 +207,30 @@ pub async fn fetch_queue_data(client: &Client) -> Result<QueueResponse, String>
 }
 
 /// Fetch combined queue server info
-pub async fn fetch_queue_servers(client: &Client) -> Result<Vec<QueueServerInfo>, String> {
+pub async fn fetch_queue_servers(
+    client: &Client,
+    queue_url: &str,
+    mapping_url: &str,
+) -> Result<Vec<QueueServerInfo>, String> {
     // Fetch both mapping and queue data
     let (mapping_result, queue_result) = tokio::join!(
-        fetch_server_mapping(client),
-        fetch_queue_data(client)
+        fetch_server_mapping(client, mapping_url),
+        fetch_queue_data(client, queue_url)
     );
 
-    let mapping = mapping_result?;
+    let mapping = match mapping_result {
+        Ok(mapping) => {
+            *LAST_MAPPING.write() = Some(mapping.clone());
+            mapping
+        }
+        Err(e) => match last_mapping() {
+            Some(mapping) => {
+                warn!("{}; using the last server mapping", e);
+                mapping
+            }
+            None => return Err(e),
+        },
+    };
     let queue = queue_result?;
 
     let mut servers: Vec<QueueServerInfo> = Vec::new();
@@ -198,6 +255,7 @@ pub async fn fetch_queue_servers(client: &Client) -> Result<Vec<QueueServerInfo>
                 // Use the simple region from queue API (e.g., "US", "EU")
                 // NOT the detailed region from mapping (e.g., "US Central")
                 region: queue_data.region.clone(),
+                zone: server_mapping.region.clone(),
                 ping_ms: None, // Will be filled in by caller if needed
                 queue_position: queue_data.queue_position,
                 eta_seconds: queue_data.eta.map(|ms| ms / 1000), // Convert ms to seconds
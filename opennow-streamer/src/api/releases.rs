@@ -0,0 +1,236 @@
+//! GitHub Releases API
+//!
+//! Checks the OpenNOW GitHub repository for newer releases and downloads
+//! the build for the current platform (never replaces the running binary).
+
+use log::{debug, info, warn};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// App version for User-Agent header and version comparison
+pub const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Latest release endpoint (GitHub requires a User-Agent header)
+const LATEST_RELEASE_URL: &str = "https://api.github.com/repos/zortos293/OpenNOW/releases/latest";
+
+/// Release page shown when the API doesn't return a URL
+pub const RELEASES_PAGE_URL: &str = "https://github.com/zortos293/OpenNOW/releases";
+
+/// Latest published release
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseInfo {
+    /// Version without the leading "v" (e.g. "0.2.1")
+    pub version: String,
+    /// Release page URL
+    pub url: String,
+    /// Download for this platform/arch (None if the release has no matching build)
+    #[serde(default)]
+    pub asset: Option<ReleaseAsset>,
+}
+
+/// Downloadable release file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseAsset {
+    pub name: String,
+    pub download_url: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    #[serde(default)]
+    html_url: Option<String>,
+    #[serde(default)]
+    assets: Vec<GithubAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubAsset {
+    name: String,
+    browser_download_url: String,
+    #[serde(default)]
+    size: u64,
+}
+
+/// Fetch the latest release from GitHub
+pub async fn fetch_latest_release(client: &Client) -> Result<ReleaseInfo, String> {
+    debug!("Checking GitHub for the latest release...");
+
+    let response = client
+        .get(LATEST_RELEASE_URL)
+        .header("User-Agent", format!("OpenNOW/{}", APP_VERSION))
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach GitHub: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("GitHub API returned status: {}", response.status()));
+    }
+
+    let release: GithubRelease = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse release info: {}", e))?;
+
+    let version = release.tag_name.trim_start_matches(['v', 'V']).to_string();
+    info!(
+        "Latest OpenNOW release: {} (running {})",
+        version, APP_VERSION
+    );
+
+    let asset = platform_asset_name().and_then(|wanted| {
+        release
+            .assets
+            .into_iter()
+            .find(|a| a.name.eq_ignore_ascii_case(&wanted))
+            .map(|a| ReleaseAsset {
+                name: a.name,
+                download_url: a.browser_download_url,
+                size: a.size,
+            })
+    });
+    if asset.is_none() {
+        warn!(
+            "Release {} has no build for {}/{}",
+            version,
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        );
+    }
+
+    Ok(ReleaseInfo {
+        version,
+        url: release
+            .html_url
+            .unwrap_or_else(|| RELEASES_PAGE_URL.to_string()),
+        asset,
+    })
+}
+
+/// Release asset name for this build's platform, matching the CI artifact names
+/// (e.g. "OpenNOW-windows-x64.zip", "OpenNOW-linux-arm64.zip", "OpenNOW-macos-arm64.zip")
+pub fn platform_asset_name() -> Option<String> {
+    asset_name_for(std::env::consts::OS, std::env::consts::ARCH)
+}
+
+fn asset_name_for(os: &str, arch: &str) -> Option<String> {
+    let arch = match (os, arch) {
+        ("macos", "x86_64") => "x86_64", // Legacy Intel build keeps the Rust arch name
+        (_, "x86_64") => "x64",
+        (_, "aarch64") => "arm64",
+        _ => return None,
+    };
+    match os {
+        "windows" | "macos" | "linux" => Some(format!("OpenNOW-{}-{}.zip", os, arch)),
+        _ => None,
+    }
+}
+
+/// Download a release asset to the user's Downloads folder (or the cache directory
+/// if there is none or it isn't writable). Returns the saved file path.
+pub async fn download_asset(client: &Client, asset: &ReleaseAsset) -> Result<PathBuf, String> {
+    info!(
+        "Downloading {} ({:.1} MB)...",
+        asset.name,
+        asset.size as f64 / (1024.0 * 1024.0)
+    );
+
+    let response = client
+        .get(&asset.download_url)
+        .header("User-Agent", format!("OpenNOW/{}", APP_VERSION))
+        .send()
+        .await
+        .map_err(|e| format!("Download failed: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Download returned status: {}", response.status()));
+    }
+
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|e| format!("Download interrupted: {}", e))?;
+
+    // Never next to the binary: that may be a system or app-bundle directory
+    let update_dir = crate::utils::get_cache_dir().join("updates");
+    let mut last_error = String::from("No download location available");
+    for dir in [dirs::download_dir(), Some(update_dir)]
+        .into_iter()
+        .flatten()
+    {
+        let path = dir.join(&asset.name);
+        match std::fs::create_dir_all(&dir).and_then(|()| std::fs::write(&path, &bytes)) {
+            Ok(()) => {
+                info!("Saved update to {}", path.display());
+                return Ok(path);
+            }
+            Err(e) => {
+                warn!("Could not save update to {}: {}", dir.display(), e);
+                last_error = format!("Could not save update: {}", e);
+            }
+        }
+    }
+    Err(last_error)
+}
+
+/// Check if `latest` is a newer version than `current`
+/// Compares dot-separated numeric components; pre-release suffixes are ignored
+pub fn is_newer_version(latest: &str, current: &str) -> bool {
+    fn parse(version: &str) -> Vec<u64> {
+        version
+            .trim_start_matches(['v', 'V'])
+            .split(['-', '+'])
+            .next()
+            .unwrap_or("")
+            .split('.')
+            .map(|part| part.parse().unwrap_or(0))
+            .collect()
+    }
+
+    let latest = parse(latest);
+    let current = parse(current);
+    let len = latest.len().max(current.len());
+    for i in 0..len {
+        let l = latest.get(i).copied().unwrap_or(0);
+        let c = current.get(i).copied().unwrap_or(0);
+        if l != c {
+            return l > c;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_newer_version() {
+        assert!(is_newer_version("v0.2.0", "0.1.9"));
+        assert!(is_newer_version("1.0", "0.9.9"));
+        assert!(is_newer_version("0.1.10", "0.1.9"));
+        assert!(!is_newer_version("0.1.9", "0.1.9"));
+        assert!(!is_newer_version("0.1.9-beta", "0.1.9"));
+        assert!(!is_newer_version("0.1.0", "0.2.0"));
+    }
+
+    #[test]
+    fn test_asset_name_for_platform() {
+        assert_eq!(
+            asset_name_for("linux", "aarch64").as_deref(),
+            Some("OpenNOW-linux-arm64.zip")
+        );
+        assert_eq!(
+            asset_name_for("windows", "x86_64").as_deref(),
+            Some("OpenNOW-windows-x64.zip")
+        );
+        assert_eq!(
+            asset_name_for("macos", "aarch64").as_deref(),
+            Some("OpenNOW-macos-arm64.zip")
+        );
+        assert_eq!(asset_name_for("freebsd", "x86_64"), None);
+    }
+}
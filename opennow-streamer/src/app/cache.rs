@@ -76,10 +76,16 @@ pub fn tokens_path() -> Option<PathBuf> {
     get_app_data_dir().map(|p| p.join("auth.json"))
 }
 
-pub fn load_tokens() -> Option<AuthTokens> {
+/// Load tokens from disk without attempting a refresh (safe to call from async tasks)
+pub fn load_stored_tokens() -> Option<AuthTokens> {
     let path = tokens_path()?;
     let content = std::fs::read_to_string(&path).ok()?;
-    let tokens: AuthTokens = serde_json::from_str(&content).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+pub fn load_tokens() -> Option<AuthTokens> {
+    let path = tokens_path()?;
+    let tokens = load_stored_tokens()?;
 
     // If token is expired, try to refresh it
     if tokens.is_expired() {
@@ -330,6 +336,8 @@ pub fn save_subscription_cache(sub: &SubscriptionInfo) {
             "storage_size_gb": sub.storage_size_gb,
             "is_unlimited": sub.is_unlimited,
             "entitled_resolutions": sub.entitled_resolutions,
+            "addons": sub.addons,
+            "fetched_at": sub.fetched_at,
         });
         if let Ok(json) = serde_json::to_string(&cache) {
             let _ = std::fs::write(path, json);
@@ -359,6 +367,14 @@ pub fn load_subscription_cache() -> Option<SubscriptionInfo> {
             .get("entitled_resolutions")
             .and_then(|v| serde_json::from_value(v.clone()).ok())
             .unwrap_or_default(),
+        addons: cache
+            .get("addons")
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+            .unwrap_or_default(),
+        fetched_at: cache
+            .get("fetched_at")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(0),
     })
 }
 
@@ -574,9 +590,12 @@ pub fn check_launch_proceed_flag() -> bool {
 
 use super::types::ServerStatus;
 
+/// Saved ping results younger than this are shown instead of re-testing
+pub const PING_RESULTS_TTL_SECS: i64 = 10 * 60;
+
 pub fn save_ping_results(results: &[(String, Option<u32>, ServerStatus)]) {
     if let Some(path) = get_app_data_dir().map(|p| p.join("ping_results.json")) {
-        let cache: Vec<serde_json::Value> = results
+        let results: Vec<serde_json::Value> = results
             .iter()
             .map(|(id, ping, status)| {
                 serde_json::json!({
@@ -586,6 +605,10 @@ pub fn save_ping_results(results: &[(String, Option<u32>, ServerStatus)]) {
                 })
             })
             .collect();
+        let cache = serde_json::json!({
+            "measured_at": chrono::Utc::now().timestamp(),
+            "results": results,
+        });
 
         if let Ok(json) = serde_json::to_string(&cache) {
             let _ = std::fs::write(path, json);
@@ -593,13 +616,21 @@ pub fn save_ping_results(results: &[(String, Option<u32>, ServerStatus)]) {
     }
 }
 
-pub fn load_ping_results() -> Option<Vec<serde_json::Value>> {
+/// Last saved ping results and when they were measured (unix seconds)
+pub fn load_ping_results() -> Option<(i64, Vec<serde_json::Value>)> {
     let path = get_app_data_dir()?.join("ping_results.json");
     let content = std::fs::read_to_string(&path).ok()?;
-    let results: Vec<serde_json::Value> = serde_json::from_str(&content).ok()?;
-    // Clear the ping file after loading
-    let _ = std::fs::remove_file(&path);
-    Some(results)
+    let cache: serde_json::Value = serde_json::from_str(&content).ok()?;
+    let measured_at = cache.get("measured_at")?.as_i64()?;
+    let results = cache.get("results")?.as_array()?.clone();
+    Some((measured_at, results))
+}
+
+/// Remove saved ping results (a new test is about to replace them)
+pub fn clear_ping_results() {
+    if let Some(path) = get_app_data_dir().map(|p| p.join("ping_results.json")) {
+        let _ = std::fs::remove_file(path);
+    }
 }
 
 // ============================================================
@@ -681,6 +712,9 @@ pub fn clear_popup_game_details() {
 
 use crate::api::QueueServerInfo;
 
+/// Queue data older than this is flagged as out of date in the UI
+pub const QUEUE_DATA_TTL_SECS: i64 = 5 * 60;
+
 fn queue_cache_path() -> Option<PathBuf> {
     get_app_data_dir().map(|p| p.join("queue_cache.json"))
 }
@@ -690,13 +724,14 @@ pub fn save_queue_cache(servers: &[QueueServerInfo]) {
         if let Some(parent) = path.parent() {
             let _ = std::fs::create_dir_all(parent);
         }
-        let cache: Vec<serde_json::Value> = servers
+        let servers: Vec<serde_json::Value> = servers
             .iter()
             .map(|s| {
                 serde_json::json!({
                     "server_id": s.server_id,
                     "display_name": s.display_name,
                     "region": s.region,
+                    "zone": s.zone,
                     "ping_ms": s.ping_ms,
                     "queue_position": s.queue_position,
                     "eta_seconds": s.eta_seconds,
@@ -706,6 +741,10 @@ pub fn save_queue_cache(servers: &[QueueServerInfo]) {
                 })
             })
             .collect();
+        let cache = serde_json::json!({
+            "fetched_at": chrono::Utc::now().timestamp(),
+            "servers": servers,
+        });
 
         if let Ok(json) = serde_json::to_string(&cache) {
             let _ = std::fs::write(path, json);
@@ -713,19 +752,28 @@ pub fn save_queue_cache(servers: &[QueueServerInfo]) {
     }
 }
 
-pub fn load_queue_cache() -> Option<Vec<QueueServerInfo>> {
+/// Saved queue servers with the time they were fetched (unix time)
+pub fn load_queue_cache() -> Option<(i64, Vec<QueueServerInfo>)> {
     let path = queue_cache_path()?;
     let content = std::fs::read_to_string(&path).ok()?;
-    let cache: Vec<serde_json::Value> = serde_json::from_str(&content).ok()?;
+    let cache: serde_json::Value = serde_json::from_str(&content).ok()?;
+    let fetched_at = cache.get("fetched_at")?.as_i64()?;
+    let servers = cache.get("servers")?.as_array()?;
 
-    Some(
-        cache
-            .into_iter()
+    Some((
+        fetched_at,
+        servers
+            .iter()
             .filter_map(|v| {
                 Some(QueueServerInfo {
                     server_id: v.get("server_id")?.as_str()?.to_string(),
                     display_name: v.get("display_name")?.as_str()?.to_string(),
                     region: v.get("region")?.as_str()?.to_string(),
+                    zone: v
+                        .get("zone")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or_default()
+                        .to_string(),
                     ping_ms: v.get("ping_ms").and_then(|v| v.as_u64()).map(|v| v as u32),
                     queue_position: v.get("queue_position")?.as_i64()? as i32,
                     eta_seconds: v.get("eta_seconds").and_then(|v| v.as_i64()),
@@ -741,7 +789,7 @@ pub fn load_queue_cache() -> Option<Vec<QueueServerInfo>> {
                 })
             })
             .collect(),
-    )
+    ))
 }
 
 pub fn clear_queue_cache() {
@@ -751,24 +799,204 @@ pub fn clear_queue_cache() {
 }
 
 // ============================================================
-// Welcome Shown Flag (first-time user experience)
+// Queue Server Mapping (fallback when the mapping source is down)
 // ============================================================
 
-fn welcome_shown_path() -> Option<PathBuf> {
-    get_app_data_dir().map(|p| p.join("welcome_shown"))
+fn queue_mapping_path() -> Option<PathBuf> {
+    get_app_data_dir().map(|p| p.join("queue_mapping.json"))
 }
 
-/// Check if the welcome popup has been shown before
-pub fn has_shown_welcome() -> bool {
-    welcome_shown_path().map(|p| p.exists()).unwrap_or(false)
+/// Save the raw response of the last successful server mapping fetch
+pub fn save_queue_mapping(body: &str) {
+    if let Some(path) = queue_mapping_path() {
+        let _ = std::fs::write(path, body);
+    }
+}
+
+pub fn load_queue_mapping() -> Option<String> {
+    std::fs::read_to_string(queue_mapping_path()?).ok()
+}
+
+// ============================================================
+// Queue History (best time to play)
+// ============================================================
+
+use super::queue_history::QueueHistory;
+
+fn queue_history_path() -> Option<PathBuf> {
+    get_app_data_dir().map(|p| p.join("queue_history.json"))
+}
+
+pub fn save_queue_history(history: &QueueHistory) {
+    if let Some(path) = queue_history_path() {
+        if let Ok(json) = serde_json::to_string(history) {
+            let _ = std::fs::write(path, json);
+        }
+    }
+}
+
+pub fn load_queue_history() -> QueueHistory {
+    queue_history_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+// ============================================================
+// Wake Session Check (sleep/resume recovery)
+// ============================================================
+
+/// Result of checking the streaming session after the system woke from sleep
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum WakeCheckResult {
+    /// Session is still active server-side
+    Alive(ActiveSessionInfo),
+    /// Session no longer exists (ended or timed out while asleep)
+    Ended,
+    /// Could not reach the API (network not back yet)
+    Unknown,
+}
+
+fn wake_check_path() -> Option<PathBuf> {
+    get_app_data_dir().map(|p| p.join("wake_check.json"))
 }
 
-/// Mark the welcome popup as shown
-pub fn mark_welcome_shown() {
-    if let Some(path) = welcome_shown_path() {
-        // Just create an empty file as a marker
-        if let Err(e) = std::fs::write(&path, "1") {
-            warn!("Failed to save welcome shown flag: {}", e);
+pub fn save_wake_check(result: &WakeCheckResult) {
+    if let Some(path) = wake_check_path() {
+        if let Ok(json) = serde_json::to_string(result) {
+            let _ = std::fs::write(path, json);
         }
     }
 }
+
+/// Load and remove the wake check result (if the check has completed)
+pub fn take_wake_check() -> Option<WakeCheckResult> {
+    let path = wake_check_path()?;
+    let content = std::fs::read_to_string(&path).ok()?;
+    let _ = std::fs::remove_file(&path);
+    serde_json::from_str(&content).ok()
+}
+
+// ============================================================
+// Update Check (About section)
+// ============================================================
+
+use crate::api::releases::ReleaseInfo;
+
+/// Result of checking GitHub for a newer release
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum UpdateCheckResult {
+    /// Running the latest release
+    UpToDate,
+    /// A newer release is available
+    Available(ReleaseInfo),
+    /// Check failed (offline, rate limited, etc.)
+    Failed(String),
+}
+
+fn update_check_path() -> Option<PathBuf> {
+    get_app_data_dir().map(|p| p.join("update_check.json"))
+}
+
+pub fn save_update_check(result: &UpdateCheckResult) {
+    if let Some(path) = update_check_path() {
+        if let Ok(json) = serde_json::to_string(result) {
+            let _ = std::fs::write(path, json);
+        }
+    }
+}
+
+/// Load and remove the update check result (if the check has completed)
+pub fn take_update_check() -> Option<UpdateCheckResult> {
+    let path = update_check_path()?;
+    let content = std::fs::read_to_string(&path).ok()?;
+    let _ = std::fs::remove_file(&path);
+    serde_json::from_str(&content).ok()
+}
+
+/// Result of downloading the latest release for this platform
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum UpdateDownloadResult {
+    /// Saved to this path
+    Saved(String),
+    /// Download or save failed
+    Failed(String),
+}
+
+fn update_download_path() -> Option<PathBuf> {
+    get_app_data_dir().map(|p| p.join("update_download.json"))
+}
+
+pub fn save_update_download(result: &UpdateDownloadResult) {
+    if let Some(path) = update_download_path() {
+        if let Ok(json) = serde_json::to_string(result) {
+            let _ = std::fs::write(path, json);
+        }
+    }
+}
+
+/// Load and remove the update download result (if the download has finished)
+pub fn take_update_download() -> Option<UpdateDownloadResult> {
+    let path = update_download_path()?;
+    let content = std::fs::read_to_string(&path).ok()?;
+    let _ = std::fs::remove_file(&path);
+    serde_json::from_str(&content).ok()
+}
+
+// ============================================================
+// Decoder Check (Settings > Video)
+// ============================================================
+
+/// Result of decoding the built-in test frame with the selected backend
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum DecoderCheckResult {
+    /// Frame decoded in this many milliseconds
+    Passed(u64),
+    /// Decoder failed to start or produced no frame
+    Failed(String),
+    /// Backend can't be tested with the H.264 sample
+    Skipped(String),
+}
+
+fn decoder_check_path() -> Option<PathBuf> {
+    get_app_data_dir().map(|p| p.join("decoder_check.json"))
+}
+
+pub fn save_decoder_check(result: &DecoderCheckResult) {
+    if let Some(path) = decoder_check_path() {
+        if let Ok(json) = serde_json::to_string(result) {
+            let _ = std::fs::write(path, json);
+        }
+    }
+}
+
+/// Load and remove the decoder check result (if the check has completed)
+pub fn take_decoder_check() -> Option<DecoderCheckResult> {
+    let path = decoder_check_path()?;
+    let content = std::fs::read_to_string(&path).ok()?;
+    let _ = std::fs::remove_file(&path);
+    serde_json::from_str(&content).ok()
+}
+
+// ============================================================
+// Legacy Welcome Shown Flag (replaced by Settings::show_tips_on_startup)
+// ============================================================
+
+fn welcome_shown_path() -> Option<PathBuf> {
+    get_app_data_dir().map(|p| p.join("welcome_shown"))
+}
+
+/// Check for (and remove) the old one-shot welcome marker file
+/// Returns true if the user had already dismissed the welcome popup
+pub fn take_legacy_welcome_shown() -> bool {
+    let Some(path) = welcome_shown_path() else {
+        return false;
+    };
+    if !path.exists() {
+        return false;
+    }
+    if let Err(e) = std::fs::remove_file(&path) {
+        warn!("Failed to remove legacy welcome shown flag: {}", e);
+    }
+    true
+}
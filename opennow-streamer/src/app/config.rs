@@ -2,9 +2,19 @@
 //!
 //! Persistent settings for the OpenNow Streamer.
 
+use super::session::IceServerConfig;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Safe mode forced from the command line (--safe-mode), independent of saved settings
+static SAFE_MODE_OVERRIDE: AtomicBool = AtomicBool::new(false);
+
+/// Force safe mode for this run (not persisted)
+pub fn set_safe_mode_override(enabled: bool) {
+    SAFE_MODE_OVERRIDE.store(enabled, Ordering::Relaxed);
+}
 
 /// Application settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,12 +33,34 @@ pub struct Settings {
     /// Preferred video codec
     pub codec: VideoCodec,
 
+    /// Codecs to fall back to, in order, when the preferred codec can't be decoded here
+    /// or the server doesn't offer it (empty = preferred codec only)
+    pub codec_fallback: Vec<VideoCodec>,
+
     /// Maximum bitrate in Mbps (200 = unlimited)
     pub max_bitrate_mbps: u32,
 
+    /// Highest resolution this device decodes smoothly (e.g. "1280x720"), None = no limit
+    /// Sessions never request more than this, whatever the subscription allows
+    pub max_decode_resolution: Option<String>,
+
+    /// Request a lower bitrate from the server during sustained packet loss
+    pub adaptive_bitrate: bool,
+
+    /// Limit on everything the stream receives in Mbps, retransmits and audio
+    /// included (0 = off), so a shared connection isn't saturated
+    pub bandwidth_cap_mbps: u32,
+
     /// Preferred video decoder backend
     pub decoder_backend: VideoDecoderBackend,
 
+    /// Custom GStreamer decode pipeline (advanced, e.g. `nvv4l2decoder` on Jetson)
+    /// Must contain `appsrc name=src` and `appsink name=sink`; None = built-in pipeline
+    pub gstreamer_pipeline_override: Option<String>,
+
+    /// Raspberry Pi decode path (V4L2 stateful vs. other GStreamer decoders)
+    pub pi_decoder: PiDecoder,
+
     /// Color quality setting (combines bit depth and chroma format)
     pub color_quality: ColorQuality,
 
@@ -42,6 +74,15 @@ pub struct Settings {
     /// Enable surround sound
     pub surround: bool,
 
+    /// Interpolation used when the audio device runs at a different sample rate than the stream
+    pub audio_resampler_quality: ResamplerQuality,
+
+    /// Play a chime when the stream connects and when it ends
+    pub connection_sounds: bool,
+
+    /// Show data used and stream quality when a stream ends
+    pub show_session_summary: bool,
+
     // === Performance ===
     /// Enable VSync
     pub vsync: bool,
@@ -52,6 +93,20 @@ pub struct Settings {
     /// NVIDIA Reflex (auto-enabled for 120+ FPS)
     pub nvidia_reflex: bool,
 
+    /// Safe mode: software decoding and basic rendering (no zero-copy/external textures)
+    /// Also enabled for a single run with --safe-mode
+    pub safe_mode: bool,
+
+    /// Present mode used while streaming (menus always use Fifo)
+    pub present_mode: PresentModePref,
+
+    /// Present frames a steady delay after they arrive instead of as soon as decoded
+    /// (smooths decoder jitter at the cost of a few ms latency)
+    pub frame_pacing: bool,
+
+    /// What to do with decoded frames when the renderer falls behind
+    pub under_load_policy: UnderLoadPolicy,
+
     // === Input ===
     /// Mouse sensitivity multiplier
     pub mouse_sensitivity: f32,
@@ -63,12 +118,42 @@ pub struct Settings {
     /// Max 65536 bytes (64KB) per paste
     pub clipboard_paste_enabled: bool,
 
+    /// End the session after this many minutes without input (0 = never)
+    pub idle_timeout_minutes: u32,
+
+    /// How often coalesced mouse motion is sent to the server (Hz)
+    pub mouse_send_rate_hz: u32,
+
+    /// Key that toggles mouse capture while streaming
+    pub mouse_capture_key: MouseCaptureKey,
+
+    /// Leave the session running when the window is closed (to resume it later)
+    pub keep_session_on_quit: bool,
+
     // === Display ===
     /// Start in fullscreen
     pub fullscreen: bool,
 
-    /// Borderless fullscreen
-    pub borderless: bool,
+    /// Exclusive or borderless fullscreen (F11 and "Start in fullscreen")
+    pub fullscreen_mode: FullscreenMode,
+
+    /// Start with the window maximized (ignored when starting fullscreen)
+    pub start_maximized: bool,
+
+    /// Keep rendering the stream while the window is unfocused (input is still paused)
+    pub keep_streaming_unfocused: bool,
+
+    /// Mute stream audio while the window is unfocused
+    pub mute_on_focus_loss: bool,
+
+    /// Keep redrawing menus at a low rate instead of only on input (snappier, more CPU)
+    pub responsive_menus: bool,
+
+    /// Frame rate limit for input-driven menu redraws, e.g. while scrolling (0 = uncapped)
+    pub menu_fps_cap: u32,
+
+    /// Blank the menus after this many minutes without input (0 = never), for OLED screens
+    pub menu_screensaver_minutes: u32,
 
     /// Window width (0 = use default)
     pub window_width: u32,
@@ -82,10 +167,48 @@ pub struct Settings {
     /// Stats panel position
     pub stats_position: StatsPosition,
 
+    /// Stats overlay refresh interval in milliseconds
+    pub stats_refresh_ms: u32,
+
+    /// Show the stats in a separate always-on-top window while streaming
+    /// (for a second monitor or OBS capture) instead of over the game
+    pub stats_window: bool,
+
+    /// Show a one-line fps and ping counter while streaming, without the full stats
+    pub minimal_hud: bool,
+
+    /// Record per-second stats to a CSV file in every session (for benchmarking)
+    pub record_stats_csv: bool,
+
+    /// Video brightness offset applied after decoding (0.0 = unchanged)
+    pub brightness: f32,
+
+    /// Video gamma applied after decoding (1.0 = unchanged, higher lifts shadows)
+    pub gamma: f32,
+
+    /// Sharpening strength for upscaled streams (0.0 = off, 1.0 = strongest)
+    pub sharpening: f32,
+
+    /// How the video fits the window when aspect ratios differ
+    pub scaling_mode: ScalingMode,
+
+    /// Shrink the video by this percentage on every edge, for TVs that crop
+    /// the picture (overscan); 0 = off
+    pub overscan_percent: f32,
+
+    /// Show the welcome/tips popup on startup
+    pub show_tips_on_startup: bool,
+
+    /// Check GitHub for a newer release on startup
+    pub check_updates: bool,
+
     // === Network ===
-    /// Preferred server region
+    /// Only consider servers in this region (e.g. "Europe"); None = any region
     pub preferred_region: Option<String>,
 
+    /// Server IDs never picked by auto-select or shown in the server list
+    pub blocked_servers: Vec<String>,
+
     /// Selected server ID (zone ID)
     pub selected_server: Option<String>,
 
@@ -97,6 +220,22 @@ pub struct Settings {
 
     /// Disable telemetry
     pub disable_telemetry: bool,
+
+    /// How long to wait for ICE candidate gathering before answering (milliseconds)
+    pub ice_gather_timeout_ms: u32,
+
+    /// Only use relayed (TURN) candidates, for networks that block direct UDP
+    pub force_relay: bool,
+
+    /// Additional TURN servers (e.g. "turn:relay.example.com:443?transport=tcp")
+    /// Used alongside the servers provided by the session
+    pub turn_servers: Vec<IceServerConfig>,
+
+    /// Queue times endpoint (PrintedWaste by default; community mirrors use the same format)
+    pub queue_data_url: String,
+
+    /// Server ID to location mapping endpoint (PrintedWaste by default, same format for mirrors)
+    pub queue_mapping_url: String,
 }
 
 impl Default for Settings {
@@ -107,39 +246,79 @@ impl Default for Settings {
             resolution: "1920x1080".to_string(),
             fps: 60,
             codec: VideoCodec::H264,
+            codec_fallback: Vec::new(),
             max_bitrate_mbps: 150,
+            max_decode_resolution: None,
+            adaptive_bitrate: false,
+            bandwidth_cap_mbps: 0,
             decoder_backend: VideoDecoderBackend::Auto, // Auto-select best decoder
+            gstreamer_pipeline_override: None,
+            pi_decoder: PiDecoder::Auto,
             color_quality: ColorQuality::Bit10Yuv420,
             hdr_enabled: false,
 
             // Audio
             audio_codec: AudioCodec::Opus,
             surround: false,
+            audio_resampler_quality: ResamplerQuality::Balanced,
+            connection_sounds: false,
+            show_session_summary: true,
 
             // Performance
             vsync: false,
             low_latency_mode: true,
             nvidia_reflex: true,
+            safe_mode: false,
+            present_mode: PresentModePref::Auto,
+            frame_pacing: false,
+            under_load_policy: UnderLoadPolicy::DropToLatest,
 
             // Input
             mouse_sensitivity: 1.0,
             raw_input: true,
             clipboard_paste_enabled: true, // Enable by default like official client
+            idle_timeout_minutes: 0,
+            mouse_send_rate_hz: DEFAULT_MOUSE_SEND_RATE_HZ,
+            mouse_capture_key: MouseCaptureKey::default(),
+            keep_session_on_quit: false,
 
             // Display
             fullscreen: false,
-            borderless: true,
+            fullscreen_mode: FullscreenMode::Exclusive,
+            start_maximized: false,
+            keep_streaming_unfocused: true,
+            mute_on_focus_loss: false,
+            responsive_menus: false,
+            menu_fps_cap: 60,
+            menu_screensaver_minutes: 0,
             window_width: 0,  // 0 = use default
             window_height: 0, // 0 = use default
             show_stats: true,
             stats_position: StatsPosition::BottomLeft,
+            stats_refresh_ms: 200,
+            stats_window: false,
+            minimal_hud: false,
+            record_stats_csv: false,
+            brightness: 0.0,
+            gamma: 1.0,
+            sharpening: 0.0,
+            scaling_mode: ScalingMode::Fit,
+            overscan_percent: 0.0,
+            show_tips_on_startup: true,
+            check_updates: true,
 
             // Network
             preferred_region: None,
+            blocked_servers: Vec::new(),
             selected_server: None,
             auto_server_selection: true, // Default to auto
             proxy: None,
             disable_telemetry: true,
+            ice_gather_timeout_ms: 5000,
+            force_relay: false,
+            turn_servers: Vec::new(),
+            queue_data_url: crate::api::queue::DEFAULT_QUEUE_DATA_URL.to_string(),
+            queue_mapping_url: crate::api::queue::DEFAULT_QUEUE_MAPPING_URL.to_string(),
         }
     }
 }
@@ -150,6 +329,11 @@ impl Settings {
         dirs::config_dir().map(|p| p.join("opennow-streamer").join("settings.json"))
     }
 
+    /// Whether no settings file has been saved yet (first launch)
+    pub fn is_first_run() -> bool {
+        Self::file_path().is_some_and(|path| !path.exists())
+    }
+
     /// Load settings from disk
     pub fn load() -> Result<Self> {
         let path = Self::file_path().ok_or_else(|| anyhow::anyhow!("No config directory"))?;
@@ -178,16 +362,64 @@ impl Settings {
         Ok(())
     }
 
-    /// Get resolution as (width, height)
+    /// Get resolution as (width, height), limited to the max decode resolution
     pub fn resolution_tuple(&self) -> (u32, u32) {
         let parts: Vec<&str> = self.resolution.split('x').collect();
-        if parts.len() == 2 {
+        let (width, height) = if parts.len() == 2 {
             let width = parts[0].parse().unwrap_or(1920);
             let height = parts[1].parse().unwrap_or(1080);
             (width, height)
         } else {
             (1920, 1080)
+        };
+        match self.decode_cap() {
+            Some(cap) if width > cap.0 || height > cap.1 => cap,
+            _ => (width, height),
+        }
+    }
+
+    /// Codecs to try in order: the preferred codec, then the fallback chain
+    pub fn codec_preference(&self) -> Vec<VideoCodec> {
+        let mut codecs = vec![self.codec];
+        for codec in &self.codec_fallback {
+            if !codecs.contains(codec) {
+                codecs.push(*codec);
+            }
         }
+        codecs
+    }
+
+    /// Whether `server` may be picked: not blocked and in the preferred region (if set)
+    pub fn server_allowed(&self, server: &super::types::ServerInfo) -> bool {
+        !self.blocked_servers.contains(&server.id)
+            && self
+                .preferred_region
+                .as_ref()
+                .is_none_or(|region| *region == server.region)
+    }
+
+    /// Max decode resolution as (width, height), if one is set
+    pub fn decode_cap(&self) -> Option<(u32, u32)> {
+        self.max_decode_resolution
+            .as_deref()
+            .map(super::types::parse_resolution)
+    }
+
+    /// Whether `resolution` ("WIDTHxHEIGHT") fits within the max decode resolution
+    pub fn within_decode_cap(&self, resolution: &str) -> bool {
+        let (width, height) = super::types::parse_resolution(resolution);
+        self.decode_cap()
+            .is_none_or(|(max_width, max_height)| width <= max_width && height <= max_height)
+    }
+
+    /// Video quad scale factor that leaves the overscan inset on every edge
+    pub fn overscan_scale(&self) -> f32 {
+        1.0 - 2.0 * self.overscan_percent.clamp(0.0, MAX_OVERSCAN_PERCENT) / 100.0
+    }
+
+    /// Whether safe mode is active (setting or --safe-mode flag)
+    pub fn safe_mode_active(&self) -> bool {
+        self.safe_mode || SAFE_MODE_OVERRIDE.load(Ordering::Relaxed)
     }
 
     /// Get max bitrate in kbps
@@ -284,9 +516,202 @@ pub const RESOLUTIONS: &[(&str, &str)] = &[
     ("5120x1440", "Super Ultrawide"),
 ];
 
+/// Choices for the max decode resolution setting (None = no limit)
+pub const DECODE_CAP_OPTIONS: &[&str] = &["1280x720", "1920x1080", "2560x1440", "3840x2160"];
+
+/// Largest entry in `RESOLUTIONS` whose width and height fit within a GPU's
+/// maximum texture dimension (e.g. 1080p on Raspberry Pi V3D, capped at 2048)
+pub fn max_resolution_for_texture_limit(max_dimension: u32) -> Option<&'static str> {
+    RESOLUTIONS
+        .iter()
+        .filter_map(|(res, _)| {
+            let (width, height) = res.split_once('x')?;
+            let (width, height): (u32, u32) = (width.parse().ok()?, height.parse().ok()?);
+            (width <= max_dimension && height <= max_dimension)
+                .then_some((*res, width as u64 * height as u64))
+        })
+        .max_by_key(|(_, pixels)| *pixels)
+        .map(|(res, _)| res)
+}
+
+/// Closest stream mode to a display: the largest mode that fits on screen (same aspect
+/// ratio preferred) at the highest frame rate not above the refresh rate.
+/// Picks from `entitled` when the subscription reported modes, otherwise from the presets.
+pub fn match_display_mode(
+    display_width: u32,
+    display_height: u32,
+    refresh_hz: u32,
+    entitled: &[super::types::EntitledResolution],
+) -> (String, u32) {
+    let modes: Vec<(u32, u32, u32)> = if entitled.is_empty() {
+        RESOLUTIONS
+            .iter()
+            .filter_map(|(res, _)| {
+                let (width, height) = res.split_once('x')?;
+                Some((width.parse().ok()?, height.parse().ok()?))
+            })
+            .flat_map(|(width, height)| FPS_OPTIONS.iter().map(move |&fps| (width, height, fps)))
+            .collect()
+    } else {
+        entitled
+            .iter()
+            .map(|r| (r.width, r.height, r.fps))
+            .collect()
+    };
+
+    let display_aspect = display_width as f32 / display_height.max(1) as f32;
+    let (width, height) = modes
+        .iter()
+        .filter(|&&(width, height, _)| width <= display_width && height <= display_height)
+        .max_by_key(|&&(width, height, _)| {
+            let same_aspect = (width as f32 / height as f32 - display_aspect).abs() < 0.05;
+            (same_aspect, width as u64 * height as u64)
+        })
+        // Display smaller than every mode: take the smallest
+        .or_else(|| modes.iter().min_by_key(|&&(w, h, _)| w as u64 * h as u64))
+        .map(|&(width, height, _)| (width, height))
+        .unwrap_or((1920, 1080));
+
+    let mut rates: Vec<u32> = modes
+        .iter()
+        .filter(|&&(w, h, _)| (w, h) == (width, height))
+        .map(|&(_, _, fps)| fps)
+        .collect();
+    rates.sort_unstable();
+    let fps = rates
+        .iter()
+        .rev()
+        .find(|&&fps| fps <= refresh_hz)
+        .or(rates.first())
+        .copied()
+        .unwrap_or(60);
+
+    (format!("{}x{}", width, height), fps)
+}
+
+/// Closest mode the membership allows at or below a requested one: the most pixels,
+/// then the highest frame rate. Falls back to the smallest entitled mode when
+/// every one is above the request. Without entitlements, steps down to 60 FPS,
+/// then to 1080p. None when there's nothing lower to offer.
+pub fn nearest_entitled_mode(
+    width: u32,
+    height: u32,
+    fps: u32,
+    entitled: &[super::types::EntitledResolution],
+) -> Option<(String, u32)> {
+    let pixels = width as u64 * height as u64;
+    let (width, height, fps) = if entitled.is_empty() {
+        if fps > 60 {
+            (width, height, 60)
+        } else if pixels > 1920 * 1080 {
+            (1920, 1080, fps)
+        } else {
+            return None;
+        }
+    } else {
+        let area = |r: &&super::types::EntitledResolution| r.width as u64 * r.height as u64;
+        let mode = entitled
+            .iter()
+            .filter(|r| area(r) <= pixels && r.fps <= fps)
+            .max_by_key(|r| (area(r), r.fps))
+            .or_else(|| entitled.iter().min_by_key(|r| (area(r), r.fps)))?;
+        (mode.width, mode.height, mode.fps)
+    };
+    Some((format!("{}x{}", width, height), fps))
+}
+
+/// Smallest custom stream resolution accepted in Settings
+pub const MIN_CUSTOM_WIDTH: u32 = 640;
+pub const MIN_CUSTOM_HEIGHT: u32 = 360;
+
+/// Check a custom "WIDTHxHEIGHT @ FPS" against the subscription and the display.
+/// `entitled` is empty when the subscription didn't report resolutions;
+/// `monitor` is the display size in physical pixels, if known.
+pub fn validate_custom_resolution(
+    width: u32,
+    height: u32,
+    fps: u32,
+    entitled: &[super::types::EntitledResolution],
+    monitor: Option<(u32, u32)>,
+) -> std::result::Result<(), String> {
+    if width < MIN_CUSTOM_WIDTH || height < MIN_CUSTOM_HEIGHT {
+        return Err(format!(
+            "Minimum is {}x{}",
+            MIN_CUSTOM_WIDTH, MIN_CUSTOM_HEIGHT
+        ));
+    }
+    // 4:2:0 video needs even dimensions
+    if width % 2 != 0 || height % 2 != 0 {
+        return Err("Width and height must be even".to_string());
+    }
+    let max_fps = FPS_OPTIONS.iter().copied().max().unwrap_or(60);
+    if fps == 0 || fps > max_fps {
+        return Err(format!("Frame rate must be 1-{} FPS", max_fps));
+    }
+
+    if let Some((monitor_width, monitor_height)) = monitor {
+        if width > monitor_width || height > monitor_height {
+            return Err(format!(
+                "Larger than your display ({}x{})",
+                monitor_width, monitor_height
+            ));
+        }
+    }
+
+    if !entitled.is_empty() {
+        // Allowed if some entitled mode has at least as many pixels at at least this frame rate
+        let pixels = width as u64 * height as u64;
+        let covered = entitled
+            .iter()
+            .any(|r| r.width as u64 * r.height as u64 >= pixels && r.fps >= fps);
+        if !covered {
+            let best = entitled
+                .iter()
+                .max_by_key(|r| (r.width as u64 * r.height as u64, r.fps))
+                .map(|r| format!("{}x{} @ {} FPS", r.width, r.height, r.fps))
+                .unwrap_or_default();
+            return Err(format!("Not included in your membership (up to {})", best));
+        }
+    }
+
+    Ok(())
+}
+
 /// Available FPS options
 pub const FPS_OPTIONS: &[u32] = &[30, 60, 90, 120, 144, 165, 240, 360];
 
+/// Idle auto-terminate options in minutes (0 = disabled)
+pub const IDLE_TIMEOUT_OPTIONS: &[u32] = &[0, 10, 15, 30, 60];
+
+/// Menu frame rate cap options (0 = uncapped, limited only by vsync)
+pub const MENU_FPS_CAP_OPTIONS: &[u32] = &[60, 120, 144, 0];
+
+/// Menu screensaver options in minutes (0 = disabled)
+pub const MENU_SCREENSAVER_OPTIONS: &[u32] = &[0, 2, 5, 10, 30];
+
+/// Mouse motion send rate options in Hz
+pub const MOUSE_SEND_RATE_OPTIONS: &[u32] = &[125, 250, 500, 1000];
+pub const DEFAULT_MOUSE_SEND_RATE_HZ: u32 = 500;
+
+/// Allowed range for the stats overlay refresh interval (milliseconds)
+pub const MIN_STATS_REFRESH_MS: u32 = 50;
+pub const MAX_STATS_REFRESH_MS: u32 = 2000;
+
+/// Allowed range for the video brightness offset
+pub const MIN_BRIGHTNESS: f32 = -0.25;
+pub const MAX_BRIGHTNESS: f32 = 0.25;
+
+/// Allowed range for the video gamma adjustment
+pub const MIN_GAMMA: f32 = 0.5;
+pub const MAX_GAMMA: f32 = 2.0;
+
+/// Largest overscan inset per edge (percent)
+pub const MAX_OVERSCAN_PERCENT: f32 = 10.0;
+
+/// Allowed range for the ICE gathering timeout (milliseconds)
+pub const MIN_ICE_GATHER_TIMEOUT_MS: u32 = 1000;
+pub const MAX_ICE_GATHER_TIMEOUT_MS: u32 = 30000;
+
 /// Video codec options
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
@@ -323,6 +748,8 @@ pub enum VideoDecoderBackend {
     /// Vulkan Video (Linux) - cross-GPU hardware decode via Vulkan extensions
     /// Based on GeForce NOW's VkVideoDecoder implementation
     VulkanVideo,
+    /// V4L2 stateful decoder via GStreamer (Linux - Raspberry Pi / embedded)
+    V4l2,
     /// Software decoding (CPU)
     Software,
 }
@@ -339,6 +766,7 @@ impl VideoDecoderBackend {
             VideoDecoderBackend::NativeDxva => "Native D3D11 (HEVC only)",
             VideoDecoderBackend::VideoToolbox => "VideoToolbox",
             VideoDecoderBackend::VulkanVideo => "GStreamer HW",
+            VideoDecoderBackend::V4l2 => "V4L2",
             VideoDecoderBackend::Software => "Software",
         }
     }
@@ -355,7 +783,7 @@ impl VideoDecoderBackend {
             }
             VideoDecoderBackend::Cuvid => {
                 "NVIDIA hardware decoding using NVDEC.\n\n\
-                 Backend: GStreamer + nvd3d11h264dec/nvd3d11h265dec\n\
+                 Backend: GStreamer + nvh264dec/nvh265dec (or nvd3d11h264dec)\n\
                  Performance: Excellent on NVIDIA GPUs\n\
                  Compatibility: NVIDIA GPUs only (GTX 600+)"
             }
@@ -370,7 +798,7 @@ impl VideoDecoderBackend {
                  Backend: GStreamer + vah264dec/vah265dec (or legacy vaapih264dec)\n\
                  Performance: Good on AMD/Intel GPUs (Linux)\n\
                  Compatibility: AMD, Intel GPUs on Linux\n\
-                 Note: Use this if GStreamer HW doesn't work"
+                 Note: Use this if GStreamer HW doesn't work - never tries V4L2"
             }
             VideoDecoderBackend::Dxva => {
                 "Windows DirectX Video Acceleration via GStreamer.\n\n\
@@ -402,6 +830,13 @@ impl VideoDecoderBackend {
                  Performance: Hardware accelerated\n\
                  Compatibility: Linux with GStreamer installed"
             }
+            VideoDecoderBackend::V4l2 => {
+                "Linux hardware decoding via Video4Linux2 (stateful codec devices).\n\n\
+                 Backend: GStreamer + v4l2h264dec/v4l2h265dec\n\
+                 Performance: Hardware accelerated, low CPU usage\n\
+                 Compatibility: Raspberry Pi and other ARM boards with /dev/video decoders\n\
+                 Note: Never tries VA-API - use this if GStreamer HW picks the wrong decoder"
+            }
             VideoDecoderBackend::Software => {
                 "CPU-based software decoding.\n\n\
                  Backend: GStreamer + avdec_h264/avdec_h265\n\
@@ -423,6 +858,7 @@ impl VideoDecoderBackend {
             VideoDecoderBackend::NativeDxva => "Native D3D11",
             VideoDecoderBackend::VideoToolbox => "FFmpeg VT",
             VideoDecoderBackend::VulkanVideo => "GStreamer HW",
+            VideoDecoderBackend::V4l2 => "GStreamer V4L2",
             VideoDecoderBackend::Software => "GStreamer CPU",
         }
     }
@@ -437,11 +873,55 @@ impl VideoDecoderBackend {
             VideoDecoderBackend::NativeDxva,
             VideoDecoderBackend::VideoToolbox,
             VideoDecoderBackend::VulkanVideo,
+            VideoDecoderBackend::V4l2,
             VideoDecoderBackend::Software,
         ]
     }
 }
 
+/// Decode path on Raspberry Pi
+/// Which one runs smoother depends on the firmware and kernel version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PiDecoder {
+    /// V4L2 stateful decoder when present, otherwise GStreamer's other decoders
+    #[default]
+    Auto,
+    /// V4L2 stateful decoder only (v4l2h264dec / v4l2h265dec, bcm2835-codec)
+    V4l2,
+    /// Skip the stateful decoder: V4L2 stateless (v4l2slh265dec, rpivid) or software
+    Gstreamer,
+}
+
+impl PiDecoder {
+    /// Get display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PiDecoder::Auto => "Auto",
+            PiDecoder::V4l2 => "V4L2 stateful",
+            PiDecoder::Gstreamer => "GStreamer (stateless / software)",
+        }
+    }
+
+    /// Get description for UI
+    pub fn description(&self) -> &'static str {
+        match self {
+            PiDecoder::Auto => "Use the V4L2 stateful decoder when available",
+            PiDecoder::V4l2 => {
+                "Always use v4l2h264dec / v4l2h265dec - try this if the other path stutters"
+            }
+            PiDecoder::Gstreamer => {
+                "Use GStreamer's V4L2 stateless decoders (Pi 5 HEVC) or software decoding"
+            }
+        }
+    }
+
+    /// Get all available options
+    pub fn all() -> &'static [PiDecoder] {
+        &[PiDecoder::Auto, PiDecoder::V4l2, PiDecoder::Gstreamer]
+    }
+}
+
 impl VideoCodec {
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -565,3 +1045,332 @@ pub enum StatsPosition {
     BottomLeft,
     BottomRight,
 }
+
+/// Present mode preference while streaming
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PresentModePref {
+    /// Lowest latency mode the surface supports (Immediate, then Mailbox, then Fifo)
+    #[default]
+    Auto,
+    /// VSync - no tearing, highest latency
+    Fifo,
+    /// Low latency without tearing (drops stale frames)
+    Mailbox,
+    /// Lowest latency, may tear
+    Immediate,
+}
+
+impl PresentModePref {
+    /// Get display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PresentModePref::Auto => "Auto",
+            PresentModePref::Fifo => "Fifo (VSync)",
+            PresentModePref::Mailbox => "Mailbox",
+            PresentModePref::Immediate => "Immediate",
+        }
+    }
+
+    /// Get description for UI
+    pub fn description(&self) -> &'static str {
+        match self {
+            PresentModePref::Auto => "Immediate if supported, otherwise Mailbox, otherwise Fifo",
+            PresentModePref::Fifo => "No tearing, adds up to a frame of latency",
+            PresentModePref::Mailbox => "No tearing, low latency - smoother on some GPUs",
+            PresentModePref::Immediate => "Lowest latency, may tear",
+        }
+    }
+
+    /// Get all available options
+    pub fn all() -> &'static [PresentModePref] {
+        &[
+            PresentModePref::Auto,
+            PresentModePref::Fifo,
+            PresentModePref::Mailbox,
+            PresentModePref::Immediate,
+        ]
+    }
+}
+
+/// Audio sample rate conversion quality (stream rate to device rate)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ResamplerQuality {
+    /// Linear interpolation - least CPU, can sound dull or add faint hiss
+    Fast,
+    /// Catmull-Rom spline - clean for most content
+    #[default]
+    Balanced,
+    /// 16-tap windowed sinc - no aliasing, for users who still hear artifacts
+    High,
+}
+
+impl ResamplerQuality {
+    /// Get display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ResamplerQuality::Fast => "Fast",
+            ResamplerQuality::Balanced => "Balanced",
+            ResamplerQuality::High => "High",
+        }
+    }
+
+    /// Get description for UI
+    pub fn description(&self) -> &'static str {
+        match self {
+            ResamplerQuality::Fast => {
+                "Linear interpolation - lowest CPU (Raspberry Pi, old laptops)"
+            }
+            ResamplerQuality::Balanced => "Spline interpolation - clean for most content",
+            ResamplerQuality::High => "Windowed sinc filter - cleanest treble, a little more CPU",
+        }
+    }
+
+    /// Get all available options
+    pub fn all() -> &'static [ResamplerQuality] {
+        &[
+            ResamplerQuality::Fast,
+            ResamplerQuality::Balanced,
+            ResamplerQuality::High,
+        ]
+    }
+}
+
+/// Frame handling when decoded frames arrive faster than they're presented
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum UnderLoadPolicy {
+    /// Show the newest frame and drop any it replaced - always current, may look choppy
+    #[default]
+    DropToLatest,
+    /// Queue a few frames and show every one - smooth, but falls behind while backed up
+    BufferSmooth,
+}
+
+impl UnderLoadPolicy {
+    /// Get display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            UnderLoadPolicy::DropToLatest => "Drop to latest",
+            UnderLoadPolicy::BufferSmooth => "Buffer (smooth)",
+        }
+    }
+
+    /// Get description for UI
+    pub fn description(&self) -> &'static str {
+        match self {
+            UnderLoadPolicy::DropToLatest => {
+                "Lowest latency - skips frames the display couldn't keep up with"
+            }
+            UnderLoadPolicy::BufferSmooth => {
+                "Shows every frame - up to a few frames of extra latency when backed up"
+            }
+        }
+    }
+
+    /// Get all available options
+    pub fn all() -> &'static [UnderLoadPolicy] {
+        &[UnderLoadPolicy::DropToLatest, UnderLoadPolicy::BufferSmooth]
+    }
+}
+
+/// Fullscreen flavor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum FullscreenMode {
+    /// Takes over the display at its highest refresh rate, bypassing the compositor
+    #[default]
+    Exclusive,
+    /// A screen-sized window - instant Alt+Tab, but composited by the OS
+    Borderless,
+}
+
+impl FullscreenMode {
+    /// Get display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            FullscreenMode::Exclusive => "Exclusive",
+            FullscreenMode::Borderless => "Borderless",
+        }
+    }
+
+    /// Get description for UI
+    pub fn description(&self) -> &'static str {
+        match self {
+            FullscreenMode::Exclusive => {
+                "Lowest latency, picks the highest refresh rate - slower to Alt+Tab"
+            }
+            FullscreenMode::Borderless => {
+                "Instant Alt+Tab and overlays, may add a frame of compositor latency"
+            }
+        }
+    }
+
+    /// Get all available options
+    pub fn all() -> &'static [FullscreenMode] {
+        &[FullscreenMode::Exclusive, FullscreenMode::Borderless]
+    }
+}
+
+/// How the video is fit into the window when aspect ratios differ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ScalingMode {
+    /// Whole picture visible, black bars on the sides or top/bottom
+    #[default]
+    Fit,
+    /// Fill the window, cropping the edges of the picture
+    Fill,
+    /// Fill the window, distorting the picture
+    Stretch,
+    /// Largest whole-number multiple that fits, nearest-neighbor sampling (retro games)
+    IntegerNearest,
+}
+
+impl ScalingMode {
+    /// Get display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ScalingMode::Fit => "Fit (letterbox)",
+            ScalingMode::Fill => "Fill (crop)",
+            ScalingMode::Stretch => "Stretch",
+            ScalingMode::IntegerNearest => "Integer (pixel-perfect)",
+        }
+    }
+
+    /// Get description for UI
+    pub fn description(&self) -> &'static str {
+        match self {
+            ScalingMode::Fit => {
+                "Show the whole picture with black bars where the aspect ratio differs"
+            }
+            ScalingMode::Fill => "No black bars - crops the edges of the picture",
+            ScalingMode::Stretch => "No black bars - stretches the picture to the window",
+            ScalingMode::IntegerNearest => {
+                "Whole-number scaling with crisp pixels - for retro and emulated games"
+            }
+        }
+    }
+
+    /// Get all available options
+    pub fn all() -> &'static [ScalingMode] {
+        &[
+            ScalingMode::Fit,
+            ScalingMode::Fill,
+            ScalingMode::Stretch,
+            ScalingMode::IntegerNearest,
+        ]
+    }
+
+    /// Whether the video should be sampled nearest-neighbor instead of filtered
+    pub fn nearest_sampling(&self) -> bool {
+        *self == ScalingMode::IntegerNearest
+    }
+
+    /// Video quad scale (x, y) and clip-space offset (x, y) for the given sizes,
+    /// inside the `overscan` fraction of the window (1.0 = whole window)
+    pub fn quad_transform(
+        &self,
+        video: (u32, u32),
+        surface: (u32, u32),
+        overscan: f32,
+    ) -> [f32; 4] {
+        if *self == ScalingMode::IntegerNearest && video.0 > 0 && video.1 > 0 {
+            let usable = |size: u32| (size as f32 * overscan) as u32;
+            let factor = (usable(surface.0) / video.0).min(usable(surface.1) / video.1);
+            // Video larger than the window can't be scaled by a whole number - fit it instead
+            if factor > 0 {
+                // Same factor on both axes, and the quad starts on a whole pixel so every
+                // source pixel covers exactly factor x factor screen pixels
+                let axis = |video: u32, surface: u32| {
+                    let size = video * factor;
+                    let start = (surface - size) / 2;
+                    let centered = (surface - size) as f32 / 2.0;
+                    let scale = size as f32 / surface as f32;
+                    (scale, (start as f32 - centered) * 2.0 / surface as f32)
+                };
+                let (scale_x, offset_x) = axis(video.0, surface.0);
+                let (scale_y, offset_y) = axis(video.1, surface.1);
+                // Clip-space y points up, pixel rows go down
+                return [scale_x, scale_y, offset_x, -offset_y];
+            }
+        }
+        let mode = if *self == ScalingMode::IntegerNearest {
+            ScalingMode::Fit
+        } else {
+            *self
+        };
+        let (scale_x, scale_y) = mode.quad_scale(video, surface);
+        [scale_x * overscan, scale_y * overscan, 0.0, 0.0]
+    }
+
+    /// Video quad scale (x, y) relative to the full window (integer scaling is
+    /// handled by `quad_transform`)
+    fn quad_scale(&self, video: (u32, u32), surface: (u32, u32)) -> (f32, f32) {
+        if *self == ScalingMode::Stretch
+            || video.0 == 0
+            || video.1 == 0
+            || surface.0 == 0
+            || surface.1 == 0
+        {
+            return (1.0, 1.0);
+        }
+        let video_aspect = video.0 as f32 / video.1 as f32;
+        let surface_aspect = surface.0 as f32 / surface.1 as f32;
+        let ratio = video_aspect / surface_aspect;
+        // Fit shrinks the overflowing axis, Fill grows the short one
+        match (self, ratio > 1.0) {
+            (ScalingMode::Fit, true) => (1.0, 1.0 / ratio),
+            (ScalingMode::Fit, false) => (ratio, 1.0),
+            (_, true) => (ratio, 1.0),
+            (_, false) => (1.0, 1.0 / ratio),
+        }
+    }
+}
+
+/// Key binding that toggles mouse capture while streaming
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MouseCaptureKey {
+    #[default]
+    F8,
+    ScrollLock,
+    Pause,
+    /// Double-tap Right Ctrl (still sent to the game, for laptops without easy F-keys)
+    DoubleTapRightCtrl,
+    /// Double-tap Right Alt / Option
+    DoubleTapRightAlt,
+}
+
+impl MouseCaptureKey {
+    /// Get display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            MouseCaptureKey::F8 => "F8",
+            MouseCaptureKey::ScrollLock => "Scroll Lock",
+            MouseCaptureKey::Pause => "Pause",
+            MouseCaptureKey::DoubleTapRightCtrl => "Double-tap Right Ctrl",
+            MouseCaptureKey::DoubleTapRightAlt => "Double-tap Right Alt",
+        }
+    }
+
+    /// Get all available options
+    pub fn all() -> &'static [MouseCaptureKey] {
+        &[
+            MouseCaptureKey::F8,
+            MouseCaptureKey::ScrollLock,
+            MouseCaptureKey::Pause,
+            MouseCaptureKey::DoubleTapRightCtrl,
+            MouseCaptureKey::DoubleTapRightAlt,
+        ]
+    }
+
+    /// Whether the key has to be pressed twice in quick succession
+    pub fn is_double_tap(&self) -> bool {
+        matches!(
+            self,
+            MouseCaptureKey::DoubleTapRightCtrl | MouseCaptureKey::DoubleTapRightAlt
+        )
+    }
+}
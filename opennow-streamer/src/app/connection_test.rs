@@ -0,0 +1,301 @@
+//! Connection Test
+//!
+//! "Test my connection" (Settings > Server & Network) starts a real session, samples
+//! `StreamStats` for `TEST_DURATION` once video is flowing, then ends the session and
+//! grades latency, jitter and loss. Unlike the TCP ping this covers the whole path:
+//! session setup, WebRTC, the video stream and the decoder.
+//!
+//! The encoder only sends what the game's content needs (a menu can sit at a few Mbps),
+//! so the measured bitrate is a lower bound on throughput, not the connection's capacity.
+//!
+//! CloudMatch has no public network-test app (`networkTestSessionId` is always sent
+//! as null), so the test streams a game from the library with the current settings.
+
+use std::time::{Duration, Instant};
+
+use log::info;
+
+use crate::media::StreamStats;
+
+/// How long stats are sampled once video arrives
+pub const TEST_DURATION: Duration = Duration::from_secs(30);
+
+/// Samples from the first seconds are skipped while the bitrate ramps up
+const WARMUP: Duration = Duration::from_secs(5);
+
+/// Time between samples
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Connection quality, worst to best
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConnectionGrade {
+    Poor,
+    Fair,
+    Good,
+    Excellent,
+}
+
+impl ConnectionGrade {
+    pub fn label(self) -> &'static str {
+        match self {
+            ConnectionGrade::Poor => "Poor",
+            ConnectionGrade::Fair => "Fair",
+            ConnectionGrade::Good => "Good",
+            ConnectionGrade::Excellent => "Excellent",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            ConnectionGrade::Poor => {
+                "Expect stutter and blurry video - try a wired connection or a closer server"
+            }
+            ConnectionGrade::Fair => "Playable at lower settings",
+            ConnectionGrade::Good => "Smooth 1080p streaming",
+            ConnectionGrade::Excellent => "Ready for high resolutions and frame rates",
+        }
+    }
+}
+
+/// Stream settings suggested by a test
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecommendedSettings {
+    pub max_bitrate_mbps: u32,
+    pub resolution: String,
+    pub fps: u32,
+}
+
+/// Averages over the test and what they mean
+#[derive(Debug, Clone)]
+pub struct ConnectionTestResult {
+    pub game_title: String,
+    pub server: String,
+    pub avg_bitrate_mbps: f32,
+    /// Bitrate the stream held 90% of the time
+    pub sustained_bitrate_mbps: f32,
+    /// The stream used (almost) all of the max bitrate setting, so the
+    /// connection may be faster than measured
+    pub bitrate_capped: bool,
+    pub avg_rtt_ms: f32,
+    pub avg_jitter_ms: f32,
+    pub packet_loss_pct: f32,
+    pub avg_fps: f32,
+    pub grade: ConnectionGrade,
+    pub recommended: RecommendedSettings,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    bitrate_mbps: f32,
+    rtt_ms: f32,
+    jitter_ms: f32,
+    packet_loss: f32,
+    fps: f32,
+}
+
+/// Test waiting for its session, or sampling the stream
+pub struct ConnectionTest {
+    pub game_title: String,
+    /// When video started arriving (None while the session is being set up)
+    started: Option<Instant>,
+    next_sample: Instant,
+    samples: Vec<Sample>,
+}
+
+impl ConnectionTest {
+    pub fn new(game_title: &str) -> Self {
+        Self {
+            game_title: game_title.to_string(),
+            started: None,
+            next_sample: Instant::now(),
+            samples: Vec::new(),
+        }
+    }
+
+    /// Record the latest stats; the clock starts with the first decoded frame
+    pub fn sample(&mut self, stats: &StreamStats) {
+        if stats.frames_decoded == 0 {
+            return;
+        }
+        let now = Instant::now();
+        let started = *self.started.get_or_insert_with(|| {
+            info!(
+                "Connection test: video arrived, sampling for {}s",
+                TEST_DURATION.as_secs()
+            );
+            now
+        });
+        if now < self.next_sample || now.duration_since(started) < WARMUP {
+            return;
+        }
+        self.next_sample = now + SAMPLE_INTERVAL;
+        self.samples.push(Sample {
+            bitrate_mbps: stats.bitrate_mbps,
+            rtt_ms: stats.rtt_ms,
+            jitter_ms: stats.jitter_ms,
+            packet_loss: stats.packet_loss,
+            fps: stats.fps,
+        });
+    }
+
+    /// Seconds sampled so far (0 until video arrives)
+    pub fn elapsed(&self) -> Duration {
+        self.started
+            .map_or(Duration::ZERO, |started| started.elapsed())
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.elapsed() >= TEST_DURATION
+    }
+
+    /// Grade the samples; None if the stream never got past the warmup
+    pub fn finish(
+        self,
+        server: &str,
+        max_bitrate_mbps: u32,
+        target_fps: u32,
+    ) -> Option<ConnectionTestResult> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        let count = self.samples.len() as f32;
+        let avg = |field: fn(&Sample) -> f32| self.samples.iter().map(field).sum::<f32>() / count;
+
+        let mut bitrates: Vec<f32> = self.samples.iter().map(|s| s.bitrate_mbps).collect();
+        bitrates.sort_by(f32::total_cmp);
+        let sustained_bitrate_mbps = bitrates[bitrates.len() / 10];
+        let avg_bitrate_mbps = avg(|s| s.bitrate_mbps);
+
+        let avg_rtt_ms = avg(|s| s.rtt_ms);
+        let avg_jitter_ms = avg(|s| s.jitter_ms);
+        let packet_loss_pct = avg(|s| s.packet_loss);
+        let grade = grade(avg_rtt_ms, avg_jitter_ms, packet_loss_pct);
+
+        let result = ConnectionTestResult {
+            game_title: self.game_title,
+            server: server.to_string(),
+            avg_bitrate_mbps,
+            sustained_bitrate_mbps,
+            bitrate_capped: avg_bitrate_mbps >= max_bitrate_mbps as f32 * 0.9,
+            avg_rtt_ms,
+            avg_jitter_ms,
+            packet_loss_pct,
+            avg_fps: avg(|s| s.fps).min(target_fps as f32),
+            grade,
+            recommended: recommend(
+                sustained_bitrate_mbps,
+                max_bitrate_mbps,
+                avg_jitter_ms,
+                packet_loss_pct,
+            ),
+        };
+        info!("Connection test result: {:?}", result);
+        Some(result)
+    }
+}
+
+/// Overall grade: the worst of the per-metric grades. The bitrate isn't graded: a
+/// low one usually means undemanding content, and a congested path shows up as loss
+/// and jitter anyway
+fn grade(rtt_ms: f32, jitter_ms: f32, packet_loss_pct: f32) -> ConnectionGrade {
+    use ConnectionGrade::*;
+
+    let by_rtt = match rtt_ms {
+        r if r <= 30.0 => Excellent,
+        r if r <= 60.0 => Good,
+        r if r <= 100.0 => Fair,
+        _ => Poor,
+    };
+    let by_jitter = match jitter_ms {
+        j if j <= 5.0 => Excellent,
+        j if j <= 10.0 => Good,
+        j if j <= 20.0 => Fair,
+        _ => Poor,
+    };
+    let by_loss = match packet_loss_pct {
+        l if l <= 0.5 => Excellent,
+        l if l <= 1.5 => Good,
+        l if l <= 5.0 => Fair,
+        _ => Poor,
+    };
+    by_rtt.min(by_jitter).min(by_loss)
+}
+
+/// Settings for the tested connection. A steady one keeps the current max bitrate;
+/// an unsteady one steps down from it, but never below 80% of what it sustained
+fn recommend(
+    sustained_mbps: f32,
+    max_bitrate_mbps: u32,
+    jitter_ms: f32,
+    packet_loss_pct: f32,
+) -> RecommendedSettings {
+    // (minimum Mbps, resolution, fps)
+    const TIERS: &[(f32, &str, u32)] = &[
+        (75.0, "3840x2160", 60),
+        (45.0, "2560x1440", 120),
+        (35.0, "1920x1080", 120),
+        (20.0, "1920x1080", 60),
+        (12.0, "1280x720", 60),
+        (0.0, "1280x720", 30),
+    ];
+    let max_bitrate_mbps = max_bitrate_mbps as f32;
+    let unsteady = jitter_ms > 10.0 || packet_loss_pct > 1.5;
+    let budget = if unsteady {
+        (max_bitrate_mbps * 0.75)
+            .max(sustained_mbps * 0.8)
+            .min(max_bitrate_mbps)
+    } else {
+        max_bitrate_mbps
+    };
+    let (_, resolution, mut fps) = TIERS
+        .iter()
+        .copied()
+        .find(|(min_mbps, _, _)| budget >= *min_mbps)
+        .unwrap_or(TIERS[TIERS.len() - 1]);
+
+    // An unsteady connection can't keep up with high frame rates
+    if unsteady {
+        fps = fps.min(60);
+    }
+
+    RecommendedSettings {
+        // Same range and step as the Max Bitrate slider
+        max_bitrate_mbps: ((budget / 5.0).floor() as u32 * 5).clamp(10, 200),
+        resolution: resolution.to_string(),
+        fps,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grade_and_recommendation() {
+        assert_eq!(grade(15.0, 2.0, 0.0), ConnectionGrade::Excellent);
+        // One bad metric decides the grade
+        assert_eq!(grade(15.0, 2.0, 8.0), ConnectionGrade::Poor);
+        assert_eq!(grade(45.0, 4.0, 0.2), ConnectionGrade::Good);
+
+        let fast = recommend(100.0, 100, 2.0, 0.0);
+        assert_eq!(fast.resolution, "3840x2160");
+        assert_eq!(fast.max_bitrate_mbps, 100);
+
+        // A quiet scene on a steady connection doesn't lower the max bitrate
+        let quiet = recommend(6.0, 50, 2.0, 0.0);
+        assert_eq!(
+            (quiet.resolution.as_str(), quiet.fps, quiet.max_bitrate_mbps),
+            ("2560x1440", 120, 50)
+        );
+
+        let unsteady = recommend(50.0, 50, 15.0, 0.0);
+        assert_eq!(unsteady.resolution, "1920x1080");
+        assert_eq!((unsteady.fps, unsteady.max_bitrate_mbps), (60, 40));
+
+        let slow = recommend(8.0, 10, 2.0, 8.0);
+        assert_eq!(
+            (slow.resolution.as_str(), slow.fps, slow.max_bitrate_mbps),
+            ("1280x720", 30, 10)
+        );
+    }
+}
@@ -0,0 +1,137 @@
+//! Decode Slowdown Monitor
+//!
+//! Learns the decoder's usual per-frame time early in the stream, then watches for it
+//! climbing well above that for a sustained stretch. On laptops and small boards this
+//! is usually thermal throttling or another program loading the CPU/GPU; the stutter
+//! looks like a network problem, so it's called out separately.
+
+use log::warn;
+
+use crate::media::StreamStats;
+
+/// Seconds skipped at the start while the decoder and bitrate settle
+const WARMUP_SECS: u32 = 10;
+
+/// Seconds sampled after the warmup to learn the usual decode time
+const BASELINE_SECS: usize = 20;
+
+/// Decode time above this multiple of the baseline counts as slow...
+const SLOW_FACTOR: f32 = 1.75;
+
+/// ...if it's also this many ms above it (ignores doubling from 1 to 2 ms)
+const MIN_SLOWDOWN_MS: f32 = 3.0;
+
+/// ...and takes up at least this fraction of the frame interval, so it can cause stutter
+const MIN_FRAME_BUDGET_FRACTION: f32 = 0.5;
+
+/// Consecutive slow seconds before warning
+const SUSTAIN_SECS: u32 = 10;
+
+/// Per-stream decode time tracker (fed once per stats update)
+pub struct DecodeMonitor {
+    seconds: u32,
+    baseline_samples: Vec<f32>,
+    baseline_ms: Option<f32>,
+    slow_secs: u32,
+    warned: bool,
+}
+
+impl DecodeMonitor {
+    pub fn new() -> Self {
+        Self {
+            seconds: 0,
+            baseline_samples: Vec::with_capacity(BASELINE_SECS),
+            baseline_ms: None,
+            slow_secs: 0,
+            warned: false,
+        }
+    }
+
+    /// Record one second of stats; returns a warning the first time decoding stays slow
+    pub fn sample(&mut self, stats: &StreamStats) -> Option<String> {
+        // No frames this second (paused or reconnecting) leaves a stale decode time
+        if self.warned || stats.fps < 1.0 || stats.frame_delivery_ms <= 0.0 {
+            return None;
+        }
+        self.seconds += 1;
+        if self.seconds <= WARMUP_SECS {
+            return None;
+        }
+        let decode_ms = stats.frame_delivery_ms;
+
+        let Some(baseline_ms) = self.baseline_ms else {
+            self.baseline_samples.push(decode_ms);
+            if self.baseline_samples.len() == BASELINE_SECS {
+                // Median, so a hiccup while learning doesn't raise the bar
+                self.baseline_samples.sort_by(f32::total_cmp);
+                self.baseline_ms = Some(self.baseline_samples[BASELINE_SECS / 2]);
+            }
+            return None;
+        };
+
+        let frame_budget_ms = if stats.target_fps > 0 {
+            1000.0 / stats.target_fps as f32
+        } else {
+            1000.0 / 60.0
+        };
+        let slow = decode_ms > baseline_ms * SLOW_FACTOR
+            && decode_ms - baseline_ms >= MIN_SLOWDOWN_MS
+            && decode_ms >= frame_budget_ms * MIN_FRAME_BUDGET_FRACTION;
+        self.slow_secs = if slow { self.slow_secs + 1 } else { 0 };
+        if self.slow_secs < SUSTAIN_SECS {
+            return None;
+        }
+
+        self.warned = true;
+        warn!(
+            "Decode time up from {:.1} ms to {:.1} ms for {}s (frame interval {:.1} ms)",
+            baseline_ms, decode_ms, SUSTAIN_SECS, frame_budget_ms
+        );
+        Some(format!(
+            "Decode slowing ({:.0} → {:.0} ms) - possible thermal throttling or CPU load",
+            baseline_ms, decode_ms
+        ))
+    }
+}
+
+impl Default for DecodeMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(decode_ms: f32) -> StreamStats {
+        StreamStats {
+            fps: 60.0,
+            target_fps: 60,
+            frame_delivery_ms: decode_ms,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_sustained_slowdown_warns_once() {
+        let mut monitor = DecodeMonitor::new();
+        for _ in 0..(WARMUP_SECS as usize + BASELINE_SECS) {
+            assert_eq!(monitor.sample(&stats(4.0)), None);
+        }
+
+        // A short spike and a small rise are both ignored
+        for _ in 0..5 {
+            assert_eq!(monitor.sample(&stats(15.0)), None);
+        }
+        for _ in 0..30 {
+            assert_eq!(monitor.sample(&stats(6.0)), None);
+        }
+
+        let warnings: Vec<String> = (0..30)
+            .filter_map(|_| monitor.sample(&stats(12.0)))
+            .collect();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("4 → 12 ms"));
+    }
+}
@@ -4,15 +4,25 @@
 
 pub mod cache;
 pub mod config;
+pub mod connection_test;
+pub mod decode_monitor;
+pub mod queue_alert;
+pub mod queue_history;
 pub mod session;
+pub mod session_summary;
+pub mod stats_log;
 pub mod types;
 
-pub use config::{AudioCodec, ColorQuality, Settings, StatsPosition, StreamQuality, VideoCodec};
-pub use session::{ActiveSessionInfo, SessionInfo, SessionState};
+pub use config::{
+    AudioCodec, ColorQuality, FullscreenMode, PresentModePref, ScalingMode, Settings,
+    StatsPosition, StreamQuality, VideoCodec,
+};
+pub use session::{ActiveSessionInfo, SessionInfo, SessionState, SessionUiUpdate};
+use types::{find_saved_server, server_region};
 pub use types::{
-    parse_resolution, AppState, GameInfo, GameSection, GameVariant, GamesTab, QueueRegionFilter,
-    QueueSortMode, ServerInfo, ServerStatus, SettingChange, SharedFrame, SubscriptionInfo,
-    UiAction,
+    parse_resolution, resolution_label, AppState, GameInfo, GameSection, GameVariant, GamesTab,
+    QueueRegionFilter, QueueSortMode, ServerInfo, ServerStatus, SettingChange, SharedFrame,
+    SubscriptionInfo, UiAction,
 };
 
 use log::{error, info, warn};
@@ -21,6 +31,7 @@ use std::sync::Arc;
 use tokio::runtime::Handle;
 use tokio::sync::mpsc;
 
+use crate::api::capabilities::{record_feature, ProviderFeature};
 use crate::api::{self, DynamicServerRegion, GfnApiClient};
 use crate::auth::{self, AuthTokens, LoginProvider, PkceChallenge, UserInfo};
 
@@ -32,6 +43,21 @@ use crate::webrtc::StreamingSession;
 /// Cache for dynamic regions fetched from serverInfo API
 static DYNAMIC_REGIONS_CACHE: RwLock<Option<Vec<DynamicServerRegion>>> = RwLock::new(None);
 
+/// Error from the last failed queue times fetch (picked up by the update loop)
+static QUEUE_FETCH_ERROR: RwLock<Option<String>> = RwLock::new(None);
+
+/// Game looked up for a deep link that wasn't in the loaded lists (picked up by the update loop)
+static DEEP_LINK_GAME: RwLock<Option<GameInfo>> = RwLock::new(None);
+
+/// Sessions ended from the session list: (session ID, error if the stop failed)
+static ENDED_SESSIONS: RwLock<Vec<(String, Option<String>)>> = RwLock::new(Vec::new());
+
+/// Latest session ID and state from create/claim/poll responses (the session cache keeps only a few states)
+static POLLED_SESSION: RwLock<Option<(String, SessionState)>> = RwLock::new(None);
+
+/// Settings to retry with after the server refused the requested stream (picked up with the session error)
+static STREAM_SETTINGS_OFFER: RwLock<Option<session::StreamSettingsOffer>> = RwLock::new(None);
+
 /// Main application structure
 pub struct App {
     /// Current application state
@@ -67,6 +93,9 @@ pub struct App {
     /// Shared frame holder for zero-latency frame delivery
     pub shared_frame: Option<Arc<SharedFrame>>,
 
+    /// Presentation scheduling from frame timestamps (Settings > Frame Pacing)
+    frame_pacer: crate::media::FramePacer,
+
     /// Stream statistics
     pub stats: StreamStats,
 
@@ -140,30 +169,67 @@ pub struct App {
     /// Whether ping test is running
     pub ping_testing: bool,
 
+    /// When the server pings shown were measured (unix seconds)
+    pub ping_measured_at: Option<i64>,
+
+    /// Dynamic region fetch failed; the built-in server list is in use
+    pub servers_offline: bool,
+
+    /// Dynamic region fetch (with retries) in flight
+    region_fetch_in_progress: bool,
+
+    /// When the last dynamic region fetch gave up (for periodic re-fetch)
+    region_fetch_failed_at: Option<std::time::Instant>,
+
     /// Whether queue server ping test is running
     pub queue_ping_testing: bool,
 
     /// Whether settings modal is visible
     pub show_settings_modal: bool,
 
+    /// Whether the plan details panel is visible
+    pub show_account_panel: bool,
+
     /// Active sessions detected
     pub active_sessions: Vec<ActiveSessionInfo>,
 
     /// Whether showing session conflict dialog
     pub show_session_conflict: bool,
 
+    /// Sessions being ended from the session conflict dialog
+    pub ending_sessions: Vec<String>,
+
+    /// Why the last session couldn't be ended
+    pub end_session_error: Option<String>,
+
     /// Whether showing AV1 unsupported warning dialog
     pub show_av1_warning: bool,
 
     /// Whether showing Alliance experimental warning dialog
     pub show_alliance_warning: bool,
 
+    /// Low GPU memory warning (Raspberry Pi V3D), shown as a dialog
+    pub gpu_memory_warning: Option<String>,
+
+    /// Highest resolution this GPU can stream at, applied to each session without
+    /// changing the saved setting
+    resolution_cap: Option<&'static str>,
+
     /// Whether showing first-time welcome popup
     pub show_welcome_popup: bool,
 
+    /// Menus are blanked by the screensaver (set by the event loop, cleared on input)
+    pub screensaver_active: bool,
+
     /// Pending game launch (waiting for session conflict resolution)
     pub pending_game_launch: Option<GameInfo>,
 
+    /// Game waiting for the user to confirm launching with little playtime left
+    pub low_hours_launch: Option<GameInfo>,
+
+    /// Link forwarded by another OpenNOW process, waiting for confirmation (app ID, name to show)
+    pub forwarded_launch: Option<(String, String)>,
+
     /// Last time we polled the session (for rate limiting)
     last_poll_time: std::time::Instant,
 
@@ -181,6 +247,12 @@ pub struct App {
     /// Last time anti-AFK sent a key press
     anti_afk_last_send: std::time::Instant,
 
+    /// Per-second stats CSV being recorded (Ctrl+Shift+F9 to toggle)
+    stats_log: Option<stats_log::StatsLog>,
+
+    /// Data used and stream quality of the running stream, reported when it ends
+    session_totals: Option<session_summary::SessionTotals>,
+
     /// Whether a token refresh is currently in progress
     token_refresh_in_progress: bool,
 
@@ -190,6 +262,21 @@ pub struct App {
     /// Whether queue data is loading
     pub queue_loading: bool,
 
+    /// Why the last queue fetch failed (queue data source unreachable)
+    pub queue_error: Option<String>,
+
+    /// When the shown queue data was fetched (unix time)
+    pub queue_fetched_at: Option<i64>,
+
+    /// When the running queue fetch started (unix time), so an older cache isn't taken as its result
+    queue_fetch_started_at: i64,
+
+    /// Average queue waits by hour of the week (best time to play)
+    pub queue_history: queue_history::QueueHistory,
+
+    /// Location being watched for a short queue (desktop notification)
+    pub queue_alert: Option<queue_alert::QueueAlert>,
+
     /// Last time queue data was fetched
     queue_last_fetch: std::time::Instant,
 
@@ -216,11 +303,118 @@ pub struct App {
 
     /// Ads total duration in seconds
     pub ads_total_secs: u32,
+
+    /// Warning shown when the server delivers a lower resolution than requested
+    pub resolution_downgrade_warning: Option<String>,
+
+    /// Warning shown when 4:4:4 was requested but the stream is chroma subsampled
+    pub chroma_downgrade_warning: Option<String>,
+
+    /// Requested resolution was lowered to fit the GPU's texture limit (shown once when streaming)
+    pub texture_limit_warning: Option<String>,
+
+    /// Decoding got much slower than earlier in the stream (shown once per session)
+    pub decode_slowdown_warning: Option<String>,
+
+    /// Watches the decode time for thermal throttling or CPU load
+    decode_monitor: decode_monitor::DecodeMonitor,
+
+    /// Largest texture the GPU can allocate (0 until the renderer reports it)
+    max_texture_dimension: u32,
+
+    /// GPU adapter and backend (reported by the renderer, used in issue reports)
+    gpu_description: String,
+
+    /// First start: the codec default waits for the renderer's GPU
+    first_run: bool,
+
+    /// Latest polled session ID and setup state
+    polled_session: Option<(String, SessionState)>,
+
+    /// How long the session has been in its current setup state
+    stuck_tracker: session::StuckTracker,
+
+    /// Session ID of a session that timed out during setup (enables Retry)
+    pub stuck_session_id: Option<String>,
+
+    /// Closest accepted settings after the server refused the requested stream
+    pub stream_settings_offer: Option<session::StreamSettingsOffer>,
+
+    /// Wall-clock and monotonic time of the previous update (for detecting system sleep)
+    last_update_wall_time: std::time::SystemTime,
+    last_update_instant: std::time::Instant,
+
+    /// Sleep duration of a wake event whose session check is still in flight
+    pending_wake_check: Option<std::time::Duration>,
+
+    /// GitHub release check in flight (Settings > About)
+    pub update_check_in_progress: bool,
+
+    /// Result of the last release check
+    pub update_check_result: Option<cache::UpdateCheckResult>,
+
+    /// Show a notice when the startup release check finds a newer version
+    show_update_notice: bool,
+
+    /// Crash report left by the previous run (shown once on startup)
+    last_crash: Option<crate::utils::CrashReport>,
+
+    /// Release download in flight
+    pub update_download_in_progress: bool,
+
+    /// Result of the last release download
+    pub update_download_result: Option<cache::UpdateDownloadResult>,
+
+    /// Decoder health check in flight (Settings > Video)
+    pub decoder_check_in_progress: bool,
+
+    /// Result of the last decoder health check
+    pub decoder_check_result: Option<cache::DecoderCheckResult>,
+
+    /// Seconds until the idle timeout ends the session (Some while the warning is shown)
+    pub idle_terminate_in_secs: Option<u64>,
+
+    /// Bitrate of the running stream in Mbps (starts at the max bitrate setting,
+    /// changed mid-session from the stream overlay)
+    pub stream_bitrate_mbps: u32,
+
+    /// "Test my connection" run waiting for its session or sampling the stream
+    pub connection_test: Option<connection_test::ConnectionTest>,
+
+    /// Outcome of the last connection test (shown as a dialog until closed)
+    pub connection_test_result: Option<Result<connection_test::ConnectionTestResult, String>>,
+
+    /// Report for the last stream (shown as a dialog until closed)
+    pub session_summary: Option<session_summary::SessionSummary>,
 }
 
 /// Poll interval for session status (2 seconds)
 const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
 
+/// Longest the app waits for the session to be stopped when the window is closed
+const QUIT_STOP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// Attempts per dynamic region fetch before falling back to the built-in list
+const REGION_FETCH_ATTEMPTS: u32 = 4;
+
+/// Delay before the first region fetch retry (doubles after each failure)
+const REGION_FETCH_RETRY_DELAY: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// How often to re-fetch regions while on the built-in list
+const REGION_REFETCH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Clock gap between updates that indicates the system was suspended
+const SUSPEND_DETECT_GAP: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Sleeps longer than this outlive WebRTC consent checks, so the stream must be re-attached
+const WAKE_RECONNECT_THRESHOLD: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// How long before an idle timeout the countdown warning is shown
+const IDLE_WARNING_DURATION: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Remaining playtime (hours) below which launching asks for confirmation on metered tiers
+const LOW_HOURS_WARNING_THRESHOLD: f32 = 0.5;
+
 // Mutex re-export for streaming session
 use parking_lot::Mutex;
 
@@ -231,11 +425,43 @@ impl App {
     /// Create new application instance
     pub fn new(runtime: Handle) -> Self {
         // Load settings
-        let settings = Settings::load().unwrap_or_default();
+        let first_run = Settings::is_first_run();
+        let mut settings = Settings::load().unwrap_or_default();
+        if first_run {
+            Self::apply_first_run_defaults(&mut settings);
+            if let Err(e) = settings.save() {
+                warn!("Failed to save first-run settings: {}", e);
+            }
+        }
+
+        // Migrate the old one-shot "welcome shown" marker into the startup tips preference
+        if cache::take_legacy_welcome_shown() {
+            settings.show_tips_on_startup = false;
+            if let Err(e) = settings.save() {
+                warn!("Failed to save migrated welcome setting: {}", e);
+            }
+        }
+
+        // A saved decoder that no longer initializes (driver/plugin removed) falls back to Auto
+        if !crate::media::get_supported_decoder_backends().contains(&settings.decoder_backend) {
+            warn!(
+                "Saved decoder {} is not available on this system, using Auto",
+                settings.decoder_backend.as_str()
+            );
+            settings.decoder_backend = config::VideoDecoderBackend::Auto;
+        }
+        crate::media::set_gstreamer_pipeline_override(settings.gstreamer_pipeline_override.clone());
+        crate::media::set_pi_decoder(settings.pi_decoder);
         let auto_server = settings.auto_server_selection; // Save before move
+        let show_stats = settings.show_stats;
+        let show_tips_on_startup = settings.show_tips_on_startup;
 
-        // Try to load saved tokens
-        let auth_tokens = cache::load_tokens();
+        // Try to load saved tokens (mock mode starts logged in with placeholder tokens)
+        let auth_tokens = if api::mock::is_mock_mode() {
+            Some(api::mock::tokens())
+        } else {
+            cache::load_tokens()
+        };
         let has_token = auth_tokens
             .as_ref()
             .map(|t| !t.is_expired())
@@ -270,7 +496,9 @@ impl App {
             rt.spawn(async move {
                 let mut api_client = GfnApiClient::new();
                 api_client.set_access_token(token);
-                match api_client.get_active_sessions().await {
+                let result = api_client.get_active_sessions().await;
+                record_feature(ProviderFeature::ActiveSessions, &result);
+                match result {
                     Ok(sessions) => {
                         info!(
                             "Checked active sessions at startup: found {}",
@@ -292,7 +520,9 @@ impl App {
             let token = auth_tokens.as_ref().unwrap().jwt().to_string();
             let user_id = auth_tokens.as_ref().unwrap().user_id().to_string();
             rt.spawn(async move {
-                match crate::api::fetch_subscription(&token, &user_id).await {
+                let result = crate::api::fetch_subscription(&token, &user_id).await;
+                record_feature(ProviderFeature::Subscription, &result);
+                match result {
                     Ok(sub) => {
                         info!("Fetched subscription startup: tier={}", sub.membership_tier);
                         cache::save_subscription_cache(&sub);
@@ -304,7 +534,7 @@ impl App {
             });
         }
 
-        Self {
+        let mut app = Self {
             state: initial_state,
             runtime,
             settings,
@@ -316,8 +546,9 @@ impl App {
             cursor_captured: false,
             current_frame: None,
             shared_frame: None,
+            frame_pacer: crate::media::FramePacer::new(),
             stats: StreamStats::default(),
-            show_stats: true,
+            show_stats,
             status_message: "Welcome to OpenNOW".to_string(),
             error_message: None,
             games: Vec::new(),
@@ -340,15 +571,27 @@ impl App {
             selected_server_index: 0,
             auto_server_selection: auto_server, // Load from settings
             ping_testing: false,
+            ping_measured_at: None,
+            servers_offline: false,
+            region_fetch_in_progress: false,
+            region_fetch_failed_at: None,
             queue_ping_testing: false,
             show_settings_modal: false,
+            show_account_panel: false,
             active_sessions: Vec::new(),
             show_session_conflict: false,
+            ending_sessions: Vec::new(),
+            end_session_error: None,
             show_av1_warning: false,
+            gpu_memory_warning: None,
+            resolution_cap: None,
             show_alliance_warning: false,
-            show_welcome_popup: !cache::has_shown_welcome(),
+            show_welcome_popup: show_tips_on_startup,
+            screensaver_active: false,
 
             pending_game_launch: None,
+            low_hours_launch: None,
+            forwarded_launch: None,
             last_poll_time: std::time::Instant::now(),
             render_frame_count: 0,
             last_render_fps_time: std::time::Instant::now(),
@@ -356,9 +599,16 @@ impl App {
             session_ready_poll_count: 0,
             anti_afk_enabled: false,
             anti_afk_last_send: std::time::Instant::now(),
+            stats_log: None,
+            session_totals: None,
             token_refresh_in_progress: false,
             queue_servers: Vec::new(),
             queue_loading: false,
+            queue_error: None,
+            queue_fetched_at: None,
+            queue_fetch_started_at: 0,
+            queue_history: queue_history::QueueHistory::default(),
+            queue_alert: None,
             queue_last_fetch: std::time::Instant::now() - std::time::Duration::from_secs(60), // Force initial fetch
             queue_sort_mode: QueueSortMode::default(),
             queue_region_filter: QueueRegionFilter::default(),
@@ -368,6 +618,94 @@ impl App {
             ads_required: false,
             ads_remaining_secs: 0,
             ads_total_secs: 0,
+            resolution_downgrade_warning: None,
+            chroma_downgrade_warning: None,
+            texture_limit_warning: None,
+            decode_slowdown_warning: None,
+            decode_monitor: decode_monitor::DecodeMonitor::new(),
+            max_texture_dimension: 0,
+            gpu_description: String::new(),
+            first_run,
+            polled_session: None,
+            stuck_tracker: session::StuckTracker::default(),
+            stuck_session_id: None,
+            stream_settings_offer: None,
+            last_update_wall_time: std::time::SystemTime::now(),
+            last_update_instant: std::time::Instant::now(),
+            pending_wake_check: None,
+            update_check_in_progress: false,
+            update_check_result: None,
+            show_update_notice: false,
+            last_crash: crate::utils::take_pending_crash_report(),
+            update_download_in_progress: false,
+            update_download_result: None,
+            decoder_check_in_progress: false,
+            decoder_check_result: None,
+            idle_terminate_in_secs: None,
+            stream_bitrate_mbps: 0,
+            connection_test: None,
+            connection_test_result: None,
+            session_summary: None,
+        };
+
+        // Nudge users on old builds towards the latest release
+        if app.settings.check_updates {
+            app.show_update_notice = true;
+            app.check_for_updates();
+        }
+
+        app
+    }
+
+    /// Pick codec/resolution defaults the local hardware can actually decode.
+    /// Only runs on first launch - saved settings are never overridden.
+    fn apply_first_run_defaults(settings: &mut Settings) {
+        let recommendation = crate::media::recommended_codec(settings.decoder_backend);
+        settings.codec = recommendation.codec;
+
+        #[cfg(target_os = "linux")]
+        if crate::media::raspberry_pi_detected() {
+            let model = crate::media::get_pi_model();
+            // V3D textures top out at 2048 and older Pis can't decode 1080p60 reliably
+            if model.is_some_and(|m| m < 4) {
+                settings.resolution = "1280x720".to_string();
+                settings.fps = 30;
+            } else {
+                settings.resolution = "1920x1080".to_string();
+                settings.fps = 60;
+            }
+            info!(
+                "First run on Raspberry Pi {}: defaulting to {:?} at {} {}fps",
+                model.map_or_else(|| "(unknown model)".to_string(), |m| m.to_string()),
+                settings.codec,
+                settings.resolution,
+                settings.fps
+            );
+            return;
+        }
+
+        // Software decode only: H.264 at 1080p is the most a CPU can keep up with
+        let backends = crate::media::get_supported_decoder_backends();
+        let software_only = backends.iter().all(|b| {
+            matches!(
+                b,
+                config::VideoDecoderBackend::Auto | config::VideoDecoderBackend::Software
+            )
+        });
+        if software_only {
+            let (width, height) = settings.resolution_tuple();
+            if width * height > 1920 * 1080 {
+                settings.resolution = "1920x1080".to_string();
+            }
+            info!(
+                "First run without hardware decoding: defaulting to H.264 at {}",
+                settings.resolution
+            );
+        } else {
+            info!(
+                "First run: defaulting to {:?} ({})",
+                settings.codec, recommendation.reason
+            );
         }
     }
 
@@ -382,6 +720,34 @@ impl App {
         }
     }
 
+    /// Start or stop recording stats to CSV; returns a message for the user
+    pub fn toggle_stats_log(&mut self) -> String {
+        match self.stats_log.take() {
+            Some(log) => {
+                info!("Stats recording stopped: {}", log.path().display());
+                format!("Stats saved to {}", log.path().display())
+            }
+            None => {
+                self.start_stats_log();
+                match self.stats_log {
+                    Some(ref log) => format!("Recording stats to {}", log.path().display()),
+                    None => "Couldn't start stats recording (see log)".to_string(),
+                }
+            }
+        }
+    }
+
+    fn start_stats_log(&mut self) {
+        let label = self
+            .selected_game
+            .as_ref()
+            .map_or("session", |game| game.title.as_str());
+        match stats_log::StatsLog::start(label) {
+            Ok(log) => self.stats_log = Some(log),
+            Err(e) => warn!("Failed to start stats recording: {}", e),
+        }
+    }
+
     /// Send anti-AFK key press (F13) if enabled and interval elapsed
     pub fn update_anti_afk(&mut self) {
         if !self.anti_afk_enabled || self.state != AppState::Streaming {
@@ -445,17 +811,80 @@ impl App {
             }
             UiAction::UpdateSetting(change) => {
                 match change {
-                    SettingChange::Resolution(res) => self.settings.resolution = res,
+                    SettingChange::Resolution(res) => {
+                        if self.settings.within_decode_cap(&res) {
+                            self.settings.resolution = res;
+                        } else {
+                            warn!(
+                                "Ignoring resolution {} above the max decode resolution",
+                                res
+                            );
+                        }
+                    }
                     SettingChange::Fps(fps) => self.settings.fps = fps,
                     SettingChange::Codec(codec) => {
                         self.settings.codec = codec;
                     }
+                    SettingChange::CodecFallback(codecs) => self.settings.codec_fallback = codecs,
                     SettingChange::MaxBitrate(bitrate) => self.settings.max_bitrate_mbps = bitrate,
+                    SettingChange::MaxDecodeResolution(cap) => {
+                        self.settings.max_decode_resolution = cap;
+                        // Lower the current choice if it's now above the cap
+                        let resolution = self.settings.resolution.clone();
+                        if !self.settings.within_decode_cap(&resolution) {
+                            let (width, height) = self.settings.resolution_tuple();
+                            info!(
+                                "Resolution {} above max decode resolution, lowering to {}x{}",
+                                resolution, width, height
+                            );
+                            self.settings.resolution = format!("{}x{}", width, height);
+                        }
+                    }
+                    SettingChange::AdaptiveBitrate(enabled) => {
+                        self.settings.adaptive_bitrate = enabled
+                    }
+                    SettingChange::BandwidthCap(mbps) => self.settings.bandwidth_cap_mbps = mbps,
                     SettingChange::Fullscreen(fs) => self.settings.fullscreen = fs,
+                    SettingChange::StartMaximized(maximized) => {
+                        self.settings.start_maximized = maximized
+                    }
+                    SettingChange::KeepStreamingUnfocused(enabled) => {
+                        self.settings.keep_streaming_unfocused = enabled
+                    }
+                    SettingChange::ResponsiveMenus(enabled) => {
+                        self.settings.responsive_menus = enabled
+                    }
+                    SettingChange::MenuFpsCap(fps) => self.settings.menu_fps_cap = fps,
+                    SettingChange::MenuScreensaverMinutes(minutes) => {
+                        self.settings.menu_screensaver_minutes = minutes
+                    }
+                    SettingChange::MuteOnFocusLoss(enabled) => {
+                        self.settings.mute_on_focus_loss = enabled
+                    }
+                    SettingChange::AudioResamplerQuality(quality) => {
+                        self.settings.audio_resampler_quality = quality
+                    }
+                    SettingChange::ConnectionSounds(enabled) => {
+                        self.settings.connection_sounds = enabled
+                    }
+                    SettingChange::ShowSessionSummary(enabled) => {
+                        self.settings.show_session_summary = enabled
+                    }
                     SettingChange::VSync(vsync) => self.settings.vsync = vsync,
                     SettingChange::LowLatency(ll) => self.settings.low_latency_mode = ll,
                     SettingChange::DecoderBackend(backend) => {
-                        self.settings.decoder_backend = backend
+                        self.settings.decoder_backend = backend;
+                        self.run_decoder_check();
+                    }
+                    SettingChange::GstreamerPipelineOverride(pipeline) => {
+                        crate::media::set_gstreamer_pipeline_override(pipeline.clone());
+                        self.settings.gstreamer_pipeline_override = pipeline;
+                        self.run_decoder_check();
+                    }
+                    SettingChange::PiDecoder(pi_decoder) => {
+                        crate::media::set_pi_decoder(pi_decoder);
+                        self.settings.pi_decoder = pi_decoder;
+                        self.run_decoder_check();
                     }
                     SettingChange::ColorQuality(quality) => {
                         self.settings.color_quality = quality;
@@ -482,6 +911,68 @@ impl App {
                     SettingChange::ClipboardPasteEnabled(enabled) => {
                         self.settings.clipboard_paste_enabled = enabled;
                     }
+                    SettingChange::IdleTimeoutMinutes(minutes) => {
+                        self.settings.idle_timeout_minutes = minutes
+                    }
+                    SettingChange::MouseSendRateHz(hz) => {
+                        self.settings.mouse_send_rate_hz = hz;
+                        crate::input::set_mouse_send_rate(hz);
+                    }
+                    SettingChange::MouseCaptureKey(key) => self.settings.mouse_capture_key = key,
+                    SettingChange::KeepSessionOnQuit(enabled) => {
+                        self.settings.keep_session_on_quit = enabled
+                    }
+                    SettingChange::StatsRefreshMs(ms) => self.settings.stats_refresh_ms = ms,
+                    SettingChange::StatsWindow(enabled) => self.settings.stats_window = enabled,
+                    SettingChange::MinimalHud(enabled) => self.settings.minimal_hud = enabled,
+                    SettingChange::RecordStatsCsv(enabled) => {
+                        self.settings.record_stats_csv = enabled
+                    }
+                    SettingChange::Brightness(value) => self.settings.brightness = value,
+                    SettingChange::Gamma(value) => self.settings.gamma = value,
+                    SettingChange::Sharpening(value) => self.settings.sharpening = value,
+                    SettingChange::ScalingMode(mode) => self.settings.scaling_mode = mode,
+                    SettingChange::FullscreenMode(mode) => self.settings.fullscreen_mode = mode,
+                    SettingChange::OverscanPercent(percent) => {
+                        self.settings.overscan_percent = percent
+                    }
+                    SettingChange::IceGatherTimeoutMs(ms) => {
+                        self.settings.ice_gather_timeout_ms = ms
+                    }
+                    SettingChange::ForceRelay(enabled) => self.settings.force_relay = enabled,
+                    SettingChange::QueueDataUrl(url) => {
+                        self.settings.queue_data_url = url;
+                        self.queue_source_changed();
+                    }
+                    SettingChange::QueueMappingUrl(url) => {
+                        self.settings.queue_mapping_url = url;
+                        self.queue_source_changed();
+                    }
+                    SettingChange::PreferredRegion(region) => {
+                        self.settings.preferred_region = region;
+                        if self.auto_server_selection {
+                            self.select_best_server();
+                        }
+                    }
+                    SettingChange::BlockedServers(servers) => {
+                        self.settings.blocked_servers = servers;
+                        if self.auto_server_selection || self.saved_server_blocked() {
+                            self.select_best_server();
+                        }
+                    }
+                    SettingChange::SafeMode(enabled) => self.settings.safe_mode = enabled,
+                    SettingChange::PresentMode(mode) => self.settings.present_mode = mode,
+                    SettingChange::FramePacing(enabled) => {
+                        self.settings.frame_pacing = enabled;
+                        self.frame_pacer.reset();
+                    }
+                    SettingChange::UnderLoadPolicy(policy) => {
+                        self.settings.under_load_policy = policy
+                    }
+                    SettingChange::ShowTipsOnStartup(enabled) => {
+                        self.settings.show_tips_on_startup = enabled
+                    }
+                    SettingChange::CheckUpdates(enabled) => self.settings.check_updates = enabled,
                 }
                 self.save_settings();
             }
@@ -590,16 +1081,97 @@ impl App {
                 if self.show_settings_modal && self.servers.is_empty() {
                     self.load_servers();
                 }
+                // Queue times are shown next to ping in the server list
+                if self.show_settings_modal {
+                    self.fetch_queue_times();
+                }
+            }
+            UiAction::ToggleAccountPanel => {
+                self.show_account_panel = !self.show_account_panel;
+            }
+            UiAction::RefreshSubscription => {
+                self.fetch_subscription();
             }
             UiAction::ResumeSession(session_info) => {
                 self.resume_session(session_info);
             }
-            UiAction::TerminateAndLaunch(session_id, game) => {
-                self.terminate_and_launch(session_id, game);
+            UiAction::TerminateAndLaunch(session_ids, game) => {
+                self.terminate_and_launch(session_ids, game);
+            }
+            UiAction::EndSession(session) => {
+                self.end_session(session);
+            }
+            UiAction::RetrySession => {
+                self.retry_stuck_session();
+            }
+            UiAction::AcceptStreamSettingsOffer => {
+                if let (Some(offer), Some(game)) = (
+                    self.stream_settings_offer.take(),
+                    self.selected_game.clone(),
+                ) {
+                    info!(
+                        "{} - saving and relaunching {}",
+                        offer.retry_label, game.title
+                    );
+                    self.settings.resolution = offer.resolution;
+                    self.settings.fps = offer.fps;
+                    self.settings.codec = offer.codec;
+                    self.settings.hdr_enabled = offer.hdr_enabled;
+                    self.save_settings();
+                    self.start_new_session(&game);
+                }
             }
             UiAction::CloseSessionConflict => {
                 self.show_session_conflict = false;
                 self.pending_game_launch = None;
+                self.end_session_error = None;
+                self.connection_test = None;
+            }
+            UiAction::CreateGameShortcut(game, box_art) => {
+                // PowerShell (Windows) takes a moment - keep it off the UI thread
+                std::thread::spawn(move || {
+                    let icon = box_art
+                        .as_ref()
+                        .map(|(pixels, width, height)| (pixels.as_slice(), *width, *height));
+                    match crate::utils::create_game_shortcut(&game.id, &game.title, icon) {
+                        Ok(path) => {
+                            info!("Created shortcut for {} at {}", game.title, path.display());
+                            crate::utils::show_desktop_notification(
+                                "Shortcut created",
+                                &format!(
+                                    "{} can now be started from {}",
+                                    game.title,
+                                    crate::utils::SHORTCUT_LOCATION
+                                ),
+                            );
+                        }
+                        Err(e) => {
+                            warn!("Failed to create shortcut for {}: {:#}", game.title, e);
+                            crate::utils::show_desktop_notification(
+                                "Couldn't create shortcut",
+                                &format!("{:#}", e),
+                            );
+                        }
+                    }
+                });
+            }
+            UiAction::ConfirmLowHoursLaunch => {
+                if let Some(game) = self.low_hours_launch.take() {
+                    info!("Launching {} despite low remaining hours", game.title);
+                    self.check_sessions_and_launch(&game);
+                }
+            }
+            UiAction::CancelLowHoursLaunch => {
+                self.low_hours_launch = None;
+                self.connection_test = None;
+            }
+            UiAction::ConfirmForwardedLaunch => {
+                if let Some((app_id, _)) = self.forwarded_launch.take() {
+                    self.launch_from_deep_link(app_id);
+                }
+            }
+            UiAction::CancelForwardedLaunch => {
+                self.forwarded_launch = None;
             }
             UiAction::CloseAV1Warning => {
                 self.show_av1_warning = false;
@@ -607,13 +1179,95 @@ impl App {
             UiAction::CloseAllianceWarning => {
                 self.show_alliance_warning = false;
             }
+            UiAction::CloseGpuMemoryWarning => {
+                self.gpu_memory_warning = None;
+            }
             UiAction::CloseWelcomePopup => {
                 self.show_welcome_popup = false;
-                cache::mark_welcome_shown();
+            }
+            UiAction::CheckForUpdates => {
+                self.check_for_updates();
+            }
+            UiAction::DismissUpdateNotice => {
+                self.show_update_notice = false;
+            }
+            UiAction::OpenCrashReport => {
+                if let Some(crash) = &self.last_crash {
+                    if let Err(e) = open::that(&crash.path) {
+                        warn!("Failed to open crash report: {}", e);
+                    }
+                }
+            }
+            UiAction::CloseCrashReport => {
+                self.last_crash = None;
+            }
+            UiAction::DownloadUpdate => {
+                self.download_update();
+            }
+            UiAction::RunDecoderCheck => {
+                self.run_decoder_check();
+            }
+            UiAction::SetStreamBitrate(mbps) => {
+                if self.state == AppState::Streaming {
+                    self.stream_bitrate_mbps =
+                        mbps.clamp(10, self.settings.max_bitrate_mbps.max(10));
+                    crate::webrtc::set_session_bitrate(self.stream_bitrate_mbps * 1000);
+                }
+            }
+            UiAction::StartConnectionTest => {
+                self.start_connection_test();
+            }
+            UiAction::ApplyConnectionTestSettings(recommended) => {
+                info!("Applying connection test recommendation: {:?}", recommended);
+                self.settings.max_bitrate_mbps = recommended.max_bitrate_mbps;
+                self.settings.fps = recommended.fps;
+                if self.settings.within_decode_cap(&recommended.resolution) {
+                    self.settings.resolution = recommended.resolution;
+                }
+                self.save_settings();
+                self.connection_test_result = None;
+            }
+            UiAction::CloseConnectionTestResult => {
+                self.connection_test_result = None;
+            }
+            UiAction::CloseSessionSummary => {
+                self.session_summary = None;
+            }
+            UiAction::ShowWelcomePopup => {
+                self.show_settings_modal = false;
+                self.show_welcome_popup = true;
+            }
+            UiAction::ReportIssue => {
+                let mut decoder = if self.settings.safe_mode_active() {
+                    "Software (safe mode)".to_string()
+                } else {
+                    self.settings.decoder_backend.as_str().to_string()
+                };
+                if let Some(element) = crate::media::active_gstreamer_decoder() {
+                    decoder = format!("{} - {}", decoder, element);
+                }
+                let url = crate::utils::issue_report_url(&crate::utils::IssueReport {
+                    tier: self
+                        .subscription
+                        .as_ref()
+                        .map(|s| s.membership_tier.clone()),
+                    gpu: self.gpu_description.clone(),
+                    decoder,
+                    last_error: self.error_message.clone().or_else(|| {
+                        self.last_crash
+                            .as_ref()
+                            .map(|crash| format!("Crashed: {}", crash.message))
+                    }),
+                });
+                info!("Opening pre-filled GitHub issue");
+                if let Err(e) = open::that(&url) {
+                    warn!("Failed to open issue page: {}", e);
+                }
             }
             UiAction::ResetSettings => {
                 info!("Resetting all settings to defaults");
                 self.settings = Settings::default();
+                self.show_stats = self.settings.show_stats;
                 if let Err(e) = self.settings.save() {
                     warn!("Failed to save default settings: {}", e);
                 }
@@ -660,6 +1314,18 @@ impl App {
                 }
                 self.launch_game(&game);
             }
+            UiAction::SetQueueAlert(alert) => {
+                match alert {
+                    Some(ref alert) => queue_alert::start_watch(
+                        &self.runtime,
+                        alert.clone(),
+                        self.settings.queue_data_url.clone(),
+                        self.settings.queue_mapping_url.clone(),
+                    ),
+                    None => queue_alert::cancel_watch(),
+                }
+                self.queue_alert = alert;
+            }
             UiAction::RefreshQueueTimes => {
                 // Force refresh by resetting last fetch time
                 self.queue_last_fetch =
@@ -784,6 +1450,37 @@ impl App {
             self.last_render_fps_time = now;
         }
 
+        // Detect system sleep/wake while streaming and recover the stream
+        self.detect_system_wake();
+
+        // End the session if the user has been away too long (metered playtime)
+        self.check_idle_timeout();
+        if self.pending_wake_check.is_some() {
+            if let Some(result) = cache::take_wake_check() {
+                self.handle_wake_check(result);
+            }
+        }
+
+        // Check for a completed release check
+        if self.update_check_in_progress {
+            if let Some(result) = cache::take_update_check() {
+                self.update_check_in_progress = false;
+                self.update_check_result = Some(result);
+            }
+        }
+        if self.update_download_in_progress {
+            if let Some(result) = cache::take_update_download() {
+                self.update_download_in_progress = false;
+                self.update_download_result = Some(result);
+            }
+        }
+        if self.decoder_check_in_progress {
+            if let Some(result) = cache::take_decoder_check() {
+                self.decoder_check_in_progress = false;
+                self.decoder_check_result = Some(result);
+            }
+        }
+
         // Update anti-AFK (sends F13 every 4 minutes when enabled)
         self.update_anti_afk();
 
@@ -811,6 +1508,11 @@ impl App {
             }
         }
 
+        // Tokens refreshed inline by the API client (after a 401) are picked up the same way
+        if api::take_tokens_refreshed() {
+            self.token_refresh_in_progress = true;
+        }
+
         // Check for refreshed tokens from async refresh task
         if self.token_refresh_in_progress {
             if let Some(new_tokens) = cache::load_tokens() {
@@ -828,49 +1530,57 @@ impl App {
         }
 
         // Check for new video frames from shared frame holder
-        if let Some(ref shared) = self.shared_frame {
-            if let Some(frame) = shared.read() {
-                // Only log the first frame (when current_frame is None)
-                if self.current_frame.is_none() {
-                    log::info!(
-                        "First video frame received: {}x{}",
-                        frame.width,
-                        frame.height
-                    );
+        let frame_due = self.video_frame_due();
+        let new_frame = self
+            .shared_frame
+            .as_ref()
+            .filter(|_| frame_due)
+            .and_then(|shared| shared.read());
+        if let Some(frame) = new_frame.filter(|frame| !self.is_repeated_frame(frame)) {
+            // Only log the first frame (when current_frame is None)
+            if self.current_frame.is_none() {
+                log::info!(
+                    "First video frame received: {}x{}",
+                    frame.width,
+                    frame.height
+                );
+                self.check_resolution_downgrade(frame.width, frame.height);
+                if self.settings.connection_sounds {
+                    crate::media::play_chime(crate::media::Chime::Connected);
                 }
+            }
 
-                // Update HDR status in stats from frame's transfer function
-                use crate::media::{ColorSpace, TransferFunction};
-                let is_hdr = frame.transfer_function == TransferFunction::PQ
-                    || frame.transfer_function == TransferFunction::HLG;
-                if self.stats.is_hdr != is_hdr {
-                    self.stats.is_hdr = is_hdr;
-                    self.stats.color_space = match frame.color_space {
-                        ColorSpace::BT2020 => "BT.2020".to_string(),
-                        ColorSpace::BT709 => "BT.709".to_string(),
-                        ColorSpace::BT601 => "BT.601".to_string(),
-                    };
-                }
+            // Update HDR status in stats from frame's transfer function
+            use crate::media::{ColorSpace, TransferFunction};
+            let is_hdr = frame.transfer_function == TransferFunction::PQ
+                || frame.transfer_function == TransferFunction::HLG;
+            if self.stats.is_hdr != is_hdr {
+                self.stats.is_hdr = is_hdr;
+                self.stats.color_space = match frame.color_space {
+                    ColorSpace::BT2020 => "BT.2020".to_string(),
+                    ColorSpace::BT709 => "BT.709".to_string(),
+                    ColorSpace::BT601 => "BT.601".to_string(),
+                };
+            }
 
-                // Update resolution in stats from actual decoded frame dimensions
-                // This catches resolution changes from SSRC switches (GFN adaptive quality)
-                let new_res = format!("{}x{}", frame.width, frame.height);
-                if self.stats.resolution != new_res {
-                    if !self.stats.resolution.is_empty() {
-                        log::info!(
-                            "Resolution changed: {} -> {}",
-                            self.stats.resolution,
-                            new_res
-                        );
-                    }
-                    self.stats.resolution = new_res;
+            // Update resolution in stats from actual decoded frame dimensions
+            // This catches resolution changes from SSRC switches (GFN adaptive quality)
+            let new_res = format!("{}x{}", frame.width, frame.height);
+            if self.stats.resolution != new_res {
+                if !self.stats.resolution.is_empty() {
+                    log::info!(
+                        "Resolution changed: {} -> {}",
+                        self.stats.resolution,
+                        new_res
+                    );
                 }
-
-                self.current_frame = Some(frame);
-                // Increment render frame count only when we get a new video frame
-                // This ensures render FPS matches decode FPS
-                self.render_frame_count += 1;
+                self.stats.resolution = new_res;
             }
+
+            self.current_frame = Some(frame);
+            // Increment render frame count only when we get a new video frame
+            // This ensures render FPS matches decode FPS
+            self.render_frame_count += 1;
         }
 
         // Check for stats updates
@@ -879,12 +1589,38 @@ impl App {
                 // Preserve render_fps from our local tracking
                 stats.render_fps = self.stats.render_fps;
                 stats.frames_rendered = self.stats.frames_rendered;
+                stats.frames_repeated = self.stats.frames_repeated;
+                // Frames the decoder delivered that were never shown
+                if let Some(ref shared) = self.shared_frame {
+                    stats.frames_dropped = shared.dropped_count();
+                }
                 // Preserve resolution from actual decoded frames (more accurate than SDP)
                 if !self.stats.resolution.is_empty() {
                     stats.resolution = self.stats.resolution.clone();
                 }
+                if let Some(ref mut totals) = self.session_totals {
+                    totals.record(&stats);
+                }
+                if let Some(warning) = self.decode_monitor.sample(&stats) {
+                    self.decode_slowdown_warning = Some(warning);
+                }
                 self.stats = stats;
             }
+            self.check_chroma_downgrade();
+
+            if let Some(ref mut log) = self.stats_log {
+                if let Err(e) = log.sample(&self.stats) {
+                    warn!("Stats recording stopped: {}", e);
+                    self.stats_log = None;
+                }
+            }
+
+            if let Some(ref mut test) = self.connection_test {
+                test.sample(&self.stats);
+                if test.is_done() {
+                    self.finish_connection_test();
+                }
+            }
         }
 
         // Update cached providers
@@ -982,18 +1718,87 @@ impl App {
             }
         }
 
-        // Check if queue data was fetched and saved to cache (Queue Times tab)
-        if self.state == AppState::Games
-            && self.current_tab == GamesTab::QueueTimes
-            && self.queue_loading
-        {
-            if let Some(servers) = cache::load_queue_cache() {
-                if !servers.is_empty() {
+        // Queue alert fired (the desktop notification was already shown)
+        if let Some((alert, _)) = queue_alert::take_fired_alert() {
+            if self.queue_alert.as_ref() == Some(&alert) {
+                self.queue_alert = None;
+            }
+        }
+
+        // Sessions ended from the session list
+        let ended: Vec<_> = std::mem::take(&mut *ENDED_SESSIONS.write());
+        for (session_id, error) in ended {
+            self.ending_sessions.retain(|id| *id != session_id);
+            match error {
+                None => {
+                    info!("Session {} ended", session_id);
+                    self.active_sessions.retain(|s| s.session_id != session_id);
+                    // Nothing left to choose from and nothing to launch - close the list
+                    if self.active_sessions.is_empty() && self.pending_game_launch.is_none() {
+                        self.show_session_conflict = false;
+                    }
+                }
+                Some(error) => self.end_session_error = Some(error),
+            }
+        }
+
+        // Game requested by an opennow:// link, once signed in
+        if self.state == AppState::Games {
+            if let Some(launch) = crate::utils::take_pending_launch() {
+                if launch.forwarded {
+                    let name = self
+                        .games
+                        .iter()
+                        .chain(&self.library_games)
+                        .find(|g| {
+                            g.id == launch.app_id
+                                || g.variants.iter().any(|v| v.id == launch.app_id)
+                        })
+                        .map_or_else(|| format!("app {}", launch.app_id), |g| g.title.clone());
+                    self.forwarded_launch = Some((launch.app_id, name));
+                } else {
+                    self.launch_from_deep_link(launch.app_id);
+                }
+            }
+            if let Some(game) = DEEP_LINK_GAME.write().take() {
+                self.launch_game(&game);
+            }
+        }
+
+        // Queue data source unreachable - stop the spinner and say so
+        if let Some(error) = QUEUE_FETCH_ERROR.write().take() {
+            self.queue_loading = false;
+            self.queue_error = Some(error);
+            // Nothing shown yet (e.g. first fetch since startup) - fall back to the last saved results
+            if self.queue_servers.is_empty() {
+                if let Some((fetched_at, servers)) = cache::load_queue_cache() {
+                    info!(
+                        "Showing {} queue servers from an older cache",
+                        servers.len()
+                    );
+                    self.queue_servers = servers;
+                    self.queue_fetched_at = Some(fetched_at);
+                    self.apply_queue_to_servers();
+                }
+            }
+        }
+
+        // Check if queue data was fetched and saved to cache
+        if self.state == AppState::Games && self.queue_loading {
+            if let Some((fetched_at, servers)) = cache::load_queue_cache() {
+                // Ignore the cache from a previous fetch until the running one replaces it
+                if fetched_at >= self.queue_fetch_started_at && !servers.is_empty() {
                     info!("Loaded {} queue servers from cache", servers.len());
                     self.queue_servers = servers;
+                    self.queue_fetched_at = Some(fetched_at);
                     self.queue_loading = false;
-                    // Start ping test for queue servers
-                    self.start_queue_ping_test();
+                    self.queue_error = None;
+                    self.queue_history = cache::load_queue_history();
+                    self.apply_queue_to_servers();
+                    // Ping individual rigs only where they're listed
+                    if self.current_tab == GamesTab::QueueTimes || self.show_server_selection {
+                        self.start_queue_ping_test();
+                    }
                 }
             }
         }
@@ -1018,8 +1823,16 @@ impl App {
                 self.pending_game_launch = Some(pending);
                 self.show_session_conflict = true;
                 cache::clear_active_sessions_cache();
+            } else if self.active_sessions.len() > 1 {
+                // Leftovers from crashes or dropped connections - let the user pick what to keep
+                info!(
+                    "{} active sessions found - showing the session list",
+                    self.active_sessions.len()
+                );
+                self.show_session_conflict = true;
+                cache::clear_active_sessions_cache();
             } else if !self.active_sessions.is_empty() {
-                // Auto-resume logic: no pending game, but active sessions exist -> resume the first one
+                // Auto-resume logic: no pending game, but one active session exists -> resume it
                 if let Some(session) = self.active_sessions.first() {
                     info!("Auto-resuming active session found: {}", session.session_id);
                     let session_clone = session.clone();
@@ -1043,14 +1856,59 @@ impl App {
         }
     }
 
-    /// Logout and return to login screen
-    pub fn logout(&mut self) {
+    /// Compare the requested resolution against the first decoded frame
+    /// GFN may silently serve a lower resolution (capacity/entitlement), so surface it
+    fn check_resolution_downgrade(&mut self, width: u32, height: u32) {
+        let (req_width, req_height) = parse_resolution(&self.session_resolution());
+        let requested_pixels = req_width as u64 * req_height as u64;
+        let received_pixels = width as u64 * height as u64;
+
+        // Tolerate small differences (e.g., 1080 vs 1088 coded height) - only warn below 90%
+        if received_pixels * 10 < requested_pixels * 9 {
+            let message = format!(
+                "Server downgraded to {} (requested {})",
+                resolution_label(width, height),
+                resolution_label(req_width, req_height)
+            );
+            warn!(
+                "Requested {}x{} but server is sending {}x{}",
+                req_width, req_height, width, height
+            );
+            self.resolution_downgrade_warning = Some(message);
+        }
+    }
+
+    /// Warn once if 4:4:4 was requested but the stream's SPS reports a subsampled format
+    /// (server/game doesn't support it); text looks noticeably softer at 4:2:0
+    fn check_chroma_downgrade(&mut self) {
+        let requested_444 = self.settings.color_quality.chroma_format() == 2;
+        if !requested_444
+            || self.chroma_downgrade_warning.is_some()
+            || self.stats.chroma_format.is_empty()
+            || self.stats.chroma_format == "4:4:4"
+        {
+            return;
+        }
+
+        warn!(
+            "Requested YUV 4:4:4 but server is sending {}",
+            self.stats.chroma_format
+        );
+        self.chroma_downgrade_warning = Some(format!(
+            "Server is sending YUV {} (requested 4:4:4)",
+            self.stats.chroma_format
+        ));
+    }
+
+    /// Logout and return to login screen
+    pub fn logout(&mut self) {
         self.auth_tokens = None;
         self.user_info = None;
         self.subscription = None;
         auth::clear_login_provider();
         cache::clear_login_provider(); // Clear persisted provider too
         cache::clear_tokens();
+        api::capabilities::reset_feature_status();
         cache::clear_games_cache(); // Clear cached games
         self.state = AppState::Login;
         self.games.clear();
@@ -1076,7 +1934,9 @@ impl App {
         runtime.spawn(async move {
             // Fetch games from GraphQL MAIN panel (has images)
             // This is the same approach as the official GFN client
-            match api_client.fetch_main_games(None).await {
+            let result = api_client.fetch_main_games(None).await;
+            record_feature(ProviderFeature::Catalog, &result);
+            match result {
                 Ok(games) => {
                     info!(
                         "Fetched {} games from GraphQL MAIN panel (with images)",
@@ -1118,7 +1978,9 @@ impl App {
 
         let runtime = self.runtime.clone();
         runtime.spawn(async move {
-            match api_client.fetch_library(None).await {
+            let result = api_client.fetch_library(None).await;
+            record_feature(ProviderFeature::Library, &result);
+            match result {
                 Ok(games) => {
                     info!("Fetched {} games from LIBRARY panel", games.len());
                     cache::save_library_cache(&games);
@@ -1171,7 +2033,9 @@ impl App {
 
         let runtime = self.runtime.clone();
         runtime.spawn(async move {
-            match crate::api::fetch_subscription(&token, &user_id).await {
+            let result = crate::api::fetch_subscription(&token, &user_id).await;
+            record_feature(ProviderFeature::Subscription, &result);
+            match result {
                 Ok(sub) => {
                     info!("Fetched subscription: tier={}, hours={:.1}/{:.1}, storage={}, unlimited={}",
                         sub.membership_tier,
@@ -1189,6 +2053,170 @@ impl App {
         });
     }
 
+    /// Whether `frame` is the decoder re-emitting the picture already on screen (same frame ID)
+    /// Repeats are kept off the GPU and out of render FPS so stalls show as real FPS drops
+    fn is_repeated_frame(&mut self, frame: &crate::media::VideoFrame) -> bool {
+        let repeated = self
+            .current_frame
+            .as_ref()
+            .is_some_and(|current| current.frame_id == frame.frame_id);
+        if repeated {
+            self.stats.frames_repeated += 1;
+        }
+        repeated
+    }
+
+    /// Whether a decoded frame is ready to be presented
+    /// With frame pacing on, frames wait until their scheduled time after arrival
+    pub fn video_frame_due(&mut self) -> bool {
+        let Some(ref shared) = self.shared_frame else {
+            return false;
+        };
+        if !self.settings.frame_pacing {
+            return shared.has_new_frame();
+        }
+        let Some(timestamp_us) = shared.pending_timestamp_us() else {
+            return false;
+        };
+        let frame_interval_us = 1_000_000 / self.settings.fps.max(1) as u64;
+        self.frame_pacer.is_due(
+            timestamp_us,
+            crate::utils::monotonic_us(),
+            frame_interval_us,
+        )
+    }
+
+    /// Crash report left by the previous run, until the user closes the dialog
+    pub fn last_crash(&self) -> Option<&crate::utils::CrashReport> {
+        self.last_crash.as_ref()
+    }
+
+    /// Newer release to announce, once the startup check has found one
+    pub fn update_notice(&self) -> Option<&api::releases::ReleaseInfo> {
+        match &self.update_check_result {
+            Some(cache::UpdateCheckResult::Available(release)) if self.show_update_notice => {
+                Some(release)
+            }
+            _ => None,
+        }
+    }
+
+    /// Check GitHub for a newer release (result picked up in update())
+    pub fn check_for_updates(&mut self) {
+        if self.update_check_in_progress {
+            return;
+        }
+        self.update_check_in_progress = true;
+        self.update_check_result = None;
+        cache::take_update_check(); // Drop any stale result
+
+        self.runtime.spawn(async move {
+            let client = reqwest::Client::new();
+            let result = match api::releases::fetch_latest_release(&client).await {
+                Ok(release) => {
+                    if api::releases::is_newer_version(&release.version, api::releases::APP_VERSION)
+                    {
+                        cache::UpdateCheckResult::Available(release)
+                    } else {
+                        cache::UpdateCheckResult::UpToDate
+                    }
+                }
+                Err(e) => {
+                    warn!("Update check failed: {}", e);
+                    cache::UpdateCheckResult::Failed(e)
+                }
+            };
+            cache::save_update_check(&result);
+        });
+    }
+
+    /// Decode a test frame with the selected video decoder (result picked up in update())
+    /// Skipped while streaming so the check doesn't compete with the live decoder
+    pub fn run_decoder_check(&mut self) {
+        if self.decoder_check_in_progress || self.state == AppState::Streaming {
+            return;
+        }
+        self.decoder_check_result = None;
+        cache::take_decoder_check(); // Drop any stale result
+
+        let backend = if self.settings.safe_mode_active() {
+            config::VideoDecoderBackend::Software
+        } else {
+            self.settings.decoder_backend
+        };
+        // Native DXVA only decodes HEVC; an H.264 sample would silently test GStreamer instead
+        if backend == config::VideoDecoderBackend::NativeDxva {
+            self.decoder_check_result = Some(cache::DecoderCheckResult::Skipped(
+                "Native DXVA is HEVC-only and is tested when a stream starts".to_string(),
+            ));
+            return;
+        }
+        self.decoder_check_in_progress = true;
+
+        self.runtime.spawn(async move {
+            let result = match crate::media::decoder_check::check_decoder(backend).await {
+                Ok(elapsed) => cache::DecoderCheckResult::Passed(elapsed.as_millis() as u64),
+                Err(e) => {
+                    warn!("Decoder check failed for {}: {:#}", backend.as_str(), e);
+                    cache::DecoderCheckResult::Failed(format!("{:#}", e))
+                }
+            };
+            cache::save_decoder_check(&result);
+        });
+    }
+
+    /// Download the latest release for this platform to Downloads and reveal it
+    /// The running binary is never replaced - the user installs the update themselves
+    pub fn download_update(&mut self) {
+        if self.update_download_in_progress {
+            return;
+        }
+        let Some(cache::UpdateCheckResult::Available(release)) = &self.update_check_result else {
+            return;
+        };
+        let Some(asset) = release.asset.clone() else {
+            return;
+        };
+        self.update_download_in_progress = true;
+        self.update_download_result = None;
+        cache::take_update_download(); // Drop any stale result
+
+        self.runtime.spawn(async move {
+            let client = reqwest::Client::new();
+            let result = match api::releases::download_asset(&client, &asset).await {
+                Ok(path) => {
+                    // Reveal the download so the user can unpack it
+                    if let Some(dir) = path.parent() {
+                        if let Err(e) = open::that(dir) {
+                            warn!("Failed to open download folder: {}", e);
+                        }
+                    }
+                    cache::UpdateDownloadResult::Saved(path.display().to_string())
+                }
+                Err(e) => {
+                    warn!("Update download failed: {}", e);
+                    cache::UpdateDownloadResult::Failed(e)
+                }
+            };
+            cache::save_update_download(&result);
+        });
+    }
+
+    /// Refetch from a new queue data or mapping source, and move a running alert over to it
+    fn queue_source_changed(&mut self) {
+        // Refetch from the new source on the next request
+        self.queue_last_fetch = std::time::Instant::now() - std::time::Duration::from_secs(60);
+        self.queue_error = None;
+        if let Some(alert) = self.queue_alert.clone() {
+            queue_alert::start_watch(
+                &self.runtime,
+                alert,
+                self.settings.queue_data_url.clone(),
+                self.settings.queue_mapping_url.clone(),
+            );
+        }
+    }
+
     /// Fetch queue times from PrintedWaste API
     pub fn fetch_queue_times(&mut self) {
         // Rate limit: only fetch if more than 30 seconds since last fetch
@@ -1199,43 +2227,106 @@ impl App {
 
         self.queue_loading = true;
         self.queue_last_fetch = std::time::Instant::now();
+        self.queue_fetch_started_at = chrono::Utc::now().timestamp();
 
+        let queue_url = self.settings.queue_data_url.clone();
+        let mapping_url = self.settings.queue_mapping_url.clone();
         let runtime = self.runtime.clone();
         runtime.spawn(async move {
             let client = reqwest::Client::new();
-            match crate::api::fetch_queue_servers(&client).await {
+            match crate::api::fetch_queue_servers(&client, &queue_url, &mapping_url).await {
                 Ok(servers) => {
                     info!(
-                        "Fetched queue times for {} servers from PrintedWaste",
-                        servers.len()
+                        "Fetched queue times for {} servers from {}",
+                        servers.len(),
+                        queue_url
                     );
+                    // History first: the UI reloads it when the queue cache appears
+                    let mut history = cache::load_queue_history();
+                    if history.record(&servers, chrono::Local::now()) {
+                        cache::save_queue_history(&history);
+                    }
                     cache::save_queue_cache(&servers);
                 }
                 Err(e) => {
                     warn!("Failed to fetch queue times: {}", e);
+                    QUEUE_FETCH_ERROR.write().replace(e);
                 }
             }
         });
     }
 
+    /// Show PrintedWaste queue times on the server list
+    /// Queue rigs are matched to zones by their mapped region name (e.g. "US Central");
+    /// each zone gets the shortest queue among its rigs
+    fn apply_queue_to_servers(&mut self) {
+        fn zone_key(name: &str) -> String {
+            name.chars()
+                .filter(|c| c.is_ascii_alphanumeric())
+                .map(|c| c.to_ascii_lowercase())
+                .collect()
+        }
+
+        for server in &mut self.servers {
+            let key = zone_key(&server.name);
+            let best = self
+                .queue_servers
+                .iter()
+                .filter(|q| !q.zone.is_empty() && zone_key(&q.zone) == key)
+                .min_by_key(|q| (q.eta_seconds.unwrap_or(i64::MAX), q.queue_position));
+            server.queue_position = best.map(|q| q.queue_position);
+            server.queue_eta_secs = best.and_then(|q| q.eta_seconds);
+        }
+    }
+
     /// Load available servers/regions (tries dynamic fetch first, falls back to hardcoded)
     pub fn load_servers(&mut self) {
         info!("Loading servers...");
 
+        self.fetch_dynamic_regions();
+
+        // For now, start with hardcoded servers (will update when dynamic fetch completes)
+        self.load_hardcoded_servers();
+    }
+
+    /// Fetch regions from serverInfo in the background, retrying with backoff
+    /// An empty result in the cache means every attempt failed
+    fn fetch_dynamic_regions(&mut self) {
+        if self.region_fetch_in_progress {
+            return;
+        }
+        self.region_fetch_in_progress = true;
+
         let runtime = self.runtime.clone();
         let token = self.auth_tokens.as_ref().map(|t| t.jwt().to_string());
 
-        // Spawn async task to fetch dynamic regions
         runtime.spawn(async move {
             let client = reqwest::Client::new();
-            let regions = api::fetch_dynamic_regions(&client, token.as_deref()).await;
+            let mut delay = REGION_FETCH_RETRY_DELAY;
+            let mut regions = Vec::new();
+            for attempt in 1..=REGION_FETCH_ATTEMPTS {
+                regions = api::fetch_dynamic_regions(&client, token.as_deref()).await;
+                if !regions.is_empty() || attempt == REGION_FETCH_ATTEMPTS {
+                    break;
+                }
+                warn!(
+                    "[serverInfo] No regions (attempt {}/{}), retrying in {:?}",
+                    attempt, REGION_FETCH_ATTEMPTS, delay
+                );
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+            }
+
+            let result = if regions.is_empty() {
+                Err("no regions returned")
+            } else {
+                Ok(())
+            };
+            record_feature(ProviderFeature::ServerRegions, &result);
 
             // Store the results for the main thread to pick up
             DYNAMIC_REGIONS_CACHE.write().replace(regions);
         });
-
-        // For now, start with hardcoded servers (will update when dynamic fetch completes)
-        self.load_hardcoded_servers();
     }
 
     /// Load hardcoded servers as fallback
@@ -1280,29 +2371,50 @@ impl App {
                 url: None,
                 ping_ms: None,
                 status: ServerStatus::Unknown,
+                queue_position: None,
+                queue_eta_secs: None,
             })
             .collect();
+        self.apply_queue_to_servers();
 
         // Restore selected server from settings
-        if let Some(ref selected_id) = self.settings.selected_server {
-            if let Some(idx) = self.servers.iter().position(|s| s.id == *selected_id) {
-                self.selected_server_index = idx;
-            }
-        }
+        self.restore_selected_server();
 
         info!("Loaded {} hardcoded servers", self.servers.len());
+
+        self.restore_ping_results();
     }
 
     /// Update servers from dynamic region cache (call this periodically from update loop)
     pub fn check_dynamic_regions(&mut self) {
         let dynamic_regions = DYNAMIC_REGIONS_CACHE.write().take();
 
+        // Keep retrying in the background so the list self-heals when the network returns
+        if self.servers_offline
+            && !self.region_fetch_in_progress
+            && self
+                .region_fetch_failed_at
+                .is_none_or(|failed_at| failed_at.elapsed() >= REGION_REFETCH_INTERVAL)
+        {
+            info!("[serverInfo] Retrying region fetch");
+            self.fetch_dynamic_regions();
+        }
+
         if let Some(regions) = dynamic_regions {
-            if !regions.is_empty() {
+            self.region_fetch_in_progress = false;
+            if regions.is_empty() {
+                if !self.servers_offline {
+                    warn!("[serverInfo] Region fetch failed, using built-in server list");
+                }
+                self.servers_offline = true;
+                self.region_fetch_failed_at = Some(std::time::Instant::now());
+            } else {
+                self.servers_offline = false;
+                self.region_fetch_failed_at = None;
                 info!("[serverInfo] Applying {} dynamic regions", regions.len());
 
                 // Convert dynamic regions to ServerInfo
-                // Group by region based on URL pattern
+                // Group by region from the zone ID and name (see server_region)
                 self.servers = regions
                     .iter()
                     .map(|r| {
@@ -1317,33 +2429,7 @@ impl App {
                             .unwrap_or(&r.name);
 
                         // Determine region from name or hostname
-                        let region = if hostname.starts_with("eu-")
-                            || r.name.contains("Europe")
-                            || r.name.contains("UK")
-                            || r.name.contains("France")
-                            || r.name.contains("Germany")
-                        {
-                            "Europe"
-                        } else if hostname.starts_with("us-")
-                            || r.name.contains("US")
-                            || r.name.contains("California")
-                            || r.name.contains("Texas")
-                        {
-                            "North America"
-                        } else if hostname.starts_with("ca-")
-                            || r.name.contains("Canada")
-                            || r.name.contains("Quebec")
-                        {
-                            "Canada"
-                        } else if hostname.starts_with("ap-")
-                            || r.name.contains("Japan")
-                            || r.name.contains("Korea")
-                            || r.name.contains("Singapore")
-                        {
-                            "Asia-Pacific"
-                        } else {
-                            "Other"
-                        };
+                        let region = server_region(hostname, &r.name);
 
                         ServerInfo {
                             id: hostname.to_string(),
@@ -1352,21 +2438,22 @@ impl App {
                             url: Some(r.url.clone()),
                             ping_ms: None,
                             status: ServerStatus::Unknown,
+                            queue_position: None,
+                            queue_eta_secs: None,
                         }
                     })
                     .collect();
+                self.apply_queue_to_servers();
 
                 // Restore selected server
-                if let Some(ref selected_id) = self.settings.selected_server {
-                    if let Some(idx) = self.servers.iter().position(|s| s.id == *selected_id) {
-                        self.selected_server_index = idx;
-                    }
-                }
+                self.restore_selected_server();
 
                 info!("[serverInfo] Now have {} servers", self.servers.len());
 
-                // Auto-start ping test after loading dynamic servers
-                self.start_ping_test();
+                // Show recent ping results right away; only re-test once they're stale
+                if !self.restore_ping_results() {
+                    self.start_ping_test();
+                }
             }
         }
     }
@@ -1383,7 +2470,9 @@ impl App {
 
         let runtime = self.runtime.clone();
         runtime.spawn(async move {
-            match api_client.get_active_sessions().await {
+            let result = api_client.get_active_sessions().await;
+            record_feature(ProviderFeature::ActiveSessions, &result);
+            match result {
                 Ok(sessions) => {
                     info!("Checked active sessions: found {}", sessions.len());
                     if !sessions.is_empty() {
@@ -1404,6 +2493,8 @@ impl App {
         }
 
         self.ping_testing = true;
+        self.ping_measured_at = None;
+        cache::clear_ping_results();
         info!("Starting ping test for {} servers", self.servers.len());
 
         // Mark all servers as testing
@@ -1479,50 +2570,97 @@ impl App {
         }
     }
 
-    /// Load ping results from cache
+    /// Apply saved ping results if they are still fresh; returns false if a test is needed
+    fn restore_ping_results(&mut self) -> bool {
+        let Some((measured_at, results)) = cache::load_ping_results() else {
+            return false;
+        };
+        let age_secs = chrono::Utc::now().timestamp() - measured_at;
+        if !(0..cache::PING_RESULTS_TTL_SECS).contains(&age_secs) {
+            return false;
+        }
+        info!("Using ping results measured {}s ago", age_secs);
+        self.apply_ping_results(measured_at, results);
+        true
+    }
+
+    /// Load ping results from cache once the running test has saved them
     fn load_ping_results(&mut self) {
-        if let Some(results) = cache::load_ping_results() {
-            for result in results {
-                if let Some(id) = result.get("id").and_then(|v| v.as_str()) {
-                    if let Some(server) = self.servers.iter_mut().find(|s| s.id == id) {
-                        server.ping_ms = result
-                            .get("ping_ms")
-                            .and_then(|v| v.as_u64())
-                            .map(|v| v as u32);
-                        server.status = match result.get("status").and_then(|v| v.as_str()) {
-                            Some("Online") => ServerStatus::Online,
-                            Some("Offline") => ServerStatus::Offline,
-                            _ => ServerStatus::Unknown,
-                        };
-                    }
+        if let Some((measured_at, results)) = cache::load_ping_results() {
+            self.apply_ping_results(measured_at, results);
+        }
+    }
+
+    /// Show ping results on the server list and re-pick the selected server
+    fn apply_ping_results(&mut self, measured_at: i64, results: Vec<serde_json::Value>) {
+        for result in results {
+            if let Some(id) = result.get("id").and_then(|v| v.as_str()) {
+                if let Some(server) = self.servers.iter_mut().find(|s| s.id == id) {
+                    server.ping_ms = result
+                        .get("ping_ms")
+                        .and_then(|v| v.as_u64())
+                        .map(|v| v as u32);
+                    server.status = match result.get("status").and_then(|v| v.as_str()) {
+                        Some("Online") => ServerStatus::Online,
+                        Some("Offline") => ServerStatus::Offline,
+                        _ => ServerStatus::Unknown,
+                    };
                 }
             }
+        }
+
+        self.ping_testing = false;
+        self.ping_measured_at = Some(measured_at);
+
+        // Sort servers by ping (online first, then by ping)
+        self.servers.sort_by(|a, b| match (&a.status, &b.status) {
+            (ServerStatus::Online, ServerStatus::Online) => {
+                a.ping_ms.unwrap_or(9999).cmp(&b.ping_ms.unwrap_or(9999))
+            }
+            (ServerStatus::Online, _) => std::cmp::Ordering::Less,
+            (_, ServerStatus::Online) => std::cmp::Ordering::Greater,
+            _ => std::cmp::Ordering::Equal,
+        });
 
-            self.ping_testing = false;
+        // Update selected index after sort
+        if self.auto_server_selection || self.saved_server_blocked() {
+            // Auto-select best server
+            self.select_best_server();
+        } else {
+            self.restore_selected_server();
+        }
+    }
 
-            // Sort servers by ping (online first, then by ping)
-            self.servers.sort_by(|a, b| match (&a.status, &b.status) {
-                (ServerStatus::Online, ServerStatus::Online) => {
-                    a.ping_ms.unwrap_or(9999).cmp(&b.ping_ms.unwrap_or(9999))
+    /// Point `selected_server_index` at the saved server, or its nearest stand-in in the
+    /// same region if the current list doesn't have it. The saved ID is kept, so the
+    /// exact server is picked again once a later list includes it.
+    fn restore_selected_server(&mut self) {
+        let Some(saved_id) = self.settings.selected_server.clone() else {
+            return;
+        };
+        match find_saved_server(&self.servers, &saved_id) {
+            Some(idx) => {
+                if self.servers[idx].id != saved_id {
+                    info!(
+                        "Saved server {} not in list, using nearby {}",
+                        saved_id, self.servers[idx].name
+                    );
                 }
-                (ServerStatus::Online, _) => std::cmp::Ordering::Less,
-                (_, ServerStatus::Online) => std::cmp::Ordering::Greater,
-                _ => std::cmp::Ordering::Equal,
-            });
-
-            // Update selected index after sort
-            if self.auto_server_selection {
-                // Auto-select best server
-                self.select_best_server();
-            } else if let Some(ref selected_id) = self.settings.selected_server {
-                if let Some(idx) = self.servers.iter().position(|s| s.id == *selected_id) {
-                    self.selected_server_index = idx;
+                self.selected_server_index = idx;
+            }
+            None => {
+                warn!(
+                    "Saved server {} not in list and no server in its region",
+                    saved_id
+                );
+                if self.selected_server_index >= self.servers.len() {
+                    self.selected_server_index = 0;
                 }
             }
         }
     }
 
-    /// Select the best server based on ping (lowest ping online server)
+    /// Select the best server based on ping (lowest ping online server the settings allow)
     fn select_best_server(&mut self) {
         // Find the server with the lowest ping that is online
         let best_server = self
@@ -1530,6 +2668,7 @@ impl App {
             .iter()
             .enumerate()
             .filter(|(_, s)| s.status == ServerStatus::Online && s.ping_ms.is_some())
+            .filter(|(_, s)| self.settings.server_allowed(s))
             .min_by_key(|(_, s)| s.ping_ms.unwrap_or(9999));
 
         if let Some((idx, server)) = best_server {
@@ -1539,7 +2678,31 @@ impl App {
                 server.name,
                 server.ping_ms.unwrap_or(0)
             );
+        } else if self
+            .servers
+            .iter()
+            .any(|s| s.status == ServerStatus::Online)
+        {
+            warn!("No online server matches the region filter and blocklist");
         }
+
+        // A blocked server must not come back as the saved manual choice
+        if self.saved_server_blocked() {
+            info!(
+                "Saved server {} is blocked, clearing it",
+                self.settings.selected_server.as_deref().unwrap_or_default()
+            );
+            self.settings.selected_server = None;
+            self.save_settings();
+        }
+    }
+
+    /// Whether the manually chosen server is on the blocklist
+    fn saved_server_blocked(&self) -> bool {
+        self.settings
+            .selected_server
+            .as_ref()
+            .is_some_and(|id| self.settings.blocked_servers.contains(id))
     }
 
     /// Start ping test for queue servers (uses VPC IDs like NP-AMS-07)
@@ -1604,8 +2767,127 @@ impl App {
         }
     }
 
-    /// Launch a game session
+    /// Launch a game session (asks first when a metered plan is almost out of hours)
     pub fn launch_game(&mut self, game: &GameInfo) {
+        if let Some(remaining_hours) = self.low_remaining_hours() {
+            info!(
+                "Only {:.2}h left - confirming before launching {}",
+                remaining_hours, game.title
+            );
+            self.low_hours_launch = Some(game.clone());
+            return;
+        }
+        self.check_sessions_and_launch(game);
+    }
+
+    /// Launch a game by app ID (deep link), looking it up when it isn't in the loaded lists
+    fn launch_from_deep_link(&mut self, app_id: String) {
+        let known = self
+            .games
+            .iter()
+            .chain(&self.library_games)
+            .find(|g| g.id == app_id || g.variants.iter().any(|v| v.id == app_id));
+        if let Some(game) = known {
+            let mut game = game.clone();
+            // Launch the store variant the link points at
+            if let Some((index, variant)) = game
+                .variants
+                .iter()
+                .enumerate()
+                .find(|(_, v)| v.id == app_id)
+            {
+                game.selected_variant_index = index;
+                game.store = variant.store.clone();
+                game.id = variant.id.clone();
+                game.app_id = variant.id.parse::<i64>().ok();
+            }
+            self.launch_game(&game);
+            return;
+        }
+
+        let Some(tokens) = &self.auth_tokens else {
+            return;
+        };
+        let mut api_client = GfnApiClient::new();
+        api_client.set_access_token(tokens.jwt().to_string());
+
+        self.runtime.spawn(async move {
+            match api_client.fetch_app_details(&app_id).await {
+                Ok(Some(game)) => {
+                    info!("Deep link resolved to {}", game.title);
+                    *DEEP_LINK_GAME.write() = Some(game);
+                }
+                result => {
+                    match result {
+                        Err(e) => warn!("Failed to look up app {} for deep link: {}", app_id, e),
+                        _ => warn!("Deep link app {} not found", app_id),
+                    }
+                    crate::utils::show_desktop_notification(
+                        "Couldn't launch game",
+                        &format!("No GeForce NOW game found for app ID {}", app_id),
+                    );
+                }
+            }
+        });
+    }
+
+    /// Start a connection test: stream a library game for a while, then grade the stream
+    pub fn start_connection_test(&mut self) {
+        if self.state != AppState::Games || self.connection_test.is_some() {
+            return;
+        }
+        // Any game works; a library one is known to launch for this account
+        let Some(game) = self
+            .library_games
+            .first()
+            .or_else(|| self.games.first())
+            .cloned()
+        else {
+            self.connection_test_result = Some(Err(
+                "No games loaded yet - try again in a moment".to_string()
+            ));
+            return;
+        };
+
+        info!("Starting connection test with {}", game.title);
+        self.show_settings_modal = false;
+        self.connection_test_result = None;
+        self.connection_test = Some(connection_test::ConnectionTest::new(&game.title));
+        self.launch_game(&game);
+    }
+
+    /// Grade the finished connection test and end its session
+    fn finish_connection_test(&mut self) {
+        let Some(test) = self.connection_test.take() else {
+            return;
+        };
+        let server = self
+            .session
+            .as_ref()
+            .map(|s| s.zone.clone())
+            .unwrap_or_default();
+        self.connection_test_result = Some(
+            test.finish(&server, self.stream_bitrate_mbps, self.settings.fps)
+                .ok_or_else(|| "The stream didn't deliver enough stats to grade".to_string()),
+        );
+        self.terminate_current_session();
+        // The test result covers this stream
+        self.session_summary = None;
+        self.status_message = "Connection test finished".to_string();
+    }
+
+    /// Remaining hours if the plan is metered and below the warning threshold
+    fn low_remaining_hours(&self) -> Option<f32> {
+        let sub = self.subscription.as_ref()?;
+        // Unlimited tiers have no cap; no total means the hours aren't known
+        if sub.is_unlimited || sub.total_hours <= 0.0 {
+            return None;
+        }
+        (sub.remaining_hours < LOW_HOURS_WARNING_THRESHOLD).then_some(sub.remaining_hours)
+    }
+
+    /// Check for active sessions, then launch (the conflict dialog handles existing ones)
+    fn check_sessions_and_launch(&mut self, game: &GameInfo) {
         info!("Launching game: {} (ID: {})", game.title, game.id);
 
         // Get token first
@@ -1650,6 +2932,21 @@ impl App {
         });
     }
 
+    /// Turn off settings the title doesn't support, for this launch only
+    fn apply_game_capabilities(settings: &mut Settings, game: &GameInfo) {
+        if settings.hdr_enabled && game.supports_hdr() == Some(false) {
+            info!("{} doesn't support HDR - launching in SDR", game.title);
+            settings.hdr_enabled = false;
+        }
+        if settings.fps > 60 && game.supports_high_fps() == Some(false) {
+            info!(
+                "{} doesn't support high frame rates - launching at 60 FPS instead of {}",
+                game.title, settings.fps
+            );
+            settings.fps = 60;
+        }
+    }
+
     /// Start creating a new session (after checking for conflicts)
     fn start_new_session(&mut self, game: &GameInfo) {
         info!("Starting new session for: {}", game.title);
@@ -1663,6 +2960,8 @@ impl App {
         self.error_message = None;
         self.is_loading = true;
         self.last_poll_time = std::time::Instant::now() - POLL_INTERVAL;
+        self.reset_stuck_tracking();
+        self.stream_settings_offer = None;
 
         let token = match &self.auth_tokens {
             Some(t) => t.jwt().to_string(),
@@ -1673,9 +2972,12 @@ impl App {
             }
         };
 
+        self.apply_texture_limit();
+
         let app_id = game.id.clone();
         let game_title = game.title.clone();
-        let settings = self.settings.clone();
+        let mut settings = self.session_settings();
+        Self::apply_game_capabilities(&mut settings, game);
 
         let zone = self
             .servers
@@ -1684,6 +2986,11 @@ impl App {
             .unwrap_or_else(|| "eu-netherlands-south".to_string());
 
         let is_install_to_play = game.is_install_to_play;
+        let entitled = self
+            .subscription
+            .as_ref()
+            .map(|sub| sub.entitled_resolutions.clone())
+            .unwrap_or_default();
 
         let mut api_client = GfnApiClient::new();
         api_client.set_access_token(token);
@@ -1701,6 +3008,7 @@ impl App {
                         details.is_install_to_play
                     );
                     account_linked = !details.is_install_to_play;
+                    Self::apply_game_capabilities(&mut settings, &details);
                 }
                 Ok(None) => warn!(
                     "App details not found, using cached info: is_install_to_play={}",
@@ -1714,20 +3022,38 @@ impl App {
                 game_title, account_linked
             );
 
-            match api_client
+            let result = api_client
                 .create_session(&app_id, &game_title, &settings, &zone, account_linked)
-                .await
-            {
+                .await;
+            record_feature(ProviderFeature::Sessions, &result);
+            match result {
                 Ok(session) => {
                     info!(
                         "Session created: {} (state: {:?})",
                         session.session_id, session.state
                     );
                     cache::save_session_cache(&session);
+                    *POLLED_SESSION.write() = Some((session.session_id, session.state));
                 }
                 Err(e) => {
                     error!("Failed to create session: {}", e);
-                    cache::save_session_error(&format!("Failed to create session: {}", e));
+                    // A refused resolution / codec / HDR request gets a clear message and a way out
+                    let offer = e
+                        .downcast_ref::<api::SessionError>()
+                        .and_then(|err| err.stream_setting_rejection())
+                        .and_then(|rejection| {
+                            session::stream_settings_offer(rejection, &settings, &entitled)
+                        });
+                    match offer {
+                        Some(offer) => {
+                            info!("{} - offering: {}", offer.message, offer.retry_label);
+                            *STREAM_SETTINGS_OFFER.write() = Some(offer.clone());
+                            cache::save_session_error(&offer.message);
+                        }
+                        None => {
+                            cache::save_session_error(&format!("Failed to create session: {}", e))
+                        }
+                    }
                 }
             }
         });
@@ -1750,6 +3076,7 @@ impl App {
         self.error_message = None;
         self.is_loading = true;
         self.last_poll_time = std::time::Instant::now() - POLL_INTERVAL;
+        self.reset_stuck_tracking();
 
         // Session status codes:
         // 2 = Ready (needs RESUME PUT to re-attach client)
@@ -1775,7 +3102,7 @@ impl App {
         };
 
         let app_id = session_info.app_id.to_string();
-        let settings = self.settings.clone();
+        let settings = self.session_settings();
 
         let mut api_client = GfnApiClient::new();
         api_client.set_access_token(token);
@@ -1792,6 +3119,7 @@ impl App {
                         session.session_id, session.state
                     );
                     cache::save_session_cache(&session);
+                    *POLLED_SESSION.write() = Some((session.session_id, session.state));
                 }
                 Err(e) => {
                     error!("Failed to claim session: {}", e);
@@ -1802,10 +3130,10 @@ impl App {
     }
 
     /// Terminate existing session and start new game
-    fn terminate_and_launch(&mut self, session_id: String, game: GameInfo) {
+    fn terminate_and_launch(&mut self, session_ids: Vec<String>, game: GameInfo) {
         info!(
-            "Terminating session {} and launching {}",
-            session_id, game.title
+            "Terminating session(s) {:?} and launching {}",
+            session_ids, game.title
         );
 
         self.show_session_conflict = false;
@@ -1826,82 +3154,112 @@ impl App {
         let runtime = self.runtime.clone();
         let game_for_launch = game.clone();
         runtime.spawn(async move {
-            match api_client.stop_session(&session_id, "", None).await {
-                Ok(_) => {
-                    info!("Session terminated, waiting before launching new session");
-                    tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
-                    cache::save_launch_proceed_flag();
-                    cache::save_pending_game_cache(&game_for_launch);
-                }
-                Err(e) => {
-                    warn!("Session termination failed ({}), proceeding anyway", e);
-                    cache::save_launch_proceed_flag();
-                    cache::save_pending_game_cache(&game_for_launch);
+            let mut any_stopped = false;
+            for session_id in &session_ids {
+                match api_client.stop_session(session_id, "", None).await {
+                    Ok(_) => any_stopped = true,
+                    Err(e) => warn!(
+                        "Terminating session {} failed ({}), proceeding anyway",
+                        session_id, e
+                    ),
                 }
             }
+            if any_stopped {
+                info!("Session(s) terminated, waiting before launching new session");
+                tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
+            }
+            cache::save_launch_proceed_flag();
+            cache::save_pending_game_cache(&game_for_launch);
+        });
+    }
+
+    /// End one active session from the session list (e.g. a leftover after a crash)
+    fn end_session(&mut self, session: ActiveSessionInfo) {
+        if self.ending_sessions.contains(&session.session_id) {
+            return;
+        }
+        let Some(token) = self.auth_tokens.as_ref().map(|t| t.jwt().to_string()) else {
+            return;
+        };
+        info!("Ending active session {}", session.session_id);
+        self.ending_sessions.push(session.session_id.clone());
+        self.end_session_error = None;
+
+        let mut api_client = GfnApiClient::new();
+        api_client.set_access_token(token);
+        self.runtime.spawn(async move {
+            let result = api_client
+                .stop_session(&session.session_id, "", session.server_ip.as_deref())
+                .await;
+            let error = result.err().map(|e| {
+                warn!("Failed to end session {}: {}", session.session_id, e);
+                e.to_string()
+            });
+            ENDED_SESSIONS.write().push((session.session_id, error));
         });
     }
 
+    /// Title of the game running in an active session
+    pub fn active_session_title(&self, session: &ActiveSessionInfo) -> String {
+        self.games
+            .iter()
+            .chain(&self.library_games)
+            .find(|game| {
+                game.app_id == Some(session.app_id)
+                    || game
+                        .variants
+                        .iter()
+                        .any(|v| v.id == session.app_id.to_string())
+            })
+            .map_or_else(
+                || format!("App {}", session.app_id),
+                |game| game.title.clone(),
+            )
+    }
+
     /// Poll session state and update UI
     fn poll_session_status(&mut self) {
-        // First check cache for state updates (from in-flight or completed requests)
+        if let Some(polled) = POLLED_SESSION.write().take() {
+            self.polled_session = Some(polled);
+        }
+
         // First check cache for state updates (from in-flight or completed requests)
         if let Some(session) = cache::load_session_cache() {
-            if session.state == SessionState::Ready {
-                // User requested: "make it pull few times before connecting to it so you can get the candidates"
-                // We delay streaming start until we've polled a few times in Ready state
-                if self.session_ready_poll_count < 3 {
-                    self.status_message = format!(
-                        "Session ready, finalizing connection ({}/3)...",
-                        self.session_ready_poll_count + 1
-                    );
-                    // Don't return, allow fall-through to polling logic
-                } else {
+            match session::next_session_ui_state(&session, self.session_ready_poll_count) {
+                SessionUiUpdate::StartStreaming { status } => {
                     info!(
                         "Session ready! GPU: {:?}, Server: {}",
                         session.gpu_type, session.server_ip
                     );
-
-                    // Update status message
-                    if let Some(gpu) = &session.gpu_type {
-                        self.status_message = format!("Connecting to GPU: {}", gpu);
-                    } else {
-                        self.status_message =
-                            format!("Connecting to server: {}", session.server_ip);
-                    }
-
+                    self.status_message = status;
                     cache::clear_session_cache();
                     self.start_streaming(session);
                     return;
                 }
-            } else if let SessionState::InQueue { position, eta_secs } = session.state {
-                self.status_message = format!("Queue position: {} (ETA: {}s)", position, eta_secs);
-            } else if let SessionState::WatchingAds {
-                remaining_secs,
-                total_secs,
-            } = session.state
-            {
-                self.ads_required = true;
-                self.ads_remaining_secs = remaining_secs;
-                self.ads_total_secs = total_secs;
-                self.status_message =
-                    format!("Waiting for ads... (~{}s remaining)", remaining_secs);
-            } else if let SessionState::Error(ref msg) = session.state {
-                self.error_message = Some(msg.clone());
-                self.is_loading = false;
-                cache::clear_session_cache();
-                return;
-            } else if session.state == SessionState::Connecting {
-                self.status_message = "Connecting to server...".to_string();
-            } else if session.state == SessionState::CleaningUp {
-                self.status_message = "Cleaning up previous session...".to_string();
-            } else if session.state == SessionState::WaitingForStorage {
-                self.status_message = "Waiting for storage to be ready...".to_string();
-            } else {
-                self.status_message = "Setting up session...".to_string();
+                SessionUiUpdate::Failed(msg) => {
+                    self.error_message = Some(msg);
+                    self.is_loading = false;
+                    cache::clear_session_cache();
+                    return;
+                }
+                SessionUiUpdate::WatchingAds {
+                    status,
+                    remaining_secs,
+                    total_secs,
+                } => {
+                    self.ads_required = true;
+                    self.ads_remaining_secs = remaining_secs;
+                    self.ads_total_secs = total_secs;
+                    self.status_message = status;
+                }
+                SessionUiUpdate::Waiting { status, .. } => self.status_message = status,
             }
         }
 
+        if self.check_session_stuck() {
+            return;
+        }
+
         // Rate limit polling - only poll every POLL_INTERVAL (2 seconds)
         let now = std::time::Instant::now();
         if now.duration_since(self.last_poll_time) < POLL_INTERVAL {
@@ -1909,25 +3267,13 @@ impl App {
         }
 
         if let Some(session) = cache::load_session_cache() {
-            let mut should_poll = matches!(
-                session.state,
-                SessionState::Requesting
-                    | SessionState::Launching
-                    | SessionState::Connecting
-                    | SessionState::CleaningUp
-                    | SessionState::WaitingForStorage
-                    | SessionState::InQueue { .. }
-                    | SessionState::WatchingAds { .. }
-            );
-
-            // Also poll if Ready but count < 3
-            if session.state == SessionState::Ready && self.session_ready_poll_count < 3 {
-                should_poll = true;
-                // Increment poll count here, as we are about to poll
-                self.session_ready_poll_count += 1;
-            }
+            if session::next_session_ui_state(&session, self.session_ready_poll_count).should_poll()
+            {
+                // Ready sessions count their warm-up polls
+                if session.state == SessionState::Ready {
+                    self.session_ready_poll_count += 1;
+                }
 
-            if should_poll {
                 // Update timestamp to rate limit next poll
                 self.last_poll_time = now;
 
@@ -1956,6 +3302,8 @@ impl App {
                         Ok(updated_session) => {
                             info!("Session poll: state={:?}", updated_session.state);
                             cache::save_session_cache(&updated_session);
+                            *POLLED_SESSION.write() =
+                                Some((updated_session.session_id, updated_session.state));
                         }
                         Err(e) => {
                             error!("Session poll failed: {}", e);
@@ -1968,6 +3316,7 @@ impl App {
         // Check for session errors
         if let Some(error) = cache::load_session_error() {
             self.error_message = Some(error);
+            self.stream_settings_offer = STREAM_SETTINGS_OFFER.write().take();
             self.is_loading = false;
             cache::clear_session_error();
         }
@@ -1987,6 +3336,230 @@ impl App {
         }
     }
 
+    /// Track how long the session has been in its current setup state.
+    /// Returns true (and surfaces an error) if the per-state timeout was exceeded.
+    fn check_session_stuck(&mut self) -> bool {
+        if self.stuck_session_id.is_some() {
+            return true;
+        }
+
+        let Some((session_id, state)) = self.polled_session.clone() else {
+            return false;
+        };
+        let Some(elapsed) = self.stuck_tracker.check(&state, std::time::Instant::now()) else {
+            return false;
+        };
+
+        warn!(
+            "Session {} stuck in {:?} for {}s (timeout {}s)",
+            session_id,
+            state,
+            elapsed.as_secs(),
+            state.stuck_timeout().unwrap_or_default().as_secs()
+        );
+
+        self.error_message = Some(format!(
+            "Session setup is taking too long (stuck in {:?} for {}s). \
+             The server may be overloaded - retry or cancel.",
+            state,
+            elapsed.as_secs()
+        ));
+        self.status_message = "Session setup timed out".to_string();
+        self.is_loading = false;
+        self.reset_stuck_tracking();
+        self.stuck_session_id = Some(session_id);
+
+        // Stop polling the stuck session
+        cache::clear_session_cache();
+        true
+    }
+
+    /// Forget the polled setup state and any stuck session
+    fn reset_stuck_tracking(&mut self) {
+        *POLLED_SESSION.write() = None;
+        self.polled_session = None;
+        self.stuck_tracker = session::StuckTracker::default();
+        self.stuck_session_id = None;
+    }
+
+    /// Retry launching after a session got stuck during setup
+    fn retry_stuck_session(&mut self) {
+        let game = match self.selected_game.clone() {
+            Some(g) => g,
+            None => {
+                self.stop_streaming();
+                return;
+            }
+        };
+
+        self.error_message = None;
+        self.is_loading = true;
+
+        match self.stuck_session_id.take() {
+            // Terminate the stuck session server-side before launching again
+            Some(session_id) => self.terminate_and_launch(vec![session_id], game),
+            None => self.check_sessions_and_launch(&game),
+        }
+    }
+
+    /// Terminate the session after `idle_timeout_minutes` without input,
+    /// showing a countdown for the last minute so the user can cancel it by moving
+    fn check_idle_timeout(&mut self) {
+        let minutes = self.settings.idle_timeout_minutes;
+        if self.state != AppState::Streaming || minutes == 0 {
+            self.idle_terminate_in_secs = None;
+            return;
+        }
+
+        let timeout = std::time::Duration::from_secs(minutes as u64 * 60);
+        let idle = crate::input::idle_duration();
+        if idle >= timeout {
+            warn!(
+                "No input for {} minutes - terminating session to save playtime",
+                minutes
+            );
+            self.idle_terminate_in_secs = None;
+            self.terminate_current_session();
+        } else if timeout - idle <= IDLE_WARNING_DURATION {
+            let remaining = (timeout - idle).as_secs() + 1;
+            if self.idle_terminate_in_secs.is_none() {
+                info!("Idle timeout warning: session ends in {}s", remaining);
+            }
+            self.idle_terminate_in_secs = Some(remaining);
+        } else {
+            self.idle_terminate_in_secs = None;
+        }
+    }
+
+    /// Detect a system suspend/resume by looking for a large clock gap between updates
+    fn detect_system_wake(&mut self) {
+        let now_wall = std::time::SystemTime::now();
+        let now_instant = std::time::Instant::now();
+        let wall_gap = now_wall
+            .duration_since(self.last_update_wall_time)
+            .unwrap_or_default();
+        let instant_gap = now_instant.duration_since(self.last_update_instant);
+        self.last_update_wall_time = now_wall;
+        self.last_update_instant = now_instant;
+
+        if self.state != AppState::Streaming {
+            return;
+        }
+
+        // The monotonic clock stops during suspend on Linux/macOS, so sleep shows up as
+        // wall time advancing without it. On Windows it keeps counting, so use the raw gap.
+        let suspended = if cfg!(target_os = "windows") {
+            wall_gap
+        } else {
+            wall_gap.saturating_sub(instant_gap)
+        };
+        if suspended < SUSPEND_DETECT_GAP {
+            return;
+        }
+
+        warn!(
+            "System wake detected: ~{}s suspended (wall gap {}s, monotonic gap {}s)",
+            suspended.as_secs(),
+            wall_gap.as_secs(),
+            instant_gap.as_secs()
+        );
+        self.handle_system_wake(suspended);
+    }
+
+    /// Recover the stream after the system woke from sleep
+    fn handle_system_wake(&mut self, suspended: std::time::Duration) {
+        // Input timestamps are based on session start, rebase them after the clock jump
+        crate::input::reset_session_timing();
+        crate::input::init_session_timing();
+
+        // Decoder state is stale after sleep - request a fresh keyframe
+        self.runtime.spawn(async {
+            info!("Requesting keyframe after system wake");
+            crate::webrtc::request_keyframe().await;
+        });
+
+        let session_id = match &self.session {
+            Some(s) => s.session_id.clone(),
+            None => return,
+        };
+        let token = match &self.auth_tokens {
+            Some(t) => t.jwt().to_string(),
+            None => return,
+        };
+
+        // Check the session still exists server-side (also re-validates the token,
+        // since the API client refreshes on 401)
+        self.pending_wake_check = Some(suspended);
+        cache::take_wake_check(); // Drop any stale result
+
+        let mut api_client = GfnApiClient::new();
+        api_client.set_access_token(token);
+        self.runtime.spawn(async move {
+            let mut result = cache::WakeCheckResult::Unknown;
+            // The network is often not back yet right after wake, so retry a few times
+            for attempt in 1..=5 {
+                match api_client.get_active_sessions().await {
+                    Ok(sessions) => {
+                        result = match sessions.into_iter().find(|s| s.session_id == session_id) {
+                            Some(active) => cache::WakeCheckResult::Alive(active),
+                            None => cache::WakeCheckResult::Ended,
+                        };
+                        break;
+                    }
+                    Err(e) => {
+                        warn!("Wake session check attempt {} failed: {}", attempt, e);
+                        tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+                    }
+                }
+            }
+            cache::save_wake_check(&result);
+        });
+    }
+
+    /// Apply the result of the post-wake session check
+    fn handle_wake_check(&mut self, result: cache::WakeCheckResult) {
+        let suspended = self.pending_wake_check.take().unwrap_or_default();
+        if self.state != AppState::Streaming {
+            return;
+        }
+
+        match result {
+            cache::WakeCheckResult::Alive(active) if suspended >= WAKE_RECONNECT_THRESHOLD => {
+                // WebRTC connection is dead after a long sleep, re-attach via the resume flow
+                info!(
+                    "Session {} still active after {}s sleep - resuming",
+                    active.session_id,
+                    suspended.as_secs()
+                );
+                let game = self.selected_game.clone();
+                self.stop_streaming();
+                self.selected_game = game;
+                self.resume_session(active);
+            }
+            cache::WakeCheckResult::Alive(active) => {
+                info!("Session {} still active after wake", active.session_id);
+            }
+            cache::WakeCheckResult::Ended => {
+                warn!("Session ended while the system was asleep");
+                let game = self.selected_game.clone();
+                self.stop_streaming();
+
+                // Stay on the session screen so the user sees why the stream stopped
+                self.selected_game = game;
+                self.state = AppState::Session;
+                self.status_message = "Session ended".to_string();
+                self.error_message = Some(
+                    "The session ended while the system was asleep. \
+                     Cancel to return to the library and launch again."
+                        .to_string(),
+                );
+            }
+            cache::WakeCheckResult::Unknown => {
+                warn!("Could not verify session after wake - keeping current stream");
+            }
+        }
+    }
+
     /// Start streaming once session is ready
     pub fn start_streaming(&mut self, session: SessionInfo) {
         info!("Starting streaming to {}", session.server_ip);
@@ -1999,19 +3572,28 @@ impl App {
 
         // Reset session ready poll count for this new session
         self.session_ready_poll_count = 0;
+        self.resolution_downgrade_warning = None;
+        self.chroma_downgrade_warning = None;
+        self.decode_slowdown_warning = None;
+        self.decode_monitor = decode_monitor::DecodeMonitor::new();
+
+        if self.settings.record_stats_csv {
+            self.start_stats_log();
+        }
 
         // Initialize session timing for proper input timestamps
         // This must be called BEFORE any input events are sent
         crate::input::init_session_timing();
+        crate::input::set_mouse_send_rate(self.settings.mouse_send_rate_hz);
 
         // Set local cursor dimensions for instant visual feedback
         // Parse resolution from settings (e.g., "1920x1080" -> width, height)
-        let (width, height) = parse_resolution(&self.settings.resolution);
-        #[cfg(any(target_os = "windows", target_os = "macos"))]
+        let (width, height) = parse_resolution(&self.session_resolution());
+        #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
         crate::input::set_local_cursor_dimensions(width, height);
 
         // Reset coalescing state to ensure clean input state for new session
-        #[cfg(any(target_os = "windows", target_os = "macos"))]
+        #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
         crate::input::reset_coalescing();
 
         info!(
@@ -2020,8 +3602,8 @@ impl App {
         );
 
         // Create shared frame holder for zero-latency frame delivery
-        // No buffering - decoder writes latest frame, renderer reads it immediately
-        let shared_frame = Arc::new(SharedFrame::new());
+        // Decoder writes frames, renderer reads them immediately (a short queue with BufferSmooth)
+        let shared_frame = Arc::new(SharedFrame::with_policy(self.settings.under_load_policy));
         self.shared_frame = Some(shared_frame.clone());
 
         // Stats channel (small buffer is fine for stats)
@@ -2032,6 +3614,8 @@ impl App {
         );
 
         self.stats_rx = Some(stats_rx);
+        self.session_totals = Some(session_summary::SessionTotals::new());
+        self.session_summary = None;
 
         // Create input handler with clean state
         let input_handler = Arc::new(InputHandler::new());
@@ -2039,14 +3623,21 @@ impl App {
 
         self.status_message = "Connecting...".to_string();
 
+        self.stream_bitrate_mbps = self.settings.max_bitrate_mbps;
+
         // Clone settings for the async task
-        let settings = self.settings.clone();
+        let mut settings = self.session_settings();
+        if settings.safe_mode_active() {
+            info!("Safe mode: forcing software video decoder");
+            settings.decoder_backend = config::VideoDecoderBackend::Software;
+        }
 
         // Spawn the streaming task
         let runtime = self.runtime.clone();
         runtime.spawn(async move {
             use crate::webrtc::StreamingResult;
 
+            let connection_sounds = settings.connection_sounds;
             match crate::webrtc::run_streaming(
                 session.clone(),
                 settings.clone(),
@@ -2077,7 +3668,9 @@ impl App {
                     info!("Auto-reconnecting to session {}...", session.session_id);
 
                     // Create new shared frame for reconnection
-                    let new_shared_frame = std::sync::Arc::new(crate::app::SharedFrame::new());
+                    let new_shared_frame = std::sync::Arc::new(crate::app::SharedFrame::with_policy(
+                        settings.under_load_policy,
+                    ));
 
                     // Create new stats channel
                     let (new_stats_tx, _new_stats_rx) = tokio::sync::mpsc::channel(8);
@@ -2085,6 +3678,9 @@ impl App {
                     // Create new input handler
                     let new_input_handler = std::sync::Arc::new(crate::input::InputHandler::new());
 
+                    // Start a fresh input timeline for the new connection
+                    crate::input::init_session_timing();
+
                     // Attempt reconnection
                     match crate::webrtc::run_streaming(
                         session,
@@ -2111,6 +3707,10 @@ impl App {
                     }
                 }
             }
+
+            if connection_sounds {
+                crate::media::play_chime(crate::media::Chime::Disconnected);
+            }
         });
     }
 
@@ -2156,6 +3756,41 @@ impl App {
         self.stop_streaming();
     }
 
+    /// End the running or queued session before the app exits, unless the user keeps
+    /// sessions alive on quit. Blocks for at most QUIT_STOP_TIMEOUT.
+    pub fn shutdown(&mut self) {
+        let Some(session) = self
+            .session
+            .clone()
+            .or_else(cache::load_session_cache)
+            .filter(|s| !s.session_id.is_empty())
+        else {
+            return;
+        };
+        if self.settings.keep_session_on_quit {
+            info!("Keeping session {} alive on quit", session.session_id);
+            return;
+        }
+        let Some(token) = self.auth_tokens.as_ref().map(|t| t.jwt().to_string()) else {
+            return;
+        };
+
+        info!("Stopping session {} before exit", session.session_id);
+        let mut api_client = GfnApiClient::new();
+        api_client.set_access_token(token);
+        let server_ip = (!session.server_ip.is_empty()).then(|| session.server_ip.clone());
+        let result = self.runtime.block_on(tokio::time::timeout(
+            QUIT_STOP_TIMEOUT,
+            api_client.stop_session(&session.session_id, &session.zone, server_ip.as_deref()),
+        ));
+        match result {
+            Ok(Ok(())) => info!("Session {} stopped", session.session_id),
+            Ok(Err(e)) => warn!("Failed to stop session on exit: {}", e),
+            Err(_) => warn!("Timed out stopping session on exit"),
+        }
+        cache::clear_session_cache();
+    }
+
     /// Stop streaming and return to games
     pub fn stop_streaming(&mut self) {
         info!("Stopping streaming");
@@ -2167,11 +3802,11 @@ impl App {
         crate::input::reset_session_timing();
 
         // Reset input coalescing and local cursor state
-        #[cfg(any(target_os = "windows", target_os = "macos"))]
+        #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
         crate::input::reset_coalescing();
 
         // Clear raw input sender to prevent stale events from being processed
-        #[cfg(any(target_os = "windows", target_os = "macos"))]
+        #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
         crate::input::clear_raw_input_sender();
 
         self.cursor_captured = false;
@@ -2181,7 +3816,39 @@ impl App {
         self.input_handler = None;
         self.current_frame = None;
         self.shared_frame = None;
+        self.frame_pacer.reset();
         self.stats_rx = None;
+        if let Some(log) = self.stats_log.take() {
+            info!("Stats recording saved: {}", log.path().display());
+        }
+        let game_title = self
+            .selected_game
+            .as_ref()
+            .map_or("session", |game| game.title.as_str());
+        if let Some(summary) = self
+            .session_totals
+            .take()
+            .and_then(|totals| totals.finish(game_title))
+        {
+            info!(
+                "Session summary for {}: {} over {}, {:.1} Mbps avg / {:.1} Mbps peak, {:.0} fps avg, {:.2}% packet loss",
+                summary.game_title,
+                summary.data_used(),
+                summary.duration_text(),
+                summary.avg_bitrate_mbps,
+                summary.peak_bitrate_mbps,
+                summary.avg_fps,
+                summary.avg_packet_loss_pct
+            );
+            if self.settings.show_session_summary && self.connection_test.is_none() {
+                self.session_summary = Some(summary);
+            }
+        }
+        if let Some(test) = self.connection_test.take() {
+            info!("Connection test with {} ended early", test.game_title);
+            self.connection_test_result =
+                Some(Err("The session ended before the test finished".to_string()));
+        }
         self.selected_game = None;
         self.is_loading = false;
         self.error_message = None;
@@ -2194,12 +3861,145 @@ impl App {
         self.ads_remaining_secs = 0;
         self.ads_total_secs = 0;
 
+        self.resolution_downgrade_warning = None;
+        self.chroma_downgrade_warning = None;
+        self.texture_limit_warning = None;
+        self.decode_slowdown_warning = None;
+
+        // Reset stuck session tracking
+        self.reset_stuck_tracking();
+        self.stream_settings_offer = None;
+
+        self.pending_wake_check = None;
+        self.idle_terminate_in_secs = None;
+
         self.status_message = "Stream ended".to_string();
     }
 
-    /// Toggle stats overlay
+    /// Show the low GPU memory warning and cap the stream resolution to 1080p,
+    /// since V3D cannot allocate larger textures without enough GPU memory
+    pub fn set_gpu_memory_warning(&mut self, message: String) {
+        let (width, height) = self.settings.resolution_tuple();
+        let mut message = message;
+        self.cap_resolution("1920x1080");
+        if width * height > 1920 * 1080 {
+            warn!(
+                "Low GPU memory: streaming at 1920x1080 instead of {}x{}",
+                width, height
+            );
+            message.push_str(&format!(
+                "\n\nStreams on this GPU use 1920x1080 instead of {}x{}. Your saved resolution is unchanged.",
+                width, height
+            ));
+        }
+        self.gpu_memory_warning = Some(message);
+    }
+
+    /// Limit the resolution of future sessions (keeps the lowest cap)
+    fn cap_resolution(&mut self, cap: &'static str) {
+        let (width, height) = parse_resolution(cap);
+        let lower = self.resolution_cap.is_none_or(|current| {
+            let (current_width, current_height) = parse_resolution(current);
+            width * height < current_width * current_height
+        });
+        if lower {
+            self.resolution_cap = Some(cap);
+        }
+    }
+
+    /// Resolution to request for a session: the saved one, lowered to the GPU's cap
+    fn session_resolution(&self) -> String {
+        let (width, height) = self.settings.resolution_tuple();
+        match self.resolution_cap {
+            Some(cap) => {
+                let (cap_width, cap_height) = parse_resolution(cap);
+                if width > cap_width || height > cap_height {
+                    cap.to_string()
+                } else {
+                    self.settings.resolution.clone()
+                }
+            }
+            None => self.settings.resolution.clone(),
+        }
+    }
+
+    /// Settings for a session, with the resolution lowered to what the GPU can handle
+    fn session_settings(&self) -> Settings {
+        let mut settings = self.settings.clone();
+        settings.resolution = self.session_resolution();
+        settings
+    }
+
+    /// Record the GPU adapter and backend (reported by the renderer)
+    pub fn set_gpu_description(&mut self, description: String) {
+        crate::utils::set_crash_gpu(description.clone());
+        self.gpu_description = description;
+    }
+
+    /// Record the renderer's GPU adapter, which decides the recommended codec
+    /// (checked once here so the codec dropdown doesn't stall on first open)
+    pub fn set_gpu_adapter_name(&mut self, name: &str) {
+        crate::media::set_primary_gpu_name(name);
+        let recommendation = crate::media::recommended_codec(self.settings.decoder_backend);
+        if recommendation.codec == self.settings.codec {
+            return;
+        }
+        if self.first_run {
+            info!(
+                "First run: defaulting to {:?} for {} ({})",
+                recommendation.codec, name, recommendation.reason
+            );
+            self.settings.codec = recommendation.codec;
+            self.save_settings();
+        } else {
+            info!(
+                "Saved codec {:?} differs from the recommended {:?} ({})",
+                self.settings.codec, recommendation.codec, recommendation.reason
+            );
+        }
+    }
+
+    /// Record the GPU's maximum texture dimension (reported by the renderer)
+    pub fn set_max_texture_dimension(&mut self, max_dimension: u32) {
+        info!("GPU max texture dimension: {}", max_dimension);
+        self.max_texture_dimension = max_dimension;
+    }
+
+    /// Lower the session's resolution if decoded frames wouldn't fit in a GPU texture
+    /// (Raspberry Pi V3D tops out at 2048, so a 4K stream could never be displayed).
+    /// The saved setting is kept for when the app runs on a bigger GPU
+    fn apply_texture_limit(&mut self) {
+        let max = self.max_texture_dimension;
+        let (width, height) = self.settings.resolution_tuple();
+        if max == 0 || (width <= max && height <= max) {
+            return;
+        }
+
+        let Some(capped) = config::max_resolution_for_texture_limit(max) else {
+            warn!(
+                "GPU texture limit {} is below every supported resolution",
+                max
+            );
+            return;
+        };
+        let (capped_width, capped_height) = parse_resolution(capped);
+        warn!(
+            "{}x{} exceeds GPU texture limit {} - requesting {} instead",
+            width, height, max, capped
+        );
+        self.texture_limit_warning = Some(format!(
+            "Your GPU supports up to {} - streaming at that instead of {}",
+            resolution_label(capped_width, capped_height),
+            resolution_label(width, height)
+        ));
+        self.cap_resolution(capped);
+    }
+
+    /// Toggle stats overlay (persisted so the choice survives restarts)
     pub fn toggle_stats(&mut self) {
         self.show_stats = !self.show_stats;
+        self.settings.show_stats = self.show_stats;
+        self.save_settings();
     }
 
     /// Save settings
@@ -0,0 +1,120 @@
+//! Queue Alerts
+//!
+//! Background watch on PrintedWaste queue times for one location. Raises a desktop
+//! notification once the wait drops below the chosen threshold, then stops.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use log::{info, warn};
+use parking_lot::Mutex;
+use tokio::runtime::Handle;
+
+use crate::api::QueueServerInfo;
+
+/// Threshold choices in minutes
+pub const QUEUE_ALERT_THRESHOLD_OPTIONS: &[u32] = &[1, 5, 10, 15, 30];
+
+pub const DEFAULT_QUEUE_ALERT_THRESHOLD_MINUTES: u32 = 5;
+
+/// How often queue times are polled while an alert is set
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Bumped on every start/cancel so an outdated watch stops at its next poll
+static WATCH_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Alert that fired (with the wait in seconds), picked up by the app
+static FIRED_ALERT: Mutex<Option<(QueueAlert, i64)>> = Mutex::new(None);
+
+/// Location to watch, e.g. "Frankfurt" in region "EU"
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueAlert {
+    /// Simple region from the queue API
+    pub region: String,
+    /// Location display name
+    pub location: String,
+    pub threshold_minutes: u32,
+}
+
+/// Start watching (replaces any running watch)
+pub fn start_watch(runtime: &Handle, alert: QueueAlert, queue_url: String, mapping_url: String) {
+    let generation = WATCH_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    info!(
+        "Queue alert set: {} ({}) under {} min",
+        alert.location, alert.region, alert.threshold_minutes
+    );
+
+    runtime.spawn(async move {
+        let client = reqwest::Client::new();
+        while WATCH_GENERATION.load(Ordering::SeqCst) == generation {
+            match crate::api::fetch_queue_servers(&client, &queue_url, &mapping_url).await {
+                Ok(servers) => {
+                    if WATCH_GENERATION.load(Ordering::SeqCst) != generation {
+                        break;
+                    }
+                    if let Some(wait) = location_wait_secs(&servers, &alert) {
+                        if wait < alert.threshold_minutes as i64 * 60 {
+                            info!("Queue alert: {} wait is {}s", alert.location, wait);
+                            crate::utils::show_desktop_notification(
+                                "GeForce NOW queue is short",
+                                &format!(
+                                    "{} queue is down to {}",
+                                    alert.location,
+                                    crate::api::format_queue_eta(Some(wait))
+                                ),
+                            );
+                            *FIRED_ALERT.lock() = Some((alert, wait));
+                            break;
+                        }
+                    }
+                }
+                Err(e) => warn!("Queue alert poll failed: {}", e),
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    });
+}
+
+/// Stop the running watch, if any
+pub fn cancel_watch() {
+    WATCH_GENERATION.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Alert that fired since the last call
+pub fn take_fired_alert() -> Option<(QueueAlert, i64)> {
+    FIRED_ALERT.lock().take()
+}
+
+/// Shortest known wait among the location's rigs (None if the location isn't
+/// listed or none of its rigs report an ETA)
+fn location_wait_secs(servers: &[QueueServerInfo], alert: &QueueAlert) -> Option<i64> {
+    servers
+        .iter()
+        .filter(|s| s.region == alert.region && s.display_name == alert.location)
+        .filter_map(|s| s.eta_seconds)
+        .map(|eta| eta.max(0))
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location_wait_is_shortest_rig() {
+        let alert = QueueAlert {
+            region: "EU".to_string(),
+            location: "Frankfurt".to_string(),
+            threshold_minutes: 5,
+        };
+        let servers = [
+            QueueServerInfo::for_test("EU", "Frankfurt", Some(1200)),
+            QueueServerInfo::for_test("EU", "Frankfurt", None),
+            QueueServerInfo::for_test("EU", "Frankfurt", Some(240)),
+            QueueServerInfo::for_test("EU", "Paris", Some(0)),
+        ];
+        // A rig without an ETA isn't a zero wait
+        assert_eq!(location_wait_secs(&servers, &alert), Some(240));
+        assert_eq!(location_wait_secs(&servers[1..2], &alert), None);
+        assert_eq!(location_wait_secs(&servers[3..], &alert), None);
+    }
+}
@@ -0,0 +1,138 @@
+//! Queue History
+//!
+//! Queue waits from PrintedWaste, averaged per region into hour-of-week buckets
+//! (local time) so the Queue Times tab can show when queues are usually short.
+//! Samples are only taken while OpenNOW fetches queue data, so the map fills in
+//! over the hours the app is actually used.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Datelike, Local, Timelike};
+use serde::{Deserialize, Serialize};
+
+use crate::api::QueueServerInfo;
+
+/// Hour-of-week buckets (Monday 00:00 first)
+pub const HOURS_PER_WEEK: usize = 7 * 24;
+
+/// Minimum time between samples, so a long stay on the Queue Times tab
+/// (refreshing every 30s) doesn't outweigh everything else
+const SAMPLE_INTERVAL_SECS: i64 = 15 * 60;
+
+/// Average queue wait for one hour of the week
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct QueueHistoryBucket {
+    pub total_eta_secs: i64,
+    pub samples: u32,
+}
+
+impl QueueHistoryBucket {
+    /// Average wait in seconds, if any samples were taken
+    pub fn average_eta_secs(&self) -> Option<i64> {
+        (self.samples > 0).then(|| self.total_eta_secs / self.samples as i64)
+    }
+}
+
+/// Hour-of-week queue averages per region (the simple region from the queue API, e.g. "EU")
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QueueHistory {
+    /// Unix time of the last recorded sample
+    pub last_sample_at: i64,
+    pub regions: HashMap<String, Vec<QueueHistoryBucket>>,
+}
+
+impl QueueHistory {
+    /// Add the average wait of each region to the current hour's bucket (rigs
+    /// without an ETA are skipped). Returns false when the last sample is too
+    /// recent or no rig reported an ETA (nothing recorded)
+    pub fn record(&mut self, servers: &[QueueServerInfo], now: DateTime<Local>) -> bool {
+        let timestamp = now.timestamp();
+        if (0..SAMPLE_INTERVAL_SECS).contains(&(timestamp - self.last_sample_at)) {
+            return false;
+        }
+
+        let mut region_etas: HashMap<&str, (i64, i64)> = HashMap::new();
+        for server in servers {
+            let Some(eta) = server.eta_seconds else {
+                continue;
+            };
+            let entry = region_etas.entry(server.region.as_str()).or_default();
+            entry.0 += eta.max(0);
+            entry.1 += 1;
+        }
+        if region_etas.is_empty() {
+            return false;
+        }
+
+        let hour = hour_of_week(now);
+        for (region, (total, count)) in region_etas {
+            let buckets = self
+                .regions
+                .entry(region.to_string())
+                .or_insert_with(|| vec![QueueHistoryBucket::default(); HOURS_PER_WEEK]);
+            buckets.resize(HOURS_PER_WEEK, QueueHistoryBucket::default());
+            buckets[hour].total_eta_secs += total / count;
+            buckets[hour].samples += 1;
+        }
+        self.last_sample_at = timestamp;
+        true
+    }
+
+    /// Buckets for a region (empty if it was never sampled)
+    pub fn buckets(&self, region: &str) -> &[QueueHistoryBucket] {
+        self.regions.get(region).map_or(&[], |b| b.as_slice())
+    }
+
+    /// Hour of the week with the shortest average wait for a region
+    pub fn best_hour(&self, region: &str) -> Option<(usize, i64)> {
+        self.buckets(region)
+            .iter()
+            .enumerate()
+            .filter_map(|(hour, bucket)| Some((hour, bucket.average_eta_secs()?)))
+            .min_by_key(|&(_, eta)| eta)
+    }
+}
+
+/// Bucket index for a local time (Monday 00:00 = 0)
+pub fn hour_of_week(time: DateTime<Local>) -> usize {
+    time.weekday().num_days_from_monday() as usize * 24 + time.hour() as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn server(region: &str, eta_seconds: Option<i64>) -> QueueServerInfo {
+        QueueServerInfo::for_test(region, "Test", eta_seconds)
+    }
+
+    #[test]
+    fn test_samples_average_per_region_and_hour() {
+        let mut history = QueueHistory::default();
+        // Tuesday 07:30 local
+        let tuesday = Local.with_ymd_and_hms(2024, 1, 2, 7, 30, 0).unwrap();
+        assert!(history.record(
+            &[
+                server("EU", Some(600)),
+                server("EU", Some(0)),
+                server("US", None)
+            ],
+            tuesday
+        ));
+        // Too soon after the last sample
+        assert!(!history.record(&[server("EU", Some(6000))], tuesday));
+        assert!(history.record(
+            &[server("EU", Some(100))],
+            tuesday + chrono::Duration::minutes(20)
+        ));
+
+        let hour = hour_of_week(tuesday);
+        assert_eq!(hour, 24 + 7);
+        assert_eq!(history.buckets("EU")[hour].samples, 2);
+        assert_eq!(history.buckets("EU")[hour].average_eta_secs(), Some(200));
+        // A region without ETAs isn't recorded as a zero wait
+        assert_eq!(history.best_hour("US"), None);
+        assert_eq!(history.best_hour("EU"), Some((hour, 200)));
+    }
+}
@@ -4,6 +4,10 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::config::{nearest_entitled_mode, Settings, VideoCodec};
+use super::types::EntitledResolution;
+use crate::api::error_codes::StreamSettingRejection;
+
 /// Session information
 #[derive(Debug, Clone)]
 pub struct SessionInfo {
@@ -118,6 +122,48 @@ pub enum SessionState {
     Terminated,
 }
 
+impl SessionState {
+    /// Maximum time a session may sit in this state before it is considered stuck.
+    /// Queue and ad states return None since their duration depends on demand.
+    pub fn stuck_timeout(&self) -> Option<std::time::Duration> {
+        let secs = match self {
+            SessionState::Requesting => 60,
+            SessionState::Connecting => 120,
+            SessionState::Launching => 180,
+            SessionState::CleaningUp => 180,
+            SessionState::WaitingForStorage => 300,
+            _ => return None,
+        };
+        Some(std::time::Duration::from_secs(secs))
+    }
+}
+
+/// Tracks how long a session has been in its current setup state (for stuck detection)
+#[derive(Debug, Default)]
+pub struct StuckTracker {
+    since: Option<(std::mem::Discriminant<SessionState>, std::time::Instant)>,
+}
+
+impl StuckTracker {
+    /// Note the latest polled state; returns how long it has lasted once past its timeout
+    pub fn check(
+        &mut self,
+        state: &SessionState,
+        now: std::time::Instant,
+    ) -> Option<std::time::Duration> {
+        let discriminant = std::mem::discriminant(state);
+        let since = match self.since {
+            Some((d, since)) if d == discriminant => since,
+            _ => {
+                self.since = Some((discriminant, now));
+                now
+            }
+        };
+        let elapsed = now.duration_since(since);
+        (elapsed >= state.stuck_timeout()?).then_some(elapsed)
+    }
+}
+
 impl SessionInfo {
     /// Create a new session in requesting state
     pub fn new_requesting(zone: &str) -> Self {
@@ -154,6 +200,158 @@ impl SessionInfo {
     }
 }
 
+/// Polls made in the Ready state before streaming starts, so the server has
+/// time to publish its ICE candidates
+pub const READY_WARMUP_POLLS: u32 = 3;
+
+/// What the session screen should do for the latest polled session state
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionUiUpdate {
+    /// Still setting up: show `status`, and poll the server again if `poll`
+    Waiting { status: String, poll: bool },
+    /// Free tier ads are playing while queued (keeps polling)
+    WatchingAds {
+        status: String,
+        remaining_secs: u32,
+        total_secs: u32,
+    },
+    /// Ready and warmed up: start streaming with `status` shown
+    StartStreaming { status: String },
+    /// Setup failed with this message
+    Failed(String),
+}
+
+impl SessionUiUpdate {
+    /// Whether the server should be polled again
+    pub fn should_poll(&self) -> bool {
+        match self {
+            SessionUiUpdate::Waiting { poll, .. } => *poll,
+            SessionUiUpdate::WatchingAds { .. } => true,
+            SessionUiUpdate::StartStreaming { .. } | SessionUiUpdate::Failed(_) => false,
+        }
+    }
+}
+
+/// Session screen update for the last polled session.
+/// `ready_polls` counts the polls already made since the session became Ready.
+/// A failed poll (e.g. HTTP 500) leaves the previous session cached, so the same
+/// state comes back here and is polled again on the next interval.
+pub fn next_session_ui_state(current: &SessionInfo, ready_polls: u32) -> SessionUiUpdate {
+    let waiting = |status: String, poll: bool| SessionUiUpdate::Waiting { status, poll };
+    match current.state {
+        SessionState::Ready if ready_polls < READY_WARMUP_POLLS => waiting(
+            format!(
+                "Session ready, finalizing connection ({}/{})...",
+                ready_polls + 1,
+                READY_WARMUP_POLLS
+            ),
+            true,
+        ),
+        SessionState::Ready => SessionUiUpdate::StartStreaming {
+            status: match &current.gpu_type {
+                Some(gpu) => format!("Connecting to GPU: {}", gpu),
+                None => format!("Connecting to server: {}", current.server_ip),
+            },
+        },
+        SessionState::InQueue { position, eta_secs } => waiting(
+            format!("Queue position: {} (ETA: {}s)", position, eta_secs),
+            true,
+        ),
+        SessionState::WatchingAds {
+            remaining_secs,
+            total_secs,
+        } => SessionUiUpdate::WatchingAds {
+            // The server ends the ad break; until it does, don't show a stuck "~0s"
+            status: if remaining_secs > 0 {
+                format!("Waiting for ads... (~{}s remaining)", remaining_secs)
+            } else {
+                "Ads finished, waiting for the queue...".to_string()
+            },
+            remaining_secs,
+            total_secs,
+        },
+        SessionState::Error(ref msg) => SessionUiUpdate::Failed(msg.clone()),
+        SessionState::Connecting => waiting("Connecting to server...".to_string(), true),
+        SessionState::CleaningUp => waiting("Cleaning up previous session...".to_string(), true),
+        SessionState::WaitingForStorage => {
+            waiting("Waiting for storage to be ready...".to_string(), true)
+        }
+        SessionState::Requesting | SessionState::Launching => {
+            waiting("Setting up session...".to_string(), true)
+        }
+        SessionState::Streaming | SessionState::Terminated => {
+            waiting("Setting up session...".to_string(), false)
+        }
+    }
+}
+
+/// Settings to retry with after the server refused the requested stream
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamSettingsOffer {
+    /// What was refused, e.g. "Your membership doesn't support 4K at 120 FPS"
+    pub message: String,
+    /// Button text, e.g. "Retry at 1440p 120 FPS"
+    pub retry_label: String,
+    pub resolution: String,
+    pub fps: u32,
+    pub codec: VideoCodec,
+    pub hdr_enabled: bool,
+}
+
+/// The closest settings to `settings` that avoid a stream setting rejection,
+/// None when nothing can be changed to get past it
+pub fn stream_settings_offer(
+    rejection: StreamSettingRejection,
+    settings: &Settings,
+    entitled: &[EntitledResolution],
+) -> Option<StreamSettingsOffer> {
+    let (width, height) = settings.resolution_tuple();
+    let mut offer = StreamSettingsOffer {
+        message: String::new(),
+        retry_label: String::new(),
+        resolution: settings.resolution.clone(),
+        fps: settings.fps,
+        codec: settings.codec,
+        hdr_enabled: settings.hdr_enabled,
+    };
+    match rejection {
+        StreamSettingRejection::Mode => {
+            let (resolution, fps) = nearest_entitled_mode(width, height, settings.fps, entitled)?;
+            if (resolution.as_str(), fps) == (settings.resolution.as_str(), settings.fps) {
+                return None;
+            }
+            offer.message = format!(
+                "Your membership doesn't support {} at {} FPS",
+                super::resolution_label(width, height),
+                settings.fps
+            );
+            let (new_width, new_height) = super::parse_resolution(&resolution);
+            offer.retry_label = format!(
+                "Retry at {} {} FPS",
+                super::resolution_label(new_width, new_height),
+                fps
+            );
+            offer.resolution = resolution;
+            offer.fps = fps;
+        }
+        StreamSettingRejection::Codec if settings.codec != VideoCodec::H264 => {
+            offer.message = format!(
+                "The server doesn't offer {} for this game",
+                settings.codec.as_str()
+            );
+            offer.retry_label = "Retry with H.264".to_string();
+            offer.codec = VideoCodec::H264;
+        }
+        StreamSettingRejection::Hdr if settings.hdr_enabled => {
+            offer.message = "HDR isn't available for this game or membership".to_string();
+            offer.retry_label = "Retry in SDR".to_string();
+            offer.hdr_enabled = false;
+        }
+        StreamSettingRejection::Codec | StreamSettingRejection::Hdr => return None,
+    }
+    Some(offer)
+}
+
 // ============================================
 // CloudMatch API Request Types (Browser Format)
 // ============================================
@@ -598,3 +796,174 @@ pub struct ActiveSessionInfo {
     pub resolution: Option<String>,
     pub fps: Option<u32>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(state: SessionState) -> SessionInfo {
+        SessionInfo {
+            state,
+            server_ip: "10.0.0.1".to_string(),
+            ..SessionInfo::new_requesting("test")
+        }
+    }
+
+    #[test]
+    fn test_queue_to_ready_to_streaming() {
+        let queued = session(SessionState::InQueue {
+            position: 4,
+            eta_secs: 60,
+        });
+        let update = next_session_ui_state(&queued, 0);
+        assert!(update.should_poll());
+        assert_eq!(
+            update,
+            SessionUiUpdate::Waiting {
+                status: "Queue position: 4 (ETA: 60s)".to_string(),
+                poll: true
+            }
+        );
+
+        // Ready keeps polling for the warm-up polls before streaming starts
+        let mut ready = session(SessionState::Ready);
+        ready.gpu_type = Some("RTX 4080".to_string());
+        for polls in 0..READY_WARMUP_POLLS {
+            assert!(next_session_ui_state(&ready, polls).should_poll());
+        }
+        let update = next_session_ui_state(&ready, READY_WARMUP_POLLS);
+        assert_eq!(
+            update,
+            SessionUiUpdate::StartStreaming {
+                status: "Connecting to GPU: RTX 4080".to_string()
+            }
+        );
+        assert!(!update.should_poll());
+
+        // Once streaming, the session screen stops polling
+        let streaming = session(SessionState::Streaming);
+        assert!(!next_session_ui_state(&streaming, READY_WARMUP_POLLS).should_poll());
+    }
+
+    #[test]
+    fn test_stuck_tracker_times_out_each_setup_state() {
+        let start = std::time::Instant::now();
+        for state in [
+            SessionState::Requesting,
+            SessionState::Connecting,
+            SessionState::Launching,
+            SessionState::CleaningUp,
+            SessionState::WaitingForStorage,
+        ] {
+            let timeout = state.stuck_timeout().unwrap();
+            let mut tracker = StuckTracker::default();
+            // A different state first: the timer starts when this one is entered
+            assert_eq!(
+                tracker.check(
+                    &SessionState::InQueue {
+                        position: 1,
+                        eta_secs: 0
+                    },
+                    start
+                ),
+                None
+            );
+            let entered = start + std::time::Duration::from_secs(30);
+            assert_eq!(tracker.check(&state, entered), None);
+            let almost = entered + timeout - std::time::Duration::from_secs(1);
+            assert_eq!(tracker.check(&state, almost), None, "{:?}", state);
+            assert_eq!(tracker.check(&state, entered + timeout), Some(timeout));
+        }
+
+        // Queue waits depend on demand and never time out
+        let mut tracker = StuckTracker::default();
+        let queued = SessionState::InQueue {
+            position: 50,
+            eta_secs: 3600,
+        };
+        assert_eq!(tracker.check(&queued, start), None);
+        let later = start + std::time::Duration::from_secs(7200);
+        assert_eq!(tracker.check(&queued, later), None);
+    }
+
+    #[test]
+    fn test_ads_timeout_keeps_polling() {
+        let playing = session(SessionState::WatchingAds {
+            remaining_secs: 30,
+            total_secs: 30,
+        });
+        assert!(next_session_ui_state(&playing, 0).should_poll());
+
+        // Ads ran out but the server hasn't moved on: no stuck timeout, keep polling
+        let finished = session(SessionState::WatchingAds {
+            remaining_secs: 0,
+            total_secs: 30,
+        });
+        assert_eq!(finished.state.stuck_timeout(), None);
+        match next_session_ui_state(&finished, 0) {
+            SessionUiUpdate::WatchingAds { status, .. } => {
+                assert_eq!(status, "Ads finished, waiting for the queue...")
+            }
+            other => panic!("unexpected update: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_failed_poll_retries_and_error_stops() {
+        // A poll that fails with HTTP 500 leaves the cached session unchanged,
+        // so the same state must ask for another poll
+        for state in [
+            SessionState::Requesting,
+            SessionState::Launching,
+            SessionState::Connecting,
+            SessionState::CleaningUp,
+            SessionState::WaitingForStorage,
+        ] {
+            let current = session(state);
+            assert!(next_session_ui_state(&current, 0).should_poll());
+            assert!(next_session_ui_state(&current, 0).should_poll());
+        }
+
+        let failed = session(SessionState::Error("Server Error".to_string()));
+        assert_eq!(
+            next_session_ui_state(&failed, 0),
+            SessionUiUpdate::Failed("Server Error".to_string())
+        );
+    }
+
+    #[test]
+    fn test_stream_settings_offer() {
+        let entitled = [
+            EntitledResolution {
+                width: 2560,
+                height: 1440,
+                fps: 120,
+            },
+            EntitledResolution {
+                width: 3840,
+                height: 2160,
+                fps: 60,
+            },
+        ];
+        let settings = Settings {
+            resolution: "3840x2160".to_string(),
+            fps: 120,
+            ..Default::default()
+        };
+
+        let offer =
+            stream_settings_offer(StreamSettingRejection::Mode, &settings, &entitled).unwrap();
+        assert_eq!(
+            offer.message,
+            "Your membership doesn't support 4K at 120 FPS"
+        );
+        assert_eq!((offer.resolution.as_str(), offer.fps), ("3840x2160", 60));
+        assert_eq!(offer.retry_label, "Retry at 4K 60 FPS");
+
+        // Already on H.264: nothing to fall back to
+        assert_eq!(
+            stream_settings_offer(StreamSettingRejection::Codec, &settings, &entitled),
+            None
+        );
+    }
+}
@@ -0,0 +1,150 @@
+//! Session Summary
+//!
+//! Totals collected from the stats updates while streaming, reported when the
+//! stream ends: data used, bitrate, frame rate, packet loss and duration. Always
+//! logged; shown as a dismissible panel when Settings > Session Summary is on.
+
+use std::time::{Duration, Instant};
+
+use crate::media::StreamStats;
+
+/// Stats updates further apart than this (e.g. across system sleep) don't count as traffic
+const MAX_UPDATE_GAP: Duration = Duration::from_secs(5);
+
+/// Running totals for the current stream
+pub struct SessionTotals {
+    started: Instant,
+    last_update: Option<Instant>,
+    megabits: f64,
+    peak_bitrate_mbps: f32,
+    bitrate_sum: f64,
+    fps_sum: f64,
+    packet_loss_sum: f64,
+    samples: u32,
+}
+
+impl SessionTotals {
+    pub fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            last_update: None,
+            megabits: 0.0,
+            peak_bitrate_mbps: 0.0,
+            bitrate_sum: 0.0,
+            fps_sum: 0.0,
+            packet_loss_sum: 0.0,
+            samples: 0,
+        }
+    }
+
+    /// Add a stats update (sent about once per second while streaming)
+    pub fn record(&mut self, stats: &StreamStats) {
+        let now = Instant::now();
+        let since_last = self
+            .last_update
+            .map_or(Duration::from_secs(1), |last| now.duration_since(last));
+        self.last_update = Some(now);
+        if since_last > MAX_UPDATE_GAP {
+            return;
+        }
+
+        // The received rate includes audio and retransmits, so it's closest to the data billed
+        let received_mbps = if stats.received_mbps > 0.0 {
+            stats.received_mbps
+        } else {
+            stats.bitrate_mbps
+        };
+        self.megabits += received_mbps as f64 * since_last.as_secs_f64();
+        self.peak_bitrate_mbps = self.peak_bitrate_mbps.max(stats.bitrate_mbps);
+        self.bitrate_sum += stats.bitrate_mbps as f64;
+        self.fps_sum += stats.fps as f64;
+        self.packet_loss_sum += stats.packet_loss as f64;
+        self.samples += 1;
+    }
+
+    /// Summary of the stream; None if no video stats arrived
+    pub fn finish(&self, game_title: &str) -> Option<SessionSummary> {
+        if self.samples == 0 {
+            return None;
+        }
+        let samples = self.samples as f64;
+        Some(SessionSummary {
+            game_title: game_title.to_string(),
+            duration: self.started.elapsed(),
+            data_used_bytes: (self.megabits * 1_000_000.0 / 8.0) as u64,
+            avg_bitrate_mbps: (self.bitrate_sum / samples) as f32,
+            peak_bitrate_mbps: self.peak_bitrate_mbps,
+            avg_fps: (self.fps_sum / samples) as f32,
+            avg_packet_loss_pct: (self.packet_loss_sum / samples) as f32,
+        })
+    }
+}
+
+impl Default for SessionTotals {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Report for an ended stream
+#[derive(Debug, Clone)]
+pub struct SessionSummary {
+    pub game_title: String,
+    pub duration: Duration,
+    /// Everything received (video, audio, retransmits)
+    pub data_used_bytes: u64,
+    pub avg_bitrate_mbps: f32,
+    pub peak_bitrate_mbps: f32,
+    pub avg_fps: f32,
+    pub avg_packet_loss_pct: f32,
+}
+
+impl SessionSummary {
+    /// Data used, e.g. "3.4 GB" or "820 MB"
+    pub fn data_used(&self) -> String {
+        let mb = self.data_used_bytes as f64 / 1_000_000.0;
+        if mb >= 1000.0 {
+            format!("{:.1} GB", mb / 1000.0)
+        } else {
+            format!("{:.0} MB", mb)
+        }
+    }
+
+    /// Duration, e.g. "1h 05m" or "12m 30s"
+    pub fn duration_text(&self) -> String {
+        let secs = self.duration.as_secs();
+        if secs >= 3600 {
+            format!("{}h {:02}m", secs / 3600, secs % 3600 / 60)
+        } else {
+            format!("{}m {:02}s", secs / 60, secs % 60)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_text() {
+        let summary = SessionSummary {
+            game_title: "Test".to_string(),
+            duration: Duration::from_secs(3900),
+            data_used_bytes: 3_400_000_000,
+            avg_bitrate_mbps: 0.0,
+            peak_bitrate_mbps: 0.0,
+            avg_fps: 0.0,
+            avg_packet_loss_pct: 0.0,
+        };
+        assert_eq!(summary.data_used(), "3.4 GB");
+        assert_eq!(summary.duration_text(), "1h 05m");
+
+        let short = SessionSummary {
+            duration: Duration::from_secs(750),
+            data_used_bytes: 820_000_000,
+            ..summary
+        };
+        assert_eq!(short.data_used(), "820 MB");
+        assert_eq!(short.duration_text(), "12m 30s");
+    }
+}
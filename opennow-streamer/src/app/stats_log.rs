@@ -0,0 +1,135 @@
+//! Stats Log
+//!
+//! Per-second `StreamStats` samples written to a CSV file, so codec, server and
+//! bitrate choices can be compared with numbers instead of impressions. One file
+//! per recording in `stats_logs/` in the app data folder.
+
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use log::info;
+
+use crate::media::StreamStats;
+
+/// Time between samples
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+const CSV_HEADER: &str = "elapsed_s,resolution,codec,fps,render_fps,target_fps,bitrate_mbps,received_mbps,rtt_ms,jitter_ms,packet_loss_pct,decode_ms,render_ms,frame_delivery_ms,input_latency_ms,frames_received,frames_decoded,frames_dropped,server_region";
+
+/// CSV recording in progress
+pub struct StatsLog {
+    file: File,
+    path: PathBuf,
+    started: Instant,
+    next_sample: Instant,
+}
+
+impl StatsLog {
+    /// Create a new CSV file named after the time and `label` (e.g. the game)
+    pub fn start(label: &str) -> Result<Self> {
+        let dir = crate::utils::get_app_data_dir().join("stats_logs");
+        crate::utils::ensure_dir(&dir)?;
+
+        let label: String = label
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let name = format!(
+            "{}_{}.csv",
+            chrono::Local::now().format("%Y-%m-%d_%H-%M-%S"),
+            label.trim_matches('_')
+        );
+        let path = dir.join(name);
+        let mut file =
+            File::create(&path).with_context(|| format!("Failed to create {}", path.display()))?;
+        writeln!(file, "{}", CSV_HEADER)?;
+
+        info!("Recording stream stats to {}", path.display());
+        let now = Instant::now();
+        Ok(Self {
+            file,
+            path,
+            started: now,
+            next_sample: now,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append a row if a sample is due
+    pub fn sample(&mut self, stats: &StreamStats) -> Result<()> {
+        let now = Instant::now();
+        if now < self.next_sample {
+            return Ok(());
+        }
+        self.next_sample = now + SAMPLE_INTERVAL;
+
+        // Written unbuffered so a crash still leaves every sample on disk
+        let elapsed = now.duration_since(self.started).as_secs_f32();
+        writeln!(self.file, "{}", csv_row(elapsed, stats))?;
+        Ok(())
+    }
+}
+
+/// One CSV line (fields in `CSV_HEADER` order)
+fn csv_row(elapsed_secs: f32, stats: &StreamStats) -> String {
+    format!(
+        "{:.1},{},{},{:.1},{:.1},{},{:.2},{:.2},{:.1},{:.1},{:.2},{:.2},{:.2},{:.2},{:.2},{},{},{},{}",
+        elapsed_secs,
+        csv_field(&stats.resolution),
+        csv_field(&stats.codec),
+        stats.fps,
+        stats.render_fps,
+        stats.target_fps,
+        stats.bitrate_mbps,
+        stats.received_mbps,
+        stats.rtt_ms,
+        stats.jitter_ms,
+        stats.packet_loss,
+        stats.decode_time_ms,
+        stats.render_time_ms,
+        stats.frame_delivery_ms,
+        stats.input_latency_ms,
+        stats.frames_received,
+        stats.frames_decoded,
+        stats.frames_dropped,
+        csv_field(&stats.server_region),
+    )
+}
+
+/// Quote a text field if it contains CSV syntax
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_row_matches_header() {
+        let stats = StreamStats {
+            resolution: "1920x1080".to_string(),
+            codec: "H.264".to_string(),
+            fps: 59.9,
+            server_region: "EU, Frankfurt".to_string(),
+            ..Default::default()
+        };
+        let row = csv_row(12.0, &stats);
+        assert!(row.starts_with("12.0,1920x1080,H.264,59.9,"));
+        assert!(row.ends_with(",\"EU, Frankfurt\""));
+        assert_eq!(
+            row.matches(',').count() - 1,
+            CSV_HEADER.matches(',').count()
+        );
+    }
+}
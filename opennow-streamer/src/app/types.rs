@@ -3,31 +3,59 @@
 //! Common types used across the application.
 
 use parking_lot::Mutex;
+use std::collections::VecDeque;
 use std::sync::atomic::{AtomicU64, Ordering};
 
-use super::config::{ColorQuality, VideoCodec, VideoDecoderBackend};
+use super::config::{
+    ColorQuality, FullscreenMode, MouseCaptureKey, PiDecoder, PresentModePref, ResamplerQuality,
+    ScalingMode, UnderLoadPolicy, VideoCodec, VideoDecoderBackend,
+};
 use crate::media::VideoFrame;
 
 /// Shared frame holder for zero-latency frame delivery
 /// Decoder writes latest frame, renderer reads it - no buffering
 pub struct SharedFrame {
-    frame: Mutex<Option<VideoFrame>>,
+    /// Unread frames, oldest first (at most one with DropToLatest)
+    frames: Mutex<VecDeque<VideoFrame>>,
     frame_count: AtomicU64,
     last_read_count: AtomicU64,
+    policy: UnderLoadPolicy,
+    /// Frames replaced or pushed out before the renderer read them
+    dropped_count: AtomicU64,
 }
 
+/// Frames queued with BufferSmooth before the oldest is dropped.
+/// Kept small: hardware decoders hand out frames from a fixed surface pool
+const BUFFERED_FRAMES: usize = 3;
+
 impl SharedFrame {
     pub fn new() -> Self {
+        Self::with_policy(UnderLoadPolicy::DropToLatest)
+    }
+
+    pub fn with_policy(policy: UnderLoadPolicy) -> Self {
         Self {
-            frame: Mutex::new(None),
+            frames: Mutex::new(VecDeque::with_capacity(BUFFERED_FRAMES)),
             frame_count: AtomicU64::new(0),
             last_read_count: AtomicU64::new(0),
+            policy,
+            dropped_count: AtomicU64::new(0),
         }
     }
 
     /// Write a new frame (called by decoder)
     pub fn write(&self, frame: VideoFrame) {
-        *self.frame.lock() = Some(frame);
+        let mut frames = self.frames.lock();
+        let capacity = match self.policy {
+            UnderLoadPolicy::DropToLatest => 1,
+            UnderLoadPolicy::BufferSmooth => BUFFERED_FRAMES,
+        };
+        while frames.len() >= capacity {
+            frames.pop_front();
+            self.dropped_count.fetch_add(1, Ordering::Relaxed);
+        }
+        frames.push_back(frame);
+        // Counted under the lock so read() sees the count and queue agree
         self.frame_count.fetch_add(1, Ordering::Release);
     }
 
@@ -38,25 +66,39 @@ impl SharedFrame {
         current > last
     }
 
-    /// Read the latest frame (called by renderer)
+    /// Timestamp of the frame waiting to be read (for presentation pacing)
+    pub fn pending_timestamp_us(&self) -> Option<u64> {
+        if !self.has_new_frame() {
+            return None;
+        }
+        self.frames.lock().front().map(|frame| frame.timestamp_us)
+    }
+
+    /// Read the next frame (called by renderer): the latest with DropToLatest,
+    /// the oldest queued with BufferSmooth
     /// Returns None if no frame available or no new frame since last read
-    /// Uses take() instead of clone() to avoid copying ~3MB per frame
+    /// Moves the frame out instead of cloning to avoid copying ~3MB per frame
     pub fn read(&self) -> Option<VideoFrame> {
-        let current = self.frame_count.load(Ordering::Acquire);
-        let last = self.last_read_count.load(Ordering::Acquire);
-
-        if current > last {
-            self.last_read_count.store(current, Ordering::Release);
-            self.frame.lock().take() // Move instead of clone - zero copy
-        } else {
-            None
+        if !self.has_new_frame() {
+            return None;
         }
+        let mut frames = self.frames.lock();
+        let frame = frames.pop_front();
+        let current = self.frame_count.load(Ordering::Acquire);
+        self.last_read_count
+            .store(current - frames.len() as u64, Ordering::Release);
+        frame
     }
 
     /// Get frame count for stats
     pub fn frame_count(&self) -> u64 {
         self.frame_count.load(Ordering::Relaxed)
     }
+
+    /// Decoded frames that were never presented (replaced or pushed out of the queue)
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped_count.load(Ordering::Relaxed)
+    }
 }
 
 impl Default for SharedFrame {
@@ -78,6 +120,16 @@ pub fn parse_resolution(res: &str) -> (u32, u32) {
     }
 }
 
+/// Short human-readable label for a resolution (e.g., "1080p", "4K")
+/// Falls back to "WIDTHxHEIGHT" for non-standard sizes
+pub fn resolution_label(width: u32, height: u32) -> String {
+    match (width, height) {
+        (1280, 720) | (1920, 1080) | (2560, 1440) => format!("{}p", height),
+        (3840, 2160) => "4K".to_string(),
+        _ => format!("{}x{}", width, height),
+    }
+}
+
 /// Game variant (platform/store option)
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct GameVariant {
@@ -114,6 +166,40 @@ pub struct GameInfo {
     /// Index of the currently selected variant
     #[serde(default)]
     pub selected_variant_index: usize,
+    /// Streaming features the catalog lists for this title (e.g. "HDR", "RTX")
+    /// None = the catalog didn't report any
+    #[serde(default)]
+    pub features: Option<Vec<String>>,
+    /// Features the catalog explicitly flags as unsupported (same labels as `features`)
+    #[serde(default)]
+    pub unsupported_features: Vec<String>,
+}
+
+impl GameInfo {
+    /// Whether the title supports HDR (None = unknown)
+    pub fn supports_hdr(&self) -> Option<bool> {
+        self.has_feature("HDR")
+    }
+
+    /// Whether the title supports streaming above 60 FPS (None = unknown)
+    pub fn supports_high_fps(&self) -> Option<bool> {
+        self.has_feature("High FPS")
+    }
+
+    /// Some(false) only when the catalog flags the feature off; unlisted features are unknown
+    fn has_feature(&self, feature: &str) -> Option<bool> {
+        if self
+            .features
+            .as_ref()
+            .is_some_and(|features| features.iter().any(|f| f == feature))
+        {
+            Some(true)
+        } else if self.unsupported_features.iter().any(|f| f == feature) {
+            Some(false)
+        } else {
+            None
+        }
+    }
 }
 
 /// Section of games with a title (e.g., "Trending", "Free to Play")
@@ -134,6 +220,18 @@ pub struct SubscriptionInfo {
     pub storage_size_gb: Option<u32>,
     pub is_unlimited: bool, // true if subType is UNLIMITED (no hour cap)
     pub entitled_resolutions: Vec<EntitledResolution>,
+    pub addons: Vec<SubscriptionAddon>,
+    /// Unix time the subscription was fetched (0 if unknown)
+    pub fetched_at: i64,
+}
+
+/// Subscription add-on (e.g. type "STORAGE", sub-type "PERMANENT_STORAGE")
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionAddon {
+    pub addon_type: String,
+    pub sub_type: Option<String>,
+    /// "OK" when active
+    pub status: Option<String>,
 }
 
 #[derive(
@@ -198,6 +296,178 @@ pub struct ServerInfo {
     pub url: Option<String>,
     pub ping_ms: Option<u32>,
     pub status: ServerStatus,
+    /// Queue length from PrintedWaste for this zone (shortest rig queue)
+    pub queue_position: Option<i32>,
+    /// Estimated queue wait in seconds
+    pub queue_eta_secs: Option<i64>,
+}
+
+/// Region groups by zone ID prefix (NVIDIA zones, e.g. "eu-netherlands-south")
+const ZONE_PREFIX_REGIONS: &[(&str, &str)] = &[
+    ("eu-", "Europe"),
+    ("us-", "North America"),
+    ("ca-", "Canada"),
+    ("ap-", "Asia-Pacific"),
+    ("sa-", "South America"),
+    ("me-", "Middle East"),
+    ("af-", "Africa"),
+];
+
+/// Region groups by whole words in the zone's display name (Alliance partners and
+/// NVIDIA's "US West" / "EU Northwest" style names). Multi-word entries match as a phrase.
+const NAME_KEYWORD_REGIONS: &[(&str, &str)] = &[
+    // Europe
+    ("eu", "Europe"),
+    ("europe", "Europe"),
+    ("uk", "Europe"),
+    ("united kingdom", "Europe"),
+    ("london", "Europe"),
+    ("ireland", "Europe"),
+    ("france", "Europe"),
+    ("paris", "Europe"),
+    ("germany", "Europe"),
+    ("frankfurt", "Europe"),
+    ("netherlands", "Europe"),
+    ("amsterdam", "Europe"),
+    ("sweden", "Europe"),
+    ("stockholm", "Europe"),
+    ("norway", "Europe"),
+    ("finland", "Europe"),
+    ("denmark", "Europe"),
+    ("poland", "Europe"),
+    ("warsaw", "Europe"),
+    ("spain", "Europe"),
+    ("madrid", "Europe"),
+    ("italy", "Europe"),
+    ("milan", "Europe"),
+    ("bulgaria", "Europe"),
+    ("sofia", "Europe"),
+    ("romania", "Europe"),
+    ("russia", "Europe"),
+    ("moscow", "Europe"),
+    ("ukraine", "Europe"),
+    ("armenia", "Europe"),
+    // North America
+    ("us", "North America"),
+    ("usa", "North America"),
+    ("united states", "North America"),
+    ("california", "North America"),
+    ("texas", "North America"),
+    ("dallas", "North America"),
+    ("virginia", "North America"),
+    ("chicago", "North America"),
+    ("seattle", "North America"),
+    ("phoenix", "North America"),
+    ("mexico", "North America"),
+    // Canada
+    ("canada", "Canada"),
+    ("quebec", "Canada"),
+    ("montreal", "Canada"),
+    ("toronto", "Canada"),
+    // Asia-Pacific
+    ("japan", "Asia-Pacific"),
+    ("tokyo", "Asia-Pacific"),
+    ("osaka", "Asia-Pacific"),
+    ("korea", "Asia-Pacific"),
+    ("seoul", "Asia-Pacific"),
+    ("taiwan", "Asia-Pacific"),
+    ("hong kong", "Asia-Pacific"),
+    ("singapore", "Asia-Pacific"),
+    ("malaysia", "Asia-Pacific"),
+    ("thailand", "Asia-Pacific"),
+    ("vietnam", "Asia-Pacific"),
+    ("indonesia", "Asia-Pacific"),
+    ("philippines", "Asia-Pacific"),
+    ("india", "Asia-Pacific"),
+    ("mumbai", "Asia-Pacific"),
+    ("australia", "Asia-Pacific"),
+    ("sydney", "Asia-Pacific"),
+    ("new zealand", "Asia-Pacific"),
+    ("oceania", "Asia-Pacific"),
+    // South America
+    ("brazil", "South America"),
+    ("sao paulo", "South America"),
+    ("chile", "South America"),
+    ("argentina", "South America"),
+    ("colombia", "South America"),
+    ("peru", "South America"),
+    ("latam", "South America"),
+    ("south america", "South America"),
+    // Middle East
+    ("middle east", "Middle East"),
+    ("turkey", "Middle East"),
+    ("turkiye", "Middle East"),
+    ("istanbul", "Middle East"),
+    ("saudi arabia", "Middle East"),
+    ("uae", "Middle East"),
+    ("dubai", "Middle East"),
+    ("israel", "Middle East"),
+    ("bahrain", "Middle East"),
+    ("qatar", "Middle East"),
+    ("kuwait", "Middle East"),
+    // Africa
+    ("africa", "Africa"),
+    ("south africa", "Africa"),
+    ("johannesburg", "Africa"),
+    ("egypt", "Africa"),
+];
+
+/// Region group for a server, from its zone ID prefix (e.g. "eu-") or the
+/// words in its display name; "Other" only if neither is known
+pub fn server_region(id: &str, name: &str) -> &'static str {
+    let id = id.to_lowercase();
+    if let Some(&(_, region)) = ZONE_PREFIX_REGIONS
+        .iter()
+        .find(|(prefix, _)| id.starts_with(prefix))
+    {
+        return region;
+    }
+
+    // " us west " style, so keywords only match whole words ("US" isn't in "AUSTRALIA")
+    let words: Vec<String> = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    let padded = format!(" {} ", words.join(" "));
+    // Longest keyword first so "south africa" wins over a shorter, less specific match
+    NAME_KEYWORD_REGIONS
+        .iter()
+        .filter(|(keyword, _)| padded.contains(&format!(" {} ", keyword)))
+        .max_by_key(|(keyword, _)| keyword.len())
+        .map_or("Other", |&(_, region)| region)
+}
+
+/// Index of the saved server in `servers`, or the closest stand-in when the list no
+/// longer has it (e.g. after dynamic regions replaced the built-in list): the server in
+/// the same region sharing the most of its zone ID (eu-netherlands-north ->
+/// eu-netherlands-south), then the lowest ping. A saved server of unknown region has
+/// no stand-in: "Other" servers could be anywhere.
+pub fn find_saved_server(servers: &[ServerInfo], saved_id: &str) -> Option<usize> {
+    if let Some(idx) = servers.iter().position(|s| s.id == saved_id) {
+        return Some(idx);
+    }
+    let region = server_region(saved_id, "");
+    if region == "Other" {
+        return None;
+    }
+    let shared_parts = |id: &str| {
+        id.split('-')
+            .zip(saved_id.split('-'))
+            .take_while(|(a, b)| a == b)
+            .count()
+    };
+    servers
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| s.region == region)
+        .min_by_key(|(_, s)| {
+            (
+                std::cmp::Reverse(shared_parts(&s.id)),
+                s.ping_ms.unwrap_or(u32::MAX),
+            )
+        })
+        .map(|(idx, _)| idx)
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -249,18 +519,66 @@ pub enum UiAction {
     StartPingTest,
     /// Toggle settings modal
     ToggleSettingsModal,
+    /// Toggle the plan details panel
+    ToggleAccountPanel,
+    /// Fetch subscription info again
+    RefreshSubscription,
     /// Resume an active session
     ResumeSession(super::session::ActiveSessionInfo),
-    /// Terminate existing session and start new game
-    TerminateAndLaunch(String, GameInfo),
+    /// Terminate the existing sessions and start a new game
+    TerminateAndLaunch(Vec<String>, GameInfo),
+    /// End one active session, keeping the dialog open
+    EndSession(super::session::ActiveSessionInfo),
+    /// Retry launching after session setup timed out
+    RetrySession,
+    /// Save the settings offered after a refused stream request and launch again
+    AcceptStreamSettingsOffer,
     /// Close session conflict dialog
     CloseSessionConflict,
+    /// Create a Start menu / launcher shortcut for a game (with its box art if loaded)
+    CreateGameShortcut(GameInfo, Option<(std::sync::Arc<Vec<u8>>, u32, u32)>),
+    /// Launch the game waiting on the low hours warning
+    ConfirmLowHoursLaunch,
+    /// Close the low hours warning without launching
+    CancelLowHoursLaunch,
+    /// Launch the game from a link forwarded by another OpenNOW process
+    ConfirmForwardedLaunch,
+    /// Ignore a forwarded link
+    CancelForwardedLaunch,
     /// Close AV1 warning dialog
     CloseAV1Warning,
+    /// Close low GPU memory warning dialog
+    CloseGpuMemoryWarning,
     /// Close Alliance experimental warning dialog
     CloseAllianceWarning,
     /// Close welcome popup
     CloseWelcomePopup,
+    /// Re-open the welcome popup (Settings > About)
+    ShowWelcomePopup,
+    /// Open a GitHub issue pre-filled with system details and the log tail
+    ReportIssue,
+    /// Open the crash report left by the previous run
+    OpenCrashReport,
+    /// Close the previous-crash dialog
+    CloseCrashReport,
+    /// Check GitHub for a newer release (Settings > About)
+    CheckForUpdates,
+    /// Hide the "update available" notice until next launch
+    DismissUpdateNotice,
+    /// Download the latest release for this platform (Settings > About)
+    DownloadUpdate,
+    /// Test the selected video decoder (Settings > Video)
+    RunDecoderCheck,
+    /// Change the bitrate of the running stream (Mbps, stream overlay)
+    SetStreamBitrate(u32),
+    /// Stream a game briefly to measure the connection (Settings > Server & Network)
+    StartConnectionTest,
+    /// Use the settings recommended by the last connection test
+    ApplyConnectionTestSettings(super::connection_test::RecommendedSettings),
+    /// Close the connection test result dialog
+    CloseConnectionTestResult,
+    /// Close the report shown after a stream ends
+    CloseSessionSummary,
     /// Reset all settings to defaults
     ResetSettings,
     /// Set queue sort mode
@@ -277,6 +595,8 @@ pub enum UiAction {
     LaunchWithServer(GameInfo, Option<String>),
     /// Refresh queue times
     RefreshQueueTimes,
+    /// Watch a location for a short queue (None cancels)
+    SetQueueAlert(Option<crate::app::queue_alert::QueueAlert>),
     /// Update window size (width, height) - saved to settings
     UpdateWindowSize(u32, u32),
 }
@@ -287,14 +607,55 @@ pub enum SettingChange {
     Resolution(String),
     Fps(u32),
     Codec(VideoCodec),
+    CodecFallback(Vec<VideoCodec>),
     MaxBitrate(u32),
+    MaxDecodeResolution(Option<String>),
+    GstreamerPipelineOverride(Option<String>),
+    PiDecoder(PiDecoder),
+    AdaptiveBitrate(bool),
+    BandwidthCap(u32),
     Fullscreen(bool),
+    StartMaximized(bool),
+    KeepStreamingUnfocused(bool),
+    ResponsiveMenus(bool),
+    MenuFpsCap(u32),
+    MenuScreensaverMinutes(u32),
+    MuteOnFocusLoss(bool),
+    AudioResamplerQuality(ResamplerQuality),
+    ConnectionSounds(bool),
+    ShowSessionSummary(bool),
     VSync(bool),
     LowLatency(bool),
     DecoderBackend(VideoDecoderBackend),
     ColorQuality(ColorQuality),
     Hdr(bool),
     ClipboardPasteEnabled(bool),
+    IdleTimeoutMinutes(u32),
+    MouseSendRateHz(u32),
+    MouseCaptureKey(MouseCaptureKey),
+    KeepSessionOnQuit(bool),
+    StatsRefreshMs(u32),
+    StatsWindow(bool),
+    MinimalHud(bool),
+    RecordStatsCsv(bool),
+    Brightness(f32),
+    Gamma(f32),
+    Sharpening(f32),
+    ScalingMode(ScalingMode),
+    FullscreenMode(FullscreenMode),
+    OverscanPercent(f32),
+    SafeMode(bool),
+    ShowTipsOnStartup(bool),
+    PresentMode(PresentModePref),
+    FramePacing(bool),
+    UnderLoadPolicy(UnderLoadPolicy),
+    IceGatherTimeoutMs(u32),
+    ForceRelay(bool),
+    QueueDataUrl(String),
+    QueueMappingUrl(String),
+    CheckUpdates(bool),
+    PreferredRegion(Option<String>),
+    BlockedServers(Vec<String>),
 }
 
 /// Application state enum
@@ -309,3 +670,80 @@ pub enum AppState {
     /// Active streaming
     Streaming,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(timestamp_us: u64) -> VideoFrame {
+        let mut frame = VideoFrame::empty(2, 2);
+        frame.timestamp_us = timestamp_us;
+        frame
+    }
+
+    #[test]
+    fn test_shared_frame_under_load_policies() {
+        let latest = SharedFrame::with_policy(UnderLoadPolicy::DropToLatest);
+        for ts in 1..=3 {
+            latest.write(frame(ts));
+        }
+        assert_eq!(latest.read().map(|f| f.timestamp_us), Some(3));
+        assert!(latest.read().is_none());
+        assert_eq!(latest.dropped_count(), 2);
+
+        let buffered = SharedFrame::with_policy(UnderLoadPolicy::BufferSmooth);
+        for ts in 1..=4 {
+            buffered.write(frame(ts));
+        }
+        // The oldest frame is pushed out once the queue is full, the rest play in order
+        assert_eq!(buffered.pending_timestamp_us(), Some(2));
+        let played: Vec<u64> = std::iter::from_fn(|| buffered.read())
+            .map(|f| f.timestamp_us)
+            .collect();
+        assert_eq!(played, vec![2, 3, 4]);
+        assert!(!buffered.has_new_frame());
+        assert_eq!(buffered.dropped_count(), 1);
+    }
+
+    fn server(id: &str, region: &str, ping_ms: Option<u32>) -> ServerInfo {
+        ServerInfo {
+            id: id.to_string(),
+            name: id.to_string(),
+            region: region.to_string(),
+            url: None,
+            ping_ms,
+            status: ServerStatus::Online,
+            queue_position: None,
+            queue_eta_secs: None,
+        }
+    }
+
+    #[test]
+    fn test_saved_server_falls_back_to_nearest_in_region() {
+        let servers = vec![
+            server("us-california-north", "North America", Some(10)),
+            server("eu-germany-frankfurt-1", "Europe", Some(20)),
+            server("eu-netherlands-south", "Europe", Some(40)),
+            server("np-ams-06", "Other", Some(5)),
+        ];
+        assert_eq!(
+            find_saved_server(&servers, "eu-germany-frankfurt-1"),
+            Some(1)
+        );
+        assert_eq!(find_saved_server(&servers, "eu-netherlands-north"), Some(2));
+        assert_eq!(find_saved_server(&servers, "eu-sweden-stockholm"), Some(1));
+        assert_eq!(find_saved_server(&servers, "ap-japan-tokyo-1"), None);
+        assert_eq!(find_saved_server(&servers, "np-sjc-02"), None);
+    }
+
+    #[test]
+    fn test_server_region_uses_zone_prefix_then_name_words() {
+        assert_eq!(server_region("eu-netherlands-south", ""), "Europe");
+        assert_eq!(server_region("np-sjc6-04", "US West"), "North America");
+        assert_eq!(server_region("np-syd-01", "AUSTRALIA"), "Asia-Pacific");
+        assert_eq!(server_region("gfn-ist", "Turkey - Istanbul"), "Middle East");
+        assert_eq!(server_region("gfn-gru", "Sao Paulo"), "South America");
+        assert_eq!(server_region("x", "Johannesburg"), "Africa");
+        assert_eq!(server_region("x", "Somewhere New"), "Other");
+    }
+}
@@ -4,10 +4,12 @@
 
 mod renderer;
 mod stats_panel;
+mod stats_window;
 mod shaders;
 pub mod screens;
 pub mod image_cache;
 
 pub use renderer::Renderer;
-pub use stats_panel::StatsPanel;
+pub use stats_panel::{FrameTimeTracker, StatsPanel};
+pub use stats_window::StatsWindow;
 pub use image_cache::{get_image, request_image, update_cache};
@@ -15,11 +15,12 @@ macro_rules! profile_scope {
 
 use anyhow::{Context, Result};
 use log::{debug, error, info, warn};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use winit::dpi::PhysicalSize;
+use winit::dpi::{PhysicalPosition, PhysicalSize};
 use winit::event::WindowEvent;
 use winit::event_loop::ActiveEventLoop;
-use winit::window::{CursorGrabMode, Fullscreen, Window, WindowAttributes};
+use winit::window::{CursorGrabMode, Fullscreen, Window, WindowAttributes, WindowId};
 
 #[cfg(target_os = "macos")]
 use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
@@ -27,14 +28,24 @@ use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
 
 use super::image_cache;
 use super::screens::{
-    render_ads_required_screen, render_alliance_warning_dialog, render_av1_warning_dialog,
-    render_login_screen, render_session_conflict_dialog, render_session_screen,
-    render_settings_modal, render_welcome_popup,
+    format_age, render_account_panel, render_ads_required_screen, render_alliance_warning_dialog,
+    render_av1_warning_dialog, render_connection_test_dialog, render_crash_report_dialog,
+    render_forwarded_launch_dialog, render_gpu_memory_warning_dialog, render_login_screen,
+    render_low_hours_dialog, render_menu_screensaver, render_provider_features,
+    render_queue_heatmap, render_session_conflict_dialog, render_session_screen,
+    render_session_summary_dialog, render_settings_modal, render_update_notice,
+    render_welcome_popup, AboutInfo,
 };
 use super::shaders::{EXTERNAL_TEXTURE_SHADER, NV12_HDR_TONEMAP_SHADER, NV12_SHADER, VIDEO_SHADER};
-use super::StatsPanel;
+use super::{FrameTimeTracker, StatsPanel, StatsWindow};
+use crate::app::config::{
+    max_resolution_for_texture_limit, MAX_BRIGHTNESS, MAX_GAMMA, MAX_STATS_REFRESH_MS,
+    MIN_BRIGHTNESS, MIN_GAMMA, MIN_STATS_REFRESH_MS, RESOLUTIONS,
+};
 use crate::app::session::ActiveSessionInfo;
-use crate::app::{App, AppState, GameInfo, GamesTab, UiAction};
+use crate::app::{
+    App, AppState, FullscreenMode, GameInfo, GamesTab, PresentModePref, Settings, UiAction,
+};
 #[cfg(target_os = "windows")]
 use crate::media::D3D11TextureWrapper;
 #[cfg(target_os = "linux")]
@@ -57,6 +68,10 @@ use windows::Win32::Graphics::Direct3D12::{ID3D12Device, ID3D12Resource};
 // Color conversion is now hardcoded in the shader using official GFN client BT.709 values
 // This eliminates potential initialization bugs with uniform buffers
 
+/// Neutral `VideoAdjust` uniform (no adjustment, full-window quad):
+/// brightness, gamma, sharpening, nearest sampling, scale x/y, offset x/y
+const DEFAULT_VIDEO_ADJUST: [f32; 8] = [0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0];
+
 /// Resolution change notification for animated popup
 struct ResolutionNotification {
     old_resolution: String,
@@ -171,9 +186,145 @@ impl WheelNotification {
     }
 }
 
+/// Resolution downgrade warning for animated popup
+/// Shows when the first decoded frame is noticeably smaller than the requested resolution
+struct DowngradeNotification {
+    message: String,
+    start_time: Instant,
+}
+
+impl DowngradeNotification {
+    const DURATION_SECS: f32 = 8.0;
+    const FADE_IN_SECS: f32 = 0.3;
+    const FADE_OUT_SECS: f32 = 0.8;
+
+    fn new(message: &str) -> Self {
+        Self {
+            message: message.to_string(),
+            start_time: Instant::now(),
+        }
+    }
+
+    fn is_expired(&self) -> bool {
+        self.start_time.elapsed().as_secs_f32() > Self::DURATION_SECS
+    }
+
+    fn alpha(&self) -> f32 {
+        let elapsed = self.start_time.elapsed().as_secs_f32();
+
+        if elapsed < Self::FADE_IN_SECS {
+            // Fade in
+            elapsed / Self::FADE_IN_SECS
+        } else if elapsed > Self::DURATION_SECS - Self::FADE_OUT_SECS {
+            // Fade out
+            let fade_progress = (Self::DURATION_SECS - elapsed) / Self::FADE_OUT_SECS;
+            fade_progress.max(0.0)
+        } else {
+            // Full opacity
+            1.0
+        }
+    }
+}
+
+/// Minimum GPU memory (MB) for V3D hardware rendering at 1080p
+/// Below this, device creation tends to OOM and fall back to llvmpipe
+#[cfg(all(target_os = "linux", target_arch = "aarch64"))]
+const V3D_MIN_GPU_MEM_MB: u32 = 256;
+
+/// Query GPU memory available to V3D on Raspberry Pi
+/// Uses `vcgencmd get_mem gpu` (firmware gpu_mem split), falling back to CMA from /proc/meminfo
+#[cfg(all(target_os = "linux", target_arch = "aarch64"))]
+fn query_v3d_gpu_mem_mb() -> Option<u32> {
+    // vcgencmd prints e.g. "gpu=76M"
+    if let Ok(output) = std::process::Command::new("vcgencmd")
+        .args(["get_mem", "gpu"])
+        .output()
+    {
+        let text = String::from_utf8_lossy(&output.stdout);
+        if let Some(mb) = text
+            .trim()
+            .strip_prefix("gpu=")
+            .and_then(|v| v.trim_end_matches('M').parse::<u32>().ok())
+        {
+            return Some(mb);
+        }
+    }
+
+    // KMS driver allocates from CMA, e.g. "CmaTotal:  262144 kB"
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    meminfo
+        .lines()
+        .find(|line| line.starts_with("CmaTotal:"))
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|kb| kb.parse::<u32>().ok())
+        .map(|kb| kb / 1024)
+}
+
+/// Check V3D GPU memory before device creation
+/// Returns an actionable message if memory is too low for hardware rendering
+#[cfg(all(target_os = "linux", target_arch = "aarch64"))]
+fn check_v3d_gpu_memory() -> Option<String> {
+    let gpu_mem_mb = match query_v3d_gpu_mem_mb() {
+        Some(mb) => mb,
+        None => {
+            debug!("V3D: Could not determine GPU memory");
+            return None;
+        }
+    };
+
+    info!("V3D: {} MB GPU memory available", gpu_mem_mb);
+    if gpu_mem_mb >= V3D_MIN_GPU_MEM_MB {
+        return None;
+    }
+
+    warn!(
+        "V3D: Only {} MB GPU memory (need {} MB) - device creation may OOM",
+        gpu_mem_mb, V3D_MIN_GPU_MEM_MB
+    );
+    Some(format!(
+        "Your Raspberry Pi has only {} MB of GPU memory, which may not be enough for \
+         hardware-accelerated rendering.\n\n\
+         Add 'gpu_mem=512' to /boot/firmware/config.txt and reboot.",
+        gpu_mem_mb
+    ))
+}
+
+/// Device creation attempts after a device loss (drivers may still be resetting)
+const DEVICE_RECOVERY_ATTEMPTS: u32 = 5;
+
+/// Delay between device recovery attempts
+const DEVICE_RECOVERY_DELAY: Duration = Duration::from_millis(500);
+
+/// Consecutive Outdated/Lost surface errors before forcing a reconfigure with a fresh window size
+const SURFACE_ERRORS_BEFORE_FORCED_RECOVERY: u32 = 10;
+
+/// How often to check the window's monitor for hotplug
+const MONITOR_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Identity and mode of the monitor the window is on
+/// (only the identity is compared for hotplug detection - our own exclusive fullscreen changes the mode)
+#[derive(Debug, Clone, PartialEq)]
+struct MonitorSnapshot {
+    name: Option<String>,
+    size: PhysicalSize<u32>,
+    refresh_millihertz: Option<u32>,
+}
+
+impl MonitorSnapshot {
+    fn of(window: &Window) -> Option<Self> {
+        window.current_monitor().map(|monitor| Self {
+            name: monitor.name(),
+            size: monitor.size(),
+            refresh_millihertz: monitor.refresh_rate_millihertz(),
+        })
+    }
+}
+
 /// Main renderer
 pub struct Renderer {
     window: Arc<Window>,
+    instance: wgpu::Instance,
+    adapter: wgpu::Adapter,
     surface: wgpu::Surface<'static>,
     device: wgpu::Device,
     queue: wgpu::Queue,
@@ -219,15 +370,31 @@ pub struct Renderer {
     external_texture: Option<wgpu::ExternalTexture>,
     external_texture_supported: bool,
 
+    // Brightness/gamma/sharpening/scaling uniform applied by the video shaders
+    video_adjust_buffer: wgpu::Buffer,
+    video_adjust_bind_group: wgpu::BindGroup,
+    video_adjust: [f32; 8],
+
     // Stats panel
     stats_panel: StatsPanel,
 
+    // Pop-out stats window (replaces the overlay while open)
+    stats_window: Option<StatsWindow>,
+
     // Fullscreen state
     fullscreen: bool,
+    // Preferred fullscreen flavor (Settings > Fullscreen Mode)
+    fullscreen_mode: FullscreenMode,
+    // The last fullscreen switch requested an exclusive video mode
+    exclusive_attempted: bool,
+    // An exclusive mode switch ended up borderless (warned once)
+    exclusive_fallback_warned: bool,
 
     // Swapchain error recovery state
     // Tracks consecutive Outdated errors to avoid panic-fixing with wrong resolution
     consecutive_surface_errors: u32,
+    // Window size seen at the previous surface error (reconfigure only once it stops changing)
+    last_surface_error_size: Option<PhysicalSize<u32>>,
 
     // Supported present modes (for fallback when Immediate isn't available)
     supported_present_modes: Vec<wgpu::PresentMode>,
@@ -240,6 +407,9 @@ pub struct Renderer {
     cached_stats: Option<StreamStats>,
     stats_last_update: Instant,
 
+    // Present-to-present frame times for frame pacing stats (1% / 0.1% lows)
+    frame_times: FrameTimeTracker,
+
     // === UI Optimization: Game grid caching ===
     // Cached game grid to avoid re-laying out every frame
     games_cache_hash: u64,
@@ -255,6 +425,29 @@ pub struct Renderer {
     wheel_notification: Option<WheelNotification>,
     last_wheel_count: usize,
 
+    // Resolution downgrade warning (requested vs received at session start)
+    downgrade_notification: Option<DowngradeNotification>,
+    last_downgrade_warning: Option<String>,
+    last_chroma_warning: Option<String>,
+    last_texture_limit_warning: Option<String>,
+    last_decode_slowdown_warning: Option<String>,
+
+    // Size of the last frame skipped for exceeding max_texture_dimension_2d (warn once per size)
+    oversized_frame: Option<(u32, u32)>,
+
+    // Low GPU memory warning from device creation (handed to App once)
+    gpu_memory_warning: Option<String>,
+
+    // Set by the device-lost callback (driver reset, TDR)
+    device_lost: Arc<AtomicBool>,
+
+    // Adapter in use (shown in Settings > About)
+    adapter_info: wgpu::AdapterInfo,
+
+    // Monitor hotplug / resolution change detection (TVs renegotiating HDMI)
+    monitor_snapshot: Option<MonitorSnapshot>,
+    last_monitor_check: Instant,
+
     // macOS zero-copy video rendering (Metal-based, no CPU copy)
     #[cfg(target_os = "macos")]
     zero_copy_manager: Option<ZeroCopyTextureManager>,
@@ -269,6 +462,12 @@ pub struct Renderer {
     current_imported_handle: Option<HANDLE>,
     #[cfg(target_os = "windows")]
     current_imported_texture: Option<wgpu::Texture>,
+    /// Format of the imported texture (NV12 or P010)
+    #[cfg(target_os = "windows")]
+    current_imported_format: Option<wgpu::TextureFormat>,
+    /// Device can import P010 (10-bit HDR) decoder output without a CPU copy
+    #[cfg(target_os = "windows")]
+    p010_zero_copy: bool,
 }
 
 impl Renderer {
@@ -296,7 +495,9 @@ impl Renderer {
             .with_title("OpenNow")
             .with_inner_size(initial_size)
             .with_min_inner_size(PhysicalSize::new(640, 480))
-            .with_resizable(true);
+            .with_resizable(true)
+            // Fullscreen is entered once the renderer is ready (see main.rs resumed)
+            .with_maximized(settings.start_maximized && !settings.fullscreen);
 
         // Create window and wrap in Arc for surface creation
         let window = Arc::new(
@@ -318,6 +519,14 @@ impl Renderer {
         #[cfg(target_os = "macos")]
         Self::set_macos_display_mode_120hz();
 
+        Self::with_window(window).await
+    }
+
+    /// Create the GPU device, surface, and pipelines for an existing window
+    /// Used on startup and to rebuild everything after the device is lost
+    async fn with_window(window: Arc<Window>) -> Result<Self> {
+        let size = window.inner_size();
+
         // Create wgpu instance
         // Force DX12 on Windows for better exclusive fullscreen support and lower latency
         // Vulkan on Windows has issues with exclusive fullscreen transitions causing DWM composition
@@ -454,22 +663,59 @@ impl Renderer {
         let mut required_features = wgpu::Features::empty();
         let adapter_features = adapter.features();
 
+        // Safe mode: stick to the basic video pipeline for GPU/driver troubleshooting
+        let safe_mode = crate::app::Settings::load()
+            .unwrap_or_default()
+            .safe_mode_active();
+
         // Check if EXTERNAL_TEXTURE is supported (hardware YUV->RGB conversion)
         let external_texture_supported =
-            adapter_features.contains(wgpu::Features::EXTERNAL_TEXTURE);
-        if external_texture_supported {
+            adapter_features.contains(wgpu::Features::EXTERNAL_TEXTURE) && !safe_mode;
+        if safe_mode {
+            info!("Safe mode: EXTERNAL_TEXTURE and zero-copy rendering disabled");
+        } else if external_texture_supported {
             required_features |= wgpu::Features::EXTERNAL_TEXTURE;
             info!("EXTERNAL_TEXTURE feature supported - enabling true zero-copy video");
         } else {
             info!("EXTERNAL_TEXTURE not supported - using NV12 shader path");
         }
 
+        // Windows zero-copy imports the decoder's D3D11 texture as-is: NV12 for SDR,
+        // P010 for 10-bit HDR. Without P010 the HDR path falls back to a CPU copy.
+        #[cfg(target_os = "windows")]
+        if external_texture_supported {
+            for feature in [
+                wgpu::Features::TEXTURE_FORMAT_NV12,
+                wgpu::Features::TEXTURE_FORMAT_P010,
+            ] {
+                if adapter_features.contains(feature) {
+                    required_features |= feature;
+                }
+            }
+            info!(
+                "Zero-copy texture formats: NV12={}, P010={}",
+                adapter_features.contains(wgpu::Features::TEXTURE_FORMAT_NV12),
+                adapter_features.contains(wgpu::Features::TEXTURE_FORMAT_P010)
+            );
+        }
+
         // Detect Raspberry Pi V3D hardware GPU for ultra-minimal settings
         let is_v3d_hardware = adapter_info.name.to_lowercase().contains("v3d")
             || adapter_info.name.to_lowercase().contains("videocore");
         // Detect if we're on ARM64 Linux (includes llvmpipe on Pi)
         let is_arm64_linux = cfg!(all(target_os = "linux", target_arch = "aarch64"));
 
+        // V3D: Check GPU memory up front so users get an actionable fix instead of
+        // a silent fallback to llvmpipe
+        #[cfg(all(target_os = "linux", target_arch = "aarch64"))]
+        let gpu_memory_warning = if is_v3d_hardware {
+            check_v3d_gpu_memory()
+        } else {
+            None
+        };
+        #[cfg(not(all(target_os = "linux", target_arch = "aarch64")))]
+        let gpu_memory_warning: Option<String> = None;
+
         // V3D: Don't request any optional features to minimize memory
         if is_v3d_hardware {
             required_features = wgpu::Features::empty();
@@ -629,6 +875,22 @@ impl Renderer {
             .await
             .context("Failed to create device")?;
 
+        // Flag device loss (driver reset / TDR) so the event loop can rebuild the renderer
+        // instead of rendering into a dead device
+        let device_lost = Arc::new(AtomicBool::new(false));
+        {
+            let device_lost = device_lost.clone();
+            device.set_device_lost_callback(move |reason, message| {
+                // Destroyed is reported when we drop the device ourselves
+                if reason == wgpu::DeviceLostReason::Destroyed {
+                    debug!("GPU device destroyed: {}", message);
+                    return;
+                }
+                error!("GPU device lost ({:?}): {}", reason, message);
+                device_lost.store(true, Ordering::Release);
+            });
+        }
+
         // Update adapter_info after potential ARM64 fallback to software renderer
         #[cfg(all(target_os = "linux", target_arch = "aarch64"))]
         let adapter_info = adapter.get_info();
@@ -648,6 +910,7 @@ impl Renderer {
         // Switches to Immediate when streaming for lowest latency
         let present_mode = wgpu::PresentMode::Fifo;
         info!("Using Fifo present mode (vsync) - low CPU usage for UI");
+        info!("Supported present modes: {:?}", surface_caps.present_modes);
 
         // Frame latency: 2 for smoother pacing
         let frame_latency = 2;
@@ -697,6 +960,43 @@ impl Renderer {
             egui_wgpu::RendererOptions::default(),
         );
 
+        // Brightness/gamma/sharpening/scaling uniform shared by all video pipelines (bind group 1)
+        let video_adjust_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Video Adjust Bind Group Layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                }],
+            });
+
+        let video_adjust_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Video Adjust Uniform"),
+            size: std::mem::size_of_val(&DEFAULT_VIDEO_ADJUST) as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        queue.write_buffer(
+            &video_adjust_buffer,
+            0,
+            bytemuck::cast_slice(&DEFAULT_VIDEO_ADJUST),
+        );
+
+        let video_adjust_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Video Adjust Bind Group"),
+            layout: &video_adjust_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: video_adjust_buffer.as_entire_binding(),
+            }],
+        });
+
         // Create video rendering pipeline
         let video_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Video Shader"),
@@ -754,7 +1054,7 @@ impl Renderer {
         let video_pipeline_layout =
             device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
                 label: Some("Video Pipeline Layout"),
-                bind_group_layouts: &[&video_bind_group_layout],
+                bind_group_layouts: &[&video_bind_group_layout, &video_adjust_bind_group_layout],
                 immediate_size: 0,
             });
 
@@ -847,7 +1147,7 @@ impl Renderer {
 
         let nv12_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("NV12 Pipeline Layout"),
-            bind_group_layouts: &[&nv12_bind_group_layout],
+            bind_group_layouts: &[&nv12_bind_group_layout, &video_adjust_bind_group_layout],
             immediate_size: 0,
         });
 
@@ -961,7 +1261,10 @@ impl Renderer {
                 let external_texture_pipeline_layout =
                     device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
                         label: Some("External Texture Pipeline Layout"),
-                        bind_group_layouts: &[&external_texture_bind_group_layout],
+                        bind_group_layouts: &[
+                            &external_texture_bind_group_layout,
+                            &video_adjust_bind_group_layout,
+                        ],
                         immediate_size: 0,
                     });
 
@@ -1012,8 +1315,12 @@ impl Renderer {
         // Create stats panel
         let stats_panel = StatsPanel::new();
 
+        let monitor_snapshot = MonitorSnapshot::of(&window);
+
         Ok(Self {
             window,
+            instance,
+            adapter,
             surface,
             device,
             queue,
@@ -1043,14 +1350,23 @@ impl Renderer {
             external_texture_bind_group: None,
             external_texture: None,
             external_texture_supported,
+            video_adjust_buffer,
+            video_adjust_bind_group,
+            video_adjust: DEFAULT_VIDEO_ADJUST,
             stats_panel,
+            stats_window: None,
             fullscreen: false,
+            fullscreen_mode: FullscreenMode::default(),
+            exclusive_attempted: false,
+            exclusive_fallback_warned: false,
             consecutive_surface_errors: 0,
+            last_surface_error_size: None,
             supported_present_modes: surface_caps.present_modes.clone(),
             game_textures: HashMap::new(),
             // UI optimization: stats throttling (200ms intervals)
             cached_stats: None,
             stats_last_update: Instant::now(),
+            frame_times: FrameTimeTracker::new(),
             // UI optimization: game grid caching
             games_cache_hash: 0,
             // Track last uploaded frame to avoid redundant GPU uploads
@@ -1061,10 +1377,22 @@ impl Renderer {
             // Racing wheel connection notification
             wheel_notification: None,
             last_wheel_count: 0,
+            // Resolution downgrade warning
+            downgrade_notification: None,
+            last_downgrade_warning: None,
+            last_chroma_warning: None,
+            last_texture_limit_warning: None,
+            last_decode_slowdown_warning: None,
+            oversized_frame: None,
+            gpu_memory_warning,
+            device_lost,
+            adapter_info,
+            monitor_snapshot,
+            last_monitor_check: Instant::now(),
             #[cfg(target_os = "macos")]
             zero_copy_manager: ZeroCopyTextureManager::new(),
             #[cfg(target_os = "macos")]
-            zero_copy_enabled: true, // GPU blit via Metal for zero-copy CVPixelBuffer rendering
+            zero_copy_enabled: !safe_mode, // GPU blit via Metal for zero-copy CVPixelBuffer rendering
             #[cfg(target_os = "macos")]
             current_y_cv_texture: None,
             #[cfg(target_os = "macos")]
@@ -1073,19 +1401,180 @@ impl Renderer {
             current_imported_handle: None,
             #[cfg(target_os = "windows")]
             current_imported_texture: None,
+            #[cfg(target_os = "windows")]
+            current_imported_format: None,
+            #[cfg(target_os = "windows")]
+            p010_zero_copy: required_features.contains(wgpu::Features::TEXTURE_FORMAT_P010),
         })
     }
 
+    /// Check if the GPU device was lost (driver reset, TDR) and needs recreating
+    pub fn is_device_lost(&self) -> bool {
+        self.device_lost.load(Ordering::Acquire)
+    }
+
+    /// Rebuild device, surface, and pipelines after device loss, keeping the window
+    /// The old surface is released first (some drivers allow one swapchain per window).
+    /// Video textures are rebuilt from the current frame on the next render.
+    pub async fn recreate(mut self) -> Result<Self> {
+        info!("Recreating renderer after GPU device loss...");
+
+        // Carry over state that isn't tied to the old device
+        let window = self.window.clone();
+        let fullscreen = self.fullscreen;
+        let fullscreen_mode = self.fullscreen_mode;
+        let stats_visible = self.stats_panel.visible;
+        let stats_position = self.stats_panel.position;
+        let present_mode = self.config.present_mode;
+        let shared_frame = self.shared_frame.take();
+        let last_resolution = std::mem::take(&mut self.last_resolution);
+        let last_downgrade_warning = self.last_downgrade_warning.take();
+        let last_chroma_warning = self.last_chroma_warning.take();
+        let last_texture_limit_warning = self.last_texture_limit_warning.take();
+        let last_decode_slowdown_warning = self.last_decode_slowdown_warning.take();
+        let last_wheel_count = self.last_wheel_count;
+        drop(self);
+
+        // A driver reset (TDR) can take a moment before a new device is available
+        let mut attempt = 1;
+        let mut renderer = loop {
+            match Self::with_window(window.clone()).await {
+                Ok(renderer) => break renderer,
+                Err(e) if attempt < DEVICE_RECOVERY_ATTEMPTS => {
+                    warn!(
+                        "Renderer recreation attempt {}/{} failed: {} - retrying",
+                        attempt, DEVICE_RECOVERY_ATTEMPTS, e
+                    );
+                    attempt += 1;
+                    std::thread::sleep(DEVICE_RECOVERY_DELAY);
+                }
+                Err(e) => return Err(e),
+            }
+        };
+        renderer.fullscreen = fullscreen;
+        renderer.fullscreen_mode = fullscreen_mode;
+        renderer.stats_panel.visible = stats_visible;
+        renderer.stats_panel.position = stats_position;
+        renderer.shared_frame = shared_frame;
+        renderer.last_resolution = last_resolution;
+        renderer.last_downgrade_warning = last_downgrade_warning;
+        renderer.last_chroma_warning = last_chroma_warning;
+        renderer.last_texture_limit_warning = last_texture_limit_warning;
+        renderer.last_decode_slowdown_warning = last_decode_slowdown_warning;
+        renderer.last_wheel_count = last_wheel_count;
+        if renderer.config.present_mode != present_mode
+            && renderer.supported_present_modes.contains(&present_mode)
+        {
+            renderer.config.present_mode = present_mode;
+            renderer.configure_surface();
+        }
+
+        info!("Renderer recreated successfully");
+        Ok(renderer)
+    }
+
     /// Get window reference
     pub fn window(&self) -> &Window {
         &self.window
     }
 
+    /// Largest texture width/height the device can allocate (2048 on Raspberry Pi V3D)
+    pub fn max_texture_dimension(&self) -> u32 {
+        self.device.limits().max_texture_dimension_2d
+    }
+
+    /// Name of the GPU adapter in use (e.g. "NVIDIA GeForce RTX 3070")
+    pub fn adapter_name(&self) -> &str {
+        &self.adapter_info.name
+    }
+
+    /// GPU adapter name and wgpu backend (e.g. "NVIDIA GeForce RTX 3070 (Vulkan)")
+    pub fn gpu_description(&self) -> String {
+        format!(
+            "{} ({:?})",
+            self.adapter_info.name, self.adapter_info.backend
+        )
+    }
+
+    /// Take the low GPU memory warning detected during device creation (if any)
+    pub fn take_gpu_memory_warning(&mut self) -> Option<String> {
+        self.gpu_memory_warning.take()
+    }
+
+    /// Open the pop-out stats window if it isn't open yet; false if it couldn't be opened
+    pub fn open_stats_window(&mut self, event_loop: &ActiveEventLoop) -> bool {
+        if self.stats_window.is_none() {
+            match StatsWindow::new(event_loop, &self.instance, &self.adapter, &self.device) {
+                Ok(window) => self.stats_window = Some(window),
+                Err(e) => {
+                    warn!("Failed to open stats window: {}", e);
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    pub fn close_stats_window(&mut self) {
+        if self.stats_window.take().is_some() {
+            info!("Stats window closed");
+        }
+    }
+
+    /// Window ID of the stats window, if open
+    pub fn stats_window_id(&self) -> Option<WindowId> {
+        self.stats_window.as_ref().map(|w| w.id())
+    }
+
+    /// Handle an event for the stats window
+    pub fn handle_stats_window_event(&mut self, event: &WindowEvent) {
+        if let Some(stats_window) = self.stats_window.as_mut() {
+            stats_window.handle_event(&self.device, event);
+        }
+    }
+
+    /// Redraw the stats window if the stats refresh interval has passed
+    pub fn request_stats_window_redraw(&self, settings: &Settings) {
+        if let Some(stats_window) = self.stats_window.as_ref() {
+            stats_window.request_redraw_after(Duration::from_millis(
+                settings
+                    .stats_refresh_ms
+                    .clamp(MIN_STATS_REFRESH_MS, MAX_STATS_REFRESH_MS) as u64,
+            ));
+        }
+    }
+
+    /// Draw the current stream stats into the stats window
+    pub fn render_stats_window(&mut self, app: &App) {
+        let window_mode = self.window_mode_label();
+        let Some(stats_window) = self.stats_window.as_mut() else {
+            return;
+        };
+        let mut stats = app.stats.clone();
+        self.frame_times.apply_to(&mut stats);
+        stats.window_mode = window_mode;
+        if let Err(e) = stats_window.render(&self.device, &self.queue, &stats) {
+            warn!("Stats window render failed: {}", e);
+        }
+    }
+
     /// Handle window event - returns (consumed, repaint)
     pub fn handle_event(&mut self, event: &WindowEvent) -> egui_winit::EventResponse {
         self.egui_state.on_window_event(&self.window, event)
     }
 
+    /// The window moved to a monitor with a different DPI (or the system scale changed).
+    /// egui takes the new scale from the event itself; the matching Resized event isn't
+    /// sent on every platform, so bring the surface up to the window's new physical size.
+    pub fn handle_scale_factor_changed(&mut self, scale_factor: f64) {
+        info!("Display scale changed to {:.2}", scale_factor);
+        let size = self.window.inner_size();
+        if size != self.size {
+            self.resize(size);
+        }
+        self.window.request_redraw();
+    }
+
     /// Resize the renderer
     /// Filters out spurious resize events that occur during fullscreen transitions
     pub fn resize(&mut self, new_size: PhysicalSize<u32>) {
@@ -1147,30 +1636,67 @@ impl Renderer {
 
     /// Set VSync mode - use Fifo (vsync) for UI, Immediate/Mailbox for streaming
     /// This lets the GPU handle frame pacing, reducing CPU usage to near zero when idle
-    pub fn set_vsync(&mut self, enabled: bool) {
-        let new_mode = if enabled {
-            wgpu::PresentMode::Fifo // VSync on - GPU waits for display refresh
+    /// `preference` picks the streaming mode (Auto keeps the lowest-latency selection)
+    pub fn set_vsync(&mut self, enabled: bool, preference: PresentModePref) {
+        let (new_mode, reason) = if enabled {
+            // VSync on - GPU waits for display refresh
+            (wgpu::PresentMode::Fifo, "vsync for UI".to_string())
         } else {
-            // VSync off - prefer Immediate for lowest latency, fall back to Mailbox
-            if self
-                .supported_present_modes
-                .contains(&wgpu::PresentMode::Immediate)
-            {
-                wgpu::PresentMode::Immediate
-            } else if self
-                .supported_present_modes
-                .contains(&wgpu::PresentMode::Mailbox)
-            {
-                wgpu::PresentMode::Mailbox // Good low-latency alternative
-            } else {
-                wgpu::PresentMode::Fifo // Fallback to VSync if nothing else available
-            }
+            self.select_streaming_present_mode(preference)
         };
 
         if self.config.present_mode != new_mode {
             self.config.present_mode = new_mode;
             self.surface.configure(&self.device, &self.config);
-            info!("Present mode changed to {:?}", new_mode);
+            info!("Present mode changed to {:?} ({})", new_mode, reason);
+        }
+    }
+
+    /// Pick the streaming present mode for `preference`, falling back when unsupported
+    /// Returns the mode and a short reason for logging
+    fn select_streaming_present_mode(
+        &self,
+        preference: PresentModePref,
+    ) -> (wgpu::PresentMode, String) {
+        let supported = &self.supported_present_modes;
+
+        let forced = match preference {
+            PresentModePref::Auto => None,
+            PresentModePref::Fifo => Some(wgpu::PresentMode::Fifo),
+            PresentModePref::Mailbox => Some(wgpu::PresentMode::Mailbox),
+            PresentModePref::Immediate => Some(wgpu::PresentMode::Immediate),
+        };
+
+        let fallback_note = match forced {
+            Some(mode) if supported.contains(&mode) => {
+                return (mode, "user override".to_string());
+            }
+            Some(mode) => {
+                warn!(
+                    "Requested present mode {:?} not supported (supported: {:?}) - using auto selection",
+                    mode, supported
+                );
+                format!("{:?} unsupported, ", mode)
+            }
+            None => String::new(),
+        };
+
+        // Auto: prefer Immediate for lowest latency, fall back to Mailbox, then Fifo
+        if supported.contains(&wgpu::PresentMode::Immediate) {
+            (
+                wgpu::PresentMode::Immediate,
+                format!("{}auto: lowest latency", fallback_note),
+            )
+        } else if supported.contains(&wgpu::PresentMode::Mailbox) {
+            (
+                wgpu::PresentMode::Mailbox,
+                format!("{}auto: Immediate unsupported", fallback_note),
+            )
+        } else {
+            (
+                wgpu::PresentMode::Fifo,
+                format!("{}auto: Immediate and Mailbox unsupported", fallback_note),
+            )
         }
     }
 
@@ -1199,6 +1725,7 @@ impl Renderer {
     /// and selects the highest available refresh rate for the current resolution
     pub fn toggle_fullscreen(&mut self) {
         self.fullscreen = !self.fullscreen;
+        self.exclusive_attempted = false;
 
         if self.fullscreen {
             // On macOS, use Core Graphics to force 120Hz display mode
@@ -1234,6 +1761,13 @@ impl Renderer {
                     return;
                 }
 
+                if self.fullscreen_mode == FullscreenMode::Borderless {
+                    info!("Entering borderless fullscreen (setting)");
+                    self.window
+                        .set_fullscreen(Some(Fullscreen::Borderless(None)));
+                    return;
+                }
+
                 let current_monitor = self.window.current_monitor();
 
                 if let Some(monitor) = current_monitor {
@@ -1285,6 +1819,7 @@ impl Renderer {
                         );
                         self.window
                             .set_fullscreen(Some(Fullscreen::Exclusive(mode)));
+                        self.exclusive_attempted = true;
                         return;
                     } else {
                         info!("No suitable exclusive fullscreen mode found");
@@ -1303,9 +1838,100 @@ impl Renderer {
         }
     }
 
+    /// Change the preferred fullscreen flavor; re-enters fullscreen if it's active
+    pub fn set_fullscreen_mode(&mut self, mode: FullscreenMode) {
+        if mode == self.fullscreen_mode {
+            return;
+        }
+        info!("Fullscreen mode set to {:?}", mode);
+        self.fullscreen_mode = mode;
+        self.exclusive_fallback_warned = false;
+        if self.fullscreen {
+            self.fullscreen = false;
+            self.toggle_fullscreen();
+        }
+    }
+
+    /// Fullscreen mode the window is actually in, as reported by the windowing system
+    pub fn window_mode_label(&self) -> String {
+        match self.window.fullscreen() {
+            Some(Fullscreen::Exclusive(mode)) => format!(
+                "Exclusive {}x{} @ {}Hz",
+                mode.size().width,
+                mode.size().height,
+                mode.refresh_rate_millihertz() / 1000
+            ),
+            Some(Fullscreen::Borderless(_)) => "Borderless".to_string(),
+            None => "Windowed".to_string(),
+        }
+    }
+
+    /// Tell the user once when an exclusive mode switch ended up borderless, since the
+    /// fallback is otherwise silent. Platforms that never try exclusive (macOS, Wayland)
+    /// are borderless by design and aren't warned about
+    fn check_exclusive_fallback(&mut self) {
+        if !self.fullscreen
+            || !self.exclusive_attempted
+            || self.exclusive_fallback_warned
+            || !matches!(self.window.fullscreen(), Some(Fullscreen::Borderless(_)))
+        {
+            return;
+        }
+        self.exclusive_fallback_warned = true;
+        warn!("Exclusive fullscreen mode switch fell back to borderless");
+        self.show_warning_notification("Exclusive fullscreen unavailable - using borderless");
+    }
+
+    /// Detect monitor disconnects and moves to another monitor (e.g. a TV reconnecting)
+    /// Re-selects the fullscreen video mode and reconfigures the surface when it changes
+    fn check_monitor_change(&mut self) {
+        if self.last_monitor_check.elapsed() < MONITOR_CHECK_INTERVAL {
+            return;
+        }
+        self.last_monitor_check = Instant::now();
+
+        let snapshot = MonitorSnapshot::of(&self.window);
+        let name = |snapshot: &Option<MonitorSnapshot>| snapshot.as_ref().map(|m| m.name.clone());
+        if name(&snapshot) == name(&self.monitor_snapshot) {
+            // Same monitor: a mode change is usually our own fullscreen switch - just track it
+            self.monitor_snapshot = snapshot;
+            return;
+        }
+
+        match &snapshot {
+            Some(monitor) => info!(
+                "Monitor changed: {:?} {}x{} @ {:?} mHz (was {:?})",
+                monitor.name,
+                monitor.size.width,
+                monitor.size.height,
+                monitor.refresh_millihertz,
+                self.monitor_snapshot
+            ),
+            None => warn!("Monitor disconnected (was {:?})", self.monitor_snapshot),
+        }
+        self.monitor_snapshot = snapshot;
+
+        if self.fullscreen {
+            if self.monitor_snapshot.is_some() {
+                // Re-pick the exclusive mode for the new monitor / resolution
+                self.fullscreen = false;
+                self.toggle_fullscreen();
+            } else {
+                // No monitor to pick a mode from - let the OS place a borderless window
+                self.exclusive_attempted = false;
+                self.window
+                    .set_fullscreen(Some(Fullscreen::Borderless(None)));
+            }
+        }
+
+        // The old swapchain may no longer match the display - rebuild it
+        self.recover_swapchain();
+    }
+
     /// Enter fullscreen with a specific target refresh rate
     /// Useful when the stream FPS is known (e.g., 120fps stream -> 120Hz mode)
     pub fn set_fullscreen_with_refresh(&mut self, target_fps: u32) {
+        self.exclusive_attempted = false;
         // Wayland doesn't support exclusive fullscreen - use borderless instead
         #[cfg(target_os = "linux")]
         let is_wayland = std::env::var("WAYLAND_DISPLAY").is_ok();
@@ -1323,7 +1949,10 @@ impl Renderer {
             return;
         }
 
-        let current_monitor = self.window.current_monitor();
+        let current_monitor = self
+            .window
+            .current_monitor()
+            .filter(|_| self.fullscreen_mode == FullscreenMode::Exclusive);
 
         if let Some(monitor) = current_monitor {
             let current_size = self.window.inner_size();
@@ -1364,6 +1993,7 @@ impl Renderer {
                 self.fullscreen = true;
                 self.window
                     .set_fullscreen(Some(Fullscreen::Exclusive(mode)));
+                self.exclusive_attempted = true;
 
                 #[cfg(target_os = "macos")]
                 Self::disable_macos_vsync(&self.window);
@@ -1586,12 +2216,40 @@ impl Renderer {
 
     /// Lock cursor for streaming (captures mouse)
     pub fn lock_cursor(&self) {
+        // Pointer position before the grab (physical px), to know how far the grab moves it
+        let ppp = self.egui_ctx.pixels_per_point();
+        let before = self
+            .egui_ctx
+            .input(|i| i.pointer.latest_pos())
+            .map(|p| (p.x * ppp, p.y * ppp));
+        let size = self.window.inner_size();
+        let jump_to = |x: f32, y: f32| {
+            before
+                .map(|(bx, by)| ((x - bx).round() as i32, (y - by).round() as i32))
+                .filter(|&jump| jump != (0, 0))
+        };
+
         // Try confined first, then locked mode
         if let Err(e) = self.window.set_cursor_grab(CursorGrabMode::Confined) {
             info!("Confined cursor grab failed ({}), trying locked mode", e);
-            if let Err(e) = self.window.set_cursor_grab(CursorGrabMode::Locked) {
-                log::warn!("Failed to lock cursor: {}", e);
+            // Recentre the pointer ourselves so the jump from locking it is known
+            let (cx, cy) = (size.width as f32 / 2.0, size.height as f32 / 2.0);
+            let _ = self
+                .window
+                .set_cursor_position(PhysicalPosition::new(cx as f64, cy as f64));
+            match self.window.set_cursor_grab(CursorGrabMode::Locked) {
+                Ok(()) => crate::input::note_cursor_grabbed(true, jump_to(cx, cy)),
+                Err(e) => log::warn!("Failed to lock cursor: {}", e),
             }
+        } else {
+            // Confining pulls a pointer outside the window to its nearest edge
+            let (cx, cy) = before.map_or((0.0, 0.0), |(x, y)| {
+                (
+                    x.clamp(0.0, size.width.saturating_sub(1) as f32),
+                    y.clamp(0.0, size.height.saturating_sub(1) as f32),
+                )
+            });
+            crate::input::note_cursor_grabbed(false, jump_to(cx, cy));
         }
         self.window.set_cursor_visible(false);
         info!("Cursor locked for streaming");
@@ -1600,6 +2258,7 @@ impl Renderer {
     /// Unlock cursor
     pub fn unlock_cursor(&self) {
         let _ = self.window.set_cursor_grab(CursorGrabMode::None);
+        crate::input::note_cursor_released();
         self.window.set_cursor_visible(true);
         info!("Cursor unlocked");
     }
@@ -1609,12 +2268,22 @@ impl Renderer {
         self.fullscreen
     }
 
+    /// Check if the window is maximized
+    pub fn is_maximized(&self) -> bool {
+        self.window.is_maximized()
+    }
+
     /// Set the shared frame buffer for direct frame access
     /// This allows the renderer to pull frames directly from the decoder
     pub fn set_shared_frame(&mut self, shared_frame: Arc<crate::app::SharedFrame>) {
         self.shared_frame = Some(shared_frame);
     }
 
+    /// Show a transient warning toast (same style as the resolution downgrade warning)
+    pub fn show_warning_notification(&mut self, message: &str) {
+        self.downgrade_notification = Some(DowngradeNotification::new(message));
+    }
+
     /// Show racing wheel connection notification
     /// Called when racing wheels are detected during streaming session
     pub fn show_wheel_notification(&mut self, wheel_count: usize) {
@@ -1639,6 +2308,34 @@ impl Renderer {
     /// On macOS, uses zero-copy path via CVPixelBuffer + Metal blit
     /// On Windows, uses D3D11 shared textures
     pub fn update_video(&mut self, frame: &VideoFrame) {
+        // Frames larger than the GPU's texture limit would fail texture creation
+        // (e.g. a 4K stream on Raspberry Pi V3D) - skip them with a clear message instead
+        let max_dimension = self.max_texture_dimension();
+        if frame.width > max_dimension || frame.height > max_dimension {
+            if self.oversized_frame != Some((frame.width, frame.height)) {
+                self.oversized_frame = Some((frame.width, frame.height));
+                error!(
+                    "Frame {}x{} exceeds GPU texture limit {} - not displaying",
+                    frame.width, frame.height, max_dimension
+                );
+                let supported = max_resolution_for_texture_limit(max_dimension)
+                    .and_then(|res| RESOLUTIONS.iter().find(|(value, _)| *value == res))
+                    .map(|(_, label)| *label)
+                    .unwrap_or("a lower resolution");
+                self.show_warning_notification(&format!(
+                    "Your GPU supports up to {}; reduce resolution",
+                    supported
+                ));
+            }
+            return;
+        }
+        self.oversized_frame = None;
+
+        // Already on the GPU (no new frame since the last redraw, or a repeated frame was skipped)
+        if frame.frame_id == self.last_uploaded_frame_id {
+            return;
+        }
+
         let uv_width = frame.width / 2;
         let uv_height = frame.height / 2;
 
@@ -2340,9 +3037,21 @@ impl Renderer {
             );
         }
 
+        // 10-bit HDR decoder output is P010 - import it as such or the planes are misread
+        let (format, pixel_format) = if gpu_frame.is_10bit {
+            (wgpu::TextureFormat::P010, PixelFormat::P010)
+        } else {
+            (wgpu::TextureFormat::NV12, PixelFormat::NV12)
+        };
+        let format_supported = !gpu_frame.is_10bit || self.p010_zero_copy;
+        if !format_supported && self.current_imported_format != Some(format) {
+            warn!("D3D11: GPU can't import P010 textures - HDR frames use the CPU copy path");
+            self.current_imported_format = Some(format);
+        }
+
         // Try zero-copy via Shared Handle first (only for non-array textures)
         // This eliminates the CPU copy by importing the D3D11 texture directly into DX12
-        if !is_texture_array {
+        if !is_texture_array && format_supported {
             if let Ok(handle) = gpu_frame.get_shared_handle() {
                 let mut handle_changed = false;
 
@@ -2376,7 +3085,6 @@ impl Renderer {
                                     depth_or_array_layers: 1,
                                 };
 
-                                let format = wgpu::TextureFormat::NV12;
                                 let usage = wgpu::TextureUsages::TEXTURE_BINDING
                                     | wgpu::TextureUsages::COPY_DST;
 
@@ -2415,8 +3123,22 @@ impl Renderer {
                     };
 
                     if let Some(texture) = imported_texture {
+                        if self.current_imported_format != Some(format) {
+                            info!(
+                                "Zero-copy: importing {:?} D3D11 textures ({}x{}, {})",
+                                format,
+                                frame.width,
+                                frame.height,
+                                if gpu_frame.is_10bit {
+                                    "10-bit HDR"
+                                } else {
+                                    "8-bit SDR"
+                                }
+                            );
+                        }
                         self.current_imported_texture = Some(texture);
                         self.current_imported_handle = Some(handle);
+                        self.current_imported_format = Some(format);
                         handle_changed = true;
                         // Log success once per session or on change
                         debug!(
@@ -2440,9 +3162,11 @@ impl Renderer {
                     if handle_changed || size_changed || self.external_texture_bind_group.is_none()
                     {
                         self.video_size = (frame.width, frame.height);
-                        self.current_format = PixelFormat::NV12;
+                        self.current_format = pixel_format;
 
                         // Create views for Y and UV planes
+                        // (P010 planes are viewed as R16Unorm/Rg16Unorm - the 10-bit samples
+                        // sit in the high bits, so normalized values match NV12's range)
                         let y_view = texture.create_view(&wgpu::TextureViewDescriptor {
                             label: Some("Plane 0 View"),
                             aspect: wgpu::TextureAspect::Plane0,
@@ -2456,6 +3180,7 @@ impl Renderer {
                         });
 
                         // Create ExternalTexture with color space aware conversion
+                        // Both NV12 and P010 are two-plane 4:2:0, so P010 uses the Nv12 layout
                         // Select YUV to RGB conversion matrix based on color space
                         let yuv_conversion_matrix: [f32; 16] = match frame.color_space {
                             ColorSpace::BT709 => [
@@ -3302,6 +4027,36 @@ impl Renderer {
         false
     }
 
+    /// Update the brightness/gamma/sharpening uniform and the video quad size for
+    /// the scaling mode and overscan inset (only uploads when the values change)
+    fn set_video_adjust(&mut self, settings: &Settings) {
+        let [scale_x, scale_y, offset_x, offset_y] = settings.scaling_mode.quad_transform(
+            self.video_size,
+            (self.size.width, self.size.height),
+            settings.overscan_scale(),
+        );
+        let adjust = [
+            settings.brightness.clamp(MIN_BRIGHTNESS, MAX_BRIGHTNESS),
+            settings.gamma.clamp(MIN_GAMMA, MAX_GAMMA),
+            settings.sharpening.clamp(0.0, 1.0),
+            if settings.scaling_mode.nearest_sampling() {
+                1.0
+            } else {
+                0.0
+            },
+            scale_x,
+            scale_y,
+            offset_x,
+            offset_y,
+        ];
+        if adjust == self.video_adjust {
+            return;
+        }
+        self.video_adjust = adjust;
+        self.queue
+            .write_buffer(&self.video_adjust_buffer, 0, bytemuck::cast_slice(&adjust));
+    }
+
     /// Render video frame to screen
     /// Automatically selects the correct pipeline based on current pixel format
     /// Priority: External Texture (true zero-copy) > NV12 > YUV420P
@@ -3329,6 +4084,7 @@ impl Renderer {
 
             render_pass.set_pipeline(pipeline);
             render_pass.set_bind_group(0, bind_group, &[]);
+            render_pass.set_bind_group(1, &self.video_adjust_bind_group, &[]);
             render_pass.draw(0..6, 0..1);
             return;
         }
@@ -3387,6 +4143,7 @@ impl Renderer {
 
         render_pass.set_pipeline(pipeline);
         render_pass.set_bind_group(0, bind_group, &[]);
+        render_pass.set_bind_group(1, &self.video_adjust_bind_group, &[]);
         render_pass.draw(0..6, 0..1); // Draw 6 vertices (2 triangles = 1 quad)
     }
 
@@ -3395,6 +4152,8 @@ impl Renderer {
     pub fn render(&mut self, app: &App) -> Result<(Vec<UiAction>, Option<Duration>)> {
         profile_scope!("render");
 
+        self.check_monitor_change();
+
         // Get surface texture with SMART error recovery for swapchain issues
         // Key insight: During fullscreen transitions, the window size updates AFTER
         // the surface error occurs. If we immediately "recover" with the old size,
@@ -3404,6 +4163,7 @@ impl Renderer {
             Ok(texture) => {
                 // Success - reset error counter
                 self.consecutive_surface_errors = 0;
+                self.last_surface_error_size = None;
                 texture
             }
             Err(wgpu::SurfaceError::Outdated) | Err(wgpu::SurfaceError::Lost) => {
@@ -3414,10 +4174,35 @@ impl Renderer {
                 let config_matches_window = current_window_size.width == self.config.width
                     && current_window_size.height == self.config.height;
 
-                if !config_matches_window {
-                    // Window size changed - resize event should handle this
-                    // Call resize directly to sync up
-                    debug!(
+                // Only trust the window size once it reads the same on two consecutive errors;
+                // mid-transition sizes are transient and would leave a stretched/black frame
+                let size_settled = self.last_surface_error_size == Some(current_window_size);
+                self.last_surface_error_size = Some(current_window_size);
+
+                if self.consecutive_surface_errors % SURFACE_ERRORS_BEFORE_FORCED_RECOVERY == 0 {
+                    // Persistent error - force recovery with a fresh window size query,
+                    // then back off for another round if it keeps failing
+                    warn!(
+                        "Swapchain persistently outdated ({} attempts) - forcing recovery",
+                        self.consecutive_surface_errors
+                    );
+                    if !self.recover_swapchain() {
+                        return Ok((vec![], None));
+                    }
+                    match self.surface.get_current_texture() {
+                        Ok(texture) => {
+                            self.consecutive_surface_errors = 0;
+                            self.last_surface_error_size = None;
+                            texture
+                        }
+                        Err(e) => {
+                            warn!("Failed to get texture after forced recovery: {}", e);
+                            return Ok((vec![], None));
+                        }
+                    }
+                } else if !config_matches_window && size_settled {
+                    // Window size changed and has settled - resize to sync up
+                    debug!(
                         "Swapchain outdated: window {}x{} != config {}x{} - resizing",
                         current_window_size.width,
                         current_window_size.height,
@@ -3430,6 +4215,7 @@ impl Renderer {
                     match self.surface.get_current_texture() {
                         Ok(texture) => {
                             self.consecutive_surface_errors = 0;
+                            self.last_surface_error_size = None;
                             info!(
                                 "Swapchain recovered after resize to {}x{}",
                                 current_window_size.width, current_window_size.height
@@ -3441,34 +4227,18 @@ impl Renderer {
                             return Ok((vec![], None));
                         }
                     }
-                } else if self.consecutive_surface_errors < 10 {
-                    // Sizes match but surface is outdated - likely a race condition
+                } else {
+                    // Size still changing, or sizes match (likely a race condition)
                     // YIELD to event loop to let Resized event arrive with correct size
                     debug!(
-                        "Swapchain outdated (attempt {}/10): sizes match {}x{} - yielding to event loop",
+                        "Swapchain outdated (attempt {}): window {}x{}, config {}x{} - yielding to event loop",
                         self.consecutive_surface_errors,
-                        self.config.width, self.config.height
+                        current_window_size.width,
+                        current_window_size.height,
+                        self.config.width,
+                        self.config.height
                     );
                     return Ok((vec![], None));
-                } else {
-                    // Persistent error (10+ frames) - force recovery as fallback
-                    warn!(
-                        "Swapchain persistently outdated ({} attempts) - forcing recovery",
-                        self.consecutive_surface_errors
-                    );
-                    if !self.recover_swapchain() {
-                        return Ok((vec![], None));
-                    }
-                    match self.surface.get_current_texture() {
-                        Ok(texture) => {
-                            self.consecutive_surface_errors = 0;
-                            texture
-                        }
-                        Err(e) => {
-                            warn!("Failed to get texture after forced recovery: {}", e);
-                            return Ok((vec![], None));
-                        }
-                    }
                 }
             }
             Err(wgpu::SurfaceError::Timeout) => {
@@ -3503,6 +4273,7 @@ impl Renderer {
         let has_video = self.video_bind_group.is_some() || self.nv12_bind_group.is_some();
         if app.state == AppState::Streaming && has_video {
             profile_scope!("render_video");
+            self.set_video_adjust(&app.settings);
             // Render video full-screen
             self.render_video(&mut encoder, &view);
         } else {
@@ -3532,11 +4303,19 @@ impl Renderer {
         let raw_input = self.egui_state.take_egui_input(&self.window);
         let mut actions: Vec<UiAction> = Vec::new();
 
-        // === UI Optimization: Throttle stats updates to 200ms ===
+        // === UI Optimization: Throttle stats updates (default 200ms, user-configurable) ===
         // This dramatically reduces CPU usage from stats panel rendering
-        const STATS_UPDATE_INTERVAL: Duration = Duration::from_millis(200);
-        if self.stats_last_update.elapsed() >= STATS_UPDATE_INTERVAL {
-            self.cached_stats = Some(app.stats.clone());
+        let stats_update_interval = Duration::from_millis(
+            app.settings
+                .stats_refresh_ms
+                .clamp(MIN_STATS_REFRESH_MS, MAX_STATS_REFRESH_MS) as u64,
+        );
+        if self.stats_last_update.elapsed() >= stats_update_interval {
+            let mut stats = app.stats.clone();
+            self.frame_times.apply_to(&mut stats);
+            stats.window_mode = self.window_mode_label();
+            self.check_exclusive_fallback();
+            self.cached_stats = Some(stats);
             self.stats_last_update = Instant::now();
 
             // Detect resolution changes and show notification
@@ -3558,6 +4337,38 @@ impl Renderer {
                 // Wheels disconnected - reset state
                 self.last_wheel_count = 0;
             }
+
+            // Detect server resolution downgrade and show warning once per session
+            if app.resolution_downgrade_warning != self.last_downgrade_warning {
+                if let Some(ref message) = app.resolution_downgrade_warning {
+                    self.downgrade_notification = Some(DowngradeNotification::new(message));
+                }
+                self.last_downgrade_warning = app.resolution_downgrade_warning.clone();
+            }
+
+            // Same for a 4:4:4 request served as subsampled chroma
+            if app.chroma_downgrade_warning != self.last_chroma_warning {
+                if let Some(ref message) = app.chroma_downgrade_warning {
+                    self.show_warning_notification(message);
+                }
+                self.last_chroma_warning = app.chroma_downgrade_warning.clone();
+            }
+
+            // And for a resolution lowered to fit the GPU's texture limit
+            if app.texture_limit_warning != self.last_texture_limit_warning {
+                if let Some(ref message) = app.texture_limit_warning {
+                    self.show_warning_notification(message);
+                }
+                self.last_texture_limit_warning = app.texture_limit_warning.clone();
+            }
+
+            // And for decoding slowing down mid-stream (thermal throttling, CPU load)
+            if app.decode_slowdown_warning != self.last_decode_slowdown_warning {
+                if let Some(ref message) = app.decode_slowdown_warning {
+                    self.show_warning_notification(message);
+                }
+                self.last_decode_slowdown_warning = app.decode_slowdown_warning.clone();
+            }
         }
 
         // Clean up expired notifications
@@ -3571,6 +4382,11 @@ impl Renderer {
                 self.wheel_notification = None;
             }
         }
+        if let Some(ref notif) = self.downgrade_notification {
+            if notif.is_expired() {
+                self.downgrade_notification = None;
+            }
+        }
 
         // Extract state needed for UI rendering
         let app_state = app.state;
@@ -3582,9 +4398,11 @@ impl Renderer {
         let show_stats = app.show_stats;
         let status_message = app.status_message.clone();
         let error_message = app.error_message.clone();
+        let session_stuck = app.stuck_session_id.is_some();
         let selected_game = app.selected_game.clone();
-        let stats_position = self.stats_panel.position;
-        let stats_visible = self.stats_panel.visible;
+        let stats_position = app.settings.stats_position;
+        // The overlay steps aside while the stats are popped out
+        let stats_visible = self.stats_panel.visible && self.stats_window.is_none();
         let show_settings = app.show_settings;
         let settings = app.settings.clone();
         let login_providers = app.login_providers.clone();
@@ -3592,6 +4410,7 @@ impl Renderer {
         let is_loading = app.is_loading;
         let login_url = app.login_url.clone();
         let show_welcome_popup = app.show_welcome_popup;
+        let show_tips_on_startup = app.settings.show_tips_on_startup;
         let mut search_query = app.search_query.clone();
         let runtime = app.runtime.clone();
 
@@ -3605,6 +4424,8 @@ impl Renderer {
         let selected_server_index = app.selected_server_index;
         let auto_server_selection = app.auto_server_selection;
         let ping_testing = app.ping_testing;
+        let ping_measured_at = app.ping_measured_at;
+        let servers_offline = app.servers_offline;
         let show_settings_modal = app.show_settings_modal;
 
         // Resolution notification data (extracted for use in closure)
@@ -3623,6 +4444,33 @@ impl Renderer {
             .as_ref()
             .map(|n| (n.wheel_count, n.alpha()));
 
+        // Downgrade warning data (extracted for use in closure)
+        let downgrade_notif = self
+            .downgrade_notification
+            .as_ref()
+            .map(|n| (n.message.clone(), n.alpha()));
+
+        let idle_terminate_in_secs = app.idle_terminate_in_secs;
+        let connection_test_secs_left = app.connection_test.as_ref().map(|test| {
+            crate::app::connection_test::TEST_DURATION
+                .saturating_sub(test.elapsed())
+                .as_secs()
+        });
+        let mouse_capture_key = app.settings.mouse_capture_key.display_name();
+
+        // Bitrate overlay is interactive, so only shown while the mouse is released (F8 by default)
+        let stream_bitrate = (!app.cursor_captured)
+            .then_some((app.stream_bitrate_mbps, app.settings.max_bitrate_mbps));
+
+        // WebRTC setup progress, shown until the first video frame arrives
+        let connection_progress = if app.current_frame.is_none() {
+            let (stage, failed_at) = crate::webrtc::connection_stage();
+            let failure_reason = failed_at.and(crate::webrtc::connection_failure_reason());
+            Some((stage, failed_at, failure_reason))
+        } else {
+            None
+        };
+
         // Queue times state
         let mut queue_servers = app.queue_servers.clone();
         let queue_loading = app.queue_loading;
@@ -3703,9 +4551,14 @@ impl Renderer {
                             &mut actions,
                         );
 
-                        // Show welcome popup for first-time users
+                        // Show welcome popup on startup (unless disabled in settings)
                         if show_welcome_popup {
-                            render_welcome_popup(ctx, &mut actions);
+                            render_welcome_popup(ctx, show_tips_on_startup, &mut actions);
+                        }
+
+                        // Crash report from the previous run
+                        if let Some(crash) = app.last_crash() {
+                            render_crash_report_dialog(ctx, crash, &mut actions);
                         }
                     }
                     AppState::Games => {
@@ -3738,24 +4591,76 @@ impl Renderer {
                             selected_server_index,
                             auto_server_selection,
                             ping_testing,
+                            ping_measured_at,
+                            servers_offline,
                             show_settings_modal,
                             app.show_session_conflict,
                             app.show_av1_warning,
                             app.show_alliance_warning,
+                            app.show_account_panel,
+                            app.gpu_memory_warning.as_deref(),
                             crate::auth::get_selected_provider()
                                 .login_provider_display_name
                                 .as_str(),
                             &app.active_sessions,
+                            &app
+                                .active_sessions
+                                .iter()
+                                .map(|s| app.active_session_title(s))
+                                .collect::<Vec<_>>(),
+                            &app.ending_sessions,
+                            app.end_session_error.as_deref(),
                             app.pending_game_launch.as_ref(),
+                            app.low_hours_launch.as_ref(),
                             &mut queue_servers,
                             queue_loading,
+                            app.queue_error.as_deref(),
+                            app.queue_fetched_at,
                             queue_sort_mode,
                             &queue_region_filter,
+                            &app.queue_history,
+                            app.queue_alert.as_ref(),
                             show_server_selection,
                             &selected_queue_server,
                             pending_server_selection_game.as_ref(),
+                            app.update_check_in_progress,
+                            app.update_check_result.as_ref(),
+                            app.update_download_in_progress,
+                            app.update_download_result.as_ref(),
+                            app.decoder_check_in_progress,
+                            app.decoder_check_result.as_ref(),
                             &mut actions,
                         );
+
+                        // Welcome popup re-opened from Settings > About
+                        if show_welcome_popup {
+                            render_welcome_popup(ctx, show_tips_on_startup, &mut actions);
+                        }
+
+                        // Newer release found by the startup check
+                        if let Some(release) = app.update_notice() {
+                            render_update_notice(ctx, release, &mut actions);
+                        }
+
+                        // Crash report from the previous run
+                        if let Some(crash) = app.last_crash() {
+                            render_crash_report_dialog(ctx, crash, &mut actions);
+                        }
+
+                        // Outcome of "Test my connection"
+                        if let Some(ref result) = app.connection_test_result {
+                            render_connection_test_dialog(ctx, result, &settings, &mut actions);
+                        }
+
+                        // Link handed over by another OpenNOW process
+                        if let Some((_, ref name)) = app.forwarded_launch {
+                            render_forwarded_launch_dialog(ctx, name, &mut actions);
+                        }
+
+                        // Data used and stream quality of the last stream
+                        if let Some(ref summary) = app.session_summary {
+                            render_session_summary_dialog(ctx, summary, &mut actions);
+                        }
                     }
                     AppState::Session => {
                         // Session screen shows loading spinner, update at 30fps for smooth animation
@@ -3776,6 +4681,8 @@ impl Renderer {
                                 &selected_game,
                                 &status_message,
                                 &error_message,
+                                session_stuck,
+                                app.stream_settings_offer.as_ref(),
                                 &mut actions,
                             );
                         }
@@ -3784,6 +4691,8 @@ impl Renderer {
                         // Render stats overlay
                         if show_stats && stats_visible {
                             render_stats_panel(ctx, &stats, stats_position);
+                        } else if settings.minimal_hud {
+                            render_minimal_hud(ctx, &stats, stats_position);
                         }
 
                         // Render resolution change notification
@@ -3798,15 +4707,46 @@ impl Renderer {
                             render_wheel_notification(ctx, wheel_count, alpha);
                         }
 
+                        // Render resolution downgrade warning
+                        if let Some((message, alpha)) = &downgrade_notif {
+                            render_downgrade_notification(ctx, message, *alpha);
+                        }
+
+                        // Idle timeout countdown
+                        if let Some(secs) = idle_terminate_in_secs {
+                            render_idle_warning(ctx, secs);
+                        }
+
+                        // Connection test countdown (the session ends by itself)
+                        if let Some(secs) = connection_test_secs_left {
+                            render_connection_test_progress(ctx, secs);
+                        }
+
+                        // Mid-session bitrate control
+                        if let Some((current_mbps, max_mbps)) = stream_bitrate {
+                            render_stream_bitrate_overlay(ctx, current_mbps, max_mbps, &mut actions);
+                        }
+
+                        // Render staged connection progress until video arrives
+                        if let Some((stage, failed_at, ref failure_reason)) = connection_progress {
+                            render_connection_progress(
+                                ctx,
+                                stage,
+                                failed_at,
+                                failure_reason.as_deref(),
+                            );
+                        }
+
                         // Small overlay hint
                         egui::Area::new(egui::Id::new("stream_hint"))
                             .anchor(egui::Align2::CENTER_TOP, [0.0, 10.0])
                             .interactable(false)
                             .show(ctx, |ui| {
                                 ui.label(
-                                    egui::RichText::new(
-                                        "Ctrl+Shift+Q to stop • F3 stats • F11 fullscreen",
-                                    )
+                                    egui::RichText::new(format!(
+                                        "Ctrl+Shift+Q to stop • F3 stats • {} mouse • F11 fullscreen",
+                                        mouse_capture_key
+                                    ))
                                     .color(egui::Color32::from_rgba_unmultiplied(
                                         255, 255, 255, 100,
                                     ))
@@ -3815,6 +4755,11 @@ impl Renderer {
                             });
                     }
                 }
+
+                // Screensaver covers the whole menu, dialogs included
+                if app.screensaver_active && matches!(app_state, AppState::Login | AppState::Games) {
+                    render_menu_screensaver(ctx);
+                }
             });
         } // end profile_scope!("egui_run")
 
@@ -3849,7 +4794,8 @@ impl Renderer {
         // Render egui
         let screen_descriptor = egui_wgpu::ScreenDescriptor {
             size_in_pixels: [self.size.width, self.size.height],
-            pixels_per_point: self.window.scale_factor() as f32,
+            // The scale egui laid this frame out at (matches the tessellation above)
+            pixels_per_point: full_output.pixels_per_point,
         };
 
         self.egui_renderer.update_buffers(
@@ -3896,6 +4842,13 @@ impl Renderer {
             output.present();
         }
 
+        // Track frame pacing while streaming (render is driven by new decoded frames)
+        if app.state == AppState::Streaming {
+            self.frame_times.record_present();
+        } else {
+            self.frame_times.reset();
+        }
+
         // Return repaint delay based on app state for idle throttling
         // This is set by request_repaint_after() calls in the UI code
         let repaint_delay = match app.state {
@@ -3928,20 +4881,38 @@ impl Renderer {
         selected_server_index: usize,
         auto_server_selection: bool,
         ping_testing: bool,
+        ping_measured_at: Option<i64>,
+        servers_offline: bool,
         show_settings_modal: bool,
         show_session_conflict: bool,
         show_av1_warning: bool,
         show_alliance_warning: bool,
+        show_account_panel: bool,
+        gpu_memory_warning: Option<&str>,
         alliance_provider_name: &str,
         active_sessions: &[ActiveSessionInfo],
+        session_titles: &[String],
+        ending_sessions: &[String],
+        end_session_error: Option<&str>,
         pending_game_launch: Option<&GameInfo>,
+        low_hours_launch: Option<&GameInfo>,
         queue_servers: &mut Vec<crate::api::QueueServerInfo>,
         queue_loading: bool,
+        queue_error: Option<&str>,
+        queue_fetched_at: Option<i64>,
         queue_sort_mode: crate::app::QueueSortMode,
         queue_region_filter: &crate::app::QueueRegionFilter,
+        queue_history: &crate::app::queue_history::QueueHistory,
+        queue_alert: Option<&crate::app::queue_alert::QueueAlert>,
         show_server_selection: bool,
         selected_queue_server: &Option<String>,
         pending_server_selection_game: Option<&GameInfo>,
+        update_check_in_progress: bool,
+        update_check_result: Option<&crate::app::cache::UpdateCheckResult>,
+        update_download_in_progress: bool,
+        update_download_result: Option<&crate::app::cache::UpdateDownloadResult>,
+        decoder_check_in_progress: bool,
+        decoder_check_result: Option<&crate::app::cache::DecoderCheckResult>,
         actions: &mut Vec<UiAction>,
     ) {
         // Top bar with tabs, search, and logout - subscription info moved to bottom
@@ -4163,7 +5134,7 @@ impl Renderer {
                             ),
                         };
 
-                        egui::Frame::new()
+                        let tier_badge = egui::Frame::new()
                             .fill(tier_bg)
                             .corner_radius(4.0)
                             .inner_margin(egui::Margin {
@@ -4179,7 +5150,14 @@ impl Renderer {
                                         .color(tier_fg)
                                         .strong(),
                                 );
-                            });
+                            })
+                            .response
+                            .interact(egui::Sense::click())
+                            .on_hover_cursor(egui::CursorIcon::PointingHand)
+                            .on_hover_text("Plan details");
+                        if tier_badge.clicked() {
+                            actions.push(UiAction::ToggleAccountPanel);
+                        }
 
                         // Alliance badge (if using an Alliance partner)
                         if crate::auth::get_selected_provider().is_alliance_partner() {
@@ -4200,7 +5178,9 @@ impl Renderer {
                                             .color(egui::Color32::from_rgb(100, 180, 255))
                                             .strong(),
                                     );
-                                });
+                                })
+                                .response
+                                .on_hover_ui(render_provider_features);
                         }
 
                         ui.add_space(20.0);
@@ -4271,17 +5251,25 @@ impl Renderer {
 
                     // Right side: server info
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if servers_offline {
+                            ui.label(
+                                egui::RichText::new("⚠ Offline — using built-in server list")
+                                    .size(12.0)
+                                    .color(egui::Color32::from_rgb(255, 180, 50)),
+                            )
+                            .on_hover_text(
+                                "Couldn't reach the server list API. Retrying in the background.",
+                            );
+                            ui.add_space(10.0);
+                        }
                         // Show selected server
                         if auto_server_selection {
-                            let best_server = servers
-                                .iter()
-                                .filter(|s| {
-                                    s.status == crate::app::ServerStatus::Online
-                                        && s.ping_ms.is_some()
-                                })
-                                .min_by_key(|s| s.ping_ms.unwrap_or(9999));
+                            // The server select_best_server picked (what a launch will use)
+                            let auto_server = servers.get(selected_server_index).filter(|s| {
+                                s.status == crate::app::ServerStatus::Online && s.ping_ms.is_some()
+                            });
 
-                            if let Some(server) = best_server {
+                            if let Some(server) = auto_server {
                                 ui.label(
                                     egui::RichText::new(format!(
                                         "🌐 Auto: {} ({}ms)",
@@ -4516,8 +5504,13 @@ impl Renderer {
                         ui.add_space(12.0);
 
                         // Region count badge
-                        if queue_loading {
-                            ui.spinner();
+                        if let Some(error) = queue_error.filter(|_| !queue_servers.is_empty()) {
+                            ui.label(
+                                egui::RichText::new("⚠ Queue data unavailable - showing last results")
+                                    .size(12.0)
+                                    .color(egui::Color32::from_rgb(255, 180, 50))
+                            )
+                            .on_hover_text(error);
                         } else {
                             egui::Frame::new()
                                 .fill(egui::Color32::from_rgb(40, 40, 55))
@@ -4532,6 +5525,26 @@ impl Renderer {
                                 });
                         }
 
+                        // Age of the shown data (flagged once it's past the TTL)
+                        if let Some(fetched_at) = queue_fetched_at.filter(|_| !queue_servers.is_empty()) {
+                            let age_secs = (chrono::Utc::now().timestamp() - fetched_at).max(0);
+                            ui.add_space(8.0);
+                            if age_secs > crate::app::cache::QUEUE_DATA_TTL_SECS {
+                                ui.label(
+                                    egui::RichText::new(format!("⚠ Updated {}", format_age(age_secs)))
+                                        .size(12.0)
+                                        .color(egui::Color32::from_rgb(255, 180, 50))
+                                )
+                                .on_hover_text("Queue times may have changed since. Refresh before picking a server.");
+                            } else {
+                                ui.label(
+                                    egui::RichText::new(format!("Updated {}", format_age(age_secs)))
+                                        .size(12.0)
+                                        .color(egui::Color32::from_rgb(120, 120, 120))
+                                );
+                            }
+                        }
+
                         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                             ui.add_space(16.0);
 
@@ -4544,7 +5557,14 @@ impl Renderer {
                             .fill(egui::Color32::from_rgb(50, 50, 65))
                             .corner_radius(6.0);
 
-                            if ui.add(refresh_btn).clicked() {
+                            if queue_loading {
+                                ui.label(
+                                    egui::RichText::new("Refreshing...")
+                                        .size(12.0)
+                                        .color(egui::Color32::from_rgb(150, 150, 150))
+                                );
+                                ui.spinner();
+                            } else if ui.add(refresh_btn).clicked() {
                                 actions.push(UiAction::RefreshQueueTimes);
                             }
                         });
@@ -4562,17 +5582,31 @@ impl Renderer {
                             );
                             ui.add_space(16.0);
                             ui.label(
-                                egui::RichText::new("No Queue Data Available")
+                                egui::RichText::new(if queue_error.is_some() { "Queue Data Unavailable" } else { "No Queue Data Available" })
                                     .size(18.0)
                                     .strong()
                                     .color(egui::Color32::from_rgb(180, 180, 180))
                             );
                             ui.add_space(8.0);
-                            ui.label(
-                                egui::RichText::new("Click Refresh to load queue times")
-                                    .size(14.0)
-                                    .color(egui::Color32::from_rgb(120, 120, 120))
-                            );
+                            if let Some(error) = queue_error {
+                                ui.label(
+                                    egui::RichText::new("The queue data source couldn't be reached. Try again later,\nor set a mirror under Settings > Queue Data Source.")
+                                        .size(14.0)
+                                        .color(egui::Color32::from_rgb(120, 120, 120))
+                                );
+                                ui.add_space(4.0);
+                                ui.label(
+                                    egui::RichText::new(error)
+                                        .size(11.0)
+                                        .color(egui::Color32::from_rgb(100, 100, 100))
+                                );
+                            } else {
+                                ui.label(
+                                    egui::RichText::new("Click Refresh to load queue times")
+                                        .size(14.0)
+                                        .color(egui::Color32::from_rgb(120, 120, 120))
+                                );
+                            }
                         });
                     } else {
                         egui::ScrollArea::vertical()
@@ -4722,6 +5756,75 @@ impl Renderer {
 
                                 ui.add_space(20.0);
 
+                                // Active queue alert
+                                if let Some(alert) = queue_alert {
+                                    ui.horizontal(|ui| {
+                                        ui.add_space(16.0);
+                                        egui::Frame::new()
+                                            .fill(egui::Color32::from_rgb(35, 45, 30))
+                                            .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(118, 185, 0)))
+                                            .corner_radius(8.0)
+                                            .inner_margin(egui::Margin::same(10))
+                                            .show(ui, |ui| {
+                                                ui.set_width(ui.available_width() - 32.0);
+                                                ui.horizontal(|ui| {
+                                                    ui.label(
+                                                        egui::RichText::new(format!("🔔 Notify me when the {} queue is under", alert.location))
+                                                            .size(13.0)
+                                                            .color(egui::Color32::WHITE)
+                                                    );
+                                                    egui::ComboBox::from_id_salt("queue_alert_threshold")
+                                                        .selected_text(format!("{} min", alert.threshold_minutes))
+                                                        .width(70.0)
+                                                        .show_ui(ui, |ui| {
+                                                            for &minutes in crate::app::queue_alert::QUEUE_ALERT_THRESHOLD_OPTIONS {
+                                                                if ui.selectable_label(alert.threshold_minutes == minutes, format!("{} min", minutes)).clicked() {
+                                                                    actions.push(UiAction::SetQueueAlert(Some(crate::app::queue_alert::QueueAlert {
+                                                                        threshold_minutes: minutes,
+                                                                        ..alert.clone()
+                                                                    })));
+                                                                }
+                                                            }
+                                                        });
+                                                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                                        if ui.button("Cancel").clicked() {
+                                                            actions.push(UiAction::SetQueueAlert(None));
+                                                        }
+                                                    });
+                                                });
+                                            });
+                                    });
+                                    ui.add_space(12.0);
+                                }
+
+                                // Best time to play: filtered region, else the recommended server's region
+                                let heatmap_region = match queue_region_filter {
+                                    crate::app::QueueRegionFilter::Region(region) => Some(region.clone()),
+                                    crate::app::QueueRegionFilter::All => recommended_server
+                                        .map(|s| s.region.clone())
+                                        .or_else(|| region_keys.first().cloned()),
+                                };
+                                if let Some(region) = heatmap_region {
+                                    ui.horizontal(|ui| {
+                                        ui.add_space(16.0);
+                                        ui.vertical(|ui| {
+                                            ui.set_width(ui.available_width() - 32.0);
+                                            egui::CollapsingHeader::new(
+                                                egui::RichText::new(format!("📅 Best Time to Play ({})", region))
+                                                    .size(15.0)
+                                                    .strong()
+                                                    .color(egui::Color32::WHITE)
+                                            )
+                                            .id_salt("queue_heatmap")
+                                            .default_open(false)
+                                            .show(ui, |ui| {
+                                                render_queue_heatmap(ui, queue_history, &region);
+                                            });
+                                        });
+                                    });
+                                    ui.add_space(12.0);
+                                }
+
                                 // Region sections with locations (using CollapsingHeader)
                                 for region in &region_keys {
                                     if let Some(locations) = region_locations.get(region) {
@@ -4836,6 +5939,32 @@ impl Renderer {
                                                                         }
 
                                                                         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                                                            // Queue alert toggle
+                                                                            let watched = queue_alert.is_some_and(|a| &a.region == region && a.location == location.display_name);
+                                                                            let bell = egui::Button::new(
+                                                                                egui::RichText::new("🔔")
+                                                                                    .size(11.0)
+                                                                                    .color(if watched { egui::Color32::from_rgb(118, 185, 0) } else { egui::Color32::from_rgb(120, 120, 140) })
+                                                                            )
+                                                                            .frame(watched);
+                                                                            let hover = if watched {
+                                                                                "Stop watching this queue"
+                                                                            } else {
+                                                                                "Notify me when this queue gets short"
+                                                                            };
+                                                                            if ui.add(bell).on_hover_text(hover).clicked() {
+                                                                                actions.push(UiAction::SetQueueAlert((!watched).then(|| crate::app::queue_alert::QueueAlert {
+                                                                                    region: region.clone(),
+                                                                                    location: location.display_name.clone(),
+                                                                                    threshold_minutes: queue_alert.map_or(
+                                                                                        crate::app::queue_alert::DEFAULT_QUEUE_ALERT_THRESHOLD_MINUTES,
+                                                                                        |a| a.threshold_minutes,
+                                                                                    ),
+                                                                                })));
+                                                                            }
+
+                                                                            ui.add_space(8.0);
+
                                                                             // ETA
                                                                             let eta_text = crate::api::format_queue_eta(location.avg_eta_seconds);
                                                                             let eta_color = if location.avg_eta_seconds.unwrap_or(0) <= 0 {
@@ -5041,7 +6170,7 @@ impl Renderer {
 
         // Game detail popup
         if let Some(game) = selected_game_popup {
-            Self::render_game_popup(ctx, game, game_textures, subscription, actions);
+            Self::render_game_popup(ctx, game, game_textures, subscription, settings, actions);
         }
 
         // Server selection modal (for free tier users)
@@ -5060,6 +6189,22 @@ impl Renderer {
 
         // Settings modal
         if show_settings_modal {
+            let about = AboutInfo {
+                gpu_name: &self.adapter_info.name,
+                gpu_backend: format!("{:?}", self.adapter_info.backend),
+                update_check_in_progress,
+                update_check_result,
+                update_download_in_progress,
+                update_download_result,
+                decoder_check_in_progress,
+                decoder_check_result,
+                display_mode: self.monitor_snapshot.as_ref().map(|monitor| {
+                    let refresh_hz = monitor
+                        .refresh_millihertz
+                        .map_or(60, |mhz| (mhz + 500) / 1000);
+                    (monitor.size.width, monitor.size.height, refresh_hz)
+                }),
+            };
             render_settings_modal(
                 ctx,
                 settings,
@@ -5067,14 +6212,25 @@ impl Renderer {
                 selected_server_index,
                 auto_server_selection,
                 ping_testing,
+                ping_measured_at,
+                servers_offline,
                 subscription,
+                &about,
                 actions,
             );
         }
 
         // Session conflict dialog
         if show_session_conflict {
-            render_session_conflict_dialog(ctx, active_sessions, pending_game_launch, actions);
+            render_session_conflict_dialog(
+                ctx,
+                active_sessions,
+                session_titles,
+                ending_sessions,
+                end_session_error,
+                pending_game_launch,
+                actions,
+            );
         }
 
         // AV1 hardware warning dialog
@@ -5082,10 +6238,25 @@ impl Renderer {
             render_av1_warning_dialog(ctx, actions);
         }
 
+        // Low playtime confirmation before launching
+        if let (Some(game), Some(sub)) = (low_hours_launch, subscription) {
+            render_low_hours_dialog(ctx, game, sub.remaining_hours, actions);
+        }
+
         // Alliance experimental warning dialog
         if show_alliance_warning {
             render_alliance_warning_dialog(ctx, alliance_provider_name, actions);
         }
+
+        // Plan details (opened from the tier badge)
+        if show_account_panel {
+            render_account_panel(ctx, subscription, settings, actions);
+        }
+
+        // Low GPU memory warning dialog (Raspberry Pi V3D)
+        if let Some(message) = gpu_memory_warning {
+            render_gpu_memory_warning_dialog(ctx, message, actions);
+        }
     }
 
     // Note: render_settings_modal, render_session_conflict_dialog, render_av1_warning_dialog
@@ -5098,6 +6269,7 @@ impl Renderer {
         game: &crate::app::GameInfo,
         game_textures: &HashMap<String, egui::TextureHandle>,
         subscription: Option<&crate::app::SubscriptionInfo>,
+        settings: &crate::app::Settings,
         actions: &mut Vec<UiAction>,
     ) {
         // Check if user is free tier (show server selection modal instead of direct launch).
@@ -5303,6 +6475,55 @@ impl Renderer {
                         );
                     }
 
+                    // Streaming feature badges (HDR, RTX, ...) when the catalog lists them
+                    if let Some(ref features) = game.features {
+                        if !features.is_empty() {
+                            ui.add_space(6.0);
+                            ui.horizontal_wrapped(|ui| {
+                                for feature in features {
+                                    egui::Frame::new()
+                                        .fill(egui::Color32::from_rgb(40, 60, 30))
+                                        .corner_radius(3.0)
+                                        .inner_margin(egui::Margin::symmetric(6, 2))
+                                        .show(ui, |ui| {
+                                            ui.label(
+                                                egui::RichText::new(feature)
+                                                    .size(11.0)
+                                                    .color(egui::Color32::from_rgb(118, 185, 0))
+                                                    .strong(),
+                                            );
+                                        });
+                                }
+                            });
+                        }
+                    }
+
+                    // Settings this title can't use are turned off for the launch
+                    let mut unsupported = Vec::new();
+                    if settings.hdr_enabled && game.supports_hdr() == Some(false) {
+                        unsupported.push(
+                            "HDR is on, but this game doesn't support it - it will launch in SDR"
+                                .to_string(),
+                        );
+                    }
+                    if settings.fps > 60 && game.supports_high_fps() == Some(false) {
+                        unsupported.push(format!(
+                            "This game doesn't support {} FPS - it will launch at 60 FPS",
+                            settings.fps
+                        ));
+                    }
+                    for warning in unsupported {
+                        ui.add_space(4.0);
+                        ui.add(
+                            egui::Label::new(
+                                egui::RichText::new(format!("⚠ {}", warning))
+                                    .size(11.0)
+                                    .color(egui::Color32::from_rgb(255, 180, 50)),
+                            )
+                            .wrap(),
+                        );
+                    }
+
                     ui.add_space(20.0);
 
                     // Description
@@ -5351,6 +6572,29 @@ impl Renderer {
 
                         ui.add_space(20.0);
 
+                        // Shortcut that starts the game from the Start menu / app launcher
+                        let shortcut_btn = egui::Button::new(
+                            egui::RichText::new("  Create Shortcut  ").size(14.0),
+                        )
+                        .fill(egui::Color32::from_rgb(60, 60, 80))
+                        .min_size(egui::vec2(80.0, 40.0));
+
+                        if ui
+                            .add(shortcut_btn)
+                            .on_hover_text(format!(
+                                "Add {} to {} so it starts without opening the menu",
+                                game.title,
+                                crate::utils::SHORTCUT_LOCATION
+                            ))
+                            .clicked()
+                        {
+                            let box_art =
+                                game.image_url.as_deref().and_then(image_cache::get_image);
+                            actions.push(UiAction::CreateGameShortcut(game.clone(), box_art));
+                        }
+
+                        ui.add_space(10.0);
+
                         // Close button
                         let close_btn =
                             egui::Button::new(egui::RichText::new("  Close  ").size(14.0))
@@ -6008,7 +7252,7 @@ fn render_stats_panel(
     stats: &crate::media::StreamStats,
     position: crate::app::StatsPosition,
 ) {
-    use egui::{Align2, Color32, FontId, RichText};
+    use egui::{Align2, Color32};
 
     let (anchor, offset) = match position {
         crate::app::StatsPosition::BottomLeft => (Align2::LEFT_BOTTOM, [10.0, -10.0]),
@@ -6017,9 +7261,12 @@ fn render_stats_panel(
         crate::app::StatsPosition::TopRight => (Align2::RIGHT_TOP, [-10.0, 10.0]),
     };
 
+    // Foreground order keeps the overlay above the video in every window mode,
+    // including exclusive fullscreen
     egui::Area::new(egui::Id::new("stats_panel"))
         .anchor(anchor, offset)
         .interactable(false)
+        .order(egui::Order::Foreground)
         .show(ctx, |ui| {
             egui::Frame::new()
                 .fill(Color32::from_rgba_unmultiplied(0, 0, 0, 200))
@@ -6027,249 +7274,362 @@ fn render_stats_panel(
                 .inner_margin(8.0)
                 .show(ui, |ui| {
                     ui.set_min_width(200.0);
+                    render_stats_contents(ui, stats);
+                });
+        });
+}
 
-                    // Resolution and HDR status
-                    let res_text = if stats.resolution.is_empty() {
-                        "Connecting...".to_string()
-                    } else {
-                        stats.resolution.clone()
-                    };
+/// One-line fps and ping counter (Settings > FPS Counter), drawn in the stats corner
+fn render_minimal_hud(
+    ctx: &egui::Context,
+    stats: &crate::media::StreamStats,
+    position: crate::app::StatsPosition,
+) {
+    use egui::{Align2, Color32, FontId, RichText};
 
-                    ui.horizontal(|ui| {
-                        ui.label(
-                            RichText::new(res_text)
-                                .font(FontId::monospace(13.0))
-                                .color(Color32::WHITE),
-                        );
+    let (anchor, offset) = match position {
+        crate::app::StatsPosition::BottomLeft => (Align2::LEFT_BOTTOM, [10.0, -10.0]),
+        crate::app::StatsPosition::BottomRight => (Align2::RIGHT_BOTTOM, [-10.0, -10.0]),
+        crate::app::StatsPosition::TopLeft => (Align2::LEFT_TOP, [10.0, 10.0]),
+        crate::app::StatsPosition::TopRight => (Align2::RIGHT_TOP, [-10.0, 10.0]),
+    };
 
-                        // HDR indicator
-                        if stats.is_hdr {
-                            ui.label(
-                                RichText::new(" HDR")
-                                    .font(FontId::monospace(13.0))
-                                    .color(Color32::from_rgb(255, 180, 0)), // Orange/gold for HDR
-                            );
-                        }
-                    });
+    // Render fps is what's on screen; it's 0 until the first frame is presented
+    let fps = if stats.render_fps > 0.0 {
+        stats.render_fps
+    } else {
+        stats.fps
+    };
+    let text = if stats.rtt_ms > 0.0 {
+        format!("{:.0}fps {:.0}ms", fps, stats.rtt_ms)
+    } else {
+        format!("{:.0}fps", fps)
+    };
+    let color = if stats.target_fps > 0 && fps < stats.target_fps as f32 * 0.8 {
+        Color32::YELLOW
+    } else {
+        Color32::WHITE
+    };
 
-                    // Decoded FPS vs Render FPS (shows if renderer is bottlenecked)
-                    let decode_fps = stats.fps;
-                    let render_fps = stats.render_fps;
-                    let target_fps = stats.target_fps as f32;
-
-                    // Decode FPS color
-                    let decode_color = if target_fps > 0.0 {
-                        let ratio = decode_fps / target_fps;
-                        if ratio >= 0.8 {
-                            Color32::GREEN
-                        } else if ratio >= 0.5 {
-                            Color32::YELLOW
-                        } else {
-                            Color32::from_rgb(255, 100, 100)
-                        }
-                    } else {
-                        Color32::WHITE
-                    };
+    egui::Area::new(egui::Id::new("minimal_hud"))
+        .anchor(anchor, offset)
+        .interactable(false)
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(Color32::from_rgba_unmultiplied(0, 0, 0, 150))
+                .corner_radius(3.0)
+                .inner_margin(egui::Margin::symmetric(6, 2))
+                .show(ui, |ui| {
+                    ui.label(
+                        RichText::new(text)
+                            .font(FontId::monospace(12.0))
+                            .color(color),
+                    );
+                });
+        });
+}
 
-                    // Render FPS color (critical - this is what you actually see)
-                    let render_color = if target_fps > 0.0 {
-                        let ratio = render_fps / target_fps;
-                        if ratio >= 0.8 {
-                            Color32::GREEN
-                        } else if ratio >= 0.5 {
-                            Color32::YELLOW
-                        } else {
-                            Color32::from_rgb(255, 100, 100)
-                        }
-                    } else {
-                        Color32::WHITE
-                    };
+/// Stats lines, shared by the overlay and the pop-out stats window
+pub(super) fn render_stats_contents(ui: &mut egui::Ui, stats: &crate::media::StreamStats) {
+    use egui::{Color32, FontId, RichText};
 
-                    // Show both FPS values
-                    ui.horizontal(|ui| {
-                        ui.label(
-                            RichText::new(format!("Decode: {:.0}", decode_fps))
-                                .font(FontId::monospace(11.0))
-                                .color(decode_color),
-                        );
-                        ui.label(
-                            RichText::new(format!(" | Render: {:.0}", render_fps))
-                                .font(FontId::monospace(11.0))
-                                .color(render_color),
-                        );
-                        if stats.target_fps > 0 {
-                            ui.label(
-                                RichText::new(format!(" / {} fps", stats.target_fps))
-                                    .font(FontId::monospace(11.0))
-                                    .color(Color32::GRAY),
-                            );
-                        }
-                    });
+    // Resolution and HDR status
+    let res_text = if stats.resolution.is_empty() {
+        "Connecting...".to_string()
+    } else {
+        stats.resolution.clone()
+    };
 
-                    // Codec and bitrate
-                    if !stats.codec.is_empty() {
-                        ui.label(
-                            RichText::new(format!(
-                                "{} | {:.1} Mbps",
-                                stats.codec, stats.bitrate_mbps
-                            ))
-                            .font(FontId::monospace(11.0))
-                            .color(Color32::LIGHT_GRAY),
-                        );
-                    }
+    ui.horizontal(|ui| {
+        ui.label(
+            RichText::new(res_text)
+                .font(FontId::monospace(13.0))
+                .color(Color32::WHITE),
+        );
 
-                    // Latency (decode pipeline)
-                    let latency_color = if stats.latency_ms < 30.0 {
-                        Color32::GREEN
-                    } else if stats.latency_ms < 60.0 {
-                        Color32::YELLOW
-                    } else {
-                        Color32::RED
-                    };
+        // HDR indicator
+        if stats.is_hdr {
+            ui.label(
+                RichText::new(" HDR")
+                    .font(FontId::monospace(13.0))
+                    .color(Color32::from_rgb(255, 180, 0)), // Orange/gold for HDR
+            );
+        }
+    });
+
+    // Decoded FPS vs Render FPS (shows if renderer is bottlenecked)
+    let decode_fps = stats.fps;
+    let render_fps = stats.render_fps;
+    let target_fps = stats.target_fps as f32;
+
+    // Decode FPS color
+    let decode_color = if target_fps > 0.0 {
+        let ratio = decode_fps / target_fps;
+        if ratio >= 0.8 {
+            Color32::GREEN
+        } else if ratio >= 0.5 {
+            Color32::YELLOW
+        } else {
+            Color32::from_rgb(255, 100, 100)
+        }
+    } else {
+        Color32::WHITE
+    };
 
-                    ui.label(
-                        RichText::new(format!("Decode: {:.0} ms", stats.latency_ms))
-                            .font(FontId::monospace(11.0))
-                            .color(latency_color),
-                    );
+    // Render FPS color (critical - this is what you actually see)
+    let render_color = if target_fps > 0.0 {
+        let ratio = render_fps / target_fps;
+        if ratio >= 0.8 {
+            Color32::GREEN
+        } else if ratio >= 0.5 {
+            Color32::YELLOW
+        } else {
+            Color32::from_rgb(255, 100, 100)
+        }
+    } else {
+        Color32::WHITE
+    };
 
-                    // Network RTT (round-trip time from ICE)
-                    if stats.rtt_ms > 0.0 {
-                        let rtt_color = if stats.rtt_ms < 30.0 {
-                            Color32::GREEN
-                        } else if stats.rtt_ms < 60.0 {
-                            Color32::YELLOW
-                        } else {
-                            Color32::RED
-                        };
+    // Show both FPS values
+    ui.horizontal(|ui| {
+        ui.label(
+            RichText::new(format!("Decode: {:.0}", decode_fps))
+                .font(FontId::monospace(11.0))
+                .color(decode_color),
+        );
+        ui.label(
+            RichText::new(format!(" | Render: {:.0}", render_fps))
+                .font(FontId::monospace(11.0))
+                .color(render_color),
+        );
+        if stats.target_fps > 0 {
+            ui.label(
+                RichText::new(format!(" / {} fps", stats.target_fps))
+                    .font(FontId::monospace(11.0))
+                    .color(Color32::GRAY),
+            );
+        }
+    });
+
+    // Frame pacing: average frame time and 1% / 0.1% lows
+    if !stats.frame_time_history.is_empty() {
+        let low_color = if target_fps > 0.0 && stats.low_1pct_fps < target_fps * 0.5 {
+            Color32::from_rgb(255, 100, 100)
+        } else if target_fps > 0.0 && stats.low_1pct_fps < target_fps * 0.8 {
+            Color32::YELLOW
+        } else {
+            Color32::LIGHT_GRAY
+        };
+        ui.label(
+            RichText::new(format!(
+                "Frame: {:.1} ms | 1%: {:.0} | 0.1%: {:.0}",
+                stats.frame_time_ms, stats.low_1pct_fps, stats.low_01pct_fps
+            ))
+            .font(FontId::monospace(11.0))
+            .color(low_color),
+        );
+        render_frame_time_graph(ui, &stats.frame_time_history, target_fps);
+    }
 
-                        ui.label(
-                            RichText::new(format!("RTT: {:.0} ms", stats.rtt_ms))
-                                .font(FontId::monospace(11.0))
-                                .color(rtt_color),
-                        );
-                    } else {
-                        ui.label(
-                            RichText::new("RTT: N/A")
-                                .font(FontId::monospace(11.0))
-                                .color(Color32::GRAY),
-                        );
-                    }
+    // Codec, chroma format (when known) and bitrate
+    if !stats.codec.is_empty() {
+        let codec = if stats.chroma_format.is_empty() {
+            stats.codec.clone()
+        } else {
+            format!("{} {}", stats.codec, stats.chroma_format)
+        };
+        // Total received rate when it differs noticeably (audio, retransmits)
+        let bitrate = if stats.received_mbps > stats.bitrate_mbps * 1.1 {
+            format!(
+                "{:.1} Mbps ({:.1} total)",
+                stats.bitrate_mbps, stats.received_mbps
+            )
+        } else {
+            format!("{:.1} Mbps", stats.bitrate_mbps)
+        };
+        ui.label(
+            RichText::new(format!("{} | {}", codec, bitrate))
+                .font(FontId::monospace(11.0))
+                .color(Color32::LIGHT_GRAY),
+        );
+    }
 
-                    // Estimated end-to-end latency (motion-to-photon)
-                    if stats.estimated_e2e_ms > 0.0 {
-                        let e2e_color = if stats.estimated_e2e_ms < 80.0 {
-                            Color32::GREEN
-                        } else if stats.estimated_e2e_ms < 150.0 {
-                            Color32::YELLOW
-                        } else {
-                            Color32::RED
-                        };
+    // Decoder actually in use - a CPU fallback is the usual cause of high decode times
+    if !stats.decoder.is_empty() {
+        let (mode, color) = if stats.hw_decode {
+            ("hardware", Color32::LIGHT_GRAY)
+        } else {
+            ("software", Color32::YELLOW)
+        };
+        ui.label(
+            RichText::new(format!("Decoder: {} ({})", stats.decoder, mode))
+                .font(FontId::monospace(10.0))
+                .color(color),
+        );
+    }
 
-                        ui.label(
-                            RichText::new(format!("E2E: ~{:.0} ms", stats.estimated_e2e_ms))
-                                .font(FontId::monospace(11.0))
-                                .color(e2e_color),
-                        );
-                    }
+    // Latency (decode pipeline)
+    let latency_color = if stats.latency_ms < 30.0 {
+        Color32::GREEN
+    } else if stats.latency_ms < 60.0 {
+        Color32::YELLOW
+    } else {
+        Color32::RED
+    };
 
-                    // Input rate and client-side latency
-                    if stats.input_rate > 0.0 || stats.input_latency_ms > 0.0 {
-                        let rate_str = if stats.input_rate > 0.0 {
-                            format!("{:.0}/s", stats.input_rate)
-                        } else {
-                            "0/s".to_string()
-                        };
-                        let latency_str = if stats.input_latency_ms > 0.001 {
-                            format!("{:.2}ms", stats.input_latency_ms)
-                        } else {
-                            "<0.01ms".to_string()
-                        };
-                        ui.label(
-                            RichText::new(format!("Input: {} ({})", rate_str, latency_str))
-                                .font(FontId::monospace(10.0))
-                                .color(Color32::GRAY),
-                        );
-                    }
+    ui.label(
+        RichText::new(format!("Decode: {:.0} ms", stats.latency_ms))
+            .font(FontId::monospace(11.0))
+            .color(latency_color),
+    );
+
+    // Network RTT (round-trip time from ICE)
+    if stats.rtt_ms > 0.0 {
+        let rtt_color = if stats.rtt_ms < 30.0 {
+            Color32::GREEN
+        } else if stats.rtt_ms < 60.0 {
+            Color32::YELLOW
+        } else {
+            Color32::RED
+        };
 
-                    // Frame delivery latency (RTP to decode)
-                    if stats.frame_delivery_ms > 0.0 {
-                        let delivery_color = if stats.frame_delivery_ms < 10.0 {
-                            Color32::GREEN
-                        } else if stats.frame_delivery_ms < 20.0 {
-                            Color32::YELLOW
-                        } else {
-                            Color32::RED
-                        };
-                        ui.label(
-                            RichText::new(format!(
-                                "Frame delivery: {:.1} ms",
-                                stats.frame_delivery_ms
-                            ))
-                            .font(FontId::monospace(10.0))
-                            .color(delivery_color),
-                        );
-                    }
+        ui.label(
+            RichText::new(format!("RTT: {:.0} ms", stats.rtt_ms))
+                .font(FontId::monospace(11.0))
+                .color(rtt_color),
+        );
+    } else {
+        ui.label(
+            RichText::new("RTT: N/A")
+                .font(FontId::monospace(11.0))
+                .color(Color32::GRAY),
+        );
+    }
 
-                    if stats.packet_loss > 0.0 {
-                        let loss_color = if stats.packet_loss < 1.0 {
-                            Color32::YELLOW
-                        } else {
-                            Color32::RED
-                        };
+    // Estimated end-to-end latency (motion-to-photon)
+    if stats.estimated_e2e_ms > 0.0 {
+        let e2e_color = if stats.estimated_e2e_ms < 80.0 {
+            Color32::GREEN
+        } else if stats.estimated_e2e_ms < 150.0 {
+            Color32::YELLOW
+        } else {
+            Color32::RED
+        };
 
-                        ui.label(
-                            RichText::new(format!("Packet Loss: {:.1}%", stats.packet_loss))
-                                .font(FontId::monospace(11.0))
-                                .color(loss_color),
-                        );
-                    }
+        ui.label(
+            RichText::new(format!("E2E: ~{:.0} ms", stats.estimated_e2e_ms))
+                .font(FontId::monospace(11.0))
+                .color(e2e_color),
+        );
+    }
 
-                    // Decode and render times
-                    if stats.decode_time_ms > 0.0 || stats.render_time_ms > 0.0 {
-                        ui.label(
-                            RichText::new(format!(
-                                "Decode: {:.1} ms | Render: {:.1} ms",
-                                stats.decode_time_ms, stats.render_time_ms
-                            ))
-                            .font(FontId::monospace(10.0))
-                            .color(Color32::GRAY),
-                        );
-                    }
+    // Input rate and client-side latency
+    if stats.input_rate > 0.0 || stats.input_latency_ms > 0.0 {
+        let rate_str = if stats.input_rate > 0.0 {
+            format!("{:.0}/s", stats.input_rate)
+        } else {
+            "0/s".to_string()
+        };
+        let latency_str = if stats.input_latency_ms > 0.001 {
+            format!("{:.2}ms", stats.input_latency_ms)
+        } else {
+            "<0.01ms".to_string()
+        };
+        ui.label(
+            RichText::new(format!("Input: {} ({})", rate_str, latency_str))
+                .font(FontId::monospace(10.0))
+                .color(Color32::GRAY),
+        );
+    }
 
-                    // Frame stats
-                    if stats.frames_received > 0 {
-                        ui.label(
-                            RichText::new(format!(
-                                "Frames: {} rx, {} dec, {} drop",
-                                stats.frames_received, stats.frames_decoded, stats.frames_dropped
-                            ))
-                            .font(FontId::monospace(10.0))
-                            .color(Color32::DARK_GRAY),
-                        );
-                    }
+    // Frame delivery latency (RTP to decode)
+    if stats.frame_delivery_ms > 0.0 {
+        let delivery_color = if stats.frame_delivery_ms < 10.0 {
+            Color32::GREEN
+        } else if stats.frame_delivery_ms < 20.0 {
+            Color32::YELLOW
+        } else {
+            Color32::RED
+        };
+        ui.label(
+            RichText::new(format!("Frame delivery: {:.1} ms", stats.frame_delivery_ms))
+                .font(FontId::monospace(10.0))
+                .color(delivery_color),
+        );
+    }
 
-                    // GPU and server info
-                    if !stats.gpu_type.is_empty() || !stats.server_region.is_empty() {
-                        let info = format!(
-                            "{}{}{}",
-                            stats.gpu_type,
-                            if !stats.gpu_type.is_empty() && !stats.server_region.is_empty() {
-                                " | "
-                            } else {
-                                ""
-                            },
-                            stats.server_region
-                        );
+    if stats.packet_loss > 0.0 {
+        let loss_color = if stats.packet_loss < 1.0 {
+            Color32::YELLOW
+        } else {
+            Color32::RED
+        };
 
-                        ui.label(
-                            RichText::new(info)
-                                .font(FontId::monospace(10.0))
-                                .color(Color32::DARK_GRAY),
-                        );
-                    }
-                });
-        });
+        ui.label(
+            RichText::new(format!("Packet Loss: {:.1}%", stats.packet_loss))
+                .font(FontId::monospace(11.0))
+                .color(loss_color),
+        );
+    }
+
+    // Decode and render times
+    if stats.decode_time_ms > 0.0 || stats.render_time_ms > 0.0 {
+        ui.label(
+            RichText::new(format!(
+                "Decode: {:.1} ms | Render: {:.1} ms",
+                stats.decode_time_ms, stats.render_time_ms
+            ))
+            .font(FontId::monospace(10.0))
+            .color(Color32::GRAY),
+        );
+    }
+
+    // Frame stats
+    if stats.frames_received > 0 {
+        let repeated = if stats.frames_repeated > 0 {
+            format!(", {} dup", stats.frames_repeated)
+        } else {
+            String::new()
+        };
+        ui.label(
+            RichText::new(format!(
+                "Frames: {} rx, {} dec, {} drop{}",
+                stats.frames_received, stats.frames_decoded, stats.frames_dropped, repeated
+            ))
+            .font(FontId::monospace(10.0))
+            .color(Color32::DARK_GRAY),
+        );
+    }
+
+    // Fullscreen mode actually achieved (exclusive vs borderless vs windowed)
+    if !stats.window_mode.is_empty() {
+        ui.label(
+            RichText::new(format!("Display: {}", stats.window_mode))
+                .font(FontId::monospace(10.0))
+                .color(Color32::DARK_GRAY),
+        );
+    }
+
+    // GPU and server info
+    if !stats.gpu_type.is_empty() || !stats.server_region.is_empty() {
+        let info = format!(
+            "{}{}{}",
+            stats.gpu_type,
+            if !stats.gpu_type.is_empty() && !stats.server_region.is_empty() {
+                " | "
+            } else {
+                ""
+            },
+            stats.server_region
+        );
+
+        ui.label(
+            RichText::new(info)
+                .font(FontId::monospace(10.0))
+                .color(Color32::DARK_GRAY),
+        );
+    }
 }
 
 /// Render resolution change notification popup (animated, center-top)
@@ -6459,3 +7819,302 @@ fn render_wheel_notification(ctx: &egui::Context, wheel_count: usize, alpha: f32
     // Request repaint for smooth animation
     ctx.request_repaint();
 }
+
+/// Render resolution downgrade warning popup (animated, center-top)
+/// Shows when the server delivers a lower resolution than requested at session start
+fn render_downgrade_notification(ctx: &egui::Context, message: &str, alpha: f32) {
+    use egui::{Align2, Color32, FontId, RichText};
+
+    // Calculate alpha for animation (0-255)
+    let alpha_u8 = (alpha * 255.0) as u8;
+
+    // Amber color for warnings
+    let accent_color = Color32::from_rgba_unmultiplied(255, 180, 50, alpha_u8);
+
+    // Slide-in animation: start 20px above, slide down to final position
+    let slide_offset = (1.0 - alpha.min(1.0)) * -20.0;
+
+    egui::Area::new(egui::Id::new("downgrade_notification"))
+        .anchor(Align2::CENTER_TOP, [0.0, 160.0 + slide_offset]) // Below wheel notification
+        .interactable(false)
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(Color32::from_rgba_unmultiplied(
+                    35,
+                    28,
+                    15,
+                    (alpha * 230.0) as u8,
+                ))
+                .corner_radius(8.0)
+                .inner_margin(egui::Margin::symmetric(16, 12))
+                .stroke(egui::Stroke::new(
+                    1.0,
+                    Color32::from_rgba_unmultiplied(120, 90, 40, alpha_u8),
+                ))
+                .show(ui, |ui| {
+                    ui.vertical(|ui| {
+                        ui.spacing_mut().item_spacing.y = 6.0;
+
+                        ui.horizontal(|ui| {
+                            ui.label(
+                                RichText::new("!")
+                                    .font(FontId::proportional(18.0))
+                                    .color(accent_color),
+                            );
+                            ui.label(
+                                RichText::new(message)
+                                    .font(FontId::proportional(14.0))
+                                    .strong()
+                                    .color(Color32::from_rgba_unmultiplied(
+                                        255, 255, 255, alpha_u8,
+                                    )),
+                            );
+                        });
+
+                        ui.label(
+                            RichText::new("Likely due to server capacity or your membership tier")
+                                .font(FontId::proportional(11.0))
+                                .color(Color32::from_rgba_unmultiplied(180, 170, 150, alpha_u8)),
+                        );
+                    });
+                });
+        });
+
+    // Request repaint for smooth animation
+    ctx.request_repaint();
+}
+
+/// Render a small frame-time graph (one bar per presented frame, newest on the right)
+fn render_frame_time_graph(ui: &mut egui::Ui, frame_times_ms: &[f32], target_fps: f32) {
+    use egui::{Color32, Stroke};
+
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(200.0, 36.0), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(
+        rect,
+        2.0,
+        Color32::from_rgba_unmultiplied(255, 255, 255, 12),
+    );
+
+    let target_ms = if target_fps > 0.0 {
+        1000.0 / target_fps
+    } else {
+        1000.0 / 60.0
+    };
+    // Scale to at least 3x the target frame time so small jitter doesn't look like spikes
+    let max_ms = frame_times_ms
+        .iter()
+        .copied()
+        .fold(target_ms * 3.0, f32::max);
+
+    let bar_width = rect.width() / frame_times_ms.len().max(1) as f32;
+    for (i, &ms) in frame_times_ms.iter().enumerate() {
+        let height = (ms / max_ms).min(1.0) * rect.height();
+        let x = rect.left() + i as f32 * bar_width;
+        let color = if ms <= target_ms * 1.5 {
+            Color32::from_rgb(118, 185, 0)
+        } else if ms <= target_ms * 2.5 {
+            Color32::YELLOW
+        } else {
+            Color32::from_rgb(255, 100, 100)
+        };
+        painter.rect_filled(
+            egui::Rect::from_min_max(
+                egui::pos2(x, rect.bottom() - height),
+                egui::pos2(x + bar_width.max(1.0), rect.bottom()),
+            ),
+            0.0,
+            color,
+        );
+    }
+
+    // Target frame time reference line
+    let target_y = rect.bottom() - (target_ms / max_ms) * rect.height();
+    painter.hline(
+        rect.x_range(),
+        target_y,
+        Stroke::new(1.0, Color32::from_rgba_unmultiplied(255, 255, 255, 80)),
+    );
+}
+
+/// Render staged WebRTC connection progress (shown while waiting for the first frame)
+fn render_connection_progress(
+    ctx: &egui::Context,
+    stage: crate::webrtc::ConnectionStage,
+    failed_at: Option<crate::webrtc::ConnectionStage>,
+    failure_reason: Option<&str>,
+) {
+    use crate::webrtc::ConnectionStage;
+    use egui::{Align2, Color32, RichText};
+
+    const STAGES: [(ConnectionStage, &str); 5] = [
+        (ConnectionStage::Signaling, "Contacting server"),
+        (ConnectionStage::GatheringCandidates, "Gathering candidates"),
+        (ConnectionStage::Connecting, "Connecting"),
+        (ConnectionStage::Handshaking, "Handshaking"),
+        (ConnectionStage::Connected, "Streaming"),
+    ];
+
+    egui::Area::new(egui::Id::new("connection_progress"))
+        .anchor(Align2::CENTER_CENTER, [0.0, 0.0])
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(Color32::from_rgba_unmultiplied(20, 20, 28, 230))
+                .corner_radius(8.0)
+                .inner_margin(egui::Margin::symmetric(24, 18))
+                .show(ui, |ui| {
+                    ui.set_min_width(260.0);
+                    ui.vertical(|ui| {
+                        ui.spacing_mut().item_spacing.y = 8.0;
+
+                        if let Some(failed) = failed_at {
+                            ui.label(
+                                RichText::new("Connection failed")
+                                    .size(16.0)
+                                    .strong()
+                                    .color(Color32::from_rgb(255, 100, 100)),
+                            );
+                            ui.label(
+                                RichText::new(format!("Stopped at: {}", failed.label()))
+                                    .size(13.0)
+                                    .color(Color32::LIGHT_GRAY),
+                            );
+                            if let Some(reason) = failure_reason {
+                                ui.label(
+                                    RichText::new(reason)
+                                        .size(13.0)
+                                        .color(Color32::from_rgb(255, 180, 0)),
+                                );
+                            }
+                        } else {
+                            ui.horizontal(|ui| {
+                                ui.spinner();
+                                ui.label(
+                                    RichText::new(stage.label())
+                                        .size(16.0)
+                                        .strong()
+                                        .color(Color32::WHITE),
+                                );
+                            });
+                        }
+
+                        ui.add_space(4.0);
+
+                        for (step, name) in STAGES {
+                            let (marker, color) = if failed_at == Some(step) {
+                                ("x", Color32::from_rgb(255, 100, 100))
+                            } else if step < stage {
+                                ("+", Color32::from_rgb(118, 185, 0))
+                            } else if step == stage {
+                                (">", Color32::WHITE)
+                            } else {
+                                ("-", Color32::GRAY)
+                            };
+                            ui.label(
+                                RichText::new(format!("{} {}", marker, name))
+                                    .monospace()
+                                    .size(13.0)
+                                    .color(color),
+                            );
+                        }
+                    });
+                });
+        });
+}
+
+/// Render the stream bitrate slider (changes apply to the running session)
+fn render_stream_bitrate_overlay(
+    ctx: &egui::Context,
+    current_mbps: u32,
+    max_mbps: u32,
+    actions: &mut Vec<UiAction>,
+) {
+    use egui::{Align2, Color32, RichText};
+
+    egui::Area::new(egui::Id::new("stream_bitrate_overlay"))
+        .anchor(Align2::RIGHT_TOP, [-10.0, 10.0])
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(Color32::from_rgba_unmultiplied(0, 0, 0, 200))
+                .corner_radius(6.0)
+                .inner_margin(10.0)
+                .show(ui, |ui| {
+                    ui.label(
+                        RichText::new("Stream Bitrate")
+                            .size(13.0)
+                            .strong()
+                            .color(Color32::WHITE),
+                    );
+                    let mut bitrate = current_mbps as f32;
+                    let slider = egui::Slider::new(&mut bitrate, 10.0..=max_mbps.max(10) as f32)
+                        .step_by(5.0)
+                        .suffix(" Mbps");
+                    if ui.add(slider).changed() {
+                        actions.push(UiAction::SetStreamBitrate(bitrate as u32));
+                    }
+                    ui.label(
+                        RichText::new("Applies to this session only")
+                            .size(10.0)
+                            .color(Color32::GRAY),
+                    );
+                });
+        });
+}
+
+/// Render the idle timeout countdown (any input cancels it)
+fn render_idle_warning(ctx: &egui::Context, secs: u64) {
+    use egui::{Align2, Color32, RichText};
+
+    egui::Area::new(egui::Id::new("idle_warning"))
+        .anchor(Align2::CENTER_CENTER, [0.0, -80.0])
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(Color32::from_rgba_unmultiplied(40, 30, 10, 235))
+                .corner_radius(8.0)
+                .inner_margin(egui::Margin::symmetric(24, 16))
+                .show(ui, |ui| {
+                    ui.vertical_centered(|ui| {
+                        ui.label(
+                            RichText::new(format!("Session ends in {}s", secs))
+                                .size(20.0)
+                                .strong()
+                                .color(Color32::from_rgb(255, 180, 0)),
+                        );
+                        ui.label(
+                            RichText::new("No input detected - press any key or move the mouse to keep playing")
+                                .size(13.0)
+                                .color(Color32::LIGHT_GRAY),
+                        );
+                    });
+                });
+        });
+}
+
+/// Render the connection test countdown while it samples the stream
+fn render_connection_test_progress(ctx: &egui::Context, secs_left: u64) {
+    use egui::{Align2, Color32, RichText};
+
+    egui::Area::new(egui::Id::new("connection_test_progress"))
+        .anchor(Align2::CENTER_BOTTOM, [0.0, -40.0])
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(Color32::from_rgba_unmultiplied(20, 20, 30, 235))
+                .corner_radius(8.0)
+                .inner_margin(egui::Margin::symmetric(20, 12))
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(
+                            RichText::new(format!("Testing connection... {}s left", secs_left))
+                                .size(16.0)
+                                .strong()
+                                .color(Color32::from_rgb(118, 185, 0)),
+                        );
+                    });
+                });
+        });
+}
@@ -8,9 +8,57 @@ mod session;
 pub use login::render_login_screen;
 pub use session::render_session_screen;
 
-use crate::app::config::{ColorQuality, FPS_OPTIONS, RESOLUTIONS};
+use crate::app::config::{ColorQuality, FullscreenMode, MouseCaptureKey, PiDecoder, PresentModePref, ResamplerQuality, ScalingMode, UnderLoadPolicy, DEFAULT_MOUSE_SEND_RATE_HZ, FPS_OPTIONS, IDLE_TIMEOUT_OPTIONS, MENU_FPS_CAP_OPTIONS, MENU_SCREENSAVER_OPTIONS, MAX_BRIGHTNESS, MAX_GAMMA, MAX_ICE_GATHER_TIMEOUT_MS, MAX_STATS_REFRESH_MS, MIN_BRIGHTNESS, MIN_CUSTOM_HEIGHT, MIN_CUSTOM_WIDTH, MIN_GAMMA, MIN_ICE_GATHER_TIMEOUT_MS, MIN_STATS_REFRESH_MS, MAX_OVERSCAN_PERCENT, DECODE_CAP_OPTIONS, MOUSE_SEND_RATE_OPTIONS, RESOLUTIONS, match_display_mode, validate_custom_resolution};
+use crate::api::capabilities::{feature_status, FeatureStatus, ProviderFeature};
+use crate::app::queue_history::{hour_of_week, QueueHistory};
+use crate::app::connection_test::{ConnectionGrade, ConnectionTestResult};
+use crate::app::session_summary::SessionSummary;
 use crate::app::session::ActiveSessionInfo;
-use crate::app::{GameInfo, ServerInfo, SettingChange, Settings, UiAction};
+use crate::app::{GameInfo, ServerInfo, ServerStatus, SettingChange, Settings, SubscriptionInfo, UiAction};
+use crate::app::cache::{DecoderCheckResult, UpdateCheckResult, UpdateDownloadResult};
+use crate::api::releases::{ReleaseInfo, APP_VERSION};
+use crate::utils::CrashReport;
+
+/// Diagnostics shown in the Settings > About section
+pub struct AboutInfo<'a> {
+    /// GPU adapter name
+    pub gpu_name: &'a str,
+    /// wgpu backend (Vulkan, Dx12, Metal, ...)
+    pub gpu_backend: String,
+    /// GitHub release check in flight
+    pub update_check_in_progress: bool,
+    /// Result of the last release check
+    pub update_check_result: Option<&'a UpdateCheckResult>,
+    /// Release download in flight
+    pub update_download_in_progress: bool,
+    /// Result of the last release download
+    pub update_download_result: Option<&'a UpdateDownloadResult>,
+    /// Decoder health check in flight (shown under Video)
+    pub decoder_check_in_progress: bool,
+    /// Result of the last decoder health check
+    pub decoder_check_result: Option<&'a DecoderCheckResult>,
+    /// Current monitor's resolution and refresh rate in Hz (width, height, hz)
+    pub display_mode: Option<(u32, u32, u32)>,
+}
+
+/// In-progress "Custom…" resolution entry (kept in egui memory while the modal is open)
+#[derive(Debug, Clone, Default)]
+struct CustomResolutionDraft {
+    open: bool,
+    width: u32,
+    height: u32,
+    fps: u32,
+    /// Validation error from the last Apply
+    error: Option<String>,
+}
+
+/// In-progress custom GStreamer pipeline text (kept in egui memory while the modal is open)
+#[derive(Debug, Clone, Default)]
+struct PipelineOverrideDraft {
+    text: String,
+    /// Validation error from the last Apply
+    error: Option<String>,
+}
 
 /// Render the settings modal with bitrate slider and other options
 /// Render the settings modal with bitrate slider and other options
@@ -21,7 +69,10 @@ pub fn render_settings_modal(
     selected_server_index: usize,
     auto_server_selection: bool,
     ping_testing: bool,
+    ping_measured_at: Option<i64>,
+    servers_offline: bool,
     subscription: Option<&crate::app::SubscriptionInfo>,
+    about: &AboutInfo,
     actions: &mut Vec<UiAction>,
 ) {
     egui::Window::new("Settings")
@@ -37,6 +88,16 @@ pub fn render_settings_modal(
                 ui.heading(egui::RichText::new("Video").color(egui::Color32::from_rgb(118, 185, 0)));
                 ui.add_space(8.0);
 
+                // Custom resolution editor state lives in egui memory between frames
+                let custom_draft_id = egui::Id::new("custom_resolution_draft");
+                let mut custom_draft: CustomResolutionDraft = ctx.data_mut(|d| d.get_temp(custom_draft_id)).unwrap_or_default();
+                let pipeline_draft_id = egui::Id::new("pipeline_override_draft");
+                let mut pipeline_draft: PipelineOverrideDraft = ctx.data_mut(|d| d.get_temp(pipeline_draft_id))
+                    .unwrap_or_else(|| PipelineOverrideDraft {
+                        text: settings.gstreamer_pipeline_override.clone().unwrap_or_default(),
+                        error: None,
+                    });
+
                 egui::Grid::new("video_settings_grid")
                     .num_columns(2)
                     .spacing([24.0, 16.0])
@@ -59,6 +120,35 @@ pub fn render_settings_modal(
                         });
                         ui.end_row();
 
+                        // Adaptive Bitrate
+                        ui.label("Adaptive Bitrate")
+                            .on_hover_text("Ask the server for a lower bitrate while packet loss stays high,\nand return to the maximum once the connection is clean again.\nApplies to the next session.");
+                        let mut adaptive_bitrate = settings.adaptive_bitrate;
+                        if ui.checkbox(&mut adaptive_bitrate, "Lower bitrate on packet loss").changed() {
+                            actions.push(UiAction::UpdateSetting(SettingChange::AdaptiveBitrate(adaptive_bitrate)));
+                        }
+                        ui.end_row();
+
+                        // Bandwidth Cap
+                        ui.label("Bandwidth Cap")
+                            .on_hover_text("Keep the whole stream (video, audio and retransmits) under this rate,\nso others on a shared connection still have room, e.g. for video calls.\nThe bitrate is lowered while the stream stays above it.\nApplies to the next session.");
+                        ui.horizontal(|ui| {
+                            let mut cap = settings.bandwidth_cap_mbps as f32;
+                            let slider = egui::Slider::new(&mut cap, 0.0..=100.0)
+                                .show_value(false)
+                                .step_by(5.0);
+                            if ui.add(slider).changed() {
+                                actions.push(UiAction::UpdateSetting(SettingChange::BandwidthCap(cap as u32)));
+                            }
+                            let text = if settings.bandwidth_cap_mbps == 0 {
+                                "Off".to_string()
+                            } else {
+                                format!("{} Mbps", settings.bandwidth_cap_mbps)
+                            };
+                            ui.label(egui::RichText::new(text).strong());
+                        });
+                        ui.end_row();
+
                         // Resolution
                         ui.label("Resolution")
                             .on_hover_text("The resolution of the video stream.");
@@ -133,7 +223,12 @@ pub fn render_settings_modal(
                                                             (w, h) => format!("{}x{}", w, h),
                                                         };
 
-                                                        if ui.selectable_label(settings.resolution == res_str, name).clicked() {
+                                                        let allowed = settings.within_decode_cap(&res_str);
+                                                        let clicked = ui.add_enabled_ui(allowed, |ui| ui.selectable_label(settings.resolution == res_str, name))
+                                                            .inner
+                                                            .on_disabled_hover_text("Above your max decode resolution")
+                                                            .clicked();
+                                                        if clicked {
                                                             actions.push(UiAction::UpdateSetting(SettingChange::Resolution(res_str)));
                                                         }
                                                     }
@@ -146,14 +241,113 @@ pub fn render_settings_modal(
 
                                     // Fallback to static list
                                     for (res, name) in RESOLUTIONS {
-                                        if ui.selectable_label(settings.resolution == *res, *name).clicked() {
+                                        let allowed = settings.within_decode_cap(res);
+                                        let clicked = ui.add_enabled_ui(allowed, |ui| ui.selectable_label(settings.resolution == *res, *name))
+                                            .inner
+                                            .on_disabled_hover_text("Above your max decode resolution")
+                                            .clicked();
+                                        if clicked {
                                             actions.push(UiAction::UpdateSetting(SettingChange::Resolution(res.to_string())));
                                         }
                                     }
                                 });
+
+                            // One click to match the monitor this window is on
+                            if let Some((display_width, display_height, refresh_hz)) = about.display_mode {
+                                if ui.button("Match Display")
+                                    .on_hover_text(format!("Use the closest available mode to your display ({}x{} @ {} Hz).", display_width, display_height, refresh_hz))
+                                    .clicked()
+                                {
+                                    let entitled = subscription.map(|sub| sub.entitled_resolutions.as_slice()).unwrap_or(&[]);
+                                    // Treat the max decode resolution as a smaller display
+                                    let (fit_width, fit_height) = settings.decode_cap()
+                                        .map(|(max_width, max_height)| (display_width.min(max_width), display_height.min(max_height)))
+                                        .unwrap_or((display_width, display_height));
+                                    let (resolution, fps) = match_display_mode(fit_width, fit_height, refresh_hz, entitled);
+                                    actions.push(UiAction::UpdateSetting(SettingChange::Resolution(resolution)));
+                                    actions.push(UiAction::UpdateSetting(SettingChange::Fps(fps)));
+                                }
+                            }
+
+                            if ui.selectable_label(custom_draft.open, "Custom…")
+                                .on_hover_text("Enter an exact resolution and frame rate (e.g. your ultrawide's native size).")
+                                .clicked()
+                            {
+                                let (width, height) = crate::app::types::parse_resolution(&settings.resolution);
+                                custom_draft = CustomResolutionDraft {
+                                    open: !custom_draft.open,
+                                    width,
+                                    height,
+                                    fps: settings.fps,
+                                    error: None,
+                                };
+                            }
                         });
                         ui.end_row();
 
+                        // Custom resolution editor
+                        if custom_draft.open {
+                            ui.label("");
+                            ui.horizontal(|ui| {
+                                ui.add(egui::DragValue::new(&mut custom_draft.width).range(MIN_CUSTOM_WIDTH..=7680));
+                                ui.label("x");
+                                ui.add(egui::DragValue::new(&mut custom_draft.height).range(MIN_CUSTOM_HEIGHT..=4320));
+                                ui.label("@");
+                                ui.add(egui::DragValue::new(&mut custom_draft.fps).range(1..=360).suffix(" FPS"));
+                                if ui.button("Apply").clicked() {
+                                    let entitled = subscription.map(|sub| sub.entitled_resolutions.as_slice()).unwrap_or(&[]);
+                                    let monitor = about.display_mode.map(|(width, height, _)| (width, height));
+                                    let resolution = format!("{}x{}", custom_draft.width, custom_draft.height);
+                                    let result = if settings.within_decode_cap(&resolution) {
+                                        validate_custom_resolution(custom_draft.width, custom_draft.height, custom_draft.fps, entitled, monitor)
+                                    } else {
+                                        Err("Above your max decode resolution".to_string())
+                                    };
+                                    match result {
+                                        Ok(()) => {
+                                            actions.push(UiAction::UpdateSetting(SettingChange::Resolution(resolution)));
+                                            actions.push(UiAction::UpdateSetting(SettingChange::Fps(custom_draft.fps)));
+                                            custom_draft.open = false;
+                                            custom_draft.error = None;
+                                        }
+                                        Err(e) => custom_draft.error = Some(e),
+                                    }
+                                }
+                            });
+                            ui.end_row();
+
+                            if let Some(ref error) = custom_draft.error {
+                                ui.label("");
+                                ui.label(egui::RichText::new(error).color(egui::Color32::from_rgb(255, 100, 100)));
+                                ui.end_row();
+                            }
+                        }
+
+                        // Max Decode Resolution
+                        ui.label("Max Decode Resolution")
+                            .on_hover_text("Never request more than this, whatever your membership allows.\nSet it on devices that can't decode high resolutions smoothly (e.g. 720p on a Raspberry Pi).");
+                        let cap_text = settings.max_decode_resolution.as_deref()
+                            .map(|res| {
+                                let (w, h) = crate::app::types::parse_resolution(res);
+                                crate::app::types::resolution_label(w, h)
+                            })
+                            .unwrap_or_else(|| "No limit".to_string());
+                        egui::ComboBox::from_id_salt("max_decode_resolution_combo")
+                            .selected_text(cap_text)
+                            .show_ui(ui, |ui| {
+                                if ui.selectable_label(settings.max_decode_resolution.is_none(), "No limit").clicked() {
+                                    actions.push(UiAction::UpdateSetting(SettingChange::MaxDecodeResolution(None)));
+                                }
+                                for res in DECODE_CAP_OPTIONS {
+                                    let (w, h) = crate::app::types::parse_resolution(res);
+                                    let selected = settings.max_decode_resolution.as_deref() == Some(*res);
+                                    if ui.selectable_label(selected, crate::app::types::resolution_label(w, h)).clicked() {
+                                        actions.push(UiAction::UpdateSetting(SettingChange::MaxDecodeResolution(Some(res.to_string()))));
+                                    }
+                                }
+                            });
+                        ui.end_row();
+
                         // Frame Rate
                         ui.label("Frame Rate")
                              .on_hover_text("Target frame rate for the stream.\nHigh FPS requires more bandwidth and decoder power.");
@@ -211,27 +405,62 @@ pub fn render_settings_modal(
                         ui.label("Video Codec")
                              .on_hover_text("Compression standard used for video.\nAV1 and H.265 (HEVC) offer better quality than H.264 at the same bitrate, but require compatible hardware.");
                         ui.with_layout(egui::Layout::left_to_right(egui::Align::Center), |ui| {
-                            let codec_text = match settings.codec {
-                                crate::app::VideoCodec::H264 => "H.264",
-                                crate::app::VideoCodec::H265 => "H.265 (HEVC)",
-                                crate::app::VideoCodec::AV1 => "AV1",
+                            // Mark the codec this machine decodes best in hardware
+                            let recommended = crate::media::recommended_codec(settings.decoder_backend);
+                            let codec_label = |codec: crate::app::VideoCodec| {
+                                let name = match codec {
+                                    crate::app::VideoCodec::H264 => "H.264",
+                                    crate::app::VideoCodec::H265 => "H.265 (HEVC)",
+                                    crate::app::VideoCodec::AV1 => "AV1",
+                                };
+                                if codec == recommended.codec {
+                                    format!("{} (recommended)", name)
+                                } else {
+                                    name.to_string()
+                                }
                             };
                             egui::ComboBox::from_id_salt("codec_combo")
-                                .selected_text(codec_text)
+                                .selected_text(codec_label(settings.codec))
                                 .show_ui(ui, |ui| {
-                                    if ui.selectable_label(matches!(settings.codec, crate::app::VideoCodec::H264), "H.264").clicked() {
-                                        actions.push(UiAction::UpdateSetting(SettingChange::Codec(crate::app::VideoCodec::H264)));
-                                    }
-                                    if ui.selectable_label(matches!(settings.codec, crate::app::VideoCodec::H265), "H.265 (HEVC)").clicked() {
-                                        actions.push(UiAction::UpdateSetting(SettingChange::Codec(crate::app::VideoCodec::H265)));
-                                    }
-                                    if ui.selectable_label(matches!(settings.codec, crate::app::VideoCodec::AV1), "AV1").clicked() {
-                                        actions.push(UiAction::UpdateSetting(SettingChange::Codec(crate::app::VideoCodec::AV1)));
+                                    for codec in [crate::app::VideoCodec::H264, crate::app::VideoCodec::H265, crate::app::VideoCodec::AV1] {
+                                        let mut response = ui.selectable_label(settings.codec == codec, codec_label(codec));
+                                        if codec == recommended.codec {
+                                            response = response.on_hover_text(recommended.reason);
+                                        }
+                                        if response.clicked() {
+                                            actions.push(UiAction::UpdateSetting(SettingChange::Codec(codec)));
+                                        }
                                     }
                                 });
                         });
                         ui.end_row();
 
+                        // Codec Fallback - ordered codecs tried when the preferred one can't be used
+                        ui.label("Codec Fallback")
+                             .on_hover_text("Codecs tried in order when the preferred codec can't be decoded on this system or isn't offered by the server.\nClick a codec in the chain to remove it.");
+                        ui.horizontal_wrapped(|ui| {
+                            let chain = settings.codec_preference();
+                            for (i, codec) in chain.iter().enumerate() {
+                                if i == 0 {
+                                    ui.label(codec.as_str());
+                                    continue;
+                                }
+                                ui.label("→");
+                                if ui.small_button(codec.as_str()).on_hover_text("Remove from chain").clicked() {
+                                    let remaining = chain[1..].iter().copied().filter(|c| c != codec).collect();
+                                    actions.push(UiAction::UpdateSetting(SettingChange::CodecFallback(remaining)));
+                                }
+                            }
+                            for codec in crate::app::VideoCodec::all() {
+                                if !chain.contains(codec) && ui.small_button(format!("+ {}", codec.as_str())).clicked() {
+                                    let mut extended = chain[1..].to_vec();
+                                    extended.push(*codec);
+                                    actions.push(UiAction::UpdateSetting(SettingChange::CodecFallback(extended)));
+                                }
+                            }
+                        });
+                        ui.end_row();
+
                         // Video Decoder
                         ui.label("Video Decoder")
                              .on_hover_text(settings.decoder_backend.description());
@@ -259,6 +488,93 @@ pub fn render_settings_modal(
                         });
                         ui.end_row();
 
+                        // Raspberry Pi decode path (stateful V4L2 vs. other GStreamer decoders)
+                        if crate::media::raspberry_pi_detected() {
+                            ui.label("Pi Decoder")
+                                .on_hover_text("Which decoder path to use on Raspberry Pi.\nOne may run smoother than the other depending on firmware - switch if you see stutter.");
+                            ui.horizontal(|ui| {
+                                egui::ComboBox::from_id_salt("pi_decoder_combo")
+                                    .selected_text(settings.pi_decoder.display_name())
+                                    .show_ui(ui, |ui| {
+                                        for pi_decoder in PiDecoder::all() {
+                                            if ui.selectable_label(settings.pi_decoder == *pi_decoder, pi_decoder.display_name())
+                                                .on_hover_text(pi_decoder.description())
+                                                .clicked()
+                                            {
+                                                actions.push(UiAction::UpdateSetting(SettingChange::PiDecoder(*pi_decoder)));
+                                            }
+                                        }
+                                    });
+                                if let Some(element) = crate::media::active_gstreamer_decoder() {
+                                    ui.label(egui::RichText::new(format!("In use: {}", element)).size(10.0).weak());
+                                }
+                            });
+                            ui.end_row();
+                        }
+
+                        // Custom GStreamer pipeline (advanced, GStreamer platforms only)
+                        if cfg!(any(target_os = "linux", all(windows, target_arch = "x86_64"))) {
+                            ui.label("GStreamer Pipeline")
+                                .on_hover_text("Replace the built-in decode pipeline (advanced).\nMust contain 'appsrc name=src' and 'appsink name=sink', e.g. on Jetson:\nappsrc name=src ! h264parse ! nvv4l2decoder ! nvvidconv ! video/x-raw,format=NV12 ! appsink name=sink\nLeave empty and apply to use the built-in pipeline.");
+                            ui.vertical(|ui| {
+                                ui.horizontal(|ui| {
+                                    ui.add(egui::TextEdit::singleline(&mut pipeline_draft.text)
+                                        .hint_text("Built-in pipeline")
+                                        .desired_width(320.0));
+                                    if ui.button("Apply").clicked() {
+                                        let text = pipeline_draft.text.trim().to_string();
+                                        if text.is_empty() {
+                                            actions.push(UiAction::UpdateSetting(SettingChange::GstreamerPipelineOverride(None)));
+                                            pipeline_draft.error = None;
+                                        } else {
+                                            match crate::media::validate_gstreamer_pipeline(&text) {
+                                                Ok(()) => {
+                                                    actions.push(UiAction::UpdateSetting(SettingChange::GstreamerPipelineOverride(Some(text))));
+                                                    pipeline_draft.error = None;
+                                                }
+                                                Err(e) => pipeline_draft.error = Some(e),
+                                            }
+                                        }
+                                    }
+                                });
+                                if let Some(ref error) = pipeline_draft.error {
+                                    ui.label(egui::RichText::new(error).color(egui::Color32::from_rgb(255, 100, 100)));
+                                } else if settings.gstreamer_pipeline_override.is_some() {
+                                    ui.label(egui::RichText::new("Custom pipeline active").size(10.0).weak());
+                                }
+                            });
+                            ui.end_row();
+                        }
+
+                        // Decoder health check (runs at startup and when the decoder changes)
+                        ui.label("Decoder Check")
+                            .on_hover_text("Decodes a small built-in test frame with the selected decoder.\nA failure here usually means missing drivers or GStreamer plugins.");
+                        ui.horizontal(|ui| {
+                            if about.decoder_check_in_progress {
+                                ui.spinner();
+                                ui.label("Testing...");
+                            } else {
+                                if ui.button("Test").clicked() {
+                                    actions.push(UiAction::RunDecoderCheck);
+                                }
+                                match about.decoder_check_result {
+                                    Some(DecoderCheckResult::Passed(ms)) => {
+                                        ui.label(egui::RichText::new(format!("Working ({} ms)", ms)).color(egui::Color32::from_rgb(118, 185, 0)));
+                                    }
+                                    Some(DecoderCheckResult::Failed(error)) => {
+                                        ui.label(egui::RichText::new("Failed").color(egui::Color32::from_rgb(255, 100, 100)))
+                                            .on_hover_text(error);
+                                    }
+                                    Some(DecoderCheckResult::Skipped(reason)) => {
+                                        ui.label(egui::RichText::new("Skipped").color(egui::Color32::GRAY))
+                                            .on_hover_text(reason);
+                                    }
+                                    None => {}
+                                }
+                            }
+                        });
+                        ui.end_row();
+
                         // Color Quality
                         ui.label("Color Quality")
                              .on_hover_text("Color bit depth and chroma subsampling.\n\n• 4:2:0 - Standard chroma, lower bandwidth\n• 4:4:4 - Full chroma, better for text/UI (requires HEVC)\n• 8-bit - Standard dynamic range\n• 10-bit - HDR capable, smoother gradients");
@@ -280,6 +596,64 @@ pub fn render_settings_modal(
                         });
                         ui.end_row();
 
+                        // Present Mode
+                        ui.label("Present Mode")
+                            .on_hover_text("Swapchain present mode used while streaming.\nAuto picks the lowest-latency mode your GPU supports.\nFalls back to Auto if the chosen mode is unsupported.");
+                        ui.with_layout(egui::Layout::left_to_right(egui::Align::Center), |ui| {
+                            egui::ComboBox::from_id_salt("present_mode_combo")
+                                .selected_text(settings.present_mode.display_name())
+                                .show_ui(ui, |ui| {
+                                    for &mode in PresentModePref::all() {
+                                        if ui.selectable_label(settings.present_mode == mode, mode.display_name())
+                                            .on_hover_text(mode.description())
+                                            .clicked()
+                                        {
+                                            actions.push(UiAction::UpdateSetting(SettingChange::PresentMode(mode)));
+                                        }
+                                    }
+                                });
+                        });
+                        ui.end_row();
+
+                        // Frame Pacing
+                        ui.label("Frame Pacing")
+                            .on_hover_text("Show each frame a steady delay after it arrives instead of the moment it finishes decoding.\nSmooths micro-stutter from uneven decode times.\nAdds a few milliseconds of latency (always less than one frame).");
+                        ui.horizontal(|ui| {
+                            let mut frame_pacing = settings.frame_pacing;
+                            if ui.checkbox(&mut frame_pacing, "Smooth decoder jitter").changed() {
+                                actions.push(UiAction::UpdateSetting(SettingChange::FramePacing(frame_pacing)));
+                            }
+                        });
+                        ui.end_row();
+
+                        // Under Load
+                        ui.label("When Behind")
+                            .on_hover_text("What to do when frames arrive faster than your display shows them (slow GPU, busy system).\nDrop to latest keeps the picture current; Buffer shows every frame but can lag a few frames behind.\nApplies from the next stream.");
+                        egui::ComboBox::from_id_salt("under_load_policy_combo")
+                            .selected_text(settings.under_load_policy.display_name())
+                            .show_ui(ui, |ui| {
+                                for &policy in UnderLoadPolicy::all() {
+                                    if ui.selectable_label(settings.under_load_policy == policy, policy.display_name())
+                                        .on_hover_text(policy.description())
+                                        .clicked()
+                                    {
+                                        actions.push(UiAction::UpdateSetting(SettingChange::UnderLoadPolicy(policy)));
+                                    }
+                                }
+                            });
+                        ui.end_row();
+
+                        // Safe Mode
+                        ui.label("Safe Mode")
+                            .on_hover_text("Force software decoding and the basic rendering path.\nTry this first if you see black screens or driver crashes.\nRenderer changes apply after restart.");
+                        ui.horizontal(|ui| {
+                            let mut safe_mode = settings.safe_mode;
+                            if ui.checkbox(&mut safe_mode, "Software decode only").changed() {
+                                actions.push(UiAction::UpdateSetting(SettingChange::SafeMode(safe_mode)));
+                            }
+                        });
+                        ui.end_row();
+
                         // HDR Mode
                         ui.label("HDR Mode")
                              .on_hover_text("Enable High Dynamic Range for supported displays.\nRequires 10-bit color and HEVC/AV1 codec.\nWill auto-switch settings when enabled.");
@@ -294,6 +668,299 @@ pub fn render_settings_modal(
                         });
                         ui.end_row();
                     });
+                ctx.data_mut(|d| d.insert_temp(custom_draft_id, custom_draft));
+                ctx.data_mut(|d| d.insert_temp(pipeline_draft_id, pipeline_draft));
+
+                ui.add_space(20.0);
+                ui.separator();
+                ui.add_space(8.0);
+
+                // === Display Settings Section ===
+                ui.heading(egui::RichText::new("Display").color(egui::Color32::from_rgb(118, 185, 0)));
+                ui.add_space(8.0);
+
+                egui::Grid::new("display_settings_grid")
+                    .num_columns(2)
+                    .spacing([24.0, 16.0])
+                    .show(ui, |ui| {
+                        // Stats Refresh Rate
+                        ui.label("Stats Refresh")
+                            .on_hover_text("How often the stats overlay (F3) updates.\nLower values give live telemetry at a small CPU cost.");
+                        ui.horizontal(|ui| {
+                            let mut refresh_ms = settings.stats_refresh_ms as f32;
+                            let slider = egui::Slider::new(&mut refresh_ms, MIN_STATS_REFRESH_MS as f32..=MAX_STATS_REFRESH_MS as f32)
+                                .show_value(false)
+                                .step_by(50.0);
+                            if ui.add(slider).changed() {
+                                actions.push(UiAction::UpdateSetting(SettingChange::StatsRefreshMs(refresh_ms as u32)));
+                            }
+                            ui.label(egui::RichText::new(format!("{} ms", settings.stats_refresh_ms)).strong());
+                        });
+                        ui.end_row();
+
+                        // Stats Window
+                        ui.label("Stats Window")
+                            .on_hover_text("Show the stats in their own always-on-top window while streaming,\ninstead of over the game. Put it on a second monitor or capture it separately in OBS.");
+                        let mut stats_window = settings.stats_window;
+                        if ui.checkbox(&mut stats_window, "Pop out stats").changed() {
+                            actions.push(UiAction::UpdateSetting(SettingChange::StatsWindow(stats_window)));
+                        }
+                        ui.end_row();
+
+                        // FPS Counter
+                        ui.label("FPS Counter")
+                            .on_hover_text("Keep a small fps and ping readout (e.g. \"120fps 24ms\") in the stats corner while streaming.\nHidden while the full stats (F3) are showing over the game.");
+                        let mut minimal_hud = settings.minimal_hud;
+                        if ui.checkbox(&mut minimal_hud, "Show fps and ping").changed() {
+                            actions.push(UiAction::UpdateSetting(SettingChange::MinimalHud(minimal_hud)));
+                        }
+                        ui.end_row();
+
+                        // Record Stats
+                        ui.label("Record Stats")
+                            .on_hover_text("Save fps, bitrate, RTT, jitter, packet loss and decode/render times\nonce per second to a CSV file in the stats_logs folder, for comparing settings and servers.\nCtrl+Shift+F9 starts or stops a recording mid-session.");
+                        let mut record_stats = settings.record_stats_csv;
+                        if ui.checkbox(&mut record_stats, "Every session").changed() {
+                            actions.push(UiAction::UpdateSetting(SettingChange::RecordStatsCsv(record_stats)));
+                        }
+                        ui.end_row();
+
+                        // Brightness
+                        ui.label("Brightness")
+                            .on_hover_text("Brighten or darken the stream on your side.\nUseful for dark games without touching in-game settings. Applies live.");
+                        ui.horizontal(|ui| {
+                            let mut brightness = settings.brightness;
+                            let slider = egui::Slider::new(&mut brightness, MIN_BRIGHTNESS..=MAX_BRIGHTNESS)
+                                .show_value(false)
+                                .step_by(0.01);
+                            if ui.add(slider).changed() {
+                                actions.push(UiAction::UpdateSetting(SettingChange::Brightness(brightness)));
+                            }
+                            ui.label(egui::RichText::new(format!("{:+.0}%", settings.brightness * 100.0)).strong());
+                            if settings.brightness != 0.0 && ui.small_button("Reset").clicked() {
+                                actions.push(UiAction::UpdateSetting(SettingChange::Brightness(0.0)));
+                            }
+                        });
+                        ui.end_row();
+
+                        // Gamma
+                        ui.label("Gamma")
+                            .on_hover_text("Values above 1.0 lift shadow detail without washing out highlights.\nApplies live.");
+                        ui.horizontal(|ui| {
+                            let mut gamma = settings.gamma;
+                            let slider = egui::Slider::new(&mut gamma, MIN_GAMMA..=MAX_GAMMA)
+                                .show_value(false)
+                                .step_by(0.05);
+                            if ui.add(slider).changed() {
+                                actions.push(UiAction::UpdateSetting(SettingChange::Gamma(gamma)));
+                            }
+                            ui.label(egui::RichText::new(format!("{:.2}", settings.gamma)).strong());
+                            if settings.gamma != 1.0 && ui.small_button("Reset").clicked() {
+                                actions.push(UiAction::UpdateSetting(SettingChange::Gamma(1.0)));
+                            }
+                        });
+                        ui.end_row();
+
+                        // Sharpening
+                        ui.label("Sharpening")
+                            .on_hover_text("Recovers detail when a lower stream resolution is stretched to a high-res display (e.g. 1080p on 4K).\nContrast-adaptive, so edges don't ring. Applies live.");
+                        ui.horizontal(|ui| {
+                            let mut sharpening = settings.sharpening;
+                            let slider = egui::Slider::new(&mut sharpening, 0.0..=1.0)
+                                .show_value(false)
+                                .step_by(0.05);
+                            if ui.add(slider).changed() {
+                                actions.push(UiAction::UpdateSetting(SettingChange::Sharpening(sharpening)));
+                            }
+                            let value = if settings.sharpening > 0.0 {
+                                format!("{:.0}%", settings.sharpening * 100.0)
+                            } else {
+                                "Off".to_string()
+                            };
+                            ui.label(egui::RichText::new(value).strong());
+                        });
+                        ui.end_row();
+
+                        // Scaling Mode
+                        ui.label("Scaling")
+                            .on_hover_text("How the stream fits the window when the aspect ratios differ\n(e.g. a 16:9 stream on a 21:9 ultrawide). Applies live.");
+                        ui.with_layout(egui::Layout::left_to_right(egui::Align::Center), |ui| {
+                            egui::ComboBox::from_id_salt("scaling_mode_combo")
+                                .selected_text(settings.scaling_mode.display_name())
+                                .show_ui(ui, |ui| {
+                                    for &mode in ScalingMode::all() {
+                                        if ui.selectable_label(settings.scaling_mode == mode, mode.display_name())
+                                            .on_hover_text(mode.description())
+                                            .clicked()
+                                        {
+                                            actions.push(UiAction::UpdateSetting(SettingChange::ScalingMode(mode)));
+                                        }
+                                    }
+                                });
+                        });
+                        ui.end_row();
+
+                        // Overscan
+                        ui.label("Overscan")
+                            .on_hover_text("Shrinks the stream so TVs that cut off the picture edges show all of it.\nRaise it until the green frame is fully visible on your TV. Applies live.");
+                        ui.horizontal(|ui| {
+                            let mut overscan = settings.overscan_percent;
+                            let slider = egui::Slider::new(&mut overscan, 0.0..=MAX_OVERSCAN_PERCENT)
+                                .show_value(false)
+                                .step_by(0.5);
+                            let response = ui.add(slider);
+                            if response.changed() {
+                                actions.push(UiAction::UpdateSetting(SettingChange::OverscanPercent(overscan)));
+                            }
+                            let value = if settings.overscan_percent > 0.0 {
+                                format!("{:.1}%", settings.overscan_percent)
+                            } else {
+                                "Off".to_string()
+                            };
+                            ui.label(egui::RichText::new(value).strong());
+                            if settings.overscan_percent != 0.0 && ui.small_button("Reset").clicked() {
+                                actions.push(UiAction::UpdateSetting(SettingChange::OverscanPercent(0.0)));
+                            }
+                            // Calibration frame while adjusting
+                            if response.hovered() || response.dragged() || response.has_focus() {
+                                render_overscan_calibration(ctx, overscan);
+                            }
+                        });
+                        ui.end_row();
+
+                        // Startup Window
+                        ui.label("Startup Window")
+                            .on_hover_text("How the window opens on launch.\nFullscreen is also remembered when toggled with F11.");
+                        ui.horizontal(|ui| {
+                            let mut fullscreen = settings.fullscreen;
+                            if ui.checkbox(&mut fullscreen, "Start in fullscreen").changed() {
+                                actions.push(UiAction::UpdateSetting(SettingChange::Fullscreen(fullscreen)));
+                            }
+                            let mut maximized = settings.start_maximized;
+                            if ui.add_enabled(!settings.fullscreen, egui::Checkbox::new(&mut maximized, "Start maximized")).changed() {
+                                actions.push(UiAction::UpdateSetting(SettingChange::StartMaximized(maximized)));
+                            }
+                        });
+                        ui.end_row();
+
+                        // Fullscreen Mode
+                        ui.label("Fullscreen Mode")
+                            .on_hover_text("Exclusive fullscreen bypasses the desktop compositor for the lowest latency.\nBorderless switches instantly with Alt+Tab.\nThe mode you actually got is shown in the stats (F3) - Wayland and macOS only support borderless.");
+                        egui::ComboBox::from_id_salt("fullscreen_mode_combo")
+                            .selected_text(settings.fullscreen_mode.display_name())
+                            .show_ui(ui, |ui| {
+                                for &mode in FullscreenMode::all() {
+                                    if ui.selectable_label(settings.fullscreen_mode == mode, mode.display_name())
+                                        .on_hover_text(mode.description())
+                                        .clicked()
+                                    {
+                                        actions.push(UiAction::UpdateSetting(SettingChange::FullscreenMode(mode)));
+                                    }
+                                }
+                            });
+                        ui.end_row();
+
+                        // Background Streaming
+                        ui.label("Background Streaming")
+                            .on_hover_text("Keep the video playing when the window loses focus (e.g. a windowed stream on a second monitor).\nInput is still paused until the window is focused again.\nTurn off to save power while the window is in the background.");
+                        let mut keep_streaming = settings.keep_streaming_unfocused;
+                        if ui.checkbox(&mut keep_streaming, "Keep streaming when unfocused").changed() {
+                            actions.push(UiAction::UpdateSetting(SettingChange::KeepStreamingUnfocused(keep_streaming)));
+                        }
+                        ui.end_row();
+
+                        // Background Audio
+                        ui.label("Background Audio")
+                            .on_hover_text("Silence the stream while another window is focused.\nSound returns as soon as you click back into the stream.");
+                        let mut mute_unfocused = settings.mute_on_focus_loss;
+                        if ui.checkbox(&mut mute_unfocused, "Mute when unfocused").changed() {
+                            actions.push(UiAction::UpdateSetting(SettingChange::MuteOnFocusLoss(mute_unfocused)));
+                        }
+                        ui.end_row();
+
+                        // Audio Resampling
+                        ui.label("Audio Resampling")
+                            .on_hover_text("Quality of the conversion when your audio device doesn't run at the stream's 48 kHz (e.g. 44.1 kHz outputs).\nTry High if you hear crackle or harsh treble. Devices at 48 kHz play the stream unconverted.\nApplies from the next stream.");
+                        egui::ComboBox::from_id_salt("resampler_quality_combo")
+                            .selected_text(settings.audio_resampler_quality.display_name())
+                            .show_ui(ui, |ui| {
+                                for &quality in ResamplerQuality::all() {
+                                    if ui.selectable_label(settings.audio_resampler_quality == quality, quality.display_name())
+                                        .on_hover_text(quality.description())
+                                        .clicked()
+                                    {
+                                        actions.push(UiAction::UpdateSetting(SettingChange::AudioResamplerQuality(quality)));
+                                    }
+                                }
+                            });
+                        ui.end_row();
+
+                        // Menu refresh
+                        ui.label("Menu Refresh")
+                            .on_hover_text("Menus normally redraw only when you interact with them, using almost no CPU.\nEnable this to keep them refreshing at 10 FPS so the first click after a long idle responds instantly.\nUses a little more CPU and battery.");
+                        let mut responsive_menus = settings.responsive_menus;
+                        if ui.checkbox(&mut responsive_menus, "Keep menus responsive").changed() {
+                            actions.push(UiAction::UpdateSetting(SettingChange::ResponsiveMenus(responsive_menus)));
+                        }
+                        ui.end_row();
+
+                        // Menu frame rate cap
+                        ui.label("Menu Frame Rate")
+                            .on_hover_text("Limit for menu redraws while you scroll or move the mouse.\nRaise it on high refresh rate displays for smoother menus, at the cost of more CPU and GPU while you interact.\nIdle menus don't redraw either way.");
+                        let fps_cap_label = |fps: u32| if fps == 0 { "Uncapped".to_string() } else { format!("{} FPS", fps) };
+                        egui::ComboBox::from_id_salt("menu_fps_cap_combo")
+                            .selected_text(fps_cap_label(settings.menu_fps_cap))
+                            .show_ui(ui, |ui| {
+                                for &fps in MENU_FPS_CAP_OPTIONS {
+                                    if ui.selectable_label(settings.menu_fps_cap == fps, fps_cap_label(fps)).clicked() {
+                                        actions.push(UiAction::UpdateSetting(SettingChange::MenuFpsCap(fps)));
+                                    }
+                                }
+                            });
+                        ui.end_row();
+
+                        // Menu screensaver
+                        ui.label("Menu Screensaver")
+                            .on_hover_text("Blank the menus after this long without input, leaving only a small moving logo.\nProtects OLED TVs from burn-in on always-on setups. Any input wakes it.");
+                        let screensaver_label = |minutes: u32| if minutes == 0 { "Off".to_string() } else { format!("After {} minutes", minutes) };
+                        egui::ComboBox::from_id_salt("menu_screensaver_combo")
+                            .selected_text(screensaver_label(settings.menu_screensaver_minutes))
+                            .show_ui(ui, |ui| {
+                                for &minutes in MENU_SCREENSAVER_OPTIONS {
+                                    if ui.selectable_label(settings.menu_screensaver_minutes == minutes, screensaver_label(minutes)).clicked() {
+                                        actions.push(UiAction::UpdateSetting(SettingChange::MenuScreensaverMinutes(minutes)));
+                                    }
+                                }
+                            });
+                        ui.end_row();
+
+                        // Connection Sounds
+                        ui.label("Connection Sounds")
+                            .on_hover_text("Play a short chime when the stream starts and a lower tone when it ends or drops.");
+                        let mut connection_sounds = settings.connection_sounds;
+                        if ui.checkbox(&mut connection_sounds, "Chime on connect/disconnect").changed() {
+                            actions.push(UiAction::UpdateSetting(SettingChange::ConnectionSounds(connection_sounds)));
+                        }
+                        ui.end_row();
+
+                        // Session Summary
+                        ui.label("Session Summary")
+                            .on_hover_text("After a stream ends, show the data it used with its average bitrate, frame rate and packet loss. The summary is always written to the log.");
+                        let mut show_summary = settings.show_session_summary;
+                        if ui.checkbox(&mut show_summary, "Show summary after streaming").changed() {
+                            actions.push(UiAction::UpdateSetting(SettingChange::ShowSessionSummary(show_summary)));
+                        }
+                        ui.end_row();
+
+                        // Startup Tips
+                        ui.label("Startup Tips")
+                            .on_hover_text("Show the welcome/tips popup every time OpenNOW starts.");
+                        let mut show_tips = settings.show_tips_on_startup;
+                        if ui.checkbox(&mut show_tips, "Show tips on startup").changed() {
+                            actions.push(UiAction::UpdateSetting(SettingChange::ShowTipsOnStartup(show_tips)));
+                        }
+                        ui.end_row();
+                    });
 
                 ui.add_space(20.0);
                 ui.separator();
@@ -303,13 +970,19 @@ pub fn render_settings_modal(
                 ui.heading(egui::RichText::new("Server & Network").color(egui::Color32::from_rgb(118, 185, 0)));
                 ui.add_space(8.0);
 
+                if servers_offline {
+                    ui.label(egui::RichText::new("⚠ Offline — using built-in server list (retrying in the background)")
+                        .color(egui::Color32::from_rgb(255, 180, 50)));
+                    ui.add_space(8.0);
+                }
+
                 egui::Grid::new("server_settings_grid")
                     .num_columns(2)
                     .spacing([24.0, 16.0])
                     .show(ui, |ui| {
                         // Auto Selection
                         ui.label("Server Selection")
-                             .on_hover_text("Choose a specific GeForce NOW server or let the client automatically pick the best one.");
+                             .on_hover_text("Choose a specific GeForce NOW server or let the client automatically pick the best one. Queue times (when shown) are the shortest current wait in that zone.");
 
                         ui.vertical(|ui| {
                             let mut auto_select = auto_server_selection;
@@ -317,10 +990,24 @@ pub fn render_settings_modal(
                                 actions.push(UiAction::SetAutoServerSelection(auto_select));
                             }
 
+                            // Show which server auto-select picked so users can decide whether to override
+                            if auto_server_selection {
+                                let auto_server = servers.get(selected_server_index)
+                                    .filter(|s| s.status == ServerStatus::Online && s.ping_ms.is_some());
+                                let text = if ping_testing {
+                                    "Testing servers...".to_string()
+                                } else if let Some(server) = auto_server {
+                                    format!("Using {}", server_label(server))
+                                } else {
+                                    "No server measured yet".to_string()
+                                };
+                                ui.label(egui::RichText::new(text).small().color(egui::Color32::GRAY));
+                            }
+
                             if !auto_server_selection && !servers.is_empty() {
                                 ui.add_space(4.0);
                                 let current_server = servers.get(selected_server_index)
-                                    .map(|s| format!("{} ({}ms)", s.name, s.ping_ms.unwrap_or(0)))
+                                    .map(server_label)
                                     .unwrap_or_else(|| "Select server".to_string());
 
                                 egui::ComboBox::from_id_salt("server_combo")
@@ -328,11 +1015,10 @@ pub fn render_settings_modal(
                                     .width(250.0)
                                     .show_ui(ui, |ui| {
                                         for (i, server) in servers.iter().enumerate() {
-                                            let ping_str = server.ping_ms
-                                                .map(|p| format!(" ({}ms)", p))
-                                                .unwrap_or_default();
-                                            let label = format!("{}{}", server.name, ping_str);
-                                            if ui.selectable_label(i == selected_server_index, label).clicked() {
+                                            if i != selected_server_index && !settings.server_allowed(server) {
+                                                continue;
+                                            }
+                                            if ui.selectable_label(i == selected_server_index, server_label(server)).clicked() {
                                                 actions.push(UiAction::SelectServer(i));
                                             }
                                         }
@@ -342,19 +1028,133 @@ pub fn render_settings_modal(
                         ui.end_row();
 
                         // Network Test
-                        if !auto_server_selection && !servers.is_empty() {
+                        if !servers.is_empty() {
                             ui.label("Network Test")
                                  .on_hover_text("Measure latency to available servers.");
                             ui.horizontal(|ui| {
                                 if ping_testing {
                                     ui.spinner();
                                     ui.label("Testing ping...");
-                                } else if ui.button("Test Ping").clicked() {
-                                    actions.push(UiAction::StartPingTest);
+                                } else {
+                                    if ui.button("Test Ping").clicked() {
+                                        actions.push(UiAction::StartPingTest);
+                                    }
+                                    if let Some(measured_at) = ping_measured_at {
+                                        let age_secs = (chrono::Utc::now().timestamp() - measured_at).max(0);
+                                        ui.label(egui::RichText::new(format!("Measured {}", format_age(age_secs)))
+                                            .small()
+                                            .color(egui::Color32::GRAY));
+                                    }
                                 }
                             });
                             ui.end_row();
                         }
+
+                        // End-to-end connection test
+                        ui.label("Connection Test")
+                            .on_hover_text(format!(
+                                "Streams a game from your library for {}s with your current settings, then rates the connection and suggests settings.\nThis starts a real session: it counts towards your playtime and may queue.",
+                                crate::app::connection_test::TEST_DURATION.as_secs()
+                            ));
+                        if ui.button("Test my connection").clicked() {
+                            actions.push(UiAction::StartConnectionTest);
+                        }
+                        ui.end_row();
+
+                        // Region filter
+                        if !servers.is_empty() {
+                            ui.label("Region")
+                                .on_hover_text("Only use servers in this region.\nAuto-select and the server list skip everything else.");
+                            let mut regions: Vec<&str> = servers.iter().map(|s| s.region.as_str()).collect();
+                            regions.sort_unstable();
+                            regions.dedup();
+                            egui::ComboBox::from_id_salt("preferred_region_combo")
+                                .selected_text(settings.preferred_region.as_deref().unwrap_or("Any region"))
+                                .show_ui(ui, |ui| {
+                                    if ui.selectable_label(settings.preferred_region.is_none(), "Any region").clicked() {
+                                        actions.push(UiAction::UpdateSetting(SettingChange::PreferredRegion(None)));
+                                    }
+                                    for region in regions {
+                                        let selected = settings.preferred_region.as_deref() == Some(region);
+                                        if ui.selectable_label(selected, region).clicked() {
+                                            actions.push(UiAction::UpdateSetting(SettingChange::PreferredRegion(Some(region.to_string()))));
+                                        }
+                                    }
+                                });
+                            ui.end_row();
+
+                            // Server blocklist
+                            ui.label("Blocked Servers")
+                                .on_hover_text("Servers that are never auto-selected or listed (e.g. one that is often overloaded).");
+                            egui::CollapsingHeader::new(format!("{} blocked", settings.blocked_servers.len()))
+                                .id_salt("blocked_servers")
+                                .show(ui, |ui| {
+                                    egui::ScrollArea::vertical().max_height(150.0).show(ui, |ui| {
+                                        for server in servers {
+                                            let mut blocked = settings.blocked_servers.contains(&server.id);
+                                            if ui.checkbox(&mut blocked, server.name.as_str()).on_hover_text(server.id.as_str()).changed() {
+                                                let mut blocked_servers = settings.blocked_servers.clone();
+                                                if blocked {
+                                                    blocked_servers.push(server.id.clone());
+                                                } else {
+                                                    blocked_servers.retain(|id| *id != server.id);
+                                                }
+                                                actions.push(UiAction::UpdateSetting(SettingChange::BlockedServers(blocked_servers)));
+                                            }
+                                        }
+                                    });
+                                });
+                            ui.end_row();
+                        }
+
+                        // ICE Gathering Timeout
+                        ui.label("Connection Timeout")
+                            .on_hover_text("How long to gather network candidates before connecting.\nRaise this on slow or restrictive networks (VPN, corporate firewall).");
+                        ui.horizontal(|ui| {
+                            let mut timeout_ms = settings.ice_gather_timeout_ms as f32;
+                            let slider = egui::Slider::new(&mut timeout_ms, MIN_ICE_GATHER_TIMEOUT_MS as f32..=MAX_ICE_GATHER_TIMEOUT_MS as f32)
+                                .show_value(false)
+                                .step_by(500.0);
+                            if ui.add(slider).changed() {
+                                actions.push(UiAction::UpdateSetting(SettingChange::IceGatherTimeoutMs(timeout_ms as u32)));
+                            }
+                            ui.label(egui::RichText::new(format!("{:.1} s", settings.ice_gather_timeout_ms as f32 / 1000.0)).strong());
+                        });
+                        ui.end_row();
+
+                        // Relay-only ICE
+                        ui.label("Relay Only")
+                            .on_hover_text("Connect only through TURN relays (e.g. TCP/443) for networks that block UDP.\nAdds latency - only enable if you can't connect otherwise.\nExtra TURN servers can be added to \"turn_servers\" in settings.json.");
+                        ui.horizontal(|ui| {
+                            let mut force_relay = settings.force_relay;
+                            if ui.checkbox(&mut force_relay, "Force TURN relay").changed() {
+                                actions.push(UiAction::UpdateSetting(SettingChange::ForceRelay(force_relay)));
+                            }
+                            if !settings.turn_servers.is_empty() {
+                                ui.label(
+                                    egui::RichText::new(format!("{} custom TURN server(s)", settings.turn_servers.len()))
+                                        .size(12.0)
+                                        .color(egui::Color32::GRAY)
+                                );
+                            }
+                        });
+                        ui.end_row();
+
+                        // Queue data source (PrintedWaste or a community mirror)
+                        ui.label("Queue Data Source")
+                            .on_hover_text("Where queue times come from. PrintedWaste is a community service, not NVIDIA.\nPoint this at a mirror serving the same format if it's down.\nLeave empty and apply to go back to PrintedWaste.");
+                        if let Some(url) = url_setting_editor(ui, "queue_data_url_draft", &settings.queue_data_url, crate::api::queue::DEFAULT_QUEUE_DATA_URL) {
+                            actions.push(UiAction::UpdateSetting(SettingChange::QueueDataUrl(url)));
+                        }
+                        ui.end_row();
+
+                        // Server ID to location mapping (needed alongside the queue data)
+                        ui.label("Queue Mapping Source")
+                            .on_hover_text("Where the list of queue servers and their locations comes from.\nPoint this at a mirror too if PrintedWaste is down - the last list fetched is also kept.\nLeave empty and apply to go back to PrintedWaste.");
+                        if let Some(url) = url_setting_editor(ui, "queue_mapping_url_draft", &settings.queue_mapping_url, crate::api::queue::DEFAULT_QUEUE_MAPPING_URL) {
+                            actions.push(UiAction::UpdateSetting(SettingChange::QueueMappingUrl(url)));
+                        }
+                        ui.end_row();
                     });
 
                 ui.add_space(20.0);
@@ -379,6 +1179,175 @@ pub fn render_settings_modal(
                             }
                         });
                         ui.end_row();
+
+                        // Idle Timeout
+                        ui.label("Idle Timeout")
+                            .on_hover_text("End the session after this long without keyboard, mouse or controller input.\nA countdown is shown for the last minute - any input cancels it.\nSaves playtime if you walk away or fall asleep.");
+                        let idle_label = |minutes: u32| if minutes == 0 { "Never".to_string() } else { format!("{} minutes", minutes) };
+                        egui::ComboBox::from_id_salt("idle_timeout_combo")
+                            .selected_text(idle_label(settings.idle_timeout_minutes))
+                            .show_ui(ui, |ui| {
+                                for &minutes in IDLE_TIMEOUT_OPTIONS {
+                                    if ui.selectable_label(settings.idle_timeout_minutes == minutes, idle_label(minutes)).clicked() {
+                                        actions.push(UiAction::UpdateSetting(SettingChange::IdleTimeoutMinutes(minutes)));
+                                    }
+                                }
+                            });
+                        ui.end_row();
+
+                        // Mouse send rate (advanced)
+                        ui.label("Mouse Send Rate")
+                            .on_hover_text("How often mouse movement is sent to the server. Motion in between is combined.\nLower it on slow or congested uploads to send fewer packets.\nHigher rates only help with high polling rate mice on fast connections.");
+                        let rate_label = |hz: u32| if hz == DEFAULT_MOUSE_SEND_RATE_HZ { format!("{} Hz (default)", hz) } else { format!("{} Hz", hz) };
+                        egui::ComboBox::from_id_salt("mouse_send_rate_combo")
+                            .selected_text(rate_label(settings.mouse_send_rate_hz))
+                            .show_ui(ui, |ui| {
+                                for &hz in MOUSE_SEND_RATE_OPTIONS {
+                                    if ui.selectable_label(settings.mouse_send_rate_hz == hz, rate_label(hz)).clicked() {
+                                        actions.push(UiAction::UpdateSetting(SettingChange::MouseSendRateHz(hz)));
+                                    }
+                                }
+                            });
+                        ui.end_row();
+
+                        // Mouse capture key
+                        ui.label("Mouse Capture Key")
+                            .on_hover_text("Key that captures and releases the mouse while streaming.\nDouble-tap bindings are easier to reach on laptops; the key presses are still sent to the game.");
+                        egui::ComboBox::from_id_salt("mouse_capture_key_combo")
+                            .selected_text(settings.mouse_capture_key.display_name())
+                            .show_ui(ui, |ui| {
+                                for &key in MouseCaptureKey::all() {
+                                    if ui.selectable_label(settings.mouse_capture_key == key, key.display_name()).clicked() {
+                                        actions.push(UiAction::UpdateSetting(SettingChange::MouseCaptureKey(key)));
+                                    }
+                                }
+                            });
+                        ui.end_row();
+
+                        // Keep session on quit
+                        ui.label("On Quit")
+                            .on_hover_text("Closing the window normally ends the session so it stops using your playtime.\nEnable this to leave it running and resume it on the next launch.");
+                        let mut keep_session = settings.keep_session_on_quit;
+                        if ui.checkbox(&mut keep_session, "Keep session running").changed() {
+                            actions.push(UiAction::UpdateSetting(SettingChange::KeepSessionOnQuit(keep_session)));
+                        }
+                        ui.end_row();
+                    });
+
+                ui.add_space(20.0);
+                ui.separator();
+                ui.add_space(8.0);
+
+                // === About Section ===
+                ui.heading(egui::RichText::new("About").color(egui::Color32::from_rgb(118, 185, 0)));
+                ui.add_space(8.0);
+
+                egui::Grid::new("about_settings_grid")
+                    .num_columns(2)
+                    .spacing([24.0, 16.0])
+                    .show(ui, |ui| {
+                        ui.label("Version");
+                        ui.label(egui::RichText::new(format!("OpenNOW v{}", env!("CARGO_PKG_VERSION"))).strong());
+                        ui.end_row();
+
+                        ui.label("System");
+                        ui.label(format!("{} / {}", std::env::consts::OS, std::env::consts::ARCH));
+                        ui.end_row();
+
+                        ui.label("GPU");
+                        ui.label(format!("{} ({})", about.gpu_name, about.gpu_backend));
+                        ui.end_row();
+
+                        ui.label("Decoder");
+                        if settings.safe_mode_active() {
+                            ui.label("Software (safe mode)");
+                        } else {
+                            ui.label(format!("{} ({})", settings.decoder_backend.as_str(), settings.decoder_backend.backend_name()));
+                        }
+                        ui.end_row();
+
+                        ui.label("Updates")
+                            .on_hover_text("Check GitHub for a newer OpenNOW release.");
+                        ui.horizontal(|ui| {
+                            if about.update_check_in_progress {
+                                ui.spinner();
+                                ui.label("Checking...");
+                            } else {
+                                if ui.button("Check for Updates").clicked() {
+                                    actions.push(UiAction::CheckForUpdates);
+                                }
+                                match about.update_check_result {
+                                    Some(UpdateCheckResult::UpToDate) => {
+                                        ui.label(egui::RichText::new("Up to date").color(egui::Color32::from_rgb(118, 185, 0)));
+                                    }
+                                    Some(UpdateCheckResult::Available(release)) => {
+                                        ui.hyperlink_to(
+                                            egui::RichText::new(format!("v{} available", release.version))
+                                                .color(egui::Color32::from_rgb(100, 180, 255)),
+                                            &release.url,
+                                        );
+                                    }
+                                    Some(UpdateCheckResult::Failed(error)) => {
+                                        ui.label(egui::RichText::new("Check failed").color(egui::Color32::from_rgb(255, 100, 100)))
+                                            .on_hover_text(error);
+                                    }
+                                    None => {}
+                                }
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label("Check on Startup")
+                            .on_hover_text("Look for a newer release each time OpenNOW starts and show a notice if there is one.");
+                        let mut check_updates = settings.check_updates;
+                        if ui.checkbox(&mut check_updates, "Notify about new versions").changed() {
+                            actions.push(UiAction::UpdateSetting(SettingChange::CheckUpdates(check_updates)));
+                        }
+                        ui.end_row();
+
+                        // Download the platform build (only once a newer release was found)
+                        if let Some(UpdateCheckResult::Available(release)) = about.update_check_result {
+                            if let Some(ref asset) = release.asset {
+                                ui.label("Download")
+                                    .on_hover_text("Saves the release to your Downloads folder.\nThe running app is not replaced - unzip it to update.");
+                                ui.horizontal(|ui| {
+                                    if about.update_download_in_progress {
+                                        ui.spinner();
+                                        ui.label(format!("Downloading {}...", asset.name));
+                                    } else {
+                                        if ui.button("Download Update").clicked() {
+                                            actions.push(UiAction::DownloadUpdate);
+                                        }
+                                        match about.update_download_result {
+                                            Some(UpdateDownloadResult::Saved(path)) => {
+                                                ui.label(egui::RichText::new("Saved").color(egui::Color32::from_rgb(118, 185, 0)))
+                                                    .on_hover_text(path);
+                                            }
+                                            Some(UpdateDownloadResult::Failed(error)) => {
+                                                ui.label(egui::RichText::new("Download failed").color(egui::Color32::from_rgb(255, 100, 100)))
+                                                    .on_hover_text(error);
+                                            }
+                                            None => {}
+                                        }
+                                    }
+                                });
+                                ui.end_row();
+                            }
+                        }
+
+                        ui.label("Welcome Tips")
+                            .on_hover_text("Open the welcome popup again.");
+                        if ui.button("Show Welcome Tips").clicked() {
+                            actions.push(UiAction::ShowWelcomePopup);
+                        }
+                        ui.end_row();
+
+                        ui.label("Report a Problem")
+                            .on_hover_text("Opens a GitHub issue pre-filled with your OS, tier, GPU, decoder and recent log lines.\nReview it before submitting - nothing is sent until you do.");
+                        if ui.button("Report Issue on GitHub").clicked() {
+                            actions.push(UiAction::ReportIssue);
+                        }
+                        ui.end_row();
                     });
 
                 ui.add_space(24.0);
@@ -402,93 +1371,432 @@ pub fn render_settings_modal(
         });
 }
 
+/// Text field with an Apply button for an http(s) endpoint setting (empty applies `default`)
+/// Returns the URL to apply when the button is clicked
+fn url_setting_editor(ui: &mut egui::Ui, id: &str, current: &str, default: &str) -> Option<String> {
+    let ctx = ui.ctx().clone();
+    let draft_id = egui::Id::new(id);
+    let mut draft: String = ctx.data_mut(|d| d.get_temp(draft_id))
+        .unwrap_or_else(|| current.to_string());
+    let mut applied = None;
+    ui.horizontal(|ui| {
+        ui.add(egui::TextEdit::singleline(&mut draft)
+            .hint_text(default)
+            .desired_width(260.0));
+        let url = draft.trim();
+        let valid = url.is_empty() || url.starts_with("https://") || url.starts_with("http://");
+        if ui.add_enabled(valid && url != current, egui::Button::new("Apply")).clicked() {
+            let url = if url.is_empty() { default } else { url }.to_string();
+            draft = url.clone();
+            applied = Some(url);
+        }
+        if !valid {
+            ui.label(egui::RichText::new("Must be an http(s) URL").size(12.0).color(egui::Color32::from_rgb(255, 120, 100)));
+        }
+    });
+    ctx.data_mut(|d| d.insert_temp(draft_id, draft));
+    applied
+}
+
+/// Server name with its ping and queue wait, e.g. "US Central (24ms, 5m queue)"
+fn server_label(server: &ServerInfo) -> String {
+    let mut details = Vec::new();
+    if let Some(ping) = server.ping_ms {
+        details.push(format!("{}ms", ping));
+    }
+    if let Some(position) = server.queue_position {
+        details.push(match server.queue_eta_secs {
+            Some(secs) if secs > 0 => format!("{} queue", crate::api::format_queue_eta(Some(secs))),
+            // Queued, but the server didn't say for how long
+            _ if position > 0 => format!("#{} in queue, wait unknown", position),
+            _ => "no queue".to_string(),
+        });
+    }
+    if details.is_empty() {
+        server.name.clone()
+    } else {
+        format!("{} ({})", server.name, details.join(", "))
+    }
+}
+
+/// Short "how long ago" text for a duration in seconds (e.g. "3m ago")
+pub fn format_age(secs: i64) -> String {
+    match secs {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{}m ago", secs / 60),
+        _ => format!("{}h ago", secs / 3600),
+    }
+}
+
+/// Weekday x hour grid of average queue waits for a region (local time)
+pub fn render_queue_heatmap(ui: &mut egui::Ui, history: &QueueHistory, region: &str) {
+    const DAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+    // Waits at or above this are shown fully red
+    const MAX_COLOR_ETA_SECS: f32 = 30.0 * 60.0;
+    const CELL: f32 = 14.0;
+
+    let buckets = history.buckets(region);
+    let Some((best_hour, best_eta)) = history.best_hour(region) else {
+        ui.label(
+            egui::RichText::new("No history yet. Queue times are recorded every 15 minutes while they're being viewed.")
+                .size(12.0)
+                .color(egui::Color32::GRAY),
+        );
+        return;
+    };
+
+    ui.label(
+        egui::RichText::new(format!(
+            "Shortest queues: {} {:02}:00 ({})",
+            DAYS[best_hour / 24],
+            best_hour % 24,
+            crate::api::format_queue_eta(Some(best_eta))
+        ))
+        .size(13.0)
+        .color(egui::Color32::from_rgb(118, 185, 0)),
+    );
+    ui.add_space(6.0);
+
+    let now_hour = hour_of_week(chrono::Local::now());
+    egui::Grid::new(("queue_heatmap_grid", region))
+        .spacing([2.0, 2.0])
+        .show(ui, |ui| {
+            ui.label("");
+            for hour in 0..24 {
+                let text = if hour % 6 == 0 { format!("{:02}", hour) } else { String::new() };
+                ui.label(egui::RichText::new(text).size(10.0).color(egui::Color32::GRAY));
+            }
+            ui.end_row();
+
+            for (day, day_name) in DAYS.iter().enumerate() {
+                ui.label(egui::RichText::new(*day_name).size(11.0).color(egui::Color32::GRAY));
+                for hour in 0..24 {
+                    let index = day * 24 + hour;
+                    let bucket = buckets.get(index).copied().unwrap_or_default();
+                    let color = match bucket.average_eta_secs() {
+                        Some(eta) => {
+                            let t = (eta as f32 / MAX_COLOR_ETA_SECS).clamp(0.0, 1.0);
+                            egui::Color32::from_rgb((60.0 + 170.0 * t) as u8, (185.0 - 125.0 * t) as u8, 40)
+                        }
+                        None => egui::Color32::from_rgb(40, 40, 50),
+                    };
+                    let (rect, response) = ui.allocate_exact_size(egui::vec2(CELL, CELL), egui::Sense::hover());
+                    ui.painter().rect_filled(rect, 2.0, color);
+                    if index == now_hour {
+                        ui.painter().rect_stroke(rect, 2.0, egui::Stroke::new(1.5, egui::Color32::WHITE), egui::StrokeKind::Inside);
+                    }
+                    let tooltip = match bucket.average_eta_secs() {
+                        Some(eta) => format!(
+                            "{} {:02}:00 - average wait {} ({} samples)",
+                            day_name,
+                            hour,
+                            crate::api::format_queue_eta(Some(eta)),
+                            bucket.samples
+                        ),
+                        None => format!("{} {:02}:00 - no data yet", day_name, hour),
+                    };
+                    response.on_hover_text(tooltip);
+                }
+                ui.end_row();
+            }
+        });
+}
+
+/// Plan details: playtime, storage, streaming limits and add-ons
+pub fn render_account_panel(
+    ctx: &egui::Context,
+    subscription: Option<&SubscriptionInfo>,
+    settings: &Settings,
+    actions: &mut Vec<UiAction>,
+) {
+    let section = |ui: &mut egui::Ui, title: &str| {
+        ui.add_space(10.0);
+        ui.label(egui::RichText::new(title).size(14.0).strong().color(egui::Color32::WHITE));
+        ui.add_space(4.0);
+    };
+
+    egui::Window::new("Your Plan")
+        .collapsible(false)
+        .resizable(false)
+        .default_width(380.0)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            let Some(sub) = subscription else {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label("Loading subscription info...");
+                });
+                ui.add_space(8.0);
+                if ui.button("Close").clicked() {
+                    actions.push(UiAction::ToggleAccountPanel);
+                }
+                return;
+            };
+
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(&sub.membership_tier).size(18.0).strong().color(egui::Color32::from_rgb(118, 185, 0)));
+                let provider = crate::auth::get_selected_provider();
+                if provider.is_alliance_partner() {
+                    ui.label(egui::RichText::new(format!("via {}", provider.login_provider_display_name)).color(egui::Color32::GRAY));
+                }
+            });
+
+            section(ui, "Playtime");
+            if sub.is_unlimited {
+                ui.label("Unlimited (no monthly hour cap)");
+            } else {
+                let fraction = if sub.total_hours > 0.0 { (sub.remaining_hours / sub.total_hours).clamp(0.0, 1.0) } else { 0.0 };
+                ui.add(
+                    egui::ProgressBar::new(fraction)
+                        .desired_width(340.0)
+                        .text(format!("{:.1}h of {:.0}h left", sub.remaining_hours, sub.total_hours)),
+                );
+            }
+
+            section(ui, "Storage");
+            match (sub.has_persistent_storage, sub.storage_size_gb) {
+                (true, Some(gb)) => ui.label(format!("Persistent storage: {} GB (game installs and saves are kept between sessions)", gb)),
+                (true, None) => ui.label("Persistent storage active"),
+                (false, _) => ui.label("No persistent storage - install-to-play games are reinstalled every session"),
+            };
+
+            section(ui, "Streaming Limits");
+            if sub.entitled_resolutions.is_empty() {
+                ui.label(egui::RichText::new("Not reported by the service - all resolutions are offered").color(egui::Color32::GRAY));
+            } else {
+                // Group frame rates per resolution (list is sorted highest first)
+                let mut grouped: Vec<((u32, u32), Vec<u32>)> = Vec::new();
+                for res in &sub.entitled_resolutions {
+                    match grouped.last_mut() {
+                        Some((size, fps)) if *size == (res.width, res.height) => fps.push(res.fps),
+                        _ => grouped.push(((res.width, res.height), vec![res.fps])),
+                    }
+                }
+                for ((width, height), fps) in &grouped {
+                    let fps = fps.iter().map(|f| f.to_string()).collect::<Vec<_>>().join(" / ");
+                    ui.label(format!("{}x{} @ {} fps", width, height, fps));
+                }
+                let selected = crate::app::parse_resolution(&settings.resolution);
+                let entitled = sub.entitled_resolutions.iter().any(|res| (res.width, res.height) == selected && res.fps >= settings.fps);
+                if !entitled {
+                    ui.add_space(4.0);
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{} @ {} fps (your setting) isn't in your plan; the stream may run lower",
+                            settings.resolution, settings.fps
+                        ))
+                        .size(12.0)
+                        .color(egui::Color32::from_rgb(255, 200, 80)),
+                    );
+                }
+            }
+
+            if !sub.addons.is_empty() {
+                section(ui, "Add-ons");
+                for addon in &sub.addons {
+                    let name = addon.sub_type.as_deref().unwrap_or(&addon.addon_type).replace('_', " ").to_lowercase();
+                    let active = addon.status.as_deref() == Some("OK");
+                    let status = if active { "active".to_string() } else { addon.status.clone().unwrap_or_default().to_lowercase() };
+                    ui.label(format!("{} ({})", name, status));
+                }
+            }
+
+            ui.add_space(12.0);
+            ui.horizontal(|ui| {
+                if ui.button("Refresh").clicked() {
+                    actions.push(UiAction::RefreshSubscription);
+                }
+                if sub.fetched_at > 0 {
+                    let age_secs = (chrono::Utc::now().timestamp() - sub.fetched_at).max(0);
+                    ui.label(egui::RichText::new(format!("Updated {}", format_age(age_secs))).small().color(egui::Color32::GRAY));
+                }
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.button("Close").clicked() {
+                        actions.push(UiAction::ToggleAccountPanel);
+                    }
+                });
+            });
+        });
+}
+
+/// Which of the provider's services have answered (checked as the app uses them)
+pub fn render_provider_features(ui: &mut egui::Ui) {
+    egui::Grid::new("provider_features")
+        .num_columns(2)
+        .spacing([12.0, 4.0])
+        .show(ui, |ui| {
+            for &feature in ProviderFeature::all() {
+                ui.label(egui::RichText::new(feature.display_name()).size(13.0).color(egui::Color32::LIGHT_GRAY));
+                match feature_status(feature) {
+                    FeatureStatus::Available => {
+                        ui.label(egui::RichText::new("✔ Works").size(13.0).color(egui::Color32::from_rgb(118, 185, 0)));
+                    }
+                    FeatureStatus::Unsupported(error) => {
+                        ui.label(egui::RichText::new("✖ Unsupported").size(13.0).color(egui::Color32::from_rgb(255, 120, 100)))
+                            .on_hover_text(format!("{}\n\n{}", feature.fallback(), error));
+                    }
+                    FeatureStatus::Untested => {
+                        ui.label(egui::RichText::new("… Not confirmed yet").size(13.0).color(egui::Color32::GRAY));
+                    }
+                }
+                ui.end_row();
+            }
+        });
+}
+
 /// Render session conflict dialog when user has active sessions
 pub fn render_session_conflict_dialog(
     ctx: &egui::Context,
     active_sessions: &[ActiveSessionInfo],
+    session_titles: &[String],
+    ending_sessions: &[String],
+    end_session_error: Option<&str>,
     pending_game: Option<&GameInfo>,
     actions: &mut Vec<UiAction>,
 ) {
-    egui::Window::new("Active Session")
+    let title = if active_sessions.len() > 1 { "Active Sessions" } else { "Active Session" };
+    egui::Window::new(title)
         .collapsible(false)
         .resizable(false)
-        .fixed_size([400.0, 250.0])
+        .fixed_size([460.0, 250.0])
         .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
         .show(ctx, |ui| {
             ui.vertical_centered(|ui| {
                 ui.add_space(10.0);
 
+                let heading = match active_sessions.len() {
+                    0 => "All sessions ended".to_string(),
+                    1 => "You have an active session".to_string(),
+                    count => format!("You have {} active sessions", count),
+                };
                 ui.label(
-                    egui::RichText::new("You have an active session")
+                    egui::RichText::new(heading)
                         .size(18.0)
                         .strong()
                         .color(egui::Color32::WHITE),
                 );
-
-                ui.add_space(15.0);
-
-                // Show active session info
-                if let Some(session) = active_sessions.first() {
+                if active_sessions.len() > 1 {
+                    ui.add_space(4.0);
                     ui.label(
-                        egui::RichText::new(format!("Session ID: {}", &session.session_id))
-                            .size(14.0)
-                            .color(egui::Color32::from_rgb(118, 185, 0)),
+                        egui::RichText::new("Extra sessions are usually left over from a crash or dropped connection\nand can block new launches. End the ones you don't need.")
+                            .size(12.0)
+                            .color(egui::Color32::GRAY),
                     );
+                }
+            });
+
+            ui.add_space(12.0);
+
+            // One row per session, each with its own Resume / End
+            egui::ScrollArea::vertical().max_height(220.0).show(ui, |ui| {
+                for (i, session) in active_sessions.iter().enumerate() {
+                    let ending = ending_sessions.contains(&session.session_id);
+                    egui::Frame::new()
+                        .fill(egui::Color32::from_rgb(35, 35, 45))
+                        .corner_radius(6.0)
+                        .inner_margin(10.0)
+                        .show(ui, |ui| {
+                            ui.set_width(ui.available_width());
+                            ui.horizontal(|ui| {
+                                ui.vertical(|ui| {
+                                    let title = session_titles.get(i).map_or("Unknown game", String::as_str);
+                                    ui.label(egui::RichText::new(title).size(14.0).strong().color(egui::Color32::WHITE));
+                                    let state = match session.status {
+                                        3 => "Streaming",
+                                        _ => "Ready, no client connected",
+                                    };
+                                    let mut details = vec![state.to_string()];
+                                    if let Some(ref gpu) = session.gpu_type {
+                                        details.push(gpu.clone());
+                                    }
+                                    if let Some(ref server_ip) = session.server_ip {
+                                        details.push(server_ip.clone());
+                                    }
+                                    ui.label(egui::RichText::new(details.join(" • ")).size(11.0).color(egui::Color32::GRAY));
+                                    ui.label(
+                                        egui::RichText::new(format!("Session ID: {}", session.session_id))
+                                            .size(11.0)
+                                            .color(egui::Color32::from_rgb(118, 185, 0)),
+                                    );
+                                });
 
-                    ui.add_space(5.0);
+                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                    if ending {
+                                        ui.spinner();
+                                        ui.label(egui::RichText::new("Ending...").size(12.0).color(egui::Color32::GRAY));
+                                        return;
+                                    }
+                                    let end_btn = egui::Button::new(egui::RichText::new("End").size(13.0))
+                                        .fill(egui::Color32::from_rgb(130, 70, 70))
+                                        .min_size(egui::vec2(60.0, 28.0));
+                                    if ui.add(end_btn).on_hover_text("Stop this session on the server").clicked() {
+                                        actions.push(UiAction::EndSession(session.clone()));
+                                    }
+                                    let resume_btn = egui::Button::new(egui::RichText::new("Resume").size(13.0))
+                                        .fill(egui::Color32::from_rgb(70, 130, 70))
+                                        .min_size(egui::vec2(70.0, 28.0));
+                                    if ui.add(resume_btn).clicked() {
+                                        actions.push(UiAction::ResumeSession(session.clone()));
+                                        actions.push(UiAction::CloseSessionConflict);
+                                    }
+                                });
+                            });
+                        });
+                    ui.add_space(6.0);
+                }
+            });
 
-                    if let Some(ref server_ip) = session.server_ip {
-                        ui.label(
-                            egui::RichText::new(format!("Server: {}", server_ip))
-                                .size(12.0)
-                                .color(egui::Color32::GRAY),
-                        );
-                    }
+            ui.vertical_centered(|ui| {
+                if let Some(error) = end_session_error {
+                    ui.add_space(4.0);
+                    ui.label(
+                        egui::RichText::new(format!("Couldn't end session: {}", error))
+                            .size(12.0)
+                            .color(egui::Color32::from_rgb(255, 120, 100)),
+                    );
                 }
 
-                ui.add_space(25.0);
+                ui.add_space(12.0);
 
                 ui.horizontal(|ui| {
-                    // Resume existing session
-                    let resume_btn =
-                        egui::Button::new(egui::RichText::new("Resume Session").size(14.0))
-                            .fill(egui::Color32::from_rgb(70, 130, 70))
+                    // End every session and start the new game
+                    if let Some(game) = pending_game {
+                        let label = if active_sessions.is_empty() {
+                            format!("Start {}", game.title)
+                        } else if active_sessions.len() > 1 {
+                            "End All & Start New Game".to_string()
+                        } else {
+                            "Start New Game".to_string()
+                        };
+                        let new_btn = egui::Button::new(egui::RichText::new(label).size(14.0))
+                            .fill(egui::Color32::from_rgb(130, 70, 70))
                             .min_size(egui::vec2(130.0, 35.0));
 
-                    if ui.add(resume_btn).clicked() {
-                        if let Some(session) = active_sessions.first() {
-                            actions.push(UiAction::ResumeSession(session.clone()));
+                        if ui.add_enabled(ending_sessions.is_empty(), new_btn).clicked() {
+                            actions.push(UiAction::TerminateAndLaunch(
+                                active_sessions.iter().map(|s| s.session_id.clone()).collect(),
+                                game.clone(),
+                            ));
+                            actions.push(UiAction::CloseSessionConflict);
+                        }
+                    } else if active_sessions.len() > 1 {
+                        let end_all_btn = egui::Button::new(egui::RichText::new("End All").size(14.0))
+                            .fill(egui::Color32::from_rgb(130, 70, 70))
+                            .min_size(egui::vec2(100.0, 35.0));
+                        if ui.add(end_all_btn).clicked() {
+                            for session in active_sessions {
+                                actions.push(UiAction::EndSession(session.clone()));
+                            }
                         }
-                        actions.push(UiAction::CloseSessionConflict);
                     }
 
                     ui.add_space(10.0);
 
-                    // Terminate and start new
-                    if let Some(game) = pending_game {
-                        let new_btn =
-                            egui::Button::new(egui::RichText::new("Start New Game").size(14.0))
-                                .fill(egui::Color32::from_rgb(130, 70, 70))
-                                .min_size(egui::vec2(130.0, 35.0));
-
-                        if ui.add(new_btn).clicked() {
-                            if let Some(session) = active_sessions.first() {
-                                actions.push(UiAction::TerminateAndLaunch(
-                                    session.session_id.clone(),
-                                    game.clone(),
-                                ));
-                            }
-                            actions.push(UiAction::CloseSessionConflict);
-                        }
+                    // Cancel
+                    if ui.add(egui::Button::new("Cancel").min_size(egui::vec2(80.0, 35.0))).clicked() {
+                        actions.push(UiAction::CloseSessionConflict);
                     }
                 });
-
-                ui.add_space(15.0);
-
-                // Cancel
-                if ui.button("Cancel").clicked() {
-                    actions.push(UiAction::CloseSessionConflict);
-                }
             });
         });
 }
@@ -537,6 +1845,343 @@ pub fn render_av1_warning_dialog(ctx: &egui::Context, actions: &mut Vec<UiAction
         });
 }
 
+/// Render the confirmation shown before launching with little playtime left
+pub fn render_low_hours_dialog(
+    ctx: &egui::Context,
+    game: &GameInfo,
+    remaining_hours: f32,
+    actions: &mut Vec<UiAction>,
+) {
+    let minutes = (remaining_hours.max(0.0) * 60.0).round() as u32;
+    let remaining = match minutes {
+        0 => "You have no playtime left this month.".to_string(),
+        1 => "You have 1 minute left this month.".to_string(),
+        _ => format!("You have {} minutes left this month.", minutes),
+    };
+
+    egui::Window::new("Low Playtime")
+        .collapsible(false)
+        .resizable(false)
+        .fixed_size([400.0, 180.0])
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.add_space(15.0);
+
+                ui.label(
+                    egui::RichText::new("⚠ Almost Out of Hours")
+                        .size(16.0)
+                        .strong()
+                        .color(egui::Color32::from_rgb(255, 180, 50))
+                );
+
+                ui.add_space(15.0);
+
+                ui.label(
+                    egui::RichText::new(format!("{}\nThe session ends when it runs out. Launch {} anyway?", remaining, game.title))
+                        .size(13.0)
+                        .color(egui::Color32::LIGHT_GRAY)
+                );
+
+                ui.add_space(20.0);
+
+                ui.horizontal(|ui| {
+                    if ui.button("Launch Anyway").clicked() {
+                        actions.push(UiAction::ConfirmLowHoursLaunch);
+                    }
+
+                    ui.add_space(10.0);
+
+                    if ui.button("Cancel").clicked() {
+                        actions.push(UiAction::CancelLowHoursLaunch);
+                    }
+                });
+            });
+        });
+}
+
+/// Render the confirmation shown before launching a link handed over by another process
+pub fn render_forwarded_launch_dialog(ctx: &egui::Context, name: &str, actions: &mut Vec<UiAction>) {
+    egui::Window::new("Open Link")
+        .collapsible(false)
+        .resizable(false)
+        .fixed_size([400.0, 160.0])
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.add_space(15.0);
+
+                ui.label(
+                    egui::RichText::new(format!("A link wants to launch {}", name))
+                        .size(16.0)
+                        .strong()
+                );
+
+                ui.add_space(15.0);
+
+                ui.label(
+                    egui::RichText::new("Only continue if you just opened an OpenNOW link.")
+                        .size(13.0)
+                        .color(egui::Color32::LIGHT_GRAY)
+                );
+
+                ui.add_space(20.0);
+
+                ui.horizontal(|ui| {
+                    if ui.button("Launch").clicked() {
+                        actions.push(UiAction::ConfirmForwardedLaunch);
+                    }
+
+                    ui.add_space(10.0);
+
+                    if ui.button("Cancel").clicked() {
+                        actions.push(UiAction::CancelForwardedLaunch);
+                    }
+                });
+            });
+        });
+}
+
+/// Outcome of "Test my connection", with the option to apply the suggested settings
+pub fn render_connection_test_dialog(
+    ctx: &egui::Context,
+    result: &Result<ConnectionTestResult, String>,
+    settings: &Settings,
+    actions: &mut Vec<UiAction>,
+) {
+    egui::Window::new("Connection Test")
+        .collapsible(false)
+        .resizable(false)
+        .fixed_size([420.0, 0.0])
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            let result = match result {
+                Ok(result) => result,
+                Err(message) => {
+                    ui.label(egui::RichText::new(format!("⚠ Test failed: {}", message)).color(egui::Color32::from_rgb(255, 180, 50)));
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        if ui.button("Try Again").clicked() {
+                            actions.push(UiAction::StartConnectionTest);
+                        }
+                        if ui.button("Close").clicked() {
+                            actions.push(UiAction::CloseConnectionTestResult);
+                        }
+                    });
+                    return;
+                }
+            };
+
+            let grade_color = match result.grade {
+                ConnectionGrade::Excellent | ConnectionGrade::Good => egui::Color32::from_rgb(118, 185, 0),
+                ConnectionGrade::Fair => egui::Color32::from_rgb(255, 180, 50),
+                ConnectionGrade::Poor => egui::Color32::from_rgb(255, 100, 100),
+            };
+            ui.vertical_centered(|ui| {
+                ui.label(egui::RichText::new(result.grade.label()).size(26.0).strong().color(grade_color));
+                ui.label(egui::RichText::new(result.grade.description()).size(13.0).color(egui::Color32::LIGHT_GRAY));
+                ui.label(egui::RichText::new(format!("Measured with {} on {}", result.game_title, result.server)).small().color(egui::Color32::GRAY));
+            });
+            ui.add_space(10.0);
+
+            egui::Grid::new("connection_test_grid")
+                .num_columns(2)
+                .spacing([24.0, 6.0])
+                .show(ui, |ui| {
+                    ui.label("Throughput");
+                    let capped = if result.bitrate_capped { " (at your limit)" } else { "" };
+                    ui.label(format!("{:.1} Mbps avg, {:.1} Mbps sustained{}", result.avg_bitrate_mbps, result.sustained_bitrate_mbps, capped));
+                    ui.end_row();
+                    ui.label("Latency");
+                    ui.label(format!("{:.0} ms", result.avg_rtt_ms));
+                    ui.end_row();
+                    ui.label("Jitter");
+                    ui.label(format!("{:.1} ms", result.avg_jitter_ms));
+                    ui.end_row();
+                    ui.label("Packet Loss");
+                    ui.label(format!("{:.2}%", result.packet_loss_pct));
+                    ui.end_row();
+                    ui.label("Frame Rate");
+                    ui.label(format!("{:.0} fps", result.avg_fps));
+                    ui.end_row();
+                });
+            let throughput_note = if result.bitrate_capped {
+                "The stream used all of the Max Bitrate setting, so your connection may be faster than measured."
+            } else {
+                "The game didn't need the full Max Bitrate, so throughput is a lower bound. The grade is based on latency, jitter and loss."
+            };
+            ui.label(egui::RichText::new(throughput_note).small().weak());
+
+            ui.add_space(10.0);
+            let recommended = &result.recommended;
+            let (width, height) = crate::app::parse_resolution(&recommended.resolution);
+            ui.label(egui::RichText::new(format!(
+                "Recommended: {} at {} FPS, max {} Mbps",
+                crate::app::resolution_label(width, height),
+                recommended.fps,
+                recommended.max_bitrate_mbps
+            )).strong());
+            let unchanged = settings.resolution == recommended.resolution
+                && settings.fps == recommended.fps
+                && settings.max_bitrate_mbps == recommended.max_bitrate_mbps;
+
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                if ui.add_enabled(!unchanged, egui::Button::new("Apply Recommended")).clicked() {
+                    actions.push(UiAction::ApplyConnectionTestSettings(recommended.clone()));
+                }
+                if ui.button("Close").clicked() {
+                    actions.push(UiAction::CloseConnectionTestResult);
+                }
+            });
+        });
+}
+
+/// Data used and stream quality of the stream that just ended
+pub fn render_session_summary_dialog(
+    ctx: &egui::Context,
+    summary: &SessionSummary,
+    actions: &mut Vec<UiAction>,
+) {
+    egui::Window::new("Session Summary")
+        .collapsible(false)
+        .resizable(false)
+        .fixed_size([340.0, 0.0])
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.label(egui::RichText::new(&summary.game_title).size(16.0).strong());
+                ui.label(egui::RichText::new(format!("Streamed for {}", summary.duration_text())).small().color(egui::Color32::GRAY));
+            });
+            ui.add_space(10.0);
+
+            egui::Grid::new("session_summary_grid")
+                .num_columns(2)
+                .spacing([24.0, 6.0])
+                .show(ui, |ui| {
+                    ui.label("Data Used");
+                    ui.label(egui::RichText::new(summary.data_used()).strong());
+                    ui.end_row();
+                    ui.label("Bitrate");
+                    ui.label(format!("{:.1} Mbps avg, {:.1} Mbps peak", summary.avg_bitrate_mbps, summary.peak_bitrate_mbps));
+                    ui.end_row();
+                    ui.label("Frame Rate");
+                    ui.label(format!("{:.0} fps avg", summary.avg_fps));
+                    ui.end_row();
+                    ui.label("Packet Loss");
+                    ui.label(format!("{:.2}%", summary.avg_packet_loss_pct));
+                    ui.end_row();
+                });
+
+            ui.add_space(10.0);
+            ui.vertical_centered(|ui| {
+                if ui.button("Close").clicked() {
+                    actions.push(UiAction::CloseSessionSummary);
+                }
+            });
+        });
+}
+
+/// How often the screensaver logo moves (the event loop redraws at this rate while it's on)
+pub const SCREENSAVER_MOVE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Black out the menus, leaving a dim logo that jumps around (OLED burn-in protection)
+pub fn render_menu_screensaver(ctx: &egui::Context) {
+    egui::Area::new(egui::Id::new("menu_screensaver"))
+        .fixed_pos([0.0, 0.0])
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            let screen_rect = ctx.input(|i| i.viewport_rect());
+            ui.painter().rect_filled(screen_rect, 0.0, egui::Color32::BLACK);
+
+            // Golden-ratio steps spread successive positions over the screen
+            let step = (chrono::Utc::now().timestamp() / SCREENSAVER_MOVE_INTERVAL.as_secs() as i64) % 1000;
+            let margin = egui::vec2(120.0, 60.0);
+            let area = screen_rect.shrink2(margin);
+            let pos = egui::pos2(
+                area.left() + (step as f32 * 0.618_034).fract() * area.width().max(0.0),
+                area.top() + (step as f32 * 0.414_214).fract() * area.height().max(0.0),
+            );
+            ui.painter().text(
+                pos,
+                egui::Align2::CENTER_CENTER,
+                "OpenNOW",
+                egui::FontId::proportional(28.0),
+                egui::Color32::from_rgb(40, 90, 40),
+            );
+        });
+}
+
+/// Overscan calibration: red lines on the window edges (hidden on a TV that overscans)
+/// and a green frame where the stream edges end up with `percent` inset
+fn render_overscan_calibration(ctx: &egui::Context, percent: f32) {
+    let screen_rect = ctx.input(|i| i.viewport_rect());
+    let painter = ctx.layer_painter(egui::LayerId::new(egui::Order::Foreground, egui::Id::new("overscan_calibration")));
+
+    painter.rect_stroke(screen_rect, 0.0, egui::Stroke::new(4.0, egui::Color32::from_rgb(220, 60, 60)), egui::StrokeKind::Inside);
+
+    let inset = screen_rect.size() * percent.clamp(0.0, MAX_OVERSCAN_PERCENT) / 100.0;
+    let stream_rect = screen_rect.shrink2(inset);
+    let green = egui::Color32::from_rgb(118, 185, 0);
+    painter.rect_stroke(stream_rect, 0.0, egui::Stroke::new(3.0, green), egui::StrokeKind::Inside);
+
+    // Corner markers make the frame easy to find when only part of it is visible
+    let arm = 40.0;
+    for (corner, dir) in [
+        (stream_rect.left_top(), egui::vec2(1.0, 1.0)),
+        (stream_rect.right_top(), egui::vec2(-1.0, 1.0)),
+        (stream_rect.left_bottom(), egui::vec2(1.0, -1.0)),
+        (stream_rect.right_bottom(), egui::vec2(-1.0, -1.0)),
+    ] {
+        let stroke = egui::Stroke::new(8.0, green);
+        painter.line_segment([corner, corner + egui::vec2(arm * dir.x, 0.0)], stroke);
+        painter.line_segment([corner, corner + egui::vec2(0.0, arm * dir.y)], stroke);
+    }
+
+    painter.text(
+        stream_rect.center_top() + egui::vec2(0.0, 16.0),
+        egui::Align2::CENTER_TOP,
+        "Stream edge - raise Overscan until this frame is fully visible",
+        egui::FontId::proportional(16.0),
+        green,
+    );
+}
+
+/// Render low GPU memory warning dialog (Raspberry Pi V3D)
+pub fn render_gpu_memory_warning_dialog(ctx: &egui::Context, message: &str, actions: &mut Vec<UiAction>) {
+    egui::Window::new("Low GPU Memory")
+        .collapsible(false)
+        .resizable(false)
+        .fixed_size([420.0, 200.0])
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.add_space(15.0);
+
+                ui.label(
+                    egui::RichText::new("⚠ Not Enough GPU Memory")
+                        .size(16.0)
+                        .strong()
+                        .color(egui::Color32::from_rgb(255, 180, 50))
+                );
+
+                ui.add_space(15.0);
+
+                ui.label(
+                    egui::RichText::new(message)
+                        .size(13.0)
+                        .color(egui::Color32::LIGHT_GRAY)
+                );
+
+                ui.add_space(20.0);
+
+                if ui.button("Close").clicked() {
+                    actions.push(UiAction::CloseGpuMemoryWarning);
+                }
+            });
+        });
+}
+
 /// Render Alliance experimental warning dialog
 pub fn render_alliance_warning_dialog(
     ctx: &egui::Context,
@@ -546,7 +2191,7 @@ pub fn render_alliance_warning_dialog(
     egui::Window::new("Alliance Partner")
         .collapsible(false)
         .resizable(false)
-        .fixed_size([420.0, 200.0])
+        .fixed_size([420.0, 320.0])
         .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
         .show(ctx, |ui| {
             ui.vertical_centered(|ui| {
@@ -583,11 +2228,13 @@ pub fn render_alliance_warning_dialog(
                 ui.add_space(10.0);
 
                 ui.label(
-                    egui::RichText::new("Alliance support is still experimental.")
+                    egui::RichText::new("Alliance partners don't support every feature yet:")
                         .size(14.0)
                         .color(egui::Color32::from_rgb(255, 200, 80)),
                 );
 
+                ui.add_space(6.0);
+                render_provider_features(ui);
                 ui.add_space(6.0);
 
                 ui.label(
@@ -770,12 +2417,97 @@ pub fn render_ads_required_screen(
     });
 }
 
-/// Render first-time welcome popup
-pub fn render_welcome_popup(ctx: &egui::Context, actions: &mut Vec<UiAction>) {
+/// Render the dialog pointing at the crash report left by the previous run
+pub fn render_crash_report_dialog(ctx: &egui::Context, crash: &CrashReport, actions: &mut Vec<UiAction>) {
+    egui::Window::new("OpenNOW Crashed")
+        .collapsible(false)
+        .resizable(false)
+        .fixed_size([460.0, 200.0])
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.add_space(10.0);
+
+                ui.label(
+                    egui::RichText::new("⚠ OpenNOW crashed last time")
+                        .size(16.0)
+                        .strong()
+                        .color(egui::Color32::from_rgb(255, 180, 50))
+                );
+
+                ui.add_space(10.0);
+
+                if !crash.message.is_empty() {
+                    ui.label(
+                        egui::RichText::new(&crash.message)
+                            .size(13.0)
+                            .color(egui::Color32::LIGHT_GRAY)
+                    );
+                    ui.add_space(6.0);
+                }
+
+                ui.label(
+                    egui::RichText::new(format!("A crash report was saved to:\n{}", crash.path.display()))
+                        .size(12.0)
+                        .color(egui::Color32::GRAY)
+                );
+
+                ui.add_space(15.0);
+
+                ui.horizontal(|ui| {
+                    if ui.button("Open Report").clicked() {
+                        actions.push(UiAction::OpenCrashReport);
+                    }
+                    if ui.button("Report Issue")
+                        .on_hover_text("Open a pre-filled GitHub bug report - attach the crash report file to it.")
+                        .clicked()
+                    {
+                        actions.push(UiAction::ReportIssue);
+                    }
+                    if ui.button("Close").clicked() {
+                        actions.push(UiAction::CloseCrashReport);
+                    }
+                });
+            });
+        });
+}
+
+/// Render the "update available" notice (bottom-right, non-blocking)
+pub fn render_update_notice(ctx: &egui::Context, release: &ReleaseInfo, actions: &mut Vec<UiAction>) {
+    egui::Window::new("Update Available")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::RIGHT_BOTTOM, [-16.0, -16.0])
+        .show(ctx, |ui| {
+            ui.label(
+                egui::RichText::new(format!("OpenNOW v{} is available", release.version))
+                    .size(14.0)
+                    .strong()
+                    .color(egui::Color32::from_rgb(118, 185, 0))
+            );
+            ui.label(
+                egui::RichText::new(format!("You are running v{}. Updating may fix issues you run into.", APP_VERSION))
+                    .size(12.0)
+                    .color(egui::Color32::LIGHT_GRAY)
+            );
+
+            ui.add_space(8.0);
+
+            ui.horizontal(|ui| {
+                ui.hyperlink_to("Release notes", &release.url);
+                if ui.button("Dismiss").clicked() {
+                    actions.push(UiAction::DismissUpdateNotice);
+                }
+            });
+        });
+}
+
+/// Render welcome popup (shown on startup unless disabled in settings)
+pub fn render_welcome_popup(ctx: &egui::Context, show_on_startup: bool, actions: &mut Vec<UiAction>) {
     egui::Window::new("Welcome to OpenNOW")
         .collapsible(false)
         .resizable(false)
-        .fixed_size([450.0, 280.0])
+        .fixed_size([450.0, 310.0])
         .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
         .show(ctx, |ui| {
             ui.vertical_centered(|ui| {
@@ -861,6 +2593,13 @@ pub fn render_welcome_popup(ctx: &egui::Context, actions: &mut Vec<UiAction>) {
                 if ui.add(continue_btn).clicked() {
                     actions.push(UiAction::CloseWelcomePopup);
                 }
+
+                ui.add_space(10.0);
+
+                let mut show_tips = show_on_startup;
+                if ui.checkbox(&mut show_tips, "Show tips on startup").changed() {
+                    actions.push(UiAction::UpdateSetting(SettingChange::ShowTipsOnStartup(show_tips)));
+                }
             });
         });
 }
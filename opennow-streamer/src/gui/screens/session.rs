@@ -2,6 +2,7 @@
 //!
 //! Renders the session loading/connecting screen.
 
+use crate::app::session::StreamSettingsOffer;
 use crate::app::{GameInfo, UiAction};
 
 /// Render the session screen (loading/connecting state)
@@ -10,6 +11,8 @@ pub fn render_session_screen(
     selected_game: &Option<GameInfo>,
     status_message: &str,
     error_message: &Option<String>,
+    can_retry: bool,
+    settings_offer: Option<&StreamSettingsOffer>,
     actions: &mut Vec<UiAction>
 ) {
     egui::CentralPanel::default().show(ctx, |ui| {
@@ -28,8 +31,10 @@ pub fn render_session_screen(
 
             ui.add_space(40.0);
 
-            // Spinner
-            ui.spinner();
+            // Spinner (hidden once an error is shown)
+            if error_message.is_none() {
+                ui.spinner();
+            }
 
             ui.add_space(20.0);
 
@@ -52,10 +57,42 @@ pub fn render_session_screen(
 
             ui.add_space(40.0);
 
-            // Cancel button
-            if ui.button("Cancel").clicked() {
-                actions.push(UiAction::StopStreaming);
-            }
+            ui.horizontal(|ui| {
+                // Center the button row
+                let mut buttons_width = if can_retry { 140.0 } else { 60.0 };
+                if error_message.is_some() {
+                    buttons_width += 110.0;
+                }
+                if settings_offer.is_some() {
+                    buttons_width += 180.0;
+                }
+                ui.add_space((ui.available_width() - buttons_width).max(0.0) / 2.0);
+
+                // Retry button (only after session setup timed out)
+                if can_retry && ui.button("Retry").clicked() {
+                    actions.push(UiAction::RetrySession);
+                }
+
+                // Retry with the closest settings the server accepts (after a refused request)
+                if let Some(offer) = settings_offer {
+                    if ui.button(offer.retry_label.as_str())
+                        .on_hover_text("Saves these settings and launches again")
+                        .clicked()
+                    {
+                        actions.push(UiAction::AcceptStreamSettingsOffer);
+                    }
+                }
+
+                // Report button (only once something went wrong)
+                if error_message.is_some() && ui.button("Report Issue").clicked() {
+                    actions.push(UiAction::ReportIssue);
+                }
+
+                // Cancel button
+                if ui.button("Cancel").clicked() {
+                    actions.push(UiAction::StopStreaming);
+                }
+            });
         });
     });
 }
@@ -10,10 +10,77 @@
 //! 2. BT.2020 to BT.709 color space conversion
 //! 3. ACES tone mapping to compress HDR to SDR range
 //! 4. SDR gamma for display
+//!
+//! All video shaders finish with the user's brightness/gamma adjustment
+//! (bind group 1, see `VideoAdjust`), and size the quad to the selected
+//! scaling mode (integer scaling also snaps sampling to source pixels). They also apply
+//! optional contrast-adaptive sharpening for streams upscaled to larger displays. That
+//! code is shared through the `video_common_wgsl!` prefix.
+
+/// Shared by every video shader: the `VideoAdjust` uniform, brightness/gamma,
+/// nearest sampling for integer scaling and contrast-adaptive sharpening.
+/// Each shader provides `source_dims()` and `source_luma()` for its textures.
+macro_rules! video_common_wgsl {
+    () => {
+        r#"
+// Display-side brightness/gamma/sharpening (user settings, applied after color conversion)
+struct VideoAdjust {
+    brightness: f32,
+    gamma: f32,
+    sharpening: f32,
+    // > 0 = integer scaling: sample exact source pixels (nearest neighbor)
+    nearest: f32,
+    // Quad size relative to the window (aspect-ratio fit/fill/stretch)
+    scale: vec2<f32>,
+    // Quad position in clip space (integer scaling aligns it to whole pixels)
+    offset: vec2<f32>,
+};
+@group(1) @binding(0)
+var<uniform> adjust: VideoAdjust;
+
+fn apply_adjust(rgb: vec3<f32>) -> vec3<f32> {
+    let lifted = clamp(rgb + vec3<f32>(adjust.brightness), vec3<f32>(0.0), vec3<f32>(1.0));
+    return pow(lifted, vec3<f32>(1.0 / adjust.gamma));
+}
+
+// Snap to texel centers so filtering returns exact source pixels (nearest neighbor)
+fn sample_coord(tex_coord: vec2<f32>) -> vec2<f32> {
+    if (adjust.nearest <= 0.0) {
+        return tex_coord;
+    }
+    let dims = source_dims();
+    return (floor(tex_coord * dims) + 0.5) / dims;
+}
+
+// Contrast-adaptive sharpening (CAS-like) on full-range luma.
+// Returns the luma change to add to every RGB channel after conversion; sharpens
+// less where local contrast is already high to avoid ringing on edges.
+fn sharpen_delta(tex_coord: vec2<f32>, center: f32) -> f32 {
+    if (adjust.sharpening <= 0.0) {
+        return 0.0;
+    }
+    let texel = 1.0 / source_dims();
+    let n = source_luma(tex_coord + vec2<f32>(0.0, -texel.y));
+    let s = source_luma(tex_coord + vec2<f32>(0.0, texel.y));
+    let e = source_luma(tex_coord + vec2<f32>(texel.x, 0.0));
+    let w = source_luma(tex_coord + vec2<f32>(-texel.x, 0.0));
+
+    let lo = min(center, min(min(n, s), min(e, w)));
+    let hi = max(center, max(max(n, s), max(e, w)));
+    let amount = sqrt(clamp(min(lo, 1.0 - hi) / max(hi, 0.0001), 0.0, 1.0));
+    let weight = -amount / mix(8.0, 5.0, adjust.sharpening);
+    let sharpened = (center + (n + s + e + w) * weight) / (1.0 + 4.0 * weight);
+    return sharpened - center;
+}
+"#
+    };
+}
 
 /// WGSL shader for YUV420P format (3 separate planes)
 /// Uses BT.709 Limited range conversion (standard for video)
-pub const VIDEO_SHADER: &str = r#"
+pub const VIDEO_SHADER: &str = concat!(
+    video_common_wgsl!(),
+    r#"
 struct VertexOutput {
     @builtin(position) position: vec4<f32>,
     @location(0) tex_coord: vec2<f32>,
@@ -41,7 +108,7 @@ fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
     );
 
     var output: VertexOutput;
-    output.position = vec4<f32>(positions[vertex_index], 0.0, 1.0);
+    output.position = vec4<f32>(positions[vertex_index] * adjust.scale + adjust.offset, 0.0, 1.0);
     output.tex_coord = tex_coords[vertex_index];
     return output;
 }
@@ -56,12 +123,24 @@ var v_texture: texture_2d<f32>;
 @group(0) @binding(3)
 var video_sampler: sampler;
 
+// Source size and full-range luma (see `video_common_wgsl!`)
+fn source_dims() -> vec2<f32> {
+    return vec2<f32>(textureDimensions(y_texture));
+}
+
+fn source_luma(coord: vec2<f32>) -> f32 {
+    let y_raw = textureSampleLevel(y_texture, video_sampler, coord, 0.0).r;
+    return (y_raw - 16.0/255.0) * (255.0/219.0);
+}
+
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    let tex_coord = sample_coord(input.tex_coord);
+
     // Sample Y, U, V planes
-    let y_raw = textureSample(y_texture, video_sampler, input.tex_coord).r;
-    let u_raw = textureSample(u_texture, video_sampler, input.tex_coord).r;
-    let v_raw = textureSample(v_texture, video_sampler, input.tex_coord).r;
+    let y_raw = textureSample(y_texture, video_sampler, tex_coord).r;
+    let u_raw = textureSample(u_texture, video_sampler, tex_coord).r;
+    let v_raw = textureSample(v_texture, video_sampler, tex_coord).r;
 
     // BT.709 Limited Range (TV range: Y 16-235, UV 16-240)
     // Scale from limited to full range
@@ -74,14 +153,19 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     let g = y - 0.1873 * u - 0.4681 * v;
     let b = y + 1.8556 * u;
 
-    return vec4<f32>(clamp(r, 0.0, 1.0), clamp(g, 0.0, 1.0), clamp(b, 0.0, 1.0), 1.0);
+    let sharpened = vec3<f32>(r, g, b) + vec3<f32>(sharpen_delta(tex_coord, y));
+    let rgb = clamp(sharpened, vec3<f32>(0.0), vec3<f32>(1.0));
+    return vec4<f32>(apply_adjust(rgb), 1.0);
 }
-"#;
+"#
+);
 
 /// WGSL shader for NV12 format (D3D11 on Windows, VideoToolbox on macOS)
 /// Primary GPU path - Y plane (R8) + interleaved UV plane (Rg8)
 /// Uses BT.709 Limited range conversion (standard for video)
-pub const NV12_SHADER: &str = r#"
+pub const NV12_SHADER: &str = concat!(
+    video_common_wgsl!(),
+    r#"
 struct VertexOutput {
     @builtin(position) position: vec4<f32>,
     @location(0) tex_coord: vec2<f32>,
@@ -108,7 +192,7 @@ fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
     );
 
     var output: VertexOutput;
-    output.position = vec4<f32>(positions[vertex_index], 0.0, 1.0);
+    output.position = vec4<f32>(positions[vertex_index] * adjust.scale + adjust.offset, 0.0, 1.0);
     output.tex_coord = tex_coords[vertex_index];
     return output;
 }
@@ -121,11 +205,23 @@ var uv_texture: texture_2d<f32>;
 @group(0) @binding(2)
 var video_sampler: sampler;
 
+// Source size and full-range luma (see `video_common_wgsl!`)
+fn source_dims() -> vec2<f32> {
+    return vec2<f32>(textureDimensions(y_texture));
+}
+
+fn source_luma(coord: vec2<f32>) -> f32 {
+    let y_raw = textureSampleLevel(y_texture, video_sampler, coord, 0.0).r;
+    return (y_raw - 16.0/255.0) * (255.0/219.0);
+}
+
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    let tex_coord = sample_coord(input.tex_coord);
+
     // Sample Y (full res) and UV (half res, interleaved)
-    let y_raw = textureSample(y_texture, video_sampler, input.tex_coord).r;
-    let uv = textureSample(uv_texture, video_sampler, input.tex_coord);
+    let y_raw = textureSample(y_texture, video_sampler, tex_coord).r;
+    let uv = textureSample(uv_texture, video_sampler, tex_coord);
 
     // NV12 format: U in R channel, V in G channel
     let u_raw = uv.r;
@@ -142,13 +238,18 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     let g = y - 0.1873 * u - 0.4681 * v;
     let b = y + 1.8556 * u;
 
-    return vec4<f32>(clamp(r, 0.0, 1.0), clamp(g, 0.0, 1.0), clamp(b, 0.0, 1.0), 1.0);
+    let sharpened = vec3<f32>(r, g, b) + vec3<f32>(sharpen_delta(tex_coord, y));
+    let rgb = clamp(sharpened, vec3<f32>(0.0), vec3<f32>(1.0));
+    return vec4<f32>(apply_adjust(rgb), 1.0);
 }
-"#;
+"#
+);
 
 /// WGSL shader for NV12 HDR content (BT.2020 + PQ to SDR tone mapping)
 /// Used when HDR content is detected and display is in SDR mode
-pub const NV12_HDR_TONEMAP_SHADER: &str = r#"
+pub const NV12_HDR_TONEMAP_SHADER: &str = concat!(
+    video_common_wgsl!(),
+    r#"
 struct VertexOutput {
     @builtin(position) position: vec4<f32>,
     @location(0) tex_coord: vec2<f32>,
@@ -175,7 +276,7 @@ fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
     );
 
     var output: VertexOutput;
-    output.position = vec4<f32>(positions[vertex_index], 0.0, 1.0);
+    output.position = vec4<f32>(positions[vertex_index] * adjust.scale + adjust.offset, 0.0, 1.0);
     output.tex_coord = tex_coords[vertex_index];
     return output;
 }
@@ -188,6 +289,16 @@ var uv_texture: texture_2d<f32>;
 @group(0) @binding(2)
 var video_sampler: sampler;
 
+// Source size and full-range luma (see `video_common_wgsl!`)
+fn source_dims() -> vec2<f32> {
+    return vec2<f32>(textureDimensions(y_texture));
+}
+
+fn source_luma(coord: vec2<f32>) -> f32 {
+    let y_raw = textureSampleLevel(y_texture, video_sampler, coord, 0.0).r;
+    return (y_raw - 16.0/255.0) * (255.0/219.0);
+}
+
 // PQ EOTF (SMPTE ST 2084) - converts PQ signal to linear light (nits)
 fn pq_eotf(pq: vec3<f32>) -> vec3<f32> {
     let m1 = 0.1593017578125;   // 2610/16384
@@ -238,9 +349,11 @@ fn linear_to_srgb(linear: vec3<f32>) -> vec3<f32> {
 
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    let tex_coord = sample_coord(input.tex_coord);
+
     // Sample Y (full res) and UV (half res, interleaved)
-    let y_raw = textureSample(y_texture, video_sampler, input.tex_coord).r;
-    let uv = textureSample(uv_texture, video_sampler, input.tex_coord);
+    let y_raw = textureSample(y_texture, video_sampler, tex_coord).r;
+    let uv = textureSample(uv_texture, video_sampler, tex_coord);
 
     // NV12 format: U in R channel, V in G channel
     let u_raw = uv.r;
@@ -258,7 +371,9 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     let g = y - 0.1646 * u - 0.5714 * v;
     let b = y + 1.8814 * u;
 
-    var rgb = vec3<f32>(clamp(r, 0.0, 1.0), clamp(g, 0.0, 1.0), clamp(b, 0.0, 1.0));
+    // Sharpen the PQ signal (perceptually uniform) before it is linearized
+    let sharpened = vec3<f32>(r, g, b) + vec3<f32>(sharpen_delta(tex_coord, y));
+    var rgb = clamp(sharpened, vec3<f32>(0.0), vec3<f32>(1.0));
 
     // Apply PQ EOTF to get linear light in nits
     let linear_hdr = pq_eotf(rgb);
@@ -272,14 +387,17 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
     // Apply sRGB gamma for SDR display
     let sdr = linear_to_srgb(tonemapped);
 
-    return vec4<f32>(sdr, 1.0);
+    return vec4<f32>(apply_adjust(sdr), 1.0);
 }
-"#;
+"#
+);
 
 /// WGSL shader for ExternalTexture (wgpu 28+ zero-copy video)
 /// Uses texture_external which provides hardware-accelerated YUV->RGB conversion
 /// This is the fastest path - no manual color conversion needed
-pub const EXTERNAL_TEXTURE_SHADER: &str = r#"
+pub const EXTERNAL_TEXTURE_SHADER: &str = concat!(
+    video_common_wgsl!(),
+    r#"
 struct VertexOutput {
     @builtin(position) position: vec4<f32>,
     @location(0) tex_coord: vec2<f32>,
@@ -306,7 +424,7 @@ fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
     );
 
     var output: VertexOutput;
-    output.position = vec4<f32>(positions[vertex_index], 0.0, 1.0);
+    output.position = vec4<f32>(positions[vertex_index] * adjust.scale + adjust.offset, 0.0, 1.0);
     output.tex_coord = tex_coords[vertex_index];
     return output;
 }
@@ -319,10 +437,27 @@ var video_texture: texture_external;
 @group(0) @binding(1)
 var video_sampler: sampler;
 
+// Source size and luma (see `video_common_wgsl!`), from the already converted RGB
+fn source_dims() -> vec2<f32> {
+    return vec2<f32>(textureDimensions(video_texture));
+}
+
+fn source_luma(coord: vec2<f32>) -> f32 {
+    let rgb = textureSampleBaseClampToEdge(video_texture, video_sampler, coord).rgb;
+    return dot(rgb, vec3<f32>(0.2126, 0.7152, 0.0722));
+}
+
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    let tex_coord = sample_coord(input.tex_coord);
+
     // textureSampleBaseClampToEdge automatically converts YUV to RGB
     // using the color space information from the ExternalTexture descriptor
-    return textureSampleBaseClampToEdge(video_texture, video_sampler, input.tex_coord);
+    let color = textureSampleBaseClampToEdge(video_texture, video_sampler, tex_coord);
+    let luma = dot(color.rgb, vec3<f32>(0.2126, 0.7152, 0.0722));
+    let sharpened = color.rgb + vec3<f32>(sharpen_delta(tex_coord, luma));
+    let rgb = clamp(sharpened, vec3<f32>(0.0), vec3<f32>(1.0));
+    return vec4<f32>(apply_adjust(rgb), color.a);
 }
-"#;
+"#
+);
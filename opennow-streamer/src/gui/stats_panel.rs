@@ -6,8 +6,18 @@
 use egui::{Align2, Color32, FontId, RichText};
 use crate::media::StreamStats;
 use crate::app::StatsPosition;
+use std::collections::VecDeque;
 use std::time::{Duration, Instant};
 
+/// Number of present intervals kept for frame pacing metrics (enough for a meaningful 0.1% low)
+const FRAME_TIME_WINDOW: usize = 2000;
+
+/// Number of most recent intervals shown in the frame-time graph
+const FRAME_TIME_GRAPH_SAMPLES: usize = 120;
+
+/// Intervals longer than this are pauses (stalls, minimized window), not stutter
+const MAX_FRAME_TIME_MS: f32 = 1000.0;
+
 /// Interval between stats updates (200ms = 5 updates per second)
 /// This dramatically reduces CPU usage while still providing responsive feedback
 const STATS_UPDATE_INTERVAL: Duration = Duration::from_millis(200);
@@ -246,3 +256,105 @@ impl Default for StatsPanel {
         Self::new()
     }
 }
+
+/// Tracks present-to-present frame times for frame pacing metrics (1% / 0.1% lows)
+pub struct FrameTimeTracker {
+    /// Recent frame times in ms, oldest first
+    samples: VecDeque<f32>,
+    /// Time of the previous present
+    last_present: Option<Instant>,
+}
+
+impl FrameTimeTracker {
+    pub fn new() -> Self {
+        Self {
+            samples: VecDeque::with_capacity(FRAME_TIME_WINDOW),
+            last_present: None,
+        }
+    }
+
+    /// Record a presented frame
+    pub fn record_present(&mut self) {
+        let now = Instant::now();
+        if let Some(last) = self.last_present {
+            let frame_time_ms = now.duration_since(last).as_secs_f32() * 1000.0;
+            if frame_time_ms <= MAX_FRAME_TIME_MS {
+                if self.samples.len() == FRAME_TIME_WINDOW {
+                    self.samples.pop_front();
+                }
+                self.samples.push_back(frame_time_ms);
+            }
+        }
+        self.last_present = Some(now);
+    }
+
+    /// Clear all samples (call when streaming stops)
+    pub fn reset(&mut self) {
+        self.samples.clear();
+        self.last_present = None;
+    }
+
+    /// Fill the frame pacing fields of `stats` from the recorded samples
+    pub fn apply_to(&self, stats: &mut StreamStats) {
+        if self.samples.is_empty() {
+            return;
+        }
+
+        let mut sorted: Vec<f32> = self.samples.iter().copied().collect();
+        sorted.sort_by(|a, b| b.total_cmp(a)); // Slowest first
+
+        stats.frame_time_ms = sorted.iter().sum::<f32>() / sorted.len() as f32;
+        stats.low_1pct_fps = Self::low_fps(&sorted, 0.01);
+        stats.low_01pct_fps = Self::low_fps(&sorted, 0.001);
+
+        let skip = self.samples.len().saturating_sub(FRAME_TIME_GRAPH_SAMPLES);
+        stats.frame_time_history = self.samples.iter().skip(skip).copied().collect();
+    }
+
+    /// Average FPS of the slowest `fraction` of frames (input sorted slowest first)
+    fn low_fps(sorted_desc: &[f32], fraction: f32) -> f32 {
+        let count = ((sorted_desc.len() as f32 * fraction).ceil() as usize).max(1);
+        let avg_ms = sorted_desc[..count].iter().sum::<f32>() / count as f32;
+        if avg_ms > 0.0 {
+            1000.0 / avg_ms
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Default for FrameTimeTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_low_fps_uses_slowest_frames() {
+        // 990 smooth frames at 10ms, 10 hitches at 50ms
+        let mut tracker = FrameTimeTracker::new();
+        tracker.samples.extend(std::iter::repeat(10.0).take(990));
+        tracker.samples.extend(std::iter::repeat(50.0).take(10));
+
+        let mut stats = StreamStats::default();
+        tracker.apply_to(&mut stats);
+
+        assert!((stats.low_1pct_fps - 20.0).abs() < 0.01);
+        assert!((stats.low_01pct_fps - 20.0).abs() < 0.01);
+        assert!((stats.frame_time_ms - 10.4).abs() < 0.01);
+        assert_eq!(stats.frame_time_history.len(), FRAME_TIME_GRAPH_SAMPLES);
+    }
+
+    #[test]
+    fn test_empty_tracker_leaves_stats_untouched() {
+        let tracker = FrameTimeTracker::new();
+        let mut stats = StreamStats::default();
+        tracker.apply_to(&mut stats);
+        assert_eq!(stats.low_1pct_fps, 0.0);
+        assert!(stats.frame_time_history.is_empty());
+    }
+}
@@ -0,0 +1,235 @@
+//! Stats Window
+//!
+//! Pop-out window with the stream stats, for streamers: it can sit on a second
+//! monitor or be captured on its own by OBS while the game window stays clean.
+//! Shares the game window's GPU device and is drawn on the same thread, so it
+//! never waits for vsync and only redraws at the stats refresh interval.
+
+use anyhow::{Context, Result};
+use log::info;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use winit::dpi::LogicalSize;
+use winit::event::WindowEvent;
+use winit::event_loop::ActiveEventLoop;
+use winit::window::{Window, WindowAttributes, WindowId, WindowLevel};
+
+use crate::media::StreamStats;
+
+/// Initial window size (fits the full stats list)
+const INITIAL_SIZE: LogicalSize<f64> = LogicalSize::new(320.0, 360.0);
+
+/// Always-on-top window showing the stream stats
+pub struct StatsWindow {
+    window: Arc<Window>,
+    surface: wgpu::Surface<'static>,
+    config: wgpu::SurfaceConfiguration,
+    egui_ctx: egui::Context,
+    egui_state: egui_winit::State,
+    egui_renderer: egui_wgpu::Renderer,
+    last_render: Option<Instant>,
+}
+
+impl StatsWindow {
+    /// Open the window, rendering with the game window's device
+    pub fn new(
+        event_loop: &ActiveEventLoop,
+        instance: &wgpu::Instance,
+        adapter: &wgpu::Adapter,
+        device: &wgpu::Device,
+    ) -> Result<Self> {
+        let window_attrs = WindowAttributes::default()
+            .with_title("OpenNow Stats")
+            .with_inner_size(INITIAL_SIZE)
+            .with_min_inner_size(LogicalSize::new(200.0, 120.0))
+            .with_window_level(WindowLevel::AlwaysOnTop)
+            // Opening mid-stream must not steal focus (that releases keys and pauses input)
+            .with_active(false);
+        let window = Arc::new(
+            event_loop
+                .create_window(window_attrs)
+                .context("Failed to create stats window")?,
+        );
+
+        let surface = instance
+            .create_surface(window.clone())
+            .context("Failed to create stats window surface")?;
+        let caps = surface.get_capabilities(adapter);
+        let format = caps
+            .formats
+            .iter()
+            .find(|f| !f.is_srgb())
+            .copied()
+            .unwrap_or(caps.formats[0]);
+        // Presenting must not block: a vsync wait here would delay the next game frame
+        let present_mode = [wgpu::PresentMode::Immediate, wgpu::PresentMode::Mailbox]
+            .into_iter()
+            .find(|mode| caps.present_modes.contains(mode))
+            .unwrap_or(wgpu::PresentMode::Fifo);
+        let size = window.inner_size();
+        let config = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            format,
+            width: size.width.max(1),
+            height: size.height.max(1),
+            present_mode,
+            alpha_mode: caps.alpha_modes[0],
+            view_formats: vec![],
+            desired_maximum_frame_latency: 1,
+        };
+        surface.configure(device, &config);
+
+        let egui_ctx = egui::Context::default();
+        egui_ctx.set_visuals(egui::Visuals::dark());
+        let egui_state = egui_winit::State::new(
+            egui_ctx.clone(),
+            egui::ViewportId::from_hash_of("stats_window"),
+            &window,
+            Some(window.scale_factor() as f32),
+            None,
+            None,
+        );
+        let egui_renderer =
+            egui_wgpu::Renderer::new(device, format, egui_wgpu::RendererOptions::default());
+
+        info!(
+            "Stats window opened ({}x{}, {:?})",
+            size.width, size.height, present_mode
+        );
+
+        Ok(Self {
+            window,
+            surface,
+            config,
+            egui_ctx,
+            egui_state,
+            egui_renderer,
+            last_render: None,
+        })
+    }
+
+    pub fn id(&self) -> WindowId {
+        self.window.id()
+    }
+
+    /// Handle a window event (resizes and egui input)
+    pub fn handle_event(&mut self, device: &wgpu::Device, event: &WindowEvent) {
+        let new_size = match event {
+            WindowEvent::Resized(size) => Some(*size),
+            // Moved to a monitor with a different DPI; Resized doesn't always follow
+            WindowEvent::ScaleFactorChanged { .. } => Some(self.window.inner_size()),
+            _ => None,
+        };
+        if let Some(size) = new_size {
+            if size.width > 0 && size.height > 0 {
+                self.config.width = size.width;
+                self.config.height = size.height;
+                self.surface.configure(device, &self.config);
+            }
+        }
+        if self.egui_state.on_window_event(&self.window, event).repaint {
+            self.window.request_redraw();
+        }
+    }
+
+    /// Request a redraw once `interval` has passed since the last one
+    pub fn request_redraw_after(&self, interval: Duration) {
+        if self
+            .last_render
+            .is_none_or(|last| last.elapsed() >= interval)
+        {
+            self.window.request_redraw();
+        }
+    }
+
+    /// Draw the stats
+    pub fn render(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        stats: &StreamStats,
+    ) -> Result<()> {
+        self.last_render = Some(Instant::now());
+
+        let output = match self.surface.get_current_texture() {
+            Ok(output) => output,
+            Err(wgpu::SurfaceError::Outdated | wgpu::SurfaceError::Lost) => {
+                // Minimized or resized under us - try again next time
+                self.surface.configure(device, &self.config);
+                return Ok(());
+            }
+            Err(wgpu::SurfaceError::Timeout) => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        let view = output
+            .texture
+            .create_view(&wgpu::TextureViewDescriptor::default());
+
+        let raw_input = self.egui_state.take_egui_input(&self.window);
+        let full_output = self.egui_ctx.run(raw_input, |ctx| {
+            egui::CentralPanel::default()
+                .frame(
+                    egui::Frame::new()
+                        .fill(egui::Color32::BLACK)
+                        .inner_margin(10.0),
+                )
+                .show(ctx, |ui| {
+                    egui::ScrollArea::vertical().show(ui, |ui| {
+                        super::renderer::render_stats_contents(ui, stats);
+                    });
+                });
+        });
+        self.egui_state
+            .handle_platform_output(&self.window, full_output.platform_output);
+
+        let clipped_primitives = self
+            .egui_ctx
+            .tessellate(full_output.shapes, full_output.pixels_per_point);
+        for (id, image_delta) in &full_output.textures_delta.set {
+            self.egui_renderer
+                .update_texture(device, queue, *id, image_delta);
+        }
+        let screen_descriptor = egui_wgpu::ScreenDescriptor {
+            size_in_pixels: [self.config.width, self.config.height],
+            pixels_per_point: full_output.pixels_per_point,
+        };
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Stats Window Encoder"),
+        });
+        self.egui_renderer.update_buffers(
+            device,
+            queue,
+            &mut encoder,
+            &clipped_primitives,
+            &screen_descriptor,
+        );
+        {
+            let render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Stats Window Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                    depth_slice: None,
+                })],
+                depth_stencil_attachment: None,
+                ..Default::default()
+            });
+            // forget_lifetime is safe here as render_pass is dropped before encoder.finish()
+            let mut render_pass = render_pass.forget_lifetime();
+            self.egui_renderer
+                .render(&mut render_pass, &clipped_primitives, &screen_descriptor);
+        }
+        for id in &full_output.textures_delta.free {
+            self.egui_renderer.free_texture(id);
+        }
+
+        queue.submit(std::iter::once(encoder.finish()));
+        output.present();
+        Ok(())
+    }
+}
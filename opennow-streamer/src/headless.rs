@@ -0,0 +1,279 @@
+//! Headless Mode
+//!
+//! `--headless --launch-game <app_id>` connects and decodes a session without a
+//! window or the egui UI, for automated tests of the streaming path and capture rigs.
+//! Decoding is forced to software so frames end up in system memory, and
+//! `--capture-dir <dir>` saves the latest frame as a PNG every `--capture-interval`
+//! seconds. No input is captured or sent: input handling needs a focused window.
+//!
+//! Sign in once with the normal window first; the saved login is reused.
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+use log::{info, warn};
+
+use crate::app::{App, AppState, UiAction};
+use crate::media::{PixelFormat, VideoFrame};
+
+/// How long the game lookup and launch request may take before giving up
+const LAUNCH_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// App state is polled this often (frames are picked up as they arrive)
+const POLL_INTERVAL: Duration = Duration::from_millis(2);
+
+const DEFAULT_CAPTURE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Command line options for a headless run
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeadlessOptions {
+    pub app_id: String,
+    /// Stop after streaming this long (None = until Ctrl+C or the session ends)
+    pub duration: Option<Duration>,
+    /// Folder for frame snapshots (None = don't save frames)
+    pub capture_dir: Option<PathBuf>,
+    pub capture_interval: Duration,
+}
+
+impl HeadlessOptions {
+    /// Options from the command line; None if `--headless` wasn't given
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Option<Self>> {
+        let args: Vec<String> = args.into_iter().collect();
+        if !args.iter().any(|arg| arg == "--headless") {
+            return Ok(None);
+        }
+        let value = |flag: &str| -> Option<&str> {
+            let index = args.iter().position(|arg| arg == flag)?;
+            args.get(index + 1).map(String::as_str)
+        };
+        let seconds = |flag: &str| -> Result<Option<Duration>> {
+            value(flag)
+                .map(|v| {
+                    v.parse::<f64>()
+                        .ok()
+                        .filter(|secs| *secs > 0.0)
+                        .map(Duration::from_secs_f64)
+                        .with_context(|| format!("{} needs a number of seconds, got {:?}", flag, v))
+                })
+                .transpose()
+        };
+
+        let app_id = value("--launch-game")
+            .map(str::to_string)
+            .or_else(|| {
+                args.iter()
+                    .find_map(|arg| crate::utils::parse_launch_url(arg))
+            })
+            .context("--headless needs a game: --launch-game <app_id>")?;
+
+        Ok(Some(Self {
+            app_id,
+            duration: seconds("--duration")?,
+            capture_dir: value("--capture-dir").map(PathBuf::from),
+            capture_interval: seconds("--capture-interval")?.unwrap_or(DEFAULT_CAPTURE_INTERVAL),
+        }))
+    }
+}
+
+/// Launch the game and stream it without a window until the duration is up,
+/// Ctrl+C is pressed or the session ends
+pub fn run(runtime: tokio::runtime::Handle, options: HeadlessOptions) -> Result<()> {
+    info!("Headless mode: {:?}", options);
+
+    // Hardware decoders hand frames to the renderer as GPU surfaces; there is none here
+    crate::app::config::set_safe_mode_override(true);
+
+    if let Some(ref dir) = options.capture_dir {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create capture folder {}", dir.display()))?;
+    }
+
+    let interrupted = Arc::new(AtomicBool::new(false));
+    {
+        let interrupted = interrupted.clone();
+        runtime.spawn(async move {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                info!("Ctrl+C - ending headless session");
+                interrupted.store(true, Ordering::SeqCst);
+            }
+        });
+    }
+
+    let mut app = App::new(runtime);
+    if app.state != AppState::Games {
+        bail!("Not signed in - log in once with the normal window, then retry");
+    }
+    crate::utils::handle_deep_link(&format!(
+        "{}://launch/{}",
+        crate::utils::URL_SCHEME,
+        options.app_id
+    ));
+
+    let started = Instant::now();
+    let mut launched = false;
+    let mut streaming_since: Option<Instant> = None;
+    let mut next_capture = Instant::now();
+    let mut frames = 0u64;
+    let mut last_frame_id = 0u64;
+
+    let result = loop {
+        if interrupted.load(Ordering::SeqCst) {
+            break Ok(());
+        }
+        app.update();
+
+        // Dialogs that would wait for a click: launching was asked for explicitly,
+        // but never end someone's other session
+        if app.low_hours_launch.is_some() {
+            info!("Launching despite low remaining hours");
+            app.handle_action(UiAction::ConfirmLowHoursLaunch);
+        }
+        if app.show_session_conflict {
+            break Err(anyhow::anyhow!(
+                "Another session is already running - end it first"
+            ));
+        }
+
+        match app.state {
+            AppState::Games if launched => {
+                break Err(anyhow::anyhow!(
+                    "Session ended: {}",
+                    app.error_message.as_deref().unwrap_or(&app.status_message)
+                ));
+            }
+            AppState::Games if started.elapsed() > LAUNCH_TIMEOUT => {
+                break Err(anyhow::anyhow!(
+                    "Game {} didn't launch within {}s",
+                    options.app_id,
+                    LAUNCH_TIMEOUT.as_secs()
+                ));
+            }
+            AppState::Session => {
+                launched = true;
+                if let Some(ref error) = app.error_message {
+                    break Err(anyhow::anyhow!("Session failed: {}", error));
+                }
+            }
+            AppState::Streaming => {
+                launched = true;
+                let since = *streaming_since.get_or_insert_with(|| {
+                    info!("Streaming headless");
+                    Instant::now()
+                });
+
+                if let Some(frame) = app.current_frame.as_ref() {
+                    if frame.frame_id != last_frame_id {
+                        last_frame_id = frame.frame_id;
+                        frames += 1;
+                        if let Some(ref dir) = options.capture_dir {
+                            if Instant::now() >= next_capture {
+                                next_capture = Instant::now() + options.capture_interval;
+                                save_snapshot(frame, dir, since.elapsed());
+                            }
+                        }
+                    }
+                }
+
+                if options.duration.is_some_and(|d| since.elapsed() >= d) {
+                    info!("Headless duration reached");
+                    break Ok(());
+                }
+            }
+            _ => {}
+        }
+
+        std::thread::sleep(POLL_INTERVAL);
+    };
+
+    if let Some(since) = streaming_since {
+        let secs = since.elapsed().as_secs_f32();
+        info!(
+            "Headless session: {} frames in {:.0}s ({:.1} fps), {} dropped",
+            frames,
+            secs,
+            frames as f32 / secs.max(1.0),
+            app.stats.frames_dropped
+        );
+    }
+    app.shutdown();
+    result
+}
+
+/// Save a frame as `<elapsed seconds>.png`
+fn save_snapshot(frame: &VideoFrame, dir: &std::path::Path, elapsed: Duration) {
+    // GStreamer pipelines (Linux, Windows) output NV12, FFmpeg software YUV420P
+    let cpu_format = matches!(frame.format, PixelFormat::YUV420P | PixelFormat::NV12);
+    if !cpu_format || frame.y_plane.is_empty() {
+        warn!("Can't snapshot {:?} frames", frame.format);
+        return;
+    }
+    let path = dir.join(format!("{:06}.png", elapsed.as_secs()));
+    let Some(image) = image::RgbaImage::from_raw(frame.width, frame.height, frame.to_rgba()) else {
+        return;
+    };
+    match image.save(&path) {
+        Ok(()) => info!("Saved frame {}", path.display()),
+        Err(e) => warn!("Failed to save frame {}: {}", path.display(), e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_headless_options_from_args() {
+        assert_eq!(
+            HeadlessOptions::from_args(args("opennow --mock")).unwrap(),
+            None
+        );
+
+        let options = HeadlessOptions::from_args(args(
+            "opennow --headless --launch-game 100932911 --duration 30 --capture-dir shots",
+        ))
+        .unwrap()
+        .unwrap();
+        assert_eq!(options.app_id, "100932911");
+        assert_eq!(options.duration, Some(Duration::from_secs(30)));
+        assert_eq!(options.capture_dir, Some(PathBuf::from("shots")));
+        assert_eq!(options.capture_interval, DEFAULT_CAPTURE_INTERVAL);
+
+        let options =
+            HeadlessOptions::from_args(args("opennow opennow://launch/42 --headless")).unwrap();
+        assert_eq!(options.map(|o| o.app_id), Some("42".to_string()));
+
+        assert!(HeadlessOptions::from_args(args("opennow --headless")).is_err());
+        assert!(HeadlessOptions::from_args(args(
+            "opennow --headless --launch-game 1 --duration soon"
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn test_save_nv12_snapshot() {
+        // 4x2 frame: one chroma row, U/V interleaved, strongly red (high V)
+        let mut frame = VideoFrame::empty(4, 2);
+        frame.format = PixelFormat::NV12;
+        frame.y_plane = vec![128; 8];
+        frame.u_plane = vec![128, 220, 128, 220];
+        frame.v_plane = Vec::new();
+        frame.u_stride = 4;
+
+        let dir = std::env::temp_dir().join(format!("opennow-snapshot-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        save_snapshot(&frame, &dir, Duration::from_secs(7));
+
+        let image = image::open(dir.join("000007.png")).unwrap().to_rgba8();
+        let _ = std::fs::remove_dir_all(&dir);
+        assert_eq!(image.dimensions(), (4, 2));
+        let pixel = image.get_pixel(3, 1);
+        assert!(pixel[0] > 200 && pixel[2] < 140, "{:?}", pixel);
+    }
+}
@@ -1,4 +1,4 @@
-use gilrs::{Axis, Button, Event, EventType, GamepadId, GilrsBuilder};
+use gilrs::{Axis, Button, Event, EventType, GamepadId, Gilrs, GilrsBuilder};
 use log::{debug, error, info, trace, warn};
 use parking_lot::Mutex;
 use std::collections::HashMap;
@@ -726,6 +726,48 @@ impl ControllerManager {
     }
 }
 
+/// Watch gamepads while `active` is set and call `on_input` (at most once per poll) when
+/// one is used. The menus don't take gamepad input, but it should still count as activity
+/// there. gilrs is only open while active, so it doesn't compete with a stream's
+/// `ControllerManager`.
+pub fn watch_menu_gamepads(active: Arc<AtomicBool>, on_input: impl Fn() + Send + 'static) {
+    std::thread::spawn(move || {
+        let mut gilrs: Option<Gilrs> = None;
+        loop {
+            if !active.load(Ordering::Relaxed) {
+                gilrs = None;
+                std::thread::sleep(Duration::from_millis(250));
+                continue;
+            }
+            if gilrs.is_none() {
+                match Gilrs::new() {
+                    Ok(g) => gilrs = Some(g),
+                    Err(e) => {
+                        warn!("Gamepads won't wake the menus: {}", e);
+                        return;
+                    }
+                }
+            }
+            let Some(pads) = gilrs.as_mut() else {
+                continue;
+            };
+
+            let mut used = false;
+            while let Some(Event { event, .. }) = pads.next_event() {
+                used |= match event {
+                    EventType::ButtonPressed(..) => true,
+                    EventType::AxisChanged(_, value, _) => value.abs() > 0.5,
+                    _ => false,
+                };
+            }
+            if used {
+                on_input();
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+    });
+}
+
 impl Default for ControllerManager {
     fn default() -> Self {
         Self::new()
@@ -14,11 +14,14 @@
 
 use log::{debug, error, info, warn};
 use parking_lot::Mutex;
+use std::os::fd::AsRawFd;
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU64, Ordering};
 use tokio::sync::mpsc;
 
-use crate::input::{get_timestamp_us, session_elapsed_us, MOUSE_COALESCE_INTERVAL_US};
+use crate::input::{
+    get_timestamp_us, is_grab_warp, mouse_coalesce_interval_us, session_elapsed_us,
+};
 use crate::webrtc::InputEvent;
 
 // evdev bindings
@@ -35,6 +38,8 @@ use x11::xlib;
 // Static state
 static RAW_INPUT_REGISTERED: AtomicBool = AtomicBool::new(false);
 static RAW_INPUT_ACTIVE: AtomicBool = AtomicBool::new(false);
+/// Bumped on every start so a stale evdev thread exits instead of doubling deltas
+static INPUT_GENERATION: AtomicU64 = AtomicU64::new(0);
 static ACCUMULATED_DX: AtomicI32 = AtomicI32::new(0);
 static ACCUMULATED_DY: AtomicI32 = AtomicI32::new(0);
 static STOP_REQUESTED: AtomicBool = AtomicBool::new(false);
@@ -51,6 +56,9 @@ static LOCAL_CURSOR_Y: AtomicI32 = AtomicI32::new(540);
 static LOCAL_CURSOR_WIDTH: AtomicI32 = AtomicI32::new(1920);
 static LOCAL_CURSOR_HEIGHT: AtomicI32 = AtomicI32::new(1080);
 
+/// How long the evdev thread waits for events before re-checking for a stop request
+const EVDEV_POLL_TIMEOUT_MS: i32 = 50;
+
 // Direct event sender for immediate mouse events
 static EVENT_SENDER: Mutex<Option<mpsc::Sender<InputEvent>>> = Mutex::new(None);
 
@@ -90,7 +98,7 @@ fn flush_coalesced_events() {
 /// Process mouse delta from any backend
 #[inline]
 fn process_mouse_delta(dx: i32, dy: i32) {
-    if dx == 0 && dy == 0 {
+    if (dx == 0 && dy == 0) || is_grab_warp(dx, dy) {
         return;
     }
 
@@ -115,29 +123,11 @@ fn process_mouse_delta(dx: i32, dy: i32) {
     let now_us = session_elapsed_us();
     let last_us = COALESCE_LAST_SEND_US.load(Ordering::Acquire);
 
-    if now_us.saturating_sub(last_us) >= MOUSE_COALESCE_INTERVAL_US {
+    if now_us.saturating_sub(last_us) >= mouse_coalesce_interval_us() {
         flush_coalesced_events();
     }
 }
 
-/// Process scroll wheel event
-fn process_scroll(delta: i32) {
-    if delta == 0 {
-        return;
-    }
-
-    let timestamp_us = get_timestamp_us();
-    let guard = EVENT_SENDER.lock();
-    if let Some(ref sender) = *guard {
-        // Linux scroll is typically 1 unit per notch, Windows uses 120
-        // Scale to match Windows WHEEL_DELTA
-        let _ = sender.try_send(InputEvent::MouseWheel {
-            delta: (delta * 120) as i16,
-            timestamp_us,
-        });
-    }
-}
-
 /// Find the primary mouse device in /dev/input/
 fn find_mouse_device() -> Option<String> {
     // Try common mouse device paths
@@ -189,26 +179,22 @@ fn find_mouse_device() -> Option<String> {
 
 /// evdev input thread - direct device access for lowest latency
 fn start_evdev_input(device_path: &str) -> Result<(), String> {
-    let mut device = Device::open(device_path)
+    let device = Device::open(device_path)
         .map_err(|e| format!("Failed to open evdev device {}: {}", device_path, e))?;
 
     let device_name = device.name().unwrap_or("Unknown").to_string();
     info!("evdev: Opened device '{}' at {}", device_name, device_path);
 
-    // Grab the device for exclusive access (optional - may fail on some systems)
-    // This prevents other applications from receiving the events
-    if let Err(e) = device.grab() {
-        warn!(
-            "evdev: Could not grab device exclusively: {} (continuing anyway)",
-            e
-        );
-    }
+    // The device is not grabbed: the desktop keeps the mouse while the stream is
+    // unfocused or the cursor is released, and the window's cursor lock keeps the
+    // pointer in place during capture. Only the deltas are read here, unaccelerated.
 
     // Mark as registered before spawning thread
     RAW_INPUT_REGISTERED.store(true, Ordering::SeqCst);
     RAW_INPUT_ACTIVE.store(true, Ordering::SeqCst);
     *ACTIVE_BACKEND.lock() = InputBackend::Evdev;
 
+    let generation = INPUT_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
     let device_path_owned = device_path.to_string();
     std::thread::spawn(move || {
         info!("evdev input thread started for {}", device_path_owned);
@@ -226,12 +212,23 @@ fn start_evdev_input(device_path: &str) -> Result<(), String> {
 
         // Event loop
         loop {
-            if STOP_REQUESTED.load(Ordering::SeqCst) {
+            if STOP_REQUESTED.load(Ordering::SeqCst)
+                || INPUT_GENERATION.load(Ordering::SeqCst) != generation
+            {
                 break;
             }
 
-            // Fetch events (blocking with timeout would be better, but evdev crate
-            // doesn't support that directly - we use a small sleep instead)
+            // fetch_events blocks until the mouse moves, so wait with a timeout first
+            // to notice stop requests while the mouse is idle
+            let mut poll_fd = libc::pollfd {
+                fd: device.as_raw_fd(),
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            if unsafe { libc::poll(&mut poll_fd, 1, EVDEV_POLL_TIMEOUT_MS) } <= 0 {
+                continue;
+            }
+
             match device.fetch_events() {
                 Ok(events) => {
                     if !RAW_INPUT_ACTIVE.load(Ordering::SeqCst) {
@@ -249,10 +246,7 @@ fn start_evdev_input(device_path: &str) -> Result<(), String> {
                                     RelativeAxisType::REL_Y => {
                                         process_mouse_delta(0, value);
                                     }
-                                    RelativeAxisType::REL_WHEEL
-                                    | RelativeAxisType::REL_WHEEL_HI_RES => {
-                                        process_scroll(value);
-                                    }
+                                    // Scrolling comes through winit's MouseWheel events
                                     _ => {}
                                 }
                             }
@@ -271,10 +265,11 @@ fn start_evdev_input(device_path: &str) -> Result<(), String> {
             }
         }
 
-        // Cleanup
-        let _ = device.ungrab();
-        RAW_INPUT_REGISTERED.store(false, Ordering::SeqCst);
-        RAW_INPUT_ACTIVE.store(false, Ordering::SeqCst);
+        // A newer thread owns the shared state if we were superseded
+        if INPUT_GENERATION.load(Ordering::SeqCst) == generation {
+            RAW_INPUT_REGISTERED.store(false, Ordering::SeqCst);
+            RAW_INPUT_ACTIVE.store(false, Ordering::SeqCst);
+        }
         info!("evdev input thread stopped");
     });
 
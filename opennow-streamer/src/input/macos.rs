@@ -15,7 +15,9 @@ use tokio::sync::mpsc;
 use parking_lot::Mutex;
 
 use crate::webrtc::InputEvent;
-use super::{get_timestamp_us, session_elapsed_us, MOUSE_COALESCE_INTERVAL_US};
+use super::{
+    get_timestamp_us, is_grab_warp, mouse_coalesce_interval_us, session_elapsed_us,
+};
 
 
 
@@ -125,6 +127,7 @@ enum CGEventField {
     MouseEventDeltaX = 4,
     MouseEventDeltaY = 5,
     ScrollWheelEventDeltaAxis1 = 11,
+    ScrollWheelEventDeltaAxis2 = 12,
     KeyboardEventKeycode = 9,
 }
 
@@ -242,7 +245,9 @@ extern "C" fn event_tap_callback(
                 let dx = CGEventGetIntegerValueField(event, CGEventField::MouseEventDeltaX) as i32;
                 let dy = CGEventGetIntegerValueField(event, CGEventField::MouseEventDeltaY) as i32;
 
-                if dx != 0 || dy != 0 {
+                // Locking the pointer warps it to the window and the next event's delta
+                // includes that jump - drop that one event
+                if (dx != 0 || dy != 0) && !is_grab_warp(dx, dy) {
                     // 1. Update local cursor immediately for visual feedback
                     let width = LOCAL_CURSOR_WIDTH.load(Ordering::Acquire);
                     let height = LOCAL_CURSOR_HEIGHT.load(Ordering::Acquire);
@@ -264,14 +269,16 @@ extern "C" fn event_tap_callback(
                     let now_us = session_elapsed_us();
                     let last_us = COALESCE_LAST_SEND_US.load(Ordering::Acquire);
 
-                    if now_us.saturating_sub(last_us) >= MOUSE_COALESCE_INTERVAL_US {
+                    if now_us.saturating_sub(last_us) >= mouse_coalesce_interval_us() {
                         flush_coalesced_events();
                     }
                 }
             }
             CGEventType::ScrollWheel => {
                 let delta = CGEventGetIntegerValueField(event, CGEventField::ScrollWheelEventDeltaAxis1) as i16;
-                if delta != 0 {
+                // Axis 2 is horizontal, positive = content moves right (scroll left)
+                let delta_h = CGEventGetIntegerValueField(event, CGEventField::ScrollWheelEventDeltaAxis2) as i16;
+                if delta != 0 || delta_h != 0 {
                     let timestamp_us = get_timestamp_us();
                     // Use try_lock to avoid blocking the event tap callback
                     if let Some(guard) = EVENT_SENDER.try_lock() {
@@ -279,7 +286,8 @@ extern "C" fn event_tap_callback(
                             // macOS scroll is inverted compared to Windows, and uses different scale
                             // Multiply by 120 to match Windows WHEEL_DELTA
                             let _ = sender.try_send(InputEvent::MouseWheel {
-                                delta: delta * 120,
+                                horizontal: delta_h.saturating_mul(-120),
+                                vertical: delta.saturating_mul(120),
                                 timestamp_us,
                             });
                         }
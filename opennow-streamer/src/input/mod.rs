@@ -53,39 +53,75 @@ pub use linux::{
 };
 
 use parking_lot::RwLock;
-use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 /// Session timing state - resettable for each streaming session
 /// GFN server expects timestamps relative to session start for proper input timing
 struct SessionTiming {
     start: Instant,
     unix_us: u64,
+    /// When the input clock was paused (timestamps are frozen until resumed)
+    paused_at: Option<Instant>,
+    /// Total time spent paused, left out of input timestamps
+    paused_total: Duration,
 }
 
 impl SessionTiming {
-    fn new() -> Self {
+    /// New timeline starting at the current Unix time, but never before `floor_us`
+    /// so timestamps keep increasing across re-inits and wall clock adjustments
+    fn new(floor_us: u64) -> Self {
         let unix_us = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .map(|d| d.as_micros() as u64)
             .unwrap_or(0);
         Self {
             start: Instant::now(),
-            unix_us,
+            unix_us: unix_us.max(floor_us),
+            paused_at: None,
+            paused_total: Duration::ZERO,
+        }
+    }
+
+    /// Input timestamp at `now`, excluding time spent paused
+    fn timestamp_us_at(&self, now: Instant) -> u64 {
+        let end = self.paused_at.unwrap_or(now);
+        let active = end
+            .saturating_duration_since(self.start)
+            .saturating_sub(self.paused_total);
+        self.unix_us.wrapping_add(active.as_micros() as u64)
+    }
+
+    fn pause_at(&mut self, now: Instant) {
+        if self.paused_at.is_none() {
+            self.paused_at = Some(now);
+        }
+    }
+
+    fn resume_at(&mut self, now: Instant) {
+        if let Some(paused_at) = self.paused_at.take() {
+            self.paused_total += now.saturating_duration_since(paused_at);
         }
     }
 }
 
 static SESSION_TIMING: RwLock<Option<SessionTiming>> = RwLock::new(None);
 
-/// Initialize session timing (call when streaming starts)
+/// Latest input timestamp handed out, so timestamps never go backwards
+static LAST_TIMESTAMP_US: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/// Session time (microseconds) of the most recent user input, for idle detection
+static LAST_INPUT_US: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/// Initialize session timing (call when streaming starts or the stream reconnects)
 /// This MUST be called before each new streaming session to reset timestamps
 pub fn init_session_timing() {
-    let timing = SessionTiming::new();
+    let timing = SessionTiming::new(LAST_TIMESTAMP_US.load(std::sync::atomic::Ordering::Relaxed));
     log::info!(
         "Session timing initialized at {} us (new session)",
         timing.unix_us
     );
     *SESSION_TIMING.write() = Some(timing);
+    LAST_INPUT_US.store(0, std::sync::atomic::Ordering::Relaxed);
 }
 
 /// Reset session timing (call when streaming stops)
@@ -94,15 +130,44 @@ pub fn reset_session_timing() {
     log::info!("Session timing reset");
 }
 
+/// Freeze input timestamps (call when input stops flowing, e.g. focus loss)
+pub fn pause_session_timing() {
+    if let Some(ref mut timing) = *SESSION_TIMING.write() {
+        timing.pause_at(Instant::now());
+        log::debug!("Session timing paused");
+    }
+}
+
+/// Continue input timestamps from where they were paused
+pub fn resume_session_timing() {
+    if let Some(ref mut timing) = *SESSION_TIMING.write() {
+        timing.resume_at(Instant::now());
+        log::debug!("Session timing resumed");
+    }
+}
+
+/// Record user input activity (any keyboard, mouse, controller or wheel event)
+pub fn mark_input_activity() {
+    LAST_INPUT_US.store(session_elapsed_us(), std::sync::atomic::Ordering::Relaxed);
+}
+
+/// Time since the last user input in the current session
+pub fn idle_duration() -> std::time::Duration {
+    let last = LAST_INPUT_US.load(std::sync::atomic::Ordering::Relaxed);
+    std::time::Duration::from_micros(session_elapsed_us().saturating_sub(last))
+}
+
 /// Get timestamp in microseconds
 /// Uses a hybrid approach: absolute Unix time base + relative offset from session start
-/// This provides both accurate server synchronization and consistent timing
+/// This provides both accurate server synchronization and consistent timing.
+/// Time spent paused is skipped and the result never goes backwards.
 #[inline]
 pub fn get_timestamp_us() -> u64 {
     let timing = SESSION_TIMING.read();
     if let Some(ref t) = *timing {
-        let elapsed_us = t.start.elapsed().as_micros() as u64;
-        t.unix_us.wrapping_add(elapsed_us)
+        let timestamp_us = t.timestamp_us_at(Instant::now());
+        let last = LAST_TIMESTAMP_US.fetch_max(timestamp_us, std::sync::atomic::Ordering::Relaxed);
+        timestamp_us.max(last)
     } else {
         // Fallback if not initialized (shouldn't happen during streaming)
         SystemTime::now()
@@ -131,9 +196,73 @@ use winit::event::{ElementState, MouseButton};
 
 use crate::webrtc::{InputEncoder, InputEvent, MAX_CLIPBOARD_PASTE_SIZE};
 
-/// Mouse event coalescing interval in microseconds
+/// Default mouse event coalescing interval in microseconds, from the default send rate
 /// Official client uses 4-16ms depending on browser, we use 2ms for lowest latency
-pub const MOUSE_COALESCE_INTERVAL_US: u64 = 2_000; // 2ms = 500Hz effective rate
+pub const MOUSE_COALESCE_INTERVAL_US: u64 =
+    1_000_000 / crate::app::config::DEFAULT_MOUSE_SEND_RATE_HZ as u64; // 2ms = 500Hz
+
+/// Active coalescing interval, from the mouse send rate setting
+static MOUSE_COALESCE_INTERVAL: std::sync::atomic::AtomicU64 =
+    std::sync::atomic::AtomicU64::new(MOUSE_COALESCE_INTERVAL_US);
+
+/// Set how often coalesced mouse motion is sent (Hz)
+/// Lower rates send fewer packets on constrained uplinks at the cost of smoothness
+pub fn set_mouse_send_rate(hz: u32) {
+    let interval_us = 1_000_000 / hz.max(1) as u64;
+    MOUSE_COALESCE_INTERVAL.store(interval_us, Ordering::Relaxed);
+    log::info!("Mouse send rate set to {} Hz ({} us)", hz, interval_us);
+}
+
+/// Current mouse coalescing interval in microseconds
+#[inline]
+pub fn mouse_coalesce_interval_us() -> u64 {
+    MOUSE_COALESCE_INTERVAL.load(Ordering::Relaxed)
+}
+
+/// Pointer jump expected from applying a grab, until the next motion event. Applying a
+/// grab can move the pointer (confining it into the window, recentring a locked one),
+/// and that jump shows up as one motion event that the user didn't make.
+/// Packed as `1 << 32 | dx << 16 | dy` (16-bit each); 0 when no jump is expected.
+static GRAB_WARP: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/// Whether the pointer is grabbed with CursorGrabMode::Locked (it can't move at all)
+static CURSOR_LOCKED: AtomicBool = AtomicBool::new(false);
+
+/// Record that the pointer was just grabbed (`locked` = CursorGrabMode::Locked)
+/// and how far (physical px) applying the grab moved it, if it did
+pub fn note_cursor_grabbed(locked: bool, warp: Option<(i32, i32)>) {
+    CURSOR_LOCKED.store(locked, Ordering::Relaxed);
+    let packed = warp.map_or(0, |(dx, dy)| {
+        1 << 32 | (dx as i16 as u16 as u64) << 16 | dy as i16 as u16 as u64
+    });
+    GRAB_WARP.store(packed, Ordering::Relaxed);
+}
+
+/// Record that the pointer grab was released
+pub fn note_cursor_released() {
+    CURSOR_LOCKED.store(false, Ordering::Relaxed);
+}
+
+/// Whether this motion is the pointer jump from applying a grab. Only the first motion
+/// after a grab is checked: a path that doesn't see the jump (raw input reports device
+/// motion, not warps) passes its first event through and clears the expectation
+#[inline]
+pub fn is_grab_warp(dx: i32, dy: i32) -> bool {
+    if GRAB_WARP.load(Ordering::Relaxed) == 0 {
+        return false;
+    }
+    let packed = GRAB_WARP.swap(0, Ordering::Relaxed);
+    if packed == 0 {
+        return false;
+    }
+    let (warp_dx, warp_dy) = ((packed >> 16) as u16 as i16 as i32, packed as u16 as i16 as i32);
+    // The expected jump comes from float positions, so allow a pixel of rounding
+    let is_warp = (dx - warp_dx).abs() <= 1 && (dy - warp_dy).abs() <= 1;
+    if is_warp {
+        log::debug!("Dropped pointer jump from grab ({}, {})", dx, dy);
+    }
+    is_warp
+}
 
 /// Maximum input queue depth before throttling
 /// Official client maintains 4-8 events ahead of consumption
@@ -148,24 +277,28 @@ pub struct MouseCoalescer {
     accumulated_dy: AtomicI32,
     /// Last send timestamp (microseconds since session start)
     last_send_us: std::sync::atomic::AtomicU64,
-    /// Coalescing interval in microseconds
-    coalesce_interval_us: u64,
+    /// Coalescing interval in microseconds (None = follow the mouse send rate setting)
+    coalesce_interval_us: Option<u64>,
     /// Count of coalesced events (for stats)
     coalesced_count: std::sync::atomic::AtomicU64,
 }
 
 impl MouseCoalescer {
     pub fn new() -> Self {
-        Self::with_interval(MOUSE_COALESCE_INTERVAL_US)
+        Self::build(None)
     }
 
     pub fn with_interval(interval_us: u64) -> Self {
+        Self::build(Some(interval_us))
+    }
+
+    fn build(coalesce_interval_us: Option<u64>) -> Self {
         use std::sync::atomic::AtomicU64;
         Self {
             accumulated_dx: AtomicI32::new(0),
             accumulated_dy: AtomicI32::new(0),
             last_send_us: AtomicU64::new(0),
-            coalesce_interval_us: interval_us,
+            coalesce_interval_us,
             coalesced_count: AtomicU64::new(0),
         }
     }
@@ -183,7 +316,10 @@ impl MouseCoalescer {
         let last_us = self.last_send_us.load(Ordering::Acquire);
 
         // Check if enough time has passed since last send
-        if now_us.saturating_sub(last_us) >= self.coalesce_interval_us {
+        let interval_us = self
+            .coalesce_interval_us
+            .unwrap_or_else(mouse_coalesce_interval_us);
+        if now_us.saturating_sub(last_us) >= interval_us {
             self.flush_internal(now_us)
         } else {
             None
@@ -359,6 +495,9 @@ pub struct InputHandler {
     /// Last known cursor position
     last_x: AtomicI32,
     last_y: AtomicI32,
+
+    /// Sub-unit scroll left over from fractional wheel deltas (horizontal, vertical)
+    wheel_remainder: Mutex<(f64, f64)>,
 }
 
 impl InputHandler {
@@ -376,6 +515,7 @@ impl InputHandler {
             accumulated_dy: AtomicI32::new(0),
             last_x: AtomicI32::new(0),
             last_y: AtomicI32::new(0),
+            wheel_remainder: Mutex::new((0.0, 0.0)),
         }
     }
 
@@ -456,11 +596,17 @@ impl InputHandler {
         let last_x = self.last_x.swap(x, Ordering::Relaxed);
         let last_y = self.last_y.swap(y, Ordering::Relaxed);
 
+        // A locked pointer doesn't move, so position changes are the platform warping it
+        // back (recentring); just rebase on them
+        if CURSOR_LOCKED.load(Ordering::Relaxed) {
+            return;
+        }
+
         if last_x != 0 || last_y != 0 {
             let dx = x - last_x;
             let dy = y - last_y;
 
-            if dx != 0 || dy != 0 {
+            if (dx != 0 || dy != 0) && !is_grab_warp(dx, dy) {
                 // Update local cursor for instant feedback
                 self.local_cursor.apply_delta(dx, dy);
 
@@ -479,7 +625,7 @@ impl InputHandler {
     /// Handle raw mouse delta (for captured mode) - WITH COALESCING
     /// This is the primary path for mouse input during streaming
     pub fn handle_mouse_delta(&self, dx: i16, dy: i16) {
-        if dx == 0 && dy == 0 {
+        if (dx == 0 && dy == 0) || is_grab_warp(dx as i32, dy as i32) {
             return;
         }
 
@@ -605,9 +751,25 @@ impl InputHandler {
     }
 
     /// Handle mouse wheel
-    pub fn handle_wheel(&self, delta: i16) {
+    /// Deltas are in WHEEL_DELTA units (120 per notch) and may be fractional; the
+    /// fraction carries over so slow trackpad scrolling isn't lost to truncation.
+    /// Positive horizontal = scroll right, positive vertical = scroll up.
+    pub fn handle_wheel(&self, horizontal: f64, vertical: f64) {
+        let (h, v) = {
+            let mut remainder = self.wheel_remainder.lock();
+            let h = remainder.0 + horizontal;
+            let v = remainder.1 + vertical;
+            let h_whole = h.trunc().clamp(i16::MIN as f64, i16::MAX as f64);
+            let v_whole = v.trunc().clamp(i16::MIN as f64, i16::MAX as f64);
+            *remainder = (h - h_whole, v - v_whole);
+            (h_whole as i16, v_whole as i16)
+        };
+        if h == 0 && v == 0 {
+            return;
+        }
         self.send_event(InputEvent::MouseWheel {
-            delta,
+            horizontal: h,
+            vertical: v,
             timestamp_us: get_timestamp_us(),
         });
     }
@@ -745,3 +907,49 @@ pub fn get_modifiers(modifiers: &winit::keyboard::ModifiersState) -> u16 {
 
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing_at(start: Instant) -> SessionTiming {
+        SessionTiming {
+            start,
+            unix_us: 1_000_000,
+            paused_at: None,
+            paused_total: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_timestamps_freeze_while_paused_and_resume_contiguously() {
+        let start = Instant::now();
+        let at = |ms: u64| start + Duration::from_millis(ms);
+        let mut timing = timing_at(start);
+
+        let mut samples = vec![timing.timestamp_us_at(at(0)), timing.timestamp_us_at(at(10))];
+        timing.pause_at(at(10));
+        samples.push(timing.timestamp_us_at(at(20)));
+        samples.push(timing.timestamp_us_at(at(5_000)));
+        timing.resume_at(at(5_010));
+        samples.push(timing.timestamp_us_at(at(5_010)));
+        samples.push(timing.timestamp_us_at(at(5_015)));
+
+        assert!(samples.windows(2).all(|w| w[0] <= w[1]));
+        // Paused time is skipped: 10ms before the pause + 5ms after it
+        assert_eq!(samples[2], 1_010_000);
+        assert_eq!(samples[4], 1_010_000);
+        assert_eq!(samples[5], 1_015_000);
+    }
+
+    #[test]
+    fn test_reinit_never_starts_behind_previous_timestamps() {
+        let floor = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_micros() as u64
+            + 60_000_000;
+        let timing = SessionTiming::new(floor);
+        assert!(timing.timestamp_us_at(Instant::now()) >= floor);
+    }
+}
@@ -12,7 +12,7 @@ use std::mem::size_of;
 use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU64, Ordering};
 use tokio::sync::mpsc;
 
-use super::{get_timestamp_us, session_elapsed_us, MOUSE_COALESCE_INTERVAL_US};
+use super::{get_timestamp_us, is_grab_warp, mouse_coalesce_interval_us, session_elapsed_us};
 use crate::webrtc::InputEvent;
 
 // Static state
@@ -340,7 +340,9 @@ unsafe extern "system" fn raw_input_wnd_proc(
     match msg {
         WM_INPUT => {
             if RAW_INPUT_ACTIVE.load(Ordering::SeqCst) {
-                if let Some((dx, dy)) = process_raw_input(lparam) {
+                if let Some((dx, dy)) =
+                    process_raw_input(lparam).filter(|&(dx, dy)| !is_grab_warp(dx, dy))
+                {
                     // 1. Update local cursor IMMEDIATELY for instant visual feedback
                     // This happens on every event regardless of coalescing
                     let width = LOCAL_CURSOR_WIDTH.load(Ordering::Acquire);
@@ -359,7 +361,7 @@ unsafe extern "system" fn raw_input_wnd_proc(
                     let now_us = session_elapsed_us();
                     let last_us = COALESCE_LAST_SEND_US.load(Ordering::Acquire);
 
-                    if now_us.saturating_sub(last_us) >= MOUSE_COALESCE_INTERVAL_US {
+                    if now_us.saturating_sub(last_us) >= mouse_coalesce_interval_us() {
                         flush_coalesced_events();
                     }
                 }
@@ -8,6 +8,7 @@ mod api;
 mod app;
 mod auth;
 mod gui;
+mod headless;
 mod input;
 mod media;
 mod profiling;
@@ -21,6 +22,7 @@ pub use profiling::frame_mark;
 use anyhow::Result;
 use log::info;
 use parking_lot::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use winit::application::ApplicationHandler;
 use winit::event::{DeviceEvent, DeviceId, ElementState, KeyEvent, Modifiers, WindowEvent};
@@ -29,9 +31,25 @@ use winit::keyboard::{Key, KeyCode, NamedKey, PhysicalKey};
 use winit::platform::scancode::PhysicalKeyExtScancode;
 use winit::window::WindowId;
 
-use app::{App, AppState, UiAction};
+use app::config::MouseCaptureKey;
+use app::{App, AppState, SettingChange, UiAction};
 use gui::Renderer;
 
+/// Menu redraw interval when "Keep menus responsive" is enabled (10 FPS)
+const MENU_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// Max gap between the two presses of a double-tap mouse capture key
+const DOUBLE_TAP_WINDOW: std::time::Duration = std::time::Duration::from_millis(400);
+
+/// Events sent to the event loop from other threads
+#[derive(Debug, Clone, Copy)]
+enum UserEvent {
+    /// A deep link arrived
+    DeepLink,
+    /// A gamepad was used while in the menus
+    GamepadInput,
+}
+
 /// Application handler for winit 0.30+
 struct OpenNowApp {
     /// Tokio runtime handle
@@ -44,6 +62,90 @@ struct OpenNowApp {
     modifiers: Modifiers,
     /// Track if we were streaming (for cursor lock state changes)
     was_streaming: bool,
+    /// Whether the window currently has keyboard focus
+    window_focused: bool,
+    /// Whether the window is fully hidden (covered, minimized or on another desktop)
+    window_occluded: bool,
+    /// Next low-rate menu redraw (responsive menus, or moving the screensaver logo)
+    next_menu_refresh: std::time::Instant,
+    /// Input-driven menu redraw held back by the menu frame rate cap
+    menu_repaint_at: Option<std::time::Instant>,
+    /// Last redraw, for the menu frame rate cap
+    last_redraw: std::time::Instant,
+    /// Last input while in the menus (starts the screensaver countdown)
+    last_menu_input: std::time::Instant,
+    /// Showing the login or games screen (as of the last `about_to_wait`)
+    in_menus: bool,
+    /// Gamepads are watched for screensaver wake-ups only while this is set
+    menu_gamepads: Arc<AtomicBool>,
+    /// First press of a double-tap mouse capture key, waiting for the second
+    capture_key_tapped_at: Option<std::time::Instant>,
+    /// System sleep / screensaver inhibition, held while streaming
+    keep_awake: Option<keepawake::KeepAwake>,
+}
+
+/// Whether this key press toggles mouse capture
+/// Double-tap bindings remember the first press in `tapped_at`; any other key in between cancels it
+fn is_mouse_capture_toggle(
+    key: MouseCaptureKey,
+    event: &KeyEvent,
+    tapped_at: &mut Option<std::time::Instant>,
+) -> bool {
+    if event.state != ElementState::Pressed || event.repeat {
+        return false;
+    }
+    let code = match key {
+        MouseCaptureKey::F8 => KeyCode::F8,
+        MouseCaptureKey::ScrollLock => KeyCode::ScrollLock,
+        MouseCaptureKey::Pause => KeyCode::Pause,
+        MouseCaptureKey::DoubleTapRightCtrl => KeyCode::ControlRight,
+        MouseCaptureKey::DoubleTapRightAlt => KeyCode::AltRight,
+    };
+    if event.physical_key != PhysicalKey::Code(code) {
+        *tapped_at = None;
+        return false;
+    }
+    if !key.is_double_tap() {
+        return true;
+    }
+
+    let now = std::time::Instant::now();
+    match tapped_at.take() {
+        Some(first) if now.duration_since(first) <= DOUBLE_TAP_WINDOW => true,
+        _ => {
+            *tapped_at = Some(now);
+            false
+        }
+    }
+}
+
+/// Send a key to the remote session (GFN expects Windows VK codes, not scancodes)
+fn send_key(
+    input_handler: &input::InputHandler,
+    physical_key: PhysicalKey,
+    pressed: bool,
+    modifier_flags: u16,
+) {
+    let vk_code = keycode_to_vk(physical_key);
+
+    // Don't include modifier flags when the key itself is a modifier
+    let is_modifier_key = matches!(
+        physical_key,
+        PhysicalKey::Code(KeyCode::ShiftLeft)
+            | PhysicalKey::Code(KeyCode::ShiftRight)
+            | PhysicalKey::Code(KeyCode::ControlLeft)
+            | PhysicalKey::Code(KeyCode::ControlRight)
+            | PhysicalKey::Code(KeyCode::AltLeft)
+            | PhysicalKey::Code(KeyCode::AltRight)
+            | PhysicalKey::Code(KeyCode::SuperLeft)
+            | PhysicalKey::Code(KeyCode::SuperRight)
+    );
+    let modifiers = if is_modifier_key { 0 } else { modifier_flags };
+
+    // Only send if we have a valid VK code
+    if vk_code != 0 {
+        input_handler.handle_key(vk_code, pressed, modifiers);
+    }
 }
 
 /// Convert winit KeyCode to Windows Virtual Key code
@@ -175,6 +277,16 @@ impl OpenNowApp {
             renderer: None,
             modifiers: Modifiers::default(),
             was_streaming: false,
+            window_focused: true,
+            window_occluded: false,
+            next_menu_refresh: std::time::Instant::now(),
+            menu_repaint_at: None,
+            last_redraw: std::time::Instant::now(),
+            last_menu_input: std::time::Instant::now(),
+            in_menus: true,
+            menu_gamepads: Arc::new(AtomicBool::new(false)),
+            capture_key_tapped_at: None,
+            keep_awake: None,
         }
     }
 
@@ -198,14 +310,33 @@ impl OpenNowApp {
     }
 }
 
-impl ApplicationHandler for OpenNowApp {
+impl ApplicationHandler<UserEvent> for OpenNowApp {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         // Create renderer when window is available
         if self.renderer.is_none() {
             info!("Creating renderer...");
             match pollster::block_on(Renderer::new(event_loop)) {
-                Ok(renderer) => {
+                Ok(mut renderer) => {
                     info!("Renderer initialized");
+                    self.app
+                        .lock()
+                        .set_max_texture_dimension(renderer.max_texture_dimension());
+                    self.app
+                        .lock()
+                        .set_gpu_description(renderer.gpu_description());
+                    self.app
+                        .lock()
+                        .set_gpu_adapter_name(renderer.adapter_name());
+                    self.app.lock().run_decoder_check();
+                    if let Some(warning) = renderer.take_gpu_memory_warning() {
+                        self.app.lock().set_gpu_memory_warning(warning);
+                    }
+                    // Restore fullscreen from the last session
+                    renderer.set_fullscreen_mode(self.app.lock().settings.fullscreen_mode);
+                    if self.app.lock().settings.fullscreen {
+                        info!("Entering fullscreen (saved setting)");
+                        renderer.toggle_fullscreen();
+                    }
                     self.renderer = Some(renderer);
                 }
                 Err(e) => {
@@ -219,13 +350,98 @@ impl ApplicationHandler for OpenNowApp {
     fn window_event(
         &mut self,
         event_loop: &ActiveEventLoop,
-        _window_id: WindowId,
+        window_id: WindowId,
         event: WindowEvent,
     ) {
+        // GPU device lost (driver reset / TDR) - rebuild the renderer on the same window
+        if self.renderer.as_ref().is_some_and(|r| r.is_device_lost()) {
+            let lost = self.renderer.take().expect("renderer checked above");
+            match pollster::block_on(lost.recreate()) {
+                Ok(mut renderer) => {
+                    // The stream keeps running independently of the renderer; request a
+                    // keyframe so the decoder resumes with a clean picture on the new device
+                    if self.app.lock().state == AppState::Streaming {
+                        renderer.show_warning_notification("GPU driver reset - stream recovered");
+                        self.runtime.spawn(async {
+                            log::info!("Requesting keyframe after GPU device recovery");
+                            webrtc::request_keyframe().await;
+                        });
+                    }
+                    renderer.window().request_redraw();
+                    self.renderer = Some(renderer);
+                }
+                Err(e) => {
+                    log::error!("Failed to recreate renderer after device loss: {}", e);
+                    event_loop.exit();
+                    return;
+                }
+            }
+        }
+
+        // Pop-out stats window: its events never reach the game or the stream
+        if let Some(renderer) = self.renderer.as_mut() {
+            if renderer.stats_window_id() == Some(window_id) {
+                match event {
+                    WindowEvent::CloseRequested => {
+                        renderer.close_stats_window();
+                        self.app
+                            .lock()
+                            .handle_action(UiAction::UpdateSetting(SettingChange::StatsWindow(
+                                false,
+                            )));
+                    }
+                    WindowEvent::RedrawRequested => {
+                        let app_guard = self.app.lock();
+                        renderer.render_stats_window(&app_guard);
+                    }
+                    // A click on the stats window must not take input away from the stream
+                    WindowEvent::Focused(true) => renderer.window().focus_window(),
+                    event => renderer.handle_stats_window_event(&event),
+                }
+                return;
+            }
+            if renderer.window().id() != window_id {
+                // Late event for a stats window that was already closed
+                return;
+            }
+        }
+
+        // Checked for every key press so double-tap bindings see the keys in between
+        let capture_toggle = match &event {
+            WindowEvent::KeyboardInput { event, .. } => is_mouse_capture_toggle(
+                self.app.lock().settings.mouse_capture_key,
+                event,
+                &mut self.capture_key_tapped_at,
+            ),
+            _ => false,
+        };
+
         let Some(renderer) = self.renderer.as_mut() else {
             return;
         };
 
+        // Any input wakes the menu screensaver; the waking input isn't passed on,
+        // so a click meant to wake the screen can't also press a button
+        if self.in_menus
+            && matches!(
+                event,
+                WindowEvent::MouseInput { .. }
+                    | WindowEvent::MouseWheel { .. }
+                    | WindowEvent::KeyboardInput { .. }
+                    | WindowEvent::CursorMoved { .. }
+                    | WindowEvent::Touch(_)
+            )
+        {
+            self.last_menu_input = std::time::Instant::now();
+            let mut app_guard = self.app.lock();
+            if app_guard.screensaver_active {
+                info!("Menu screensaver off");
+                app_guard.screensaver_active = false;
+                renderer.window().request_redraw();
+                return;
+            }
+        }
+
         // Let egui handle events first
         let response = renderer.handle_event(&event);
 
@@ -234,7 +450,10 @@ impl ApplicationHandler for OpenNowApp {
         // - When in session setup: always repaint (need to show progress updates)
         // - When not streaming: only repaint on actual user interaction events
         //   (egui's request_repaint_after handles timed repaints via ControlFlow)
-        let app_state = self.app.lock().state;
+        let (app_state, menu_fps_cap) = {
+            let app = self.app.lock();
+            (app.state, app.settings.menu_fps_cap)
+        };
         let should_repaint = match app_state {
             AppState::Streaming | AppState::Session => response.repaint,
             _ => {
@@ -246,28 +465,49 @@ impl ApplicationHandler for OpenNowApp {
                         | WindowEvent::KeyboardInput { .. }
                         | WindowEvent::CursorMoved { .. }
                         | WindowEvent::Resized(_)
+                        | WindowEvent::ScaleFactorChanged { .. }
                         | WindowEvent::Focused(_)
                 )
             }
         };
 
         if should_repaint {
-            renderer.window().request_redraw();
+            // Menu redraws from input (a fast mouse or wheel sends hundreds of events per
+            // second) are limited to the menu frame rate cap; one that comes too soon is
+            // held back until the cap allows it instead of being dropped
+            let next_allowed = match (app_state, menu_fps_cap) {
+                (AppState::Streaming | AppState::Session, _) | (_, 0) => None,
+                (_, fps) => Some(self.last_redraw + std::time::Duration::from_secs(1) / fps),
+            };
+            match next_allowed {
+                Some(at) if at > std::time::Instant::now() => {
+                    self.menu_repaint_at.get_or_insert(at);
+                }
+                _ => renderer.window().request_redraw(),
+            }
         }
 
         match event {
             WindowEvent::CloseRequested => {
                 info!("Window close requested");
+                self.app.lock().shutdown();
                 event_loop.exit();
             }
             WindowEvent::Resized(size) => {
                 renderer.resize(size);
-                // Save window size to settings (only when not fullscreen)
-                if !renderer.is_fullscreen() && size.width > 0 && size.height > 0 {
+                // Save window size to settings (only when not fullscreen or maximized)
+                if !renderer.is_fullscreen()
+                    && !renderer.is_maximized()
+                    && size.width > 0
+                    && size.height > 0
+                {
                     let mut app = self.app.lock();
                     app.handle_action(UiAction::UpdateWindowSize(size.width, size.height));
                 }
             }
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                renderer.handle_scale_factor_changed(scale_factor);
+            }
             // Ctrl+Shift+Q to stop streaming (instead of ESC to avoid accidental stops)
             WindowEvent::KeyboardInput {
                 event:
@@ -294,8 +534,12 @@ impl ApplicationHandler for OpenNowApp {
                 ..
             } => {
                 renderer.toggle_fullscreen();
+                // Remember fullscreen for the next launch
+                let mut app = self.app.lock();
+                app.handle_action(UiAction::UpdateSetting(SettingChange::Fullscreen(
+                    renderer.is_fullscreen(),
+                )));
                 // Lock cursor when entering fullscreen during streaming
-                let app = self.app.lock();
                 if app.state == AppState::Streaming {
                     if renderer.is_fullscreen() {
                         renderer.lock_cursor();
@@ -316,48 +560,74 @@ impl ApplicationHandler for OpenNowApp {
                 let mut app = self.app.lock();
                 app.toggle_stats();
             }
-            // Ctrl+Shift+F10 to toggle anti-AFK mode
+            // Ctrl+Shift+F9 to start/stop recording stats to CSV
             WindowEvent::KeyboardInput {
                 event:
                     KeyEvent {
-                        physical_key: PhysicalKey::Code(KeyCode::F10),
+                        physical_key: PhysicalKey::Code(KeyCode::F9),
                         state: ElementState::Pressed,
+                        repeat: false,
                         ..
                     },
                 ..
             } if self.modifiers.state().control_key() && self.modifiers.state().shift_key() => {
                 let mut app = self.app.lock();
                 if app.state == AppState::Streaming {
-                    app.toggle_anti_afk();
+                    let message = app.toggle_stats_log();
+                    renderer.show_warning_notification(&message);
                 }
             }
-            // F8 to toggle mouse lock during streaming (for windowed mode)
+            // Ctrl+Shift+F10 to toggle anti-AFK mode
             WindowEvent::KeyboardInput {
                 event:
                     KeyEvent {
-                        logical_key: Key::Named(NamedKey::F8),
+                        physical_key: PhysicalKey::Code(KeyCode::F10),
                         state: ElementState::Pressed,
                         ..
                     },
                 ..
-            } => {
+            } if self.modifiers.state().control_key() && self.modifiers.state().shift_key() => {
                 let mut app = self.app.lock();
                 if app.state == AppState::Streaming {
+                    app.toggle_anti_afk();
+                }
+            }
+            // Mouse capture key (F8 by default) to toggle mouse lock during streaming (for windowed mode)
+            WindowEvent::KeyboardInput { event, .. } if capture_toggle => {
+                let mut app = self.app.lock();
+                if app.state == AppState::Streaming {
+                    // Double-tap keys (Right Ctrl/Alt, modifiers so no flags) still reach the
+                    // game. Releasing capture: send the whole tap now, since the key-up
+                    // won't be forwarded anymore
+                    let double_tap = app.settings.mouse_capture_key.is_double_tap();
+                    if double_tap && app.cursor_captured {
+                        if let Some(ref input_handler) = app.input_handler {
+                            send_key(input_handler, event.physical_key, true, 0);
+                            send_key(input_handler, event.physical_key, false, 0);
+                        }
+                    }
+
                     // Toggle cursor capture state
                     app.cursor_captured = !app.cursor_captured;
 
                     if app.cursor_captured {
                         renderer.lock_cursor();
                         // Resume raw input when locking
-                        #[cfg(any(target_os = "windows", target_os = "macos"))]
+                        #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
                         input::resume_raw_input();
-                        info!("F8: Mouse locked");
+                        info!("Mouse capture key: Mouse locked");
+                        // Capturing: the key-up is forwarded as usual, so send the press
+                        if double_tap {
+                            if let Some(ref input_handler) = app.input_handler {
+                                send_key(input_handler, event.physical_key, true, 0);
+                            }
+                        }
                     } else {
                         renderer.unlock_cursor();
                         // Pause raw input when unlocking
-                        #[cfg(any(target_os = "windows", target_os = "macos"))]
+                        #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
                         input::pause_raw_input();
-                        info!("F8: Mouse unlocked");
+                        info!("Mouse capture key: Mouse unlocked");
                     }
                 }
             }
@@ -395,36 +665,18 @@ impl ApplicationHandler for OpenNowApp {
                     }
 
                     if let Some(ref input_handler) = app.input_handler {
-                        // Convert to Windows VK code (GFN expects VK codes, not scancodes)
-                        let vk_code = keycode_to_vk(event.physical_key);
                         let pressed = event.state == ElementState::Pressed;
-
-                        // Don't include modifier flags when the key itself is a modifier
-                        let is_modifier_key = matches!(
+                        send_key(
+                            input_handler,
                             event.physical_key,
-                            PhysicalKey::Code(KeyCode::ShiftLeft)
-                                | PhysicalKey::Code(KeyCode::ShiftRight)
-                                | PhysicalKey::Code(KeyCode::ControlLeft)
-                                | PhysicalKey::Code(KeyCode::ControlRight)
-                                | PhysicalKey::Code(KeyCode::AltLeft)
-                                | PhysicalKey::Code(KeyCode::AltRight)
-                                | PhysicalKey::Code(KeyCode::SuperLeft)
-                                | PhysicalKey::Code(KeyCode::SuperRight)
+                            pressed,
+                            self.get_modifier_flags(),
                         );
-                        let modifiers = if is_modifier_key {
-                            0
-                        } else {
-                            self.get_modifier_flags()
-                        };
-
-                        // Only send if we have a valid VK code
-                        if vk_code != 0 {
-                            input_handler.handle_key(vk_code, pressed, modifiers);
-                        }
                     }
                 }
             }
             WindowEvent::Focused(focused) => {
+                self.window_focused = focused;
                 let mut app = self.app.lock();
                 if app.state == AppState::Streaming {
                     if !focused {
@@ -434,15 +686,21 @@ impl ApplicationHandler for OpenNowApp {
                             input_handler.release_all_keys();
                         }
                         // Pause raw input while unfocused
-                        #[cfg(any(target_os = "windows", target_os = "macos"))]
+                        #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
                         input::pause_raw_input();
+                        input::pause_session_timing();
+                        if app.settings.mute_on_focus_loss {
+                            media::set_audio_muted(true);
+                        }
                     } else {
+                        media::set_audio_muted(false);
+                        input::resume_session_timing();
                         // Regained focus - re-lock cursor if it was captured
                         if app.cursor_captured {
                             log::info!("Window regained focus - re-locking cursor");
                             renderer.lock_cursor();
                             // Resume raw input
-                            #[cfg(any(target_os = "windows", target_os = "macos"))]
+                            #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
                             input::resume_raw_input();
 
                             // Request keyframe to recover video stream after focus loss
@@ -460,17 +718,32 @@ impl ApplicationHandler for OpenNowApp {
                 let app = self.app.lock();
                 if app.state == AppState::Streaming {
                     if let Some(ref input_handler) = app.input_handler {
-                        let wheel_delta = match delta {
-                            winit::event::MouseScrollDelta::LineDelta(_, y) => (y * 120.0) as i16,
-                            winit::event::MouseScrollDelta::PixelDelta(pos) => pos.y as i16,
+                        // winit's positive x moves content right (= scrolling left),
+                        // the server expects positive = scroll right like WM_MOUSEHWHEEL
+                        let (horizontal, vertical) = match delta {
+                            winit::event::MouseScrollDelta::LineDelta(x, y) => {
+                                (-x as f64 * 120.0, y as f64 * 120.0)
+                            }
+                            winit::event::MouseScrollDelta::PixelDelta(pos) => (-pos.x, pos.y),
                         };
-                        input_handler.handle_wheel(wheel_delta);
+                        input_handler.handle_wheel(horizontal, vertical);
                     }
                 }
             }
+            WindowEvent::Occluded(occluded) => {
+                self.window_occluded = occluded;
+                if occluded {
+                    log::debug!("Window occluded - pausing rendering");
+                } else {
+                    log::debug!("Window visible again - resuming rendering");
+                    renderer.window().request_redraw();
+                }
+            }
             WindowEvent::RedrawRequested => {
                 // Mark frame for Tracy profiler (if enabled)
                 profiling::frame_mark();
+                self.last_redraw = std::time::Instant::now();
+                self.menu_repaint_at = None;
 
                 let mut app_guard = self.app.lock();
                 let is_streaming = app_guard.state == AppState::Streaming;
@@ -479,11 +752,32 @@ impl ApplicationHandler for OpenNowApp {
                 if is_streaming && !self.was_streaming {
                     // Just started streaming - lock cursor, start raw input, disable vsync
                     renderer.lock_cursor();
-                    renderer.set_vsync(false); // Immediate mode for lowest latency
+                    // Immediate mode for lowest latency (unless overridden in settings)
+                    renderer.set_vsync(false, app_guard.settings.present_mode);
                     self.was_streaming = true;
 
-                    // Start Raw Input for unaccelerated mouse movement (Windows/macOS)
-                    #[cfg(any(target_os = "windows", target_os = "macos"))]
+                    // Keep the system and display awake - controller input goes to the
+                    // remote session and never resets the OS idle timer
+                    self.keep_awake = match keepawake::Builder::default()
+                        .display(true)
+                        .idle(true)
+                        .reason("Streaming a game")
+                        .app_name("OpenNOW")
+                        .app_reverse_domain("io.github.zortos293.opennow")
+                        .create()
+                    {
+                        Ok(handle) => {
+                            info!("System sleep inhibited while streaming");
+                            Some(handle)
+                        }
+                        Err(e) => {
+                            log::warn!("Failed to inhibit system sleep: {:?}", e);
+                            None
+                        }
+                    };
+
+                    // Start Raw Input for unaccelerated mouse movement (Windows/macOS/Linux)
+                    #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
                     {
                         match input::start_raw_input() {
                             Ok(()) => info!("Raw input enabled - mouse acceleration disabled"),
@@ -496,11 +790,18 @@ impl ApplicationHandler for OpenNowApp {
                 } else if !is_streaming && self.was_streaming {
                     // Just stopped streaming - unlock cursor, stop raw input, enable vsync
                     renderer.unlock_cursor();
-                    renderer.set_vsync(true); // VSync for low CPU usage in UI
+                    // VSync for low CPU usage in UI
+                    renderer.set_vsync(true, app_guard.settings.present_mode);
                     self.was_streaming = false;
+                    // Don't carry a focus-loss mute into the next session
+                    media::set_audio_muted(false);
+                    // Allow the system to sleep again
+                    if self.keep_awake.take().is_some() {
+                        info!("System sleep inhibition released");
+                    }
 
                     // Stop raw input
-                    #[cfg(any(target_os = "windows", target_os = "macos"))]
+                    #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
                     {
                         input::stop_raw_input();
                     }
@@ -561,6 +862,30 @@ impl ApplicationHandler for OpenNowApp {
         }
     }
 
+    fn user_event(&mut self, _event_loop: &ActiveEventLoop, event: UserEvent) {
+        let Some(renderer) = self.renderer.as_ref() else {
+            return;
+        };
+        match event {
+            UserEvent::DeepLink => {
+                // Bring the window up so the update loop picks the link up
+                renderer.window().set_minimized(false);
+                renderer.window().focus_window();
+                renderer.window().request_redraw();
+            }
+            UserEvent::GamepadInput => {
+                // Only sent from the menus: restart the screensaver countdown, or wake it
+                self.last_menu_input = std::time::Instant::now();
+                let mut app_guard = self.app.lock();
+                if app_guard.screensaver_active {
+                    info!("Menu screensaver off (gamepad)");
+                    app_guard.screensaver_active = false;
+                    renderer.window().request_redraw();
+                }
+            }
+        }
+    }
+
     fn device_event(
         &mut self,
         _event_loop: &ActiveEventLoop,
@@ -568,7 +893,7 @@ impl ApplicationHandler for OpenNowApp {
         event: DeviceEvent,
     ) {
         // Only use winit's MouseMotion as fallback when raw input is not active
-        #[cfg(any(target_os = "windows", target_os = "macos"))]
+        #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
         if input::is_raw_input_active() {
             return; // Raw input handles mouse movement
         }
@@ -584,24 +909,80 @@ impl ApplicationHandler for OpenNowApp {
     }
 
     fn about_to_wait(&mut self, _event_loop: &ActiveEventLoop) {
-        let Some(ref renderer) = self.renderer else {
+        let Some(ref mut renderer) = self.renderer else {
             return;
         };
 
-        let app_guard = self.app.lock();
+        let mut app_guard = self.app.lock();
         let app_state = app_guard.state;
+
+        // The pop-out stats window exists only while streaming
+        if app_state == AppState::Streaming && app_guard.settings.stats_window {
+            if renderer.open_stats_window(_event_loop) {
+                renderer.request_stats_window_redraw(&app_guard.settings);
+            } else {
+                // Don't retry every frame - back to the overlay
+                app_guard.handle_action(UiAction::UpdateSetting(SettingChange::StatsWindow(false)));
+            }
+        } else {
+            renderer.close_stats_window();
+        }
+        renderer.set_fullscreen_mode(app_guard.settings.fullscreen_mode);
+        let keep_streaming_unfocused = app_guard.settings.keep_streaming_unfocused;
+        let responsive_menus = app_guard.settings.responsive_menus;
         // Check if there's a new frame from the decoder before requesting redraw
         // This prevents rendering faster than decode rate, saving GPU cycles
-        let has_new_frame = app_guard
-            .shared_frame
-            .as_ref()
-            .map(|sf| sf.has_new_frame())
-            .unwrap_or(false);
+        // (with frame pacing, a new frame only counts once its presentation time is reached)
+        let has_new_frame = app_guard.video_frame_due();
+
+        if self.window_occluded {
+            // Nothing is visible - skip rendering entirely until Occluded(false) wakes us.
+            // The stream stays connected (frames are decoded but not presented); keep app
+            // timers like session polling and the idle timeout running once per second.
+            if matches!(app_state, AppState::Streaming | AppState::Session) {
+                app_guard.update();
+                let wake_time = std::time::Instant::now() + std::time::Duration::from_secs(1);
+                _event_loop.set_control_flow(ControlFlow::WaitUntil(wake_time));
+            } else {
+                _event_loop.set_control_flow(ControlFlow::Wait);
+            }
+            return;
+        }
+
+        // Menu screensaver: only counts down while in the menus
+        let now = std::time::Instant::now();
+        let in_menus = matches!(app_state, AppState::Login | AppState::Games);
+        if !in_menus {
+            self.last_menu_input = now;
+            app_guard.screensaver_active = false;
+        }
+        let screensaver_minutes = app_guard.settings.menu_screensaver_minutes;
+        let screensaver_at = (in_menus && screensaver_minutes > 0).then(|| {
+            self.last_menu_input + std::time::Duration::from_secs(screensaver_minutes as u64 * 60)
+        });
+        let screensaver_due = screensaver_at.is_some_and(|at| now >= at);
+        self.in_menus = in_menus;
+        self.menu_gamepads.store(screensaver_at.is_some(), Ordering::Relaxed);
+        if screensaver_due && !app_guard.screensaver_active {
+            info!("Menu screensaver on after {} min without input", screensaver_minutes);
+            app_guard.screensaver_active = true;
+            self.next_menu_refresh = now;
+        }
         drop(app_guard);
 
         // Dynamically switch control flow based on app state
         // Poll during streaming for lowest latency, Wait for menus to save CPU
         match app_state {
+            AppState::Streaming if !self.window_focused && !keep_streaming_unfocused => {
+                // Background streaming disabled - stop presenting until focus returns
+                // (the stream itself keeps running, the Focused event wakes us up).
+                // Still update once per second so app timers like the idle timeout run.
+                let wake_time = std::time::Instant::now() + std::time::Duration::from_secs(1);
+                _event_loop.set_control_flow(ControlFlow::WaitUntil(wake_time));
+                if has_new_frame {
+                    renderer.window().request_redraw();
+                }
+            }
             AppState::Streaming => {
                 _event_loop.set_control_flow(ControlFlow::Poll);
                 // Only request redraw when decoder has produced a new frame
@@ -617,8 +998,39 @@ impl ApplicationHandler for OpenNowApp {
                 _event_loop.set_control_flow(ControlFlow::WaitUntil(wake_time));
                 renderer.window().request_redraw();
             }
+            _ if screensaver_due => {
+                // Screensaver on: redraw only to move the logo
+                if now >= self.next_menu_refresh {
+                    self.next_menu_refresh = now + gui::screens::SCREENSAVER_MOVE_INTERVAL;
+                    renderer.window().request_redraw();
+                }
+                _event_loop.set_control_flow(ControlFlow::WaitUntil(self.next_menu_refresh));
+            }
+            _ if in_menus && self.menu_repaint_at.is_some() => {
+                // Input redraw held back by the menu frame rate cap
+                if let Some(at) = self.menu_repaint_at {
+                    if now >= at {
+                        self.menu_repaint_at = None;
+                        renderer.window().request_redraw();
+                    }
+                    _event_loop.set_control_flow(ControlFlow::WaitUntil(at));
+                }
+            }
+            _ if responsive_menus => {
+                // Opted into responsiveness over idle CPU: keep menus ticking at a low
+                // rate so the first input after a long idle doesn't wait on a cold frame
+                if now >= self.next_menu_refresh {
+                    self.next_menu_refresh = now + MENU_REFRESH_INTERVAL;
+                    renderer.window().request_redraw();
+                }
+                _event_loop.set_control_flow(ControlFlow::WaitUntil(self.next_menu_refresh));
+            }
             _ => {
-                _event_loop.set_control_flow(ControlFlow::Wait);
+                // Only wake up for the screensaver when it's enabled
+                match screensaver_at {
+                    Some(at) => _event_loop.set_control_flow(ControlFlow::WaitUntil(at)),
+                    None => _event_loop.set_control_flow(ControlFlow::Wait),
+                }
                 // When not streaming, rely entirely on event-driven redraws
                 // ControlFlow::Wait will block until an event arrives
                 // This reduces CPU usage from 100% to <5% when idle
@@ -633,6 +1045,9 @@ fn main() -> Result<()> {
     // Returns true if it initialized logging (we should skip env_logger)
     let profiling_initialized_logging = profiling::init();
 
+    // Write a crash report (backtrace, system info, log tail) if anything panics
+    utils::install_panic_hook();
+
     // Initialize logging (only if profiling didn't already set it up)
     if !profiling_initialized_logging {
         env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
@@ -644,13 +1059,59 @@ fn main() -> Result<()> {
     #[cfg(feature = "tracy")]
     info!("Tracy profiler ENABLED - connect with Tracy Profiler application");
 
+    // --safe-mode: software decode + basic render path for GPU/driver troubleshooting
+    if std::env::args().any(|arg| arg == "--safe-mode") {
+        app::config::set_safe_mode_override(true);
+    }
+    if app::Settings::load().unwrap_or_default().safe_mode_active() {
+        log::warn!("SAFE MODE active: software decoding, no zero-copy or external textures");
+        utils::console_print("[SAFE MODE] Software decoding and basic rendering enabled");
+    }
+
+    // --mock: serve games, subscription and sessions from bundled data (no account needed)
+    if std::env::args().any(|arg| arg == "--mock") {
+        api::mock::set_mock_mode(true);
+        log::warn!("MOCK MODE active: API responses come from bundled test data");
+        utils::console_print("[MOCK MODE] Using bundled test data instead of the GFN API");
+    }
+
     // Create tokio runtime for async operations
     let runtime = tokio::runtime::Builder::new_multi_thread()
         .enable_all()
         .build()?;
 
+    // --headless: stream a game without a window (driven by the command line only)
+    if let Some(options) = headless::HeadlessOptions::from_args(std::env::args())? {
+        return headless::run(runtime.handle().clone(), options);
+    }
+
+    // opennow://launch/<app_id> - start a game straight from a link or shortcut.
+    // The OS starts a new copy for every clicked link; if OpenNOW is already
+    // running, hand the link over and quit instead of opening a second window
+    if let Some(url) = utils::deep_link_arg() {
+        if utils::forward_to_running_instance(&url) {
+            info!("Passed {} to the running OpenNOW", url);
+            return Ok(());
+        }
+        utils::handle_deep_link(&url);
+    }
+    utils::listen_for_forwarded_links();
+
     // Create event loop
-    let event_loop = EventLoop::new()?;
+    let event_loop = EventLoop::<UserEvent>::with_user_event().build()?;
+
+    // Deep links arriving later wake the (possibly idle) event loop
+    let proxy = event_loop.create_proxy();
+    utils::set_deep_link_waker(move || {
+        let _ = proxy.send_event(UserEvent::DeepLink);
+    });
+
+    // Register the opennow:// URL scheme (macOS: also installs the URL event handler,
+    // which has to happen before the event loop starts)
+    #[cfg(target_os = "macos")]
+    utils::register_url_scheme();
+    #[cfg(not(target_os = "macos"))]
+    std::thread::spawn(utils::register_url_scheme);
     // Use Wait by default for low CPU usage in menus
     // Dynamically switch to Poll during active streaming for lowest latency
     event_loop.set_control_flow(ControlFlow::Wait);
@@ -658,6 +1119,12 @@ fn main() -> Result<()> {
     // Create application handler
     let mut app = OpenNowApp::new(runtime.handle().clone());
 
+    // Gamepads in the menus wake the screensaver too
+    let proxy = event_loop.create_proxy();
+    input::controller::watch_menu_gamepads(app.menu_gamepads.clone(), move || {
+        let _ = proxy.send_event(UserEvent::GamepadInput);
+    });
+
     // Run event loop with application handler
     event_loop.run_app(&mut app)?;
 
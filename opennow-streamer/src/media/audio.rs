@@ -14,6 +14,8 @@ use std::sync::mpsc;
 use std::sync::Arc;
 use std::thread;
 
+use crate::app::config::ResamplerQuality;
+
 // ============================================================================
 // macOS: FFmpeg-based Opus decoder
 // ============================================================================
@@ -558,19 +560,39 @@ pub struct AudioPlayer {
     resampler: Arc<Mutex<AudioResampler>>,
 }
 
-/// High-quality audio resampler using Catmull-Rom spline interpolation
-/// This provides much better quality than linear interpolation, especially for 2x upsampling
+/// Audio resampler with selectable interpolation (linear, Catmull-Rom spline or windowed sinc)
+/// Input is carried over between packets so every interpolation point has real neighbors
+/// (costs a few samples of latency: 1 for Balanced, 8 for High - under 0.2 ms at 48 kHz)
 /// Also handles channel upmixing (e.g., stereo to 7.1 surround)
 struct AudioResampler {
     input_rate: u32,
     output_rate: u32,
     input_channels: u32,
     output_channels: u32,
-    /// Fractional sample position for interpolation
-    phase: f64,
-    /// History buffer for 4-point interpolation (per input channel)
-    /// Stores [s_minus1, s0, s1, s2] for each channel
-    history: Vec<[i16; 4]>,
+    quality: ResamplerQuality,
+    /// Stereo input frames not yet fully consumed, starting with the history the
+    /// interpolator looks back on
+    pending: Vec<[f32; 2]>,
+    /// Position of the next output sample in `pending` (in input frames)
+    position: f64,
+    /// Windowed sinc weights per fractional phase (High quality only)
+    sinc_table: Vec<[f32; SINC_TAPS]>,
+}
+
+/// Taps of the High quality sinc filter (half before, half after the output point)
+const SINC_TAPS: usize = 16;
+
+/// Fractional positions the sinc filter is precomputed for
+const SINC_PHASES: usize = 1024;
+
+/// Output muted (e.g. window unfocused) - playback keeps draining the buffer so latency doesn't build up
+static AUDIO_MUTED: AtomicBool = AtomicBool::new(false);
+
+/// Mute or unmute audio output for all players
+pub fn set_audio_muted(muted: bool) {
+    if AUDIO_MUTED.swap(muted, Ordering::Relaxed) != muted {
+        info!("Audio {}", if muted { "muted" } else { "unmuted" });
+    }
 }
 
 /// Lock-free ring buffer for audio samples
@@ -632,12 +654,13 @@ impl AudioRingBuffer {
         let samples = self.samples.lock();
         let write_pos = self.write_pos.load(Ordering::Acquire);
         let mut read_pos = self.read_pos.load(Ordering::Acquire);
+        let muted = AUDIO_MUTED.load(Ordering::Relaxed);
 
         for sample in out.iter_mut() {
             if read_pos == write_pos {
                 *sample = 0; // Underrun - output silence
             } else {
-                *sample = samples[read_pos];
+                *sample = if muted { 0 } else { samples[read_pos] };
                 read_pos = (read_pos + 1) % self.capacity;
             }
         }
@@ -647,179 +670,226 @@ impl AudioRingBuffer {
 }
 
 impl AudioResampler {
-    fn new(input_rate: u32, output_rate: u32, input_channels: u32, output_channels: u32) -> Self {
+    fn new(
+        input_rate: u32,
+        output_rate: u32,
+        input_channels: u32,
+        output_channels: u32,
+        quality: ResamplerQuality,
+    ) -> Self {
         info!(
-            "Audio resampler: {}Hz {}ch -> {}Hz {}ch",
-            input_rate, input_channels, output_rate, output_channels
+            "Audio resampler: {}Hz {}ch -> {}Hz {}ch ({:?} quality)",
+            input_rate, input_channels, output_rate, output_channels, quality
         );
-        Self {
+        let mut resampler = Self {
             input_rate,
             output_rate,
             input_channels,
             output_channels,
-            phase: 0.0,
-            // Initialize history with zeros for each input channel
-            history: vec![[0i16; 4]; input_channels as usize],
-        }
+            quality,
+            pending: Vec::new(),
+            position: 0.0,
+            sinc_table: Vec::new(),
+        };
+        resampler.reset();
+        resampler
     }
 
-    /// Resample audio using Catmull-Rom spline interpolation (4-point)
-    /// This provides much better quality than linear interpolation
-    /// The Catmull-Rom spline passes through all control points and provides
-    /// smooth C1 continuous curves, ideal for audio resampling
-    /// Also handles channel upmixing (stereo -> multi-channel)
-    fn resample(&mut self, input: &[i16]) -> Vec<i16> {
-        let in_ch = self.input_channels as usize;
-        let out_ch = self.output_channels as usize;
-        let input_frames = input.len() / in_ch;
-
-        if input_frames == 0 {
-            return Vec::new();
+    /// Input frames the interpolator reads (before, after) the output position
+    fn reach(&self) -> (usize, usize) {
+        match self.quality {
+            ResamplerQuality::Fast => (0, 1),
+            ResamplerQuality::Balanced => (1, 2),
+            ResamplerQuality::High => (SINC_TAPS / 2 - 1, SINC_TAPS / 2),
         }
+    }
 
-        // Calculate output frame count based on sample rate ratio
-        let ratio = self.input_rate as f64 / self.output_rate as f64;
-        let output_frames = if self.input_rate == self.output_rate {
-            input_frames
+    /// Clear carried-over input and rebuild the filter for the current rates
+    fn reset(&mut self) {
+        let (before, _) = self.reach();
+        self.pending = vec![[0.0; 2]; before];
+        self.position = before as f64;
+        self.sinc_table = if self.quality == ResamplerQuality::High {
+            Self::build_sinc_table(self.input_rate, self.output_rate)
         } else {
-            ((input_frames as f64) / ratio).ceil() as usize
+            Vec::new()
         };
+    }
 
-        let mut output = Vec::with_capacity(output_frames * out_ch);
+    /// Blackman-windowed sinc weights, low-passed below the output Nyquist when downsampling
+    fn build_sinc_table(input_rate: u32, output_rate: u32) -> Vec<[f32; SINC_TAPS]> {
+        use std::f64::consts::PI;
+
+        let cutoff = (output_rate as f64 / input_rate as f64).min(1.0);
+        let half_width = (SINC_TAPS / 2) as f64;
+        (0..=SINC_PHASES)
+            .map(|phase| {
+                let frac = phase as f64 / SINC_PHASES as f64;
+                let mut weights = [0f64; SINC_TAPS];
+                for (tap, weight) in weights.iter_mut().enumerate() {
+                    // Distance from the output point to this input sample
+                    let x = tap as f64 - (half_width - 1.0) - frac;
+                    let sinc = if x.abs() < 1e-9 {
+                        1.0
+                    } else {
+                        (PI * cutoff * x).sin() / (PI * cutoff * x)
+                    };
+                    let window = if x.abs() >= half_width {
+                        0.0
+                    } else {
+                        0.42 + 0.5 * (PI * x / half_width).cos()
+                            + 0.08 * (2.0 * PI * x / half_width).cos()
+                    };
+                    *weight = sinc * window;
+                }
+                // Normalize so a constant signal passes unchanged
+                let sum: f64 = weights.iter().sum();
+                let mut row = [0f32; SINC_TAPS];
+                for (out, weight) in row.iter_mut().zip(weights) {
+                    *out = (weight / sum) as f32;
+                }
+                row
+            })
+            .collect()
+    }
 
-        // Ensure history is properly sized for input channels
-        if self.history.len() != in_ch {
-            self.history = vec![[0i16; 4]; in_ch];
+    /// Interpolated stereo frame at `position` in `pending`
+    fn interpolate(&self, position: f64) -> [i16; 2] {
+        let index = position as usize;
+        let frac = position - index as f64;
+        let mut frame = [0i16; 2];
+        for (ch, out) in frame.iter_mut().enumerate() {
+            let s = |offset: isize| self.pending[(index as isize + offset) as usize][ch] as f64;
+            let value = match self.quality {
+                ResamplerQuality::Fast => s(0) + (s(1) - s(0)) * frac,
+                ResamplerQuality::Balanced => {
+                    // Catmull-Rom spline: passes through every sample with a smooth slope
+                    let (s0, s1, s2, s3) = (s(-1), s(0), s(1), s(2));
+                    let t = frac;
+                    let t2 = t * t;
+                    let t3 = t2 * t;
+                    0.5 * ((2.0 * s1)
+                        + (-s0 + s2) * t
+                        + (2.0 * s0 - 5.0 * s1 + 4.0 * s2 - s3) * t2
+                        + (-s0 + 3.0 * s1 - 3.0 * s2 + s3) * t3)
+                }
+                ResamplerQuality::High => {
+                    let weights = &self.sinc_table[(frac * SINC_PHASES as f64).round() as usize];
+                    let first = index + 1 - SINC_TAPS / 2;
+                    weights
+                        .iter()
+                        .zip(&self.pending[first..first + SINC_TAPS])
+                        .map(|(w, sample)| *w as f64 * sample[ch] as f64)
+                        .sum()
+                }
+            };
+            *out = value.clamp(-32768.0, 32767.0) as i16;
         }
+        frame
+    }
 
-        for _ in 0..output_frames {
-            let input_idx = self.phase as usize;
-            let frac = self.phase - input_idx as f64;
-
-            // First, get the resampled stereo frame
-            let mut stereo_frame = [0i16; 2];
-
-            for ch in 0..in_ch.min(2) {
-                // Get 4 samples for Catmull-Rom interpolation: s[-1], s[0], s[1], s[2]
-                let get_sample = |frame_idx: isize| -> i16 {
-                    if frame_idx < 0 {
-                        // Use history for samples before current buffer
-                        let hist_idx = (4 + frame_idx) as usize;
-                        self.history[ch][hist_idx.min(3)]
-                    } else if (frame_idx as usize) < input_frames {
-                        input[frame_idx as usize * in_ch + ch]
-                    } else {
-                        // Clamp to last sample
-                        if input_frames > 0 {
-                            input[(input_frames - 1) * in_ch + ch]
-                        } else {
-                            self.history[ch][3] // Use last known sample
-                        }
-                    }
-                };
+    /// Resample to the output rate and upmix to the output channel count
+    fn resample(&mut self, input: &[i16]) -> Vec<i16> {
+        let in_ch = self.input_channels as usize;
+        let input_frames = input.len() / in_ch.max(1);
 
-                let s0 = get_sample(input_idx as isize - 1) as f64;
-                let s1 = get_sample(input_idx as isize) as f64;
-                let s2 = get_sample(input_idx as isize + 1) as f64;
-                let s3 = get_sample(input_idx as isize + 2) as f64;
+        if input_frames == 0 {
+            return Vec::new();
+        }
 
-                // Catmull-Rom spline interpolation formula
-                // This provides C1 continuity (smooth first derivative)
-                let t = frac;
-                let t2 = t * t;
-                let t3 = t2 * t;
+        // First two input channels (a mono input leaves the right side silent)
+        let stereo = |frame: &[i16]| [frame[0], if in_ch > 1 { frame[1] } else { 0 }];
 
-                let interpolated = 0.5
-                    * ((2.0 * s1)
-                        + (-s0 + s2) * t
-                        + (2.0 * s0 - 5.0 * s1 + 4.0 * s2 - s3) * t2
-                        + (-s0 + 3.0 * s1 - 3.0 * s2 + s3) * t3);
+        let ratio = self.input_rate as f64 / self.output_rate as f64;
+        let mut output = Vec::with_capacity(
+            ((input_frames as f64 / ratio) as usize + 1) * self.output_channels as usize,
+        );
 
-                stereo_frame[ch] = interpolated.clamp(-32768.0, 32767.0) as i16;
+        // Same rate: nothing to interpolate, no added latency
+        if self.input_rate == self.output_rate {
+            for frame in input.chunks_exact(in_ch) {
+                self.upmix(stereo(frame), &mut output);
             }
+            return output;
+        }
 
-            // Now upmix stereo to output channel count
-            // Standard channel mapping for common configurations:
-            // 2ch: FL, FR
-            // 6ch (5.1): FL, FR, FC, LFE, BL, BR
-            // 8ch (7.1): FL, FR, FC, LFE, BL, BR, SL, SR
-            match out_ch {
-                1 => {
-                    // Mono: mix L+R
-                    let mono = ((stereo_frame[0] as i32 + stereo_frame[1] as i32) / 2) as i16;
-                    output.push(mono);
-                }
-                2 => {
-                    // Stereo: swap L/R channels (GFN sends them inverted)
-                    output.push(stereo_frame[1]); // Right -> Left
-                    output.push(stereo_frame[0]); // Left -> Right
-                }
-                _ => {
-                    // Multi-channel (5.1, 7.1, etc.)
-                    // Standard layout: FL, FR, FC, LFE, BL, BR, [SL, SR for 7.1+]
-                    // Swap L/R channels (GFN sends them inverted)
-                    let left = stereo_frame[1];
-                    let right = stereo_frame[0];
-
-                    for ch_idx in 0..out_ch {
-                        let sample = match ch_idx {
-                            0 => left,  // Front Left
-                            1 => right, // Front Right
-                            2 => {
-                                // Center - mix of L+R at reduced level
-                                ((left as i32 + right as i32) / 3) as i16
-                            }
-                            3 => 0, // LFE - no bass routing for now
-                            4 => {
-                                // Back/Rear Left - copy of front left at reduced level
-                                (left as i32 * 2 / 3) as i16
-                            }
-                            5 => {
-                                // Back/Rear Right - copy of front right at reduced level
-                                (right as i32 * 2 / 3) as i16
-                            }
-                            6 => {
-                                // Side Left (7.1) - copy of front left at reduced level
-                                (left as i32 / 2) as i16
-                            }
-                            7 => {
-                                // Side Right (7.1) - copy of front right at reduced level
-                                (right as i32 / 2) as i16
-                            }
-                            _ => 0, // Any additional channels: silence
-                        };
-                        output.push(sample);
-                    }
-                }
-            }
+        self.pending.extend(
+            input
+                .chunks_exact(in_ch)
+                .map(|frame| stereo(frame).map(|sample| sample as f32)),
+        );
 
-            self.phase += ratio;
+        let (before, after) = self.reach();
+        while self.position as usize + after < self.pending.len() {
+            let frame = self.interpolate(self.position);
+            self.upmix(frame, &mut output);
+            self.position += ratio;
         }
 
-        // Update history with last 4 samples from this buffer for next call
-        if input_frames >= 4 {
-            for ch in 0..in_ch {
-                for i in 0..4 {
-                    self.history[ch][i] = input[(input_frames - 4 + i) * in_ch + ch];
-                }
+        // Drop input no later output can reach, keeping the history the next packet needs
+        let consumed = (self.position as usize).saturating_sub(before);
+        self.pending.drain(..consumed);
+        self.position -= consumed as f64;
+
+        output
+    }
+
+    /// Append one stereo frame, upmixed to the output channel count
+    fn upmix(&self, stereo_frame: [i16; 2], output: &mut Vec<i16>) {
+        // Standard channel mapping for common configurations:
+        // 2ch: FL, FR
+        // 6ch (5.1): FL, FR, FC, LFE, BL, BR
+        // 8ch (7.1): FL, FR, FC, LFE, BL, BR, SL, SR
+        let out_ch = self.output_channels as usize;
+        match out_ch {
+            1 => {
+                // Mono: mix L+R
+                let mono = ((stereo_frame[0] as i32 + stereo_frame[1] as i32) / 2) as i16;
+                output.push(mono);
             }
-        } else if input_frames > 0 {
-            // Shift history and add new samples
-            for ch in 0..in_ch {
-                let shift = 4 - input_frames;
-                for i in 0..shift {
-                    self.history[ch][i] = self.history[ch][i + input_frames];
-                }
-                for i in 0..input_frames {
-                    self.history[ch][shift + i] = input[i * in_ch + ch];
+            2 => {
+                // Stereo: swap L/R channels (GFN sends them inverted)
+                output.push(stereo_frame[1]); // Right -> Left
+                output.push(stereo_frame[0]); // Left -> Right
+            }
+            _ => {
+                // Multi-channel (5.1, 7.1, etc.)
+                // Standard layout: FL, FR, FC, LFE, BL, BR, [SL, SR for 7.1+]
+                // Swap L/R channels (GFN sends them inverted)
+                let left = stereo_frame[1];
+                let right = stereo_frame[0];
+
+                for ch_idx in 0..out_ch {
+                    let sample = match ch_idx {
+                        0 => left,  // Front Left
+                        1 => right, // Front Right
+                        2 => {
+                            // Center - mix of L+R at reduced level
+                            ((left as i32 + right as i32) / 3) as i16
+                        }
+                        3 => 0, // LFE - no bass routing for now
+                        4 => {
+                            // Back/Rear Left - copy of front left at reduced level
+                            (left as i32 * 2 / 3) as i16
+                        }
+                        5 => {
+                            // Back/Rear Right - copy of front right at reduced level
+                            (right as i32 * 2 / 3) as i16
+                        }
+                        6 => {
+                            // Side Left (7.1) - copy of front left at reduced level
+                            (left as i32 / 2) as i16
+                        }
+                        7 => {
+                            // Side Right (7.1) - copy of front right at reduced level
+                            (right as i32 / 2) as i16
+                        }
+                        _ => 0, // Any additional channels: silence
+                    };
+                    output.push(sample);
                 }
             }
         }
-
-        // Keep fractional phase, reset integer part
-        self.phase = self.phase.fract();
-
-        output
     }
 
     /// Update output rate and channels (for device change)
@@ -827,14 +897,10 @@ impl AudioResampler {
         if self.output_rate != output_rate || self.output_channels != output_channels {
             self.output_rate = output_rate;
             self.output_channels = output_channels;
-            self.phase = 0.0;
-            // Reset history on config change
-            for hist in &mut self.history {
-                *hist = [0i16; 4];
-            }
+            self.reset();
             info!(
-                "Resampler updated: {}Hz {}ch -> {}Hz {}ch",
-                self.input_rate, self.input_channels, output_rate, output_channels
+                "Resampler updated: stream {}Hz {}ch -> device {}Hz {}ch ({:?} quality)",
+                self.input_rate, self.input_channels, output_rate, output_channels, self.quality
             );
         }
     }
@@ -842,7 +908,12 @@ impl AudioResampler {
 
 impl AudioPlayer {
     /// Create a new audio player
-    pub fn new(sample_rate: u32, channels: u32) -> Result<Self> {
+    /// `resampler_quality` is used when the device doesn't run at the stream's sample rate
+    pub fn new(
+        sample_rate: u32,
+        channels: u32,
+        resampler_quality: ResamplerQuality,
+    ) -> Result<Self> {
         use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
         use cpal::SampleFormat;
 
@@ -1031,13 +1102,29 @@ impl AudioPlayer {
         // Create resampler for input_rate -> output_rate conversion and channel upmixing
         // Input: decoder's sample_rate (48000) and channels (2 for stereo Opus)
         // Output: device's actual_rate and actual_channels (may be 8 for 7.1 headsets)
-        let resampler =
-            AudioResampler::new(sample_rate, actual_rate.0, channels, actual_channels as u32);
+        let resampler = AudioResampler::new(
+            sample_rate,
+            actual_rate.0,
+            channels,
+            actual_channels as u32,
+            resampler_quality,
+        );
 
-        if sample_rate != actual_rate.0 || channels != actual_channels as u32 {
+        if sample_rate != actual_rate.0 {
             info!(
-                "Audio conversion enabled: {}Hz {}ch -> {}Hz {}ch",
-                sample_rate, channels, actual_rate.0, actual_channels
+                "Audio sample rates differ: stream {}Hz, device {}Hz - resampling ({:?} quality)",
+                sample_rate, actual_rate.0, resampler_quality
+            );
+        } else {
+            info!(
+                "Audio sample rates match: stream and device at {}Hz - no resampling",
+                sample_rate
+            );
+        }
+        if channels != actual_channels as u32 {
+            info!(
+                "Audio upmix enabled: {}ch -> {}ch",
+                channels, actual_channels
             );
         }
 
@@ -1244,3 +1331,136 @@ impl AudioPlayer {
         Ok(())
     }
 }
+
+// ============================================================================
+// UI sounds
+// ============================================================================
+
+/// Short notification sounds for stream state changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chime {
+    /// First video frame arrived
+    Connected,
+    /// Stream ended or failed
+    Disconnected,
+}
+
+impl Chime {
+    /// Tone sequence as (frequency Hz, duration ms)
+    fn notes(&self) -> &'static [(f32, u32)] {
+        match self {
+            Chime::Connected => &[(660.0, 90), (990.0, 150)],
+            Chime::Disconnected => &[(660.0, 90), (440.0, 180)],
+        }
+    }
+
+    /// Synthesize the chime as mono f32 samples, with short fades to avoid clicks
+    fn synthesize(&self, sample_rate: u32) -> Vec<f32> {
+        const AMPLITUDE: f32 = 0.2;
+        const FADE_SECS: f32 = 0.01;
+
+        let mut samples = Vec::new();
+        for &(freq, duration_ms) in self.notes() {
+            let count = (sample_rate as u64 * duration_ms as u64 / 1000) as usize;
+            let fade = ((sample_rate as f32 * FADE_SECS) as usize).max(1);
+            for i in 0..count {
+                let t = i as f32 / sample_rate as f32;
+                let envelope = (i.min(count - 1 - i) as f32 / fade as f32).min(1.0);
+                samples.push((t * freq * std::f32::consts::TAU).sin() * AMPLITUDE * envelope);
+            }
+        }
+        samples
+    }
+}
+
+/// Play a chime on the default output device
+/// Runs on its own short-lived stream, so it also works before/after the stream's audio player
+pub fn play_chime(chime: Chime) {
+    thread::spawn(move || {
+        if let Err(e) = play_chime_blocking(chime) {
+            warn!("Failed to play {:?} chime: {}", chime, e);
+        }
+    });
+}
+
+fn play_chime_blocking(chime: Chime) -> Result<()> {
+    use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+    use cpal::SampleFormat;
+
+    let device = cpal::default_host()
+        .default_output_device()
+        .context("No audio output device found")?;
+    let supported = device
+        .default_output_config()
+        .context("No default audio output config")?;
+    let sample_rate = supported.sample_rate().0;
+    let channels = supported.channels().max(1) as usize;
+    let config: cpal::StreamConfig = supported.config();
+
+    let samples = Arc::new(chime.synthesize(sample_rate));
+    let duration = std::time::Duration::from_secs_f64(samples.len() as f64 / sample_rate as f64);
+    let position = Arc::new(AtomicUsize::new(0));
+
+    // Same mono sample on every channel, silence once the chime is done
+    let stream = match supported.sample_format() {
+        SampleFormat::I16 => device.build_output_stream(
+            &config,
+            move |data: &mut [i16], _| {
+                for frame in data.chunks_mut(channels) {
+                    let i = position.fetch_add(1, Ordering::Relaxed);
+                    let value = samples.get(i).copied().unwrap_or(0.0);
+                    frame.fill((value * 32767.0) as i16);
+                }
+            },
+            |err| error!("Chime stream error: {}", err),
+            None,
+        ),
+        _ => device.build_output_stream(
+            &config,
+            move |data: &mut [f32], _| {
+                for frame in data.chunks_mut(channels) {
+                    let i = position.fetch_add(1, Ordering::Relaxed);
+                    frame.fill(samples.get(i).copied().unwrap_or(0.0));
+                }
+            },
+            |err| error!("Chime stream error: {}", err),
+            None,
+        ),
+    }
+    .context("Failed to create chime stream")?;
+
+    stream.play().context("Failed to start chime playback")?;
+    // Let the device drain its buffer before the stream is dropped
+    thread::sleep(duration + std::time::Duration::from_millis(150));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resampler_keeps_rate_and_level_across_packets() {
+        for quality in ResamplerQuality::all() {
+            let mut resampler = AudioResampler::new(48000, 44100, 2, 2, *quality);
+            // One second of a constant signal in 10 ms packets
+            let packet = vec![1000i16; 480 * 2];
+            let output: Vec<i16> = (0..100).flat_map(|_| resampler.resample(&packet)).collect();
+
+            // Within the few frames of carry-over of the expected 44100
+            let frames = output.len() / 2;
+            assert!(
+                (44_080..=44_100).contains(&frames),
+                "{:?}: {}",
+                quality,
+                frames
+            );
+            // No dips at packet boundaries once past the initial history
+            assert!(
+                output[64..].iter().all(|&s| (s - 1000).abs() <= 1),
+                "{:?}",
+                quality
+            );
+        }
+    }
+}
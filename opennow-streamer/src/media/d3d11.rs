@@ -403,4 +403,98 @@ mod tests {
         let uv_size = width * (height / 2);
         assert_eq!(uv_size, 1036800);
     }
+
+    #[test]
+    fn test_p010_texture_detected_and_read_back() {
+        use windows::Win32::Graphics::Direct3D::D3D_DRIVER_TYPE_WARP;
+        use windows::Win32::Graphics::Direct3D11::{
+            D3D11CreateDevice, D3D11_BIND_SHADER_RESOURCE, D3D11_CREATE_DEVICE_FLAG,
+            D3D11_SDK_VERSION, D3D11_SUBRESOURCE_DATA, D3D11_USAGE_DEFAULT,
+        };
+        use windows::Win32::Graphics::Dxgi::Common::DXGI_SAMPLE_DESC;
+
+        const WIDTH: u32 = 64;
+        const HEIGHT: u32 = 64;
+        // 10-bit samples live in the high bits of each 16-bit word
+        const Y_WHITE: u16 = 1023 << 6;
+        const UV_NEUTRAL: u16 = 512 << 6;
+
+        // Y plane followed by the interleaved UV plane, 16 bits per sample
+        let pitch = WIDTH as usize * 2;
+        let mut data: Vec<u8> = Vec::with_capacity(pitch * HEIGHT as usize * 3 / 2);
+        for _ in 0..(WIDTH * HEIGHT) {
+            data.extend_from_slice(&Y_WHITE.to_le_bytes());
+        }
+        for _ in 0..(WIDTH * HEIGHT / 2) {
+            data.extend_from_slice(&UV_NEUTRAL.to_le_bytes());
+        }
+
+        unsafe {
+            // WARP keeps the test independent of the installed GPU
+            let mut device: Option<ID3D11Device> = None;
+            if let Err(e) = D3D11CreateDevice(
+                None,
+                D3D_DRIVER_TYPE_WARP,
+                Default::default(),
+                D3D11_CREATE_DEVICE_FLAG(0),
+                None,
+                D3D11_SDK_VERSION,
+                Some(&mut device),
+                None,
+                None,
+            ) {
+                println!("Skipping P010 test - no WARP device: {:?}", e);
+                return;
+            }
+            let device = device.unwrap();
+
+            let desc = D3D11_TEXTURE2D_DESC {
+                Width: WIDTH,
+                Height: HEIGHT,
+                MipLevels: 1,
+                ArraySize: 1,
+                Format: DXGI_FORMAT_P010,
+                SampleDesc: DXGI_SAMPLE_DESC {
+                    Count: 1,
+                    Quality: 0,
+                },
+                Usage: D3D11_USAGE_DEFAULT,
+                BindFlags: D3D11_BIND_SHADER_RESOURCE.0 as u32,
+                CPUAccessFlags: 0,
+                MiscFlags: 0,
+            };
+            let init = D3D11_SUBRESOURCE_DATA {
+                pSysMem: data.as_ptr() as *const std::ffi::c_void,
+                SysMemPitch: pitch as u32,
+                SysMemSlicePitch: 0,
+            };
+            let mut texture: Option<ID3D11Texture2D> = None;
+            if let Err(e) = device.CreateTexture2D(&desc, Some(&init), Some(&mut texture)) {
+                println!("Skipping P010 test - P010 textures unsupported: {:?}", e);
+                return;
+            }
+
+            let wrapper = D3D11TextureWrapper::from_texture(texture.unwrap(), 0);
+            assert!(wrapper.is_10bit);
+            assert_eq!((wrapper.width, wrapper.height), (WIDTH, HEIGHT));
+
+            let planes = wrapper.lock_and_get_planes().unwrap();
+            assert!(planes.y_stride as usize >= pitch);
+            assert_eq!(
+                planes.y_plane.len(),
+                planes.y_stride as usize * HEIGHT as usize
+            );
+            assert_eq!(
+                planes.uv_plane.len(),
+                planes.uv_stride as usize * (HEIGHT / 2) as usize
+            );
+
+            let sample = |plane: &[u8], offset: usize| {
+                u16::from_le_bytes([plane[offset], plane[offset + 1]])
+            };
+            assert_eq!(sample(&planes.y_plane, 0), Y_WHITE);
+            assert_eq!(sample(&planes.uv_plane, 0), UV_NEUTRAL);
+            assert_eq!(sample(&planes.uv_plane, 2), UV_NEUTRAL);
+        }
+    }
 }
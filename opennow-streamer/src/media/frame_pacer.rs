@@ -0,0 +1,113 @@
+//! Frame Pacing
+//!
+//! Presents each video frame a fixed delay after its packet arrived instead of as soon
+//! as the decoder finishes, so uneven decode times don't turn into uneven frame times.
+//! The delay tracks recent decode latency and is capped below one frame interval, so
+//! the single-slot `SharedFrame` isn't overwritten while a frame waits.
+
+use std::collections::VecDeque;
+
+/// Number of recent decode latencies used to pick the presentation delay
+const LATENCY_WINDOW: usize = 120;
+
+/// Latency percentile the delay must cover (frames slower than this present late)
+const LATENCY_PERCENTILE: f32 = 0.9;
+
+/// Fraction of a frame interval the delay may use at most
+const MAX_DELAY_FRACTION: f32 = 0.75;
+
+/// Schedules frame presentation from frame timestamps
+pub struct FramePacer {
+    /// Recent receive-to-ready latencies in microseconds
+    latencies_us: VecDeque<u64>,
+    /// Timestamp of the frame currently waiting, if already sampled
+    pending_timestamp_us: Option<u64>,
+    /// Delay applied to the waiting frame
+    delay_us: u64,
+}
+
+impl FramePacer {
+    pub fn new() -> Self {
+        Self {
+            latencies_us: VecDeque::with_capacity(LATENCY_WINDOW),
+            pending_timestamp_us: None,
+            delay_us: 0,
+        }
+    }
+
+    /// Whether the waiting frame (received at `timestamp_us`) should be presented at `now_us`
+    /// Frames without a timestamp are always due.
+    pub fn is_due(&mut self, timestamp_us: u64, now_us: u64, frame_interval_us: u64) -> bool {
+        if timestamp_us == 0 {
+            return true;
+        }
+
+        // First sight of this frame: it just left the decoder, so now - receive = decode latency
+        if self.pending_timestamp_us != Some(timestamp_us) {
+            self.pending_timestamp_us = Some(timestamp_us);
+            if self.latencies_us.len() == LATENCY_WINDOW {
+                self.latencies_us.pop_front();
+            }
+            self.latencies_us
+                .push_back(now_us.saturating_sub(timestamp_us));
+            let max_delay = (frame_interval_us as f32 * MAX_DELAY_FRACTION) as u64;
+            self.delay_us = self.latency_percentile().min(max_delay);
+        }
+
+        now_us >= timestamp_us + self.delay_us
+    }
+
+    /// Forget latency history (call when streaming stops)
+    pub fn reset(&mut self) {
+        self.latencies_us.clear();
+        self.pending_timestamp_us = None;
+        self.delay_us = 0;
+    }
+
+    fn latency_percentile(&self) -> u64 {
+        let mut sorted: Vec<u64> = self.latencies_us.iter().copied().collect();
+        sorted.sort_unstable();
+        let index = ((sorted.len() as f32 * LATENCY_PERCENTILE) as usize).min(sorted.len() - 1);
+        sorted[index]
+    }
+}
+
+impl Default for FramePacer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frames_present_a_constant_delay_after_receive() {
+        let mut pacer = FramePacer::new();
+        let interval = 16_667;
+
+        // Decode usually takes 4ms, occasionally 8ms
+        for i in 0..100u64 {
+            let received = 1_000_000 + i * interval;
+            let latency = if i % 10 == 0 { 8_000 } else { 4_000 };
+            pacer.is_due(received, received + latency, interval);
+        }
+
+        // A fast 2ms decode waits for the learned delay instead of presenting early
+        let received = 10_000_000;
+        assert!(!pacer.is_due(received, received + 2_000, interval));
+        assert!(pacer.is_due(received, received + pacer.delay_us, interval));
+        assert!(pacer.delay_us >= 4_000 && pacer.delay_us <= 8_000);
+    }
+
+    #[test]
+    fn test_delay_capped_below_frame_interval() {
+        let mut pacer = FramePacer::new();
+        let interval = 8_333; // 120 FPS
+        let received = 1_000_000;
+        pacer.is_due(received, received + 50_000, interval);
+        assert!(pacer.delay_us <= (interval as f32 * MAX_DELAY_FRACTION) as u64);
+        assert!(pacer.is_due(0, 0, interval));
+    }
+}
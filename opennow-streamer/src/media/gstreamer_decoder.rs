@@ -25,11 +25,50 @@ use gstreamer as gst;
 use gstreamer::prelude::*;
 use gstreamer_app::{AppSink, AppSrc};
 use gstreamer_video as gst_video;
-use log::{debug, info, warn};
+use log::{debug, error, info, warn};
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 
 use super::{ColorRange, ColorSpace, PixelFormat, TransferFunction, VideoFrame};
+#[cfg(target_os = "linux")]
+use crate::app::config::PiDecoder;
+use crate::app::config::VideoDecoderBackend;
+
+/// Custom decode pipeline from settings, used instead of the built-in one when set
+static PIPELINE_OVERRIDE: Mutex<Option<String>> = Mutex::new(None);
+
+/// Raspberry Pi decode path from settings (ignored on other hardware)
+#[cfg(target_os = "linux")]
+static PI_DECODER: Mutex<PiDecoder> = Mutex::new(PiDecoder::Auto);
+
+/// Decoder element of the most recently created pipeline (e.g. "v4l2h264dec")
+static ACTIVE_DECODER: Mutex<Option<String>> = Mutex::new(None);
+
+/// Choose the Raspberry Pi decode path for decoders created from now on
+#[cfg(target_os = "linux")]
+pub fn set_pi_decoder(pi_decoder: PiDecoder) {
+    *PI_DECODER.lock().unwrap() = pi_decoder;
+}
+
+/// Decoder element in use by the most recently created decoder
+pub fn active_decoder_element() -> Option<String> {
+    ACTIVE_DECODER.lock().unwrap().clone()
+}
+
+/// Use a custom pipeline for decoders created from now on (None = built-in pipeline)
+pub fn set_pipeline_override(pipeline: Option<String>) {
+    if let Some(ref pipeline) = pipeline {
+        info!("GStreamer pipeline override set: {}", pipeline);
+    }
+    *PIPELINE_OVERRIDE.lock().unwrap() = pipeline;
+}
+
+/// Check that a custom pipeline parses and has the `src` appsrc and `sink` appsink
+/// the decoder feeds and reads from
+pub fn validate_pipeline(description: &str) -> Result<()> {
+    init_gstreamer()?;
+    GStreamerDecoder::parse_pipeline(description).map(|_| ())
+}
 
 /// Initialize GStreamer with support for bundled runtime on Windows
 /// This function MUST be called before any other GStreamer operations.
@@ -139,6 +178,25 @@ impl GstCodec {
         }
     }
 
+    /// Hardware decoder elements to try for `backend`, best first (Windows)
+    /// NVDEC and QSV fall back to the D3D11 decoder when their plugin is missing
+    #[cfg(target_os = "windows")]
+    fn hw_decoder_candidates(&self, backend: VideoDecoderBackend) -> Vec<&'static str> {
+        let vendor: &[&'static str] = match (backend, self) {
+            (VideoDecoderBackend::Software, _) => return Vec::new(),
+            (VideoDecoderBackend::Cuvid, GstCodec::H264) => &["nvh264dec", "nvd3d11h264dec"],
+            (VideoDecoderBackend::Cuvid, GstCodec::H265) => &["nvh265dec", "nvd3d11h265dec"],
+            (VideoDecoderBackend::Cuvid, GstCodec::AV1) => &["nvav1dec"],
+            (VideoDecoderBackend::Qsv, GstCodec::H264) => &["qsvh264dec"],
+            (VideoDecoderBackend::Qsv, GstCodec::H265) => &["qsvh265dec"],
+            (VideoDecoderBackend::Qsv, GstCodec::AV1) => &["qsvav1dec"],
+            _ => &[],
+        };
+        let mut candidates = vendor.to_vec();
+        candidates.push(self.decoder_element());
+        candidates
+    }
+
     #[cfg(target_os = "linux")]
     fn decoder_element(&self) -> &'static str {
         // Linux: V4L2 for embedded (RPi), otherwise VA-API or software
@@ -167,6 +225,9 @@ pub struct GstDecoderConfig {
     pub height: u32,
     /// Enable low latency mode (minimize buffering)
     pub low_latency: bool,
+    /// Decoder preference from settings (Linux: V4L2, VA-API or software; Windows: D3D11,
+    /// NVDEC, QSV or software; Auto picks the platform default)
+    pub backend: VideoDecoderBackend,
 }
 
 impl Default for GstDecoderConfig {
@@ -176,6 +237,7 @@ impl Default for GstDecoderConfig {
             width: 1920,
             height: 1080,
             low_latency: true, // Default to low latency for streaming
+            backend: VideoDecoderBackend::Auto,
         }
     }
 }
@@ -196,6 +258,8 @@ struct DecodedFrame {
     transfer_function: TransferFunction,
     /// Color range (Limited/Full)
     color_range: ColorRange,
+    /// Presentation timestamp (appsrc stamps every pushed access unit)
+    pts: Option<gst::ClockTime>,
 }
 
 /// GStreamer Video Decoder
@@ -214,6 +278,8 @@ pub struct GStreamerDecoder {
     last_frame: Arc<Mutex<Option<DecodedFrame>>>,
     /// Last logged transfer function (to avoid log spam)
     last_logged_transfer: TransferFunction,
+    /// PTS and frame ID of the last output, so a re-emitted picture keeps its ID
+    last_output: Option<(gst::ClockTime, u64)>,
 }
 
 // GStreamer is thread-safe
@@ -231,15 +297,46 @@ impl GStreamerDecoder {
         // Initialize GStreamer (with bundled DLL support on Windows)
         init_gstreamer()?;
 
-        // Build platform-specific pipeline
-        let pipeline_str = Self::build_pipeline_string(&config)?;
-        info!("GStreamer pipeline: {}", pipeline_str);
+        // Custom pipeline from settings, falling back to the built-in one if it's invalid
+        let custom_pipeline =
+            PIPELINE_OVERRIDE.lock().unwrap().clone().and_then(
+                |custom| match Self::parse_pipeline(&custom) {
+                    Ok(pipeline) => {
+                        info!("GStreamer pipeline (custom): {}", custom);
+                        Some(pipeline)
+                    }
+                    Err(e) => {
+                        error!(
+                            "Custom GStreamer pipeline rejected, using the built-in pipeline: {}",
+                            e
+                        );
+                        None
+                    }
+                },
+            );
 
-        // Parse and create pipeline
-        let pipeline = gst::parse::launch(&pipeline_str)
-            .map_err(|e| anyhow!("Failed to create GStreamer pipeline: {}", e))?
-            .downcast::<gst::Pipeline>()
-            .map_err(|_| anyhow!("Failed to downcast to Pipeline"))?;
+        let pipeline = match custom_pipeline {
+            Some(pipeline) => pipeline,
+            None => {
+                // Build platform-specific pipeline
+                let pipeline_str = Self::build_pipeline_string(&config)?;
+                info!("GStreamer pipeline: {}", pipeline_str);
+                Self::parse_pipeline(&pipeline_str)?
+            }
+        };
+
+        // Remember which decoder element ended up in the pipeline (built-in or custom)
+        let decoder_element = pipeline.children().iter().find_map(|element| {
+            element
+                .factory()
+                .filter(|factory| factory.klass().contains("Decoder"))
+                .map(|factory| factory.name().to_string())
+        });
+        info!(
+            "GStreamer decoder element: {}",
+            decoder_element.as_deref().unwrap_or("unknown")
+        );
+        *ACTIVE_DECODER.lock().unwrap() = decoder_element;
 
         // Get appsrc
         let appsrc = pipeline
@@ -366,6 +463,7 @@ impl GStreamerDecoder {
                                                     color_space,
                                                     transfer_function,
                                                     color_range,
+                                                    pts: buffer.pts(),
                                                 };
 
                                                 *last_frame_clone.lock().unwrap() = Some(frame);
@@ -440,13 +538,35 @@ impl GStreamerDecoder {
             frame_count: 0,
             last_frame,
             last_logged_transfer: TransferFunction::SDR,
+            last_output: None,
         })
     }
 
+    /// Parse a pipeline description into a pipeline with `src` and `sink` elements
+    fn parse_pipeline(description: &str) -> Result<gst::Pipeline> {
+        let pipeline = gst::parse::launch(description)
+            .map_err(|e| anyhow!("Failed to create GStreamer pipeline: {}", e))?
+            .downcast::<gst::Pipeline>()
+            .map_err(|_| anyhow!("Failed to downcast to Pipeline"))?;
+
+        let has_appsrc = pipeline
+            .by_name("src")
+            .is_some_and(|element| element.is::<AppSrc>());
+        let has_appsink = pipeline
+            .by_name("sink")
+            .is_some_and(|element| element.is::<AppSink>());
+        if !has_appsrc || !has_appsink {
+            return Err(anyhow!(
+                "Pipeline must contain 'appsrc name=src' and 'appsink name=sink'"
+            ));
+        }
+
+        Ok(pipeline)
+    }
+
     /// Build the GStreamer pipeline string for the current platform
     fn build_pipeline_string(config: &GstDecoderConfig) -> Result<String> {
         let parser = config.codec.parser_element();
-        let decoder = config.codec.decoder_element();
 
         // Low latency sink options - critical for streaming
         // sync=false renders frames immediately without clock sync
@@ -458,41 +578,61 @@ impl GStreamerDecoder {
             "max-buffers=2 drop=false sync=false wait-on-eos=false"
         };
 
-        // Check if the hardware decoder is available
         let registry = gst::Registry::get();
-        let hw_decoder_available = registry
-            .find_feature(decoder, gst::ElementFactory::static_type())
-            .is_some();
 
         #[cfg(target_os = "windows")]
         {
-            if hw_decoder_available {
-                // Windows D3D11 hardware decoder pipeline - ULTRA LOW LATENCY
+            // First available hardware decoder for the selected backend
+            let hw_decoder = config
+                .codec
+                .hw_decoder_candidates(config.backend)
+                .into_iter()
+                .find(|name| {
+                    registry
+                        .find_feature(name, gst::ElementFactory::static_type())
+                        .is_some()
+                });
+
+            if let Some(decoder) = hw_decoder {
+                // Windows hardware decoder pipeline - ULTRA LOW LATENCY
                 // d3d11h264dec outputs D3D11 textures, need d3d11download to copy to system memory
+                // (NVDEC/QSV elements negotiate system memory with videoconvert directly)
                 //
                 // Key optimizations:
                 // - NO queue element (queues add latency for thread sync)
                 // - is-live=true on appsrc for real-time behavior
                 // - sync=false on appsink to render immediately
                 // - videoconvert with n-threads for parallel color conversion
-                info!("Using D3D11 hardware decoder: {}", decoder);
+                let download = if decoder.starts_with("d3d11") {
+                    "! d3d11download "
+                } else {
+                    ""
+                };
+                info!("Using hardware decoder: {}", decoder);
                 Ok(format!(
                     "appsrc name=src is-live=true format=time do-timestamp=true max-buffers=1 \
                      ! {} \
                      ! {} \
-                     ! d3d11download \
-                     ! videoconvert n-threads=2 \
+                     {}! videoconvert n-threads=2 \
                      ! video/x-raw,format=NV12 \
                      ! appsink name=sink emit-signals=true {}",
-                    parser, decoder, sink_opts
+                    parser, decoder, download, sink_opts
                 ))
             } else {
                 // Fallback to software decoder - still optimized for low latency
                 let sw_decoder = config.codec.software_decoder();
-                warn!(
-                    "D3D11 decoder {} not available, falling back to software: {}",
-                    decoder, sw_decoder
-                );
+                if config.backend == VideoDecoderBackend::Software {
+                    info!(
+                        "Using software decoder: {} (selected in settings)",
+                        sw_decoder
+                    );
+                } else {
+                    warn!(
+                        "D3D11 decoder {} not available, falling back to software: {}",
+                        config.codec.decoder_element(),
+                        sw_decoder
+                    );
+                }
                 Ok(format!(
                     "appsrc name=src is-live=true format=time do-timestamp=true max-buffers=1 \
                      ! {} \
@@ -509,9 +649,10 @@ impl GStreamerDecoder {
         {
             // Linux decoder priority (from best to fallback):
             // 1. V4L2 (Raspberry Pi, embedded devices with hardware codec)
-            // 2. VA (newer va plugin - vah264dec/vah265dec/vaav1dec) for Intel/AMD
-            // 3. VAAPI (legacy vaapi plugin - vaapih264dec/vaapih265dec)
-            // 4. Software (avdec_h264/avdec_h265/av1dec)
+            // 2. V4L2 stateless (Raspberry Pi, only when chosen in settings)
+            // 3. VA (newer va plugin - vah264dec/vah265dec/vaav1dec) for Intel/AMD
+            // 4. VAAPI (legacy vaapi plugin - vaapih264dec/vaapih265dec)
+            // 5. Software (avdec_h264/avdec_h265/av1dec)
 
             // Check for V4L2 decoder (Raspberry Pi - RPi5 supports AV1)
             let v4l2_decoder = match config.codec {
@@ -523,6 +664,16 @@ impl GStreamerDecoder {
                 .find_feature(v4l2_decoder, gst::ElementFactory::static_type())
                 .is_some();
 
+            // V4L2 stateless decoders (v4l2codecs plugin - rpivid HEVC on Pi 5)
+            let v4l2_stateless_decoder = match config.codec {
+                GstCodec::H264 => "v4l2slh264dec",
+                GstCodec::H265 => "v4l2slh265dec",
+                GstCodec::AV1 => "v4l2slav1dec",
+            };
+            let v4l2_stateless_available = registry
+                .find_feature(v4l2_stateless_decoder, gst::ElementFactory::static_type())
+                .is_some();
+
             // Check for new VA plugin decoders (preferred for desktop Linux)
             // Intel Arc, AMD RDNA2+, and modern Intel iGPUs support AV1
             let va_decoder = match config.codec {
@@ -545,7 +696,31 @@ impl GStreamerDecoder {
                 .find_feature(vaapi_decoder, gst::ElementFactory::static_type())
                 .is_some();
 
-            if v4l2_available {
+            // An explicit backend choice restricts which elements are tried;
+            // otherwise the Pi decode path from settings decides on Raspberry Pi
+            let pi_decoder = if is_raspberry_pi() {
+                *PI_DECODER.lock().unwrap()
+            } else {
+                PiDecoder::Auto
+            };
+            let (try_v4l2, try_v4l2_stateless, try_va) = match config.backend {
+                VideoDecoderBackend::V4l2 => (true, false, false),
+                VideoDecoderBackend::Vaapi => (false, false, true),
+                VideoDecoderBackend::Software => (false, false, false),
+                _ => match pi_decoder {
+                    PiDecoder::Auto => (true, false, true),
+                    PiDecoder::V4l2 => {
+                        info!("Raspberry Pi decoder: V4L2 stateful (selected in settings)");
+                        (true, false, false)
+                    }
+                    PiDecoder::Gstreamer => {
+                        info!("Raspberry Pi decoder: GStreamer (selected in settings)");
+                        (false, true, false)
+                    }
+                },
+            };
+
+            if try_v4l2 && v4l2_available {
                 // Raspberry Pi / embedded V4L2 hardware decoder - ULTRA LOW LATENCY
                 // V4L2 decoders output directly to DMA buffers
                 info!(
@@ -561,7 +736,22 @@ impl GStreamerDecoder {
                      ! appsink name=sink emit-signals=true {}",
                     parser, v4l2_decoder, sink_opts
                 ))
-            } else if va_available {
+            } else if try_v4l2_stateless && v4l2_stateless_available {
+                // V4L2 stateless decoder - GStreamer does the bitstream parsing
+                info!(
+                    "Using V4L2 stateless hardware decoder: {} (Raspberry Pi)",
+                    v4l2_stateless_decoder
+                );
+                Ok(format!(
+                    "appsrc name=src is-live=true format=time do-timestamp=true max-buffers=1 \
+                     ! {} \
+                     ! {} \
+                     ! videoconvert n-threads=2 \
+                     ! video/x-raw,format=NV12 \
+                     ! appsink name=sink emit-signals=true {}",
+                    parser, v4l2_stateless_decoder, sink_opts
+                ))
+            } else if try_va && va_available {
                 // Modern VA plugin (Intel/AMD desktop Linux) - LOW LATENCY
                 // va plugin is the newer, preferred method for VAAPI
                 info!(
@@ -577,7 +767,7 @@ impl GStreamerDecoder {
                      ! appsink name=sink emit-signals=true {}",
                     parser, va_decoder, sink_opts
                 ))
-            } else if vaapi_available {
+            } else if try_va && vaapi_available {
                 // Legacy VAAPI plugin (older systems) - LOW LATENCY
                 info!("Using legacy VAAPI hardware decoder: {}", vaapi_decoder);
                 Ok(format!(
@@ -592,11 +782,20 @@ impl GStreamerDecoder {
             } else {
                 // Fallback to software decoder
                 let sw_decoder = config.codec.software_decoder();
-                warn!(
-                    "No hardware decoder available for {:?}, falling back to software: {}",
-                    config.codec, sw_decoder
-                );
-                warn!("For hardware acceleration, install: libva (Intel/AMD) or enable V4L2 (Raspberry Pi)");
+                if config.backend == VideoDecoderBackend::Software {
+                    info!(
+                        "Using software decoder: {} (selected in settings)",
+                        sw_decoder
+                    );
+                } else {
+                    warn!(
+                        "No {} decoder available for {:?}, falling back to software: {}",
+                        config.backend.as_str(),
+                        config.codec,
+                        sw_decoder
+                    );
+                    warn!("For hardware acceleration, install: libva (Intel/AMD) or enable V4L2 (Raspberry Pi)");
+                }
                 Ok(format!(
                     "appsrc name=src is-live=true format=time do-timestamp=true max-buffers=1 \
                      ! {} \
@@ -660,8 +859,16 @@ impl GStreamerDecoder {
                 self.last_logged_transfer = decoded.transfer_function;
             }
 
+            // Decoders can push the same picture again while waiting on a stalled
+            // stream; it carries the PTS of the access unit it was decoded from
+            let frame_id = match (decoded.pts, self.last_output) {
+                (Some(pts), Some((last_pts, last_id))) if pts == last_pts => last_id,
+                _ => super::next_frame_id(),
+            };
+            self.last_output = decoded.pts.map(|pts| (pts, frame_id));
+
             Ok(Some(VideoFrame {
-                frame_id: super::next_frame_id(),
+                frame_id,
                 width: decoded.width,
                 height: decoded.height,
                 y_plane: decoded.y_plane,
@@ -739,6 +946,30 @@ pub fn is_gstreamer_available() -> bool {
     }
 }
 
+/// Check if the GStreamer elements behind a Windows decoder backend are installed
+/// (NVDEC needs the nvcodec plugin, QSV the qsv plugin, Software the libav plugin)
+#[cfg(target_os = "windows")]
+pub fn is_gstreamer_backend_available(backend: VideoDecoderBackend) -> bool {
+    if init_gstreamer().is_err() {
+        return false;
+    }
+
+    let elements: &[&str] = match backend {
+        VideoDecoderBackend::Cuvid => {
+            &["nvh264dec", "nvd3d11h264dec", "nvh265dec", "nvd3d11h265dec"]
+        }
+        VideoDecoderBackend::Qsv => &["qsvh264dec", "qsvh265dec"],
+        VideoDecoderBackend::Software => &["avdec_h264", "avdec_h265"],
+        _ => &["d3d11h264dec", "d3d11h265dec", "d3d11av1dec"],
+    };
+    let registry = gst::Registry::get();
+    elements.iter().any(|name| {
+        registry
+            .find_feature(name, gst::ElementFactory::static_type())
+            .is_some()
+    })
+}
+
 /// Check if GStreamer V4L2 decoding is available (Linux - Raspberry Pi)
 #[cfg(target_os = "linux")]
 pub fn is_gstreamer_v4l2_available() -> bool {
@@ -924,6 +1155,7 @@ mod tests {
         assert_eq!(config.height, 1080);
         assert_eq!(config.codec, GstCodec::H264);
         assert!(config.low_latency);
+        assert_eq!(config.backend, VideoDecoderBackend::Auto);
     }
 
     #[test]
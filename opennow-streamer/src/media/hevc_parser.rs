@@ -968,6 +968,52 @@ impl HevcParser {
         }
         None
     }
+
+    /// Parse any SPS in an access unit and return its chroma_format_idc
+    /// (0 = monochrome, 1 = 4:2:0, 2 = 4:2:2, 3 = 4:4:4). None if the AU has no SPS.
+    pub fn sps_chroma_format(&mut self, data: &[u8]) -> Option<u8> {
+        // Parameter sets lead IDR access units - skip the full scan for ordinary frames
+        let start = if data.starts_with(&[0, 0, 0, 1]) {
+            4
+        } else if data.starts_with(&[0, 0, 1]) {
+            3
+        } else {
+            return None;
+        };
+        let first_type = HevcNalType::from((data.get(start)? >> 1) & 0x3F);
+        if !matches!(
+            first_type,
+            HevcNalType::VpsNut | HevcNalType::SpsNut | HevcNalType::AudNut
+        ) {
+            return None;
+        }
+
+        let mut chroma = None;
+        for nal in self.find_nal_units(data) {
+            if nal.nal_type == HevcNalType::SpsNut {
+                match self.parse_sps(&nal) {
+                    Ok(sps_id) => {
+                        chroma = self.sps[sps_id as usize]
+                            .as_ref()
+                            .map(|sps| sps.chroma_format_idc);
+                    }
+                    Err(e) => warn!("Failed to parse SPS: {}", e),
+                }
+            }
+        }
+        chroma
+    }
+}
+
+/// Display label for an HEVC chroma_format_idc
+pub fn chroma_format_label(chroma_format_idc: u8) -> &'static str {
+    match chroma_format_idc {
+        0 => "4:0:0",
+        1 => "4:2:0",
+        2 => "4:2:2",
+        3 => "4:4:4",
+        _ => "unknown",
+    }
 }
 
 #[cfg(test)]
@@ -5,9 +5,12 @@
 use std::sync::atomic::{AtomicU64, Ordering};
 
 mod audio;
+mod frame_pacer;
 mod rtp;
 mod video;
 
+pub mod decoder_check;
+
 /// Global frame ID counter for unique frame identification
 /// Used to avoid redundant GPU texture uploads
 static FRAME_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
@@ -26,7 +29,8 @@ pub mod d3d11;
 #[cfg(target_os = "windows")]
 pub mod dxva_decoder;
 
-#[cfg(target_os = "windows")]
+// Also used on other platforms to verify the stream's chroma format
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
 pub mod hevc_parser;
 
 #[cfg(target_os = "windows")]
@@ -44,8 +48,14 @@ pub mod v4l2;
 pub mod gstreamer_decoder;
 
 pub use audio::*;
+pub use frame_pacer::FramePacer;
 pub use rtp::{DepacketizerCodec, RtpDepacketizer};
-pub use video::{get_supported_decoder_backends, DecodeStats, UnifiedVideoDecoder, VideoDecoder};
+pub use video::{
+    active_gstreamer_decoder, get_supported_decoder_backends, raspberry_pi_detected,
+    recommended_codec, set_gstreamer_pipeline_override, set_pi_decoder, set_primary_gpu_name,
+    validate_gstreamer_pipeline, CodecRecommendation, DecodeStats, UnifiedVideoDecoder,
+    VideoDecoder,
+};
 
 #[cfg(target_os = "macos")]
 pub use videotoolbox::{
@@ -100,7 +110,8 @@ pub enum PixelFormat {
 #[derive(Debug, Clone)]
 pub struct VideoFrame {
     /// Unique frame ID for tracking (monotonically increasing)
-    /// Used to avoid redundant GPU uploads of the same frame
+    /// Used to avoid redundant GPU uploads of the same frame. A picture the decoder
+    /// emits again (same presentation timestamp) keeps its ID
     pub frame_id: u64,
     pub width: u32,
     pub height: u32,
@@ -115,6 +126,7 @@ pub struct VideoFrame {
     pub y_stride: u32,
     pub u_stride: u32,
     pub v_stride: u32,
+    /// Packet receive time of this frame (`utils::instant_to_us`), 0 if unknown
     pub timestamp_us: u64,
     /// Pixel format (YUV420P or NV12)
     pub format: PixelFormat,
@@ -230,7 +242,7 @@ impl VideoFrame {
         rgb
     }
 
-    /// Convert YUV to RGBA - optimized with integer math
+    /// Convert YUV420P or NV12 to RGBA - optimized with integer math
     pub fn to_rgba(&self) -> Vec<u8> {
         let pixel_count = (self.width * self.height) as usize;
         let mut rgba = vec![0u8; pixel_count * 4];
@@ -245,6 +257,8 @@ impl VideoFrame {
         let y_stride = self.y_stride as usize;
         let u_stride = self.u_stride as usize;
         let _v_stride = self.v_stride as usize;
+        // NV12 keeps U and V interleaved in u_plane
+        let nv12 = self.format == PixelFormat::NV12;
 
         for row in 0..height {
             let y_row_offset = row * y_stride;
@@ -253,13 +267,19 @@ impl VideoFrame {
 
             for col in 0..width {
                 let yi = y_row_offset + col;
-                let uvi = uv_row_offset + col / 2;
                 let rgba_i = rgba_row_offset + col * 4;
+                let (ui, vi, v_plane) = if nv12 {
+                    let uvi = uv_row_offset + (col / 2) * 2;
+                    (uvi, uvi + 1, &self.u_plane)
+                } else {
+                    let uvi = uv_row_offset + col / 2;
+                    (uvi, uvi, &self.v_plane)
+                };
 
                 // Safe bounds check with defaults
                 let y = *self.y_plane.get(yi).unwrap_or(&0) as i32;
-                let u = *self.u_plane.get(uvi).unwrap_or(&128) as i32 - 128;
-                let v = *self.v_plane.get(uvi).unwrap_or(&128) as i32 - 128;
+                let u = *self.u_plane.get(ui).unwrap_or(&128) as i32 - 128;
+                let v = *v_plane.get(vi).unwrap_or(&128) as i32 - 128;
 
                 // Integer math conversion (faster than float)
                 let r = (y + ((359 * v) >> 8)).clamp(0, 255) as u8;
@@ -290,6 +310,8 @@ pub struct StreamStats {
     pub target_fps: u32,
     /// Video bitrate in Mbps
     pub bitrate_mbps: f32,
+    /// Everything received on the connection (video, audio, retransmits) in Mbps
+    pub received_mbps: f32,
     /// Network latency in ms
     pub latency_ms: f32,
     /// Frame decode time in ms
@@ -300,6 +322,10 @@ pub struct StreamStats {
     pub input_latency_ms: f32,
     /// Video codec name
     pub codec: String,
+    /// Video decoder in use after any fallback (e.g. "GStreamer vah265dec")
+    pub decoder: String,
+    /// Whether that decoder runs on the GPU (false = CPU fallback)
+    pub hw_decode: bool,
     /// GPU type
     pub gpu_type: String,
     /// Server region
@@ -318,6 +344,8 @@ pub struct StreamStats {
     pub frames_dropped: u64,
     /// Total frames rendered
     pub frames_rendered: u64,
+    /// Decoded frames that repeated the previous frame's content (not rendered or counted)
+    pub frames_repeated: u64,
     /// Input events sent per second
     pub input_rate: f32,
     /// Frame delivery latency (RTP arrival to decode complete) in ms
@@ -330,8 +358,20 @@ pub struct StreamStats {
     pub is_hdr: bool,
     /// Color space (e.g., "BT.709", "BT.2020")
     pub color_space: String,
+    /// Chroma subsampling the server is actually sending (e.g., "4:2:0", "4:4:4"; HEVC only)
+    pub chroma_format: String,
+    /// Fullscreen mode the window actually got (e.g. "Exclusive 2560x1440 @ 165Hz", "Borderless")
+    pub window_mode: String,
     /// Number of racing wheels detected (0 = none)
     pub wheel_count: usize,
+    /// Average present-to-present frame time in ms
+    pub frame_time_ms: f32,
+    /// 1% low FPS (average FPS of the slowest 1% of frames)
+    pub low_1pct_fps: f32,
+    /// 0.1% low FPS (average FPS of the slowest 0.1% of frames)
+    pub low_01pct_fps: f32,
+    /// Recent present-to-present frame times in ms, oldest first (for the frame-time graph)
+    pub frame_time_history: Vec<f32>,
 }
 
 impl StreamStats {
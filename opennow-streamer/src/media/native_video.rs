@@ -183,7 +183,9 @@ impl NativeVideoDecoder {
                                     // Convert to VideoFrame and write to SharedFrame
                                     // Zero-copy: GPU texture passed directly to renderer
                                     let video_frame = Self::convert_decoded_frame(&decoded, is_hdr);
-                                    if let Some(frame) = video_frame {
+                                    if let Some(mut frame) = video_frame {
+                                        frame.timestamp_us =
+                                            crate::utils::instant_to_us(receive_time);
                                         shared_frame.write(frame);
                                     }
                                 }
@@ -22,7 +22,10 @@ use tokio::sync::mpsc as tokio_mpsc;
 use std::path::Path;
 
 use super::{ColorRange, ColorSpace, PixelFormat, TransferFunction, VideoFrame};
-use crate::app::{config::VideoDecoderBackend, SharedFrame, VideoCodec};
+use crate::app::{
+    config::{PiDecoder, VideoDecoderBackend},
+    SharedFrame, VideoCodec,
+};
 
 // FFmpeg imports - only for macOS
 #[cfg(target_os = "macos")]
@@ -72,39 +75,15 @@ pub fn detect_gpu_vendor() -> GpuVendor {
 
             for adapter in adapters {
                 let info = adapter.get_info();
-                let name = info.name.to_lowercase();
-                let mut score = 0;
-                let mut vendor = GpuVendor::Other;
-
-                // Identify vendor
-                if name.contains("nvidia") || name.contains("geforce") || name.contains("quadro") {
-                    vendor = GpuVendor::Nvidia;
-                    score += 100;
-                } else if name.contains("amd") || name.contains("adeon") || name.contains("ryzen") {
-                    vendor = GpuVendor::Amd;
-                    score += 80;
-                } else if name.contains("intel")
-                    || name.contains("uhd")
-                    || name.contains("iris")
-                    || name.contains("arc")
-                {
-                    vendor = GpuVendor::Intel;
-                    score += 50;
-                } else if name.contains("apple")
-                    || name.contains("m1")
-                    || name.contains("m2")
-                    || name.contains("m3")
-                {
-                    vendor = GpuVendor::Apple;
-                    score += 90; // Apple Silicon is high perf
-                } else if name.contains("videocore")
-                    || name.contains("broadcom")
-                    || name.contains("v3d")
-                    || name.contains("vc4")
-                {
-                    vendor = GpuVendor::Broadcom;
-                    score += 30; // Raspberry Pi - low power device
-                }
+                let vendor = gpu_vendor_from_name(&info.name);
+                let mut score = match vendor {
+                    GpuVendor::Nvidia => 100,
+                    GpuVendor::Apple => 90, // Apple Silicon is high perf
+                    GpuVendor::Amd => 80,
+                    GpuVendor::Intel => 50,
+                    GpuVendor::Broadcom => 30, // Raspberry Pi - low power device
+                    GpuVendor::Other | GpuVendor::Unknown => 0,
+                };
 
                 // Prioritize discrete GPUs
                 match info.device_type {
@@ -145,25 +124,7 @@ pub fn detect_gpu_vendor() -> GpuVendor {
 
                 // Handle Result
                 if let Ok(adapter) = adapter_result {
-                    let info = adapter.get_info();
-                    let name = info.name.to_lowercase();
-
-                    if name.contains("nvidia") {
-                        GpuVendor::Nvidia
-                    } else if name.contains("intel") {
-                        GpuVendor::Intel
-                    } else if name.contains("amd") {
-                        GpuVendor::Amd
-                    } else if name.contains("apple") {
-                        GpuVendor::Apple
-                    } else if name.contains("videocore")
-                        || name.contains("broadcom")
-                        || name.contains("v3d")
-                    {
-                        GpuVendor::Broadcom
-                    } else {
-                        GpuVendor::Other
-                    }
+                    gpu_vendor_from_name(&adapter.get_info().name)
                 } else {
                     GpuVendor::Unknown
                 }
@@ -172,6 +133,175 @@ pub fn detect_gpu_vendor() -> GpuVendor {
     })
 }
 
+/// GPU vendor from an adapter name
+fn gpu_vendor_from_name(name: &str) -> GpuVendor {
+    let name = name.to_lowercase();
+    if name.contains("nvidia") || name.contains("geforce") || name.contains("quadro") {
+        GpuVendor::Nvidia
+    } else if name.contains("amd") || name.contains("adeon") || name.contains("ryzen") {
+        GpuVendor::Amd
+    } else if name.contains("intel")
+        || name.contains("uhd")
+        || name.contains("iris")
+        || name.contains("arc")
+    {
+        GpuVendor::Intel
+    } else if name.contains("apple")
+        || name.contains("m1")
+        || name.contains("m2")
+        || name.contains("m3")
+    {
+        GpuVendor::Apple
+    } else if name.contains("videocore")
+        || name.contains("broadcom")
+        || name.contains("v3d")
+        || name.contains("vc4")
+    {
+        GpuVendor::Broadcom
+    } else {
+        GpuVendor::Other
+    }
+}
+
+/// Name of the adapter the renderer uses (set once it's created)
+static PRIMARY_GPU_NAME: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+
+/// Record the renderer's adapter, so the codec recommendation judges the GPU that
+/// actually displays the stream
+pub fn set_primary_gpu_name(name: &str) {
+    let _ = PRIMARY_GPU_NAME.set(name.to_string());
+}
+
+/// Codec suggested for this machine, with the reason shown in settings
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CodecRecommendation {
+    pub codec: VideoCodec,
+    pub reason: &'static str,
+}
+
+/// Cached hardware-based codec recommendation
+static CODEC_RECOMMENDATION: std::sync::OnceLock<CodecRecommendation> = std::sync::OnceLock::new();
+
+/// Recommend the most efficient codec this machine decodes in hardware
+/// Considers Raspberry Pi models, available decoder backends and the GPU generation.
+pub fn recommended_codec(backend: VideoDecoderBackend) -> CodecRecommendation {
+    if backend == VideoDecoderBackend::Software {
+        return CodecRecommendation {
+            codec: VideoCodec::H264,
+            reason: "Software decoding - H.264 is the lightest on the CPU",
+        };
+    }
+
+    if let Some(recommendation) = CODEC_RECOMMENDATION.get() {
+        return *recommendation;
+    }
+    let recommendation = detect_codec_recommendation();
+    // Until the renderer names its adapter the GPU generation is unknown, so don't
+    // cache that guess
+    if PRIMARY_GPU_NAME.get().is_some() && CODEC_RECOMMENDATION.set(recommendation).is_ok() {
+        info!(
+            "Recommended codec: {:?} ({})",
+            recommendation.codec, recommendation.reason
+        );
+    }
+    recommendation
+}
+
+fn detect_codec_recommendation() -> CodecRecommendation {
+    #[cfg(target_os = "linux")]
+    if raspberry_pi_detected() {
+        use super::v4l2::{get_recommended_codec, V4L2Codec};
+        return match get_recommended_codec() {
+            Some(V4L2Codec::HEVC) => CodecRecommendation {
+                codec: VideoCodec::H265,
+                reason: "Raspberry Pi 5 decodes HEVC in hardware",
+            },
+            Some(V4L2Codec::H264) | None => CodecRecommendation {
+                codec: VideoCodec::H264,
+                reason: "This Raspberry Pi only decodes H.264 in hardware",
+            },
+        };
+    }
+
+    let software_only = get_supported_decoder_backends()
+        .iter()
+        .all(|b| matches!(b, VideoDecoderBackend::Auto | VideoDecoderBackend::Software));
+    if software_only {
+        return CodecRecommendation {
+            codec: VideoCodec::H264,
+            reason: "No hardware decoder found - H.264 is the lightest on the CPU",
+        };
+    }
+
+    match PRIMARY_GPU_NAME.get() {
+        Some(name) => codec_for_gpu(gpu_vendor_from_name(name), &name.to_lowercase()),
+        None => codec_for_gpu(detect_gpu_vendor(), ""),
+    }
+}
+
+/// Best hardware-decoded codec for a GPU (`name` lowercase)
+fn codec_for_gpu(vendor: GpuVendor, name: &str) -> CodecRecommendation {
+    let av1 = CodecRecommendation {
+        codec: VideoCodec::AV1,
+        reason: "Your GPU decodes AV1 in hardware",
+    };
+    let hevc = CodecRecommendation {
+        codec: VideoCodec::H265,
+        reason: "Your GPU decodes HEVC in hardware",
+    };
+    let h264 = CodecRecommendation {
+        codec: VideoCodec::H264,
+        reason: "H.264 is the most widely supported in hardware",
+    };
+
+    match vendor {
+        // AV1 decode: RTX 30 series and newer
+        GpuVendor::Nvidia => {
+            if ["rtx 30", "rtx 40", "rtx 50", "rtx a"]
+                .iter()
+                .any(|gen| name.contains(gen))
+            {
+                av1
+            } else {
+                hevc
+            }
+        }
+        // AV1 decode: RDNA2 and newer, except Navi 24 (RX 6400/6500)
+        GpuVendor::Amd => {
+            let rdna2_or_newer = ["rx 6", "rx 7", "rx 9"]
+                .iter()
+                .any(|gen| name.contains(gen));
+            if rdna2_or_newer && !name.contains("6400") && !name.contains("6500") {
+                av1
+            } else {
+                hevc
+            }
+        }
+        // AV1 decode: Xe (11th gen) and Arc; no HEVC before Skylake
+        GpuVendor::Intel => {
+            if name.contains("arc") || name.contains("xe") {
+                av1
+            } else if name.contains("hd graphics 4000")
+                || name.contains("hd graphics 2500")
+                || name.contains("hd graphics 3000")
+            {
+                h264
+            } else {
+                hevc
+            }
+        }
+        // AV1 decode: M3 and newer
+        GpuVendor::Apple => {
+            if name.contains("m3") || name.contains("m4") {
+                av1
+            } else {
+                hevc
+            }
+        }
+        GpuVendor::Broadcom | GpuVendor::Other | GpuVendor::Unknown => h264,
+    }
+}
+
 /// Check if Intel QSV runtime is available on the system
 /// Returns true if the required DLLs are found
 #[cfg(target_os = "windows")]
@@ -238,7 +368,7 @@ fn is_qsv_runtime_available() -> bool {
     false
 }
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 fn is_qsv_runtime_available() -> bool {
     // On Linux, check for libmfx.so or libvpl.so
     use std::process::Command;
@@ -261,8 +391,10 @@ fn is_qsv_runtime_available() -> bool {
 }
 
 /// Cached QSV availability check (only check once at startup)
+#[cfg(not(target_os = "linux"))]
 static QSV_AVAILABLE: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
 
+#[cfg(not(target_os = "linux"))]
 fn check_qsv_available() -> bool {
     *QSV_AVAILABLE.get_or_init(|| {
         let available = is_qsv_runtime_available();
@@ -358,59 +490,192 @@ pub fn get_supported_decoder_backends() -> Vec<VideoDecoderBackend> {
                 backends.push(VideoDecoderBackend::VideoToolbox);
             }
 
+            // Every Windows backend is probed so the dropdown never offers a decoder
+            // that fails to initialize
             #[cfg(target_os = "windows")]
             {
-                let gpu = detect_gpu_vendor();
-                let qsv = check_qsv_available();
-
-                // GStreamer D3D11 decoder - supports both H.264 and HEVC
-                // This is the recommended decoder for Windows (stable, works on all GPUs)
-                backends.push(VideoDecoderBackend::Dxva);
-
-                // Native D3D11VA decoder (HEVC only) - EXPERIMENTAL
-                // Uses direct D3D11 Video API for zero-copy decoding
-                // WARNING: Only supports HEVC. H.264 streams will fail!
-                backends.push(VideoDecoderBackend::NativeDxva);
-
-                // GPU-specific accelerators
-                if gpu == GpuVendor::Nvidia {
-                    backends.push(VideoDecoderBackend::Cuvid);
-                }
-
-                if qsv || gpu == GpuVendor::Intel {
-                    backends.push(VideoDecoderBackend::Qsv);
+                for backend in [
+                    VideoDecoderBackend::Dxva,
+                    VideoDecoderBackend::NativeDxva,
+                    VideoDecoderBackend::Cuvid,
+                    VideoDecoderBackend::Qsv,
+                    VideoDecoderBackend::Software,
+                ] {
+                    if probe_windows_backend(backend) {
+                        backends.push(backend);
+                    }
                 }
             }
 
             #[cfg(target_os = "linux")]
             {
-                let gpu = detect_gpu_vendor();
-                let qsv = check_qsv_available();
+                use super::gstreamer_decoder::{
+                    is_gstreamer_available, is_gstreamer_v4l2_available, is_gstreamer_va_available,
+                };
 
-                // GStreamer with hardware acceleration is the preferred decoder on Linux
-                // It automatically selects the best available hardware decoder (VAAPI, NVDEC, etc.)
-                if super::gstreamer_decoder::is_gstreamer_available() {
+                // GStreamer is the preferred decoder on Linux. "GStreamer HW" lets it pick
+                // the best element; VA-API and V4L2 force one path for hardware where the
+                // automatic choice misbehaves.
+                if is_gstreamer_available() {
                     backends.push(VideoDecoderBackend::VulkanVideo); // GStreamer-based hardware decode
-                }
 
-                if gpu == GpuVendor::Nvidia {
-                    backends.push(VideoDecoderBackend::Cuvid);
-                }
+                    if is_gstreamer_va_available() && super::vaapi::is_vaapi_available() {
+                        backends.push(VideoDecoderBackend::Vaapi);
+                    }
 
-                if qsv || gpu == GpuVendor::Intel {
-                    backends.push(VideoDecoderBackend::Qsv);
-                }
+                    let v4l2_device = super::v4l2::is_v4l2_available(super::v4l2::V4L2Codec::H264)
+                        || super::v4l2::is_v4l2_available(super::v4l2::V4L2Codec::HEVC);
+                    if is_gstreamer_v4l2_available() && v4l2_device {
+                        backends.push(VideoDecoderBackend::V4l2);
+                    }
 
-                // VAAPI is generally available on Linux (AMD/Intel)
-                backends.push(VideoDecoderBackend::Vaapi);
+                    // avdec_h264/avdec_h265
+                    backends.push(VideoDecoderBackend::Software);
+                }
+                // Without GStreamer there is no decoder on Linux (FFmpeg is macOS-only),
+                // so only Auto is listed and the session reports the missing plugins
             }
 
+            #[cfg(target_os = "macos")]
             backends.push(VideoDecoderBackend::Software);
+
             backends
         })
         .clone()
 }
 
+/// Use a custom GStreamer decode pipeline for new decoders (None = built-in pipeline)
+/// No-op on platforms without GStreamer.
+pub fn set_gstreamer_pipeline_override(pipeline: Option<String>) {
+    #[cfg(any(target_os = "linux", all(windows, target_arch = "x86_64")))]
+    super::gstreamer_decoder::set_pipeline_override(pipeline);
+    #[cfg(not(any(target_os = "linux", all(windows, target_arch = "x86_64"))))]
+    let _ = pipeline;
+}
+
+/// Check a custom GStreamer pipeline before saving it
+pub fn validate_gstreamer_pipeline(description: &str) -> Result<(), String> {
+    #[cfg(any(target_os = "linux", all(windows, target_arch = "x86_64")))]
+    return super::gstreamer_decoder::validate_pipeline(description).map_err(|e| e.to_string());
+    #[cfg(not(any(target_os = "linux", all(windows, target_arch = "x86_64"))))]
+    {
+        let _ = description;
+        Err("GStreamer is not available on this platform".to_string())
+    }
+}
+
+/// Cached Raspberry Pi detection (reads /sys and /proc, so not done per frame)
+static RASPBERRY_PI: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+
+/// Whether this is a Raspberry Pi (where the Pi decode path setting applies)
+pub fn raspberry_pi_detected() -> bool {
+    *RASPBERRY_PI.get_or_init(|| {
+        #[cfg(target_os = "linux")]
+        return super::v4l2::is_raspberry_pi();
+        #[cfg(not(target_os = "linux"))]
+        false
+    })
+}
+
+/// Choose the Raspberry Pi decode path for new decoders. No-op off Linux.
+pub fn set_pi_decoder(pi_decoder: PiDecoder) {
+    #[cfg(target_os = "linux")]
+    super::gstreamer_decoder::set_pi_decoder(pi_decoder);
+    #[cfg(not(target_os = "linux"))]
+    let _ = pi_decoder;
+}
+
+/// GStreamer decoder element used by the most recent decoder (e.g. "v4l2h264dec")
+pub fn active_gstreamer_decoder() -> Option<String> {
+    #[cfg(any(target_os = "linux", all(windows, target_arch = "x86_64")))]
+    return super::gstreamer_decoder::active_decoder_element();
+    #[cfg(not(any(target_os = "linux", all(windows, target_arch = "x86_64"))))]
+    None
+}
+
+/// Whether a GStreamer decoder element decodes on the CPU (e.g. "avdec_h265", "dav1ddec")
+#[cfg(any(target_os = "linux", all(windows, target_arch = "x86_64")))]
+fn is_software_gstreamer_element(element: &str) -> bool {
+    element.starts_with("avdec_")
+        || element.starts_with("dav1d")
+        || element.starts_with("openh264")
+        || element.starts_with("libde265")
+        || element == "av1dec"
+}
+
+/// Whether the most recent GStreamer pipeline decodes in hardware (unknown elements count as hardware)
+#[cfg(any(target_os = "linux", all(windows, target_arch = "x86_64")))]
+fn gstreamer_hw_accel() -> bool {
+    super::gstreamer_decoder::active_decoder_element()
+        .is_none_or(|element| !is_software_gstreamer_element(&element))
+}
+
+/// "GStreamer <element>" for the most recent pipeline
+#[cfg(any(target_os = "linux", all(windows, target_arch = "x86_64")))]
+fn gstreamer_backend_name() -> String {
+    match super::gstreamer_decoder::active_decoder_element() {
+        Some(element) => format!("GStreamer {}", element),
+        None => "GStreamer".to_string(),
+    }
+}
+
+/// Runtime capability probe for a Windows decoder backend
+#[cfg(target_os = "windows")]
+fn probe_windows_backend(backend: VideoDecoderBackend) -> bool {
+    let available = match backend {
+        // Native D3D11VA decoder (HEVC only) - create a video device and check the HEVC profile
+        VideoDecoderBackend::NativeDxva => {
+            match super::dxva_decoder::DxvaDecoder::check_resolution_support(
+                super::dxva_decoder::DxvaCodec::HEVC,
+                1920,
+                1080,
+                false,
+            ) {
+                Ok(supported) => supported,
+                Err(e) => {
+                    warn!("Native DXVA probe failed: {}", e);
+                    false
+                }
+            }
+        }
+        // GPU-specific accelerators also need their GStreamer plugin
+        VideoDecoderBackend::Cuvid => {
+            detect_gpu_vendor() == GpuVendor::Nvidia && gstreamer_backend_available(backend)
+        }
+        VideoDecoderBackend::Qsv => {
+            (check_qsv_available() || detect_gpu_vendor() == GpuVendor::Intel)
+                && gstreamer_backend_available(backend)
+        }
+        // GStreamer D3D11 and software decoders
+        _ => gstreamer_backend_available(backend),
+    };
+
+    info!(
+        "Decoder backend {}: {}",
+        backend.as_str(),
+        if available {
+            "available"
+        } else {
+            "unavailable"
+        }
+    );
+    available
+}
+
+/// Whether GStreamer has the decoder elements for `backend` (no GStreamer on Windows ARM64)
+#[cfg(target_os = "windows")]
+fn gstreamer_backend_available(backend: VideoDecoderBackend) -> bool {
+    #[cfg(target_arch = "x86_64")]
+    {
+        super::gstreamer_decoder::is_gstreamer_backend_available(backend)
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        let _ = backend;
+        false
+    }
+}
+
 /// Commands sent to the decoder thread
 enum DecoderCommand {
     /// Decode a packet and return result via channel (blocking mode)
@@ -516,12 +781,12 @@ impl VideoDecoder {
         // GStreamer automatically selects the best available backend (VAAPI, NVDEC, V4L2, etc.)
         #[cfg(target_os = "linux")]
         {
-            // Use GStreamer decoder (auto-selects V4L2/VAAPI/NVDEC/software)
-            // The GStreamer decoder automatically selects the best available backend
+            // Use GStreamer decoder (auto-selects V4L2/VAAPI/software unless a backend is chosen)
             if super::gstreamer_decoder::is_gstreamer_available() {
                 info!(
-                    "Using GStreamer decoder for {:?} (auto-selects V4L2/VA/VAAPI/software)",
-                    codec
+                    "Using GStreamer decoder for {:?} (backend: {})",
+                    codec,
+                    backend.as_str()
                 );
 
                 let gst_codec = match codec {
@@ -535,6 +800,7 @@ impl VideoDecoder {
                     width: 1920,
                     height: 1080,
                     low_latency: true, // Enable low latency for streaming
+                    backend,
                 };
 
                 let gst_decoder = super::gstreamer_decoder::GStreamerDecoder::new(config)
@@ -576,9 +842,11 @@ impl VideoDecoder {
                                     consecutive_failures == KEYFRAME_REQUEST_THRESHOLD
                                 };
 
-                                if let Ok(Some(frame)) = result {
+                                if let Ok(Some(mut frame)) = result {
                                     frames_decoded += 1;
                                     if frames_decoded > FRAMES_TO_SKIP {
+                                        frame.timestamp_us =
+                                            crate::utils::instant_to_us(receive_time);
                                         shared_frame_clone.write(frame);
                                     }
                                 }
@@ -599,7 +867,7 @@ impl VideoDecoder {
                     cmd_tx,
                     frame_rx,
                     stats_rx: None,
-                    hw_accel: true,
+                    hw_accel: gstreamer_hw_accel(),
                     frames_decoded: 0,
                     shared_frame: Some(shared_frame),
                 };
@@ -786,9 +1054,10 @@ impl VideoDecoder {
                         // Write frame directly to SharedFrame (zero-copy handoff)
                         // Skip first few frames to let decoder settle with proper reference frames
                         // This prevents green/corrupted frames during stream startup
-                        if let Some(frame) = result {
+                        if let Some(mut frame) = result {
                             if frames_decoded > FRAMES_TO_SKIP {
                                 if let Some(ref sf) = shared_frame {
+                                    frame.timestamp_us = crate::utils::instant_to_us(receive_time);
                                     sf.write(frame);
                                 }
                             } else {
@@ -2312,6 +2581,8 @@ pub struct GStreamerDecoderWrapper {
     frames_decoded: u64,
     /// Track consecutive failures for keyframe request
     consecutive_failures: u32,
+    /// False when the pipeline fell back to a software element (e.g. avdec_h265)
+    hw_accel: bool,
 }
 
 #[cfg(target_os = "macos")]
@@ -2397,6 +2668,7 @@ impl UnifiedVideoDecoder {
                 width: 1920,
                 height: 1080,
                 low_latency: true,
+                backend,
             };
 
             let gst_decoder = super::gstreamer_decoder::GStreamerDecoder::new(gst_config)
@@ -2412,6 +2684,7 @@ impl UnifiedVideoDecoder {
                 stats_tx,
                 frames_decoded: 0,
                 consecutive_failures: 0,
+                hw_accel: gstreamer_hw_accel(),
             };
 
             return Ok((UnifiedVideoDecoder::GStreamer(wrapper), stats_rx));
@@ -2491,7 +2764,25 @@ impl UnifiedVideoDecoder {
             #[cfg(windows)]
             UnifiedVideoDecoder::Native(decoder) => decoder.is_hw_accel(),
             #[cfg(all(windows, target_arch = "x86_64"))]
-            UnifiedVideoDecoder::GStreamer(_) => true, // GStreamer uses D3D11 hardware acceleration
+            UnifiedVideoDecoder::GStreamer(wrapper) => wrapper.hw_accel,
+        }
+    }
+
+    /// Decoder actually in use after any fallback (e.g. "GStreamer vah265dec", "VideoToolbox")
+    pub fn backend_name(&self) -> String {
+        match self {
+            #[cfg(target_os = "macos")]
+            UnifiedVideoDecoder::Ffmpeg(decoder) if decoder.is_hw_accelerated() => {
+                "VideoToolbox".to_string()
+            }
+            #[cfg(target_os = "macos")]
+            UnifiedVideoDecoder::Ffmpeg(_) => "FFmpeg".to_string(),
+            #[cfg(target_os = "linux")]
+            UnifiedVideoDecoder::Ffmpeg(_) => gstreamer_backend_name(),
+            #[cfg(windows)]
+            UnifiedVideoDecoder::Native(_) => "Native DXVA".to_string(),
+            #[cfg(all(windows, target_arch = "x86_64"))]
+            UnifiedVideoDecoder::GStreamer(_) => gstreamer_backend_name(),
         }
     }
 
@@ -2518,9 +2809,10 @@ impl GStreamerDecoderWrapper {
         let decode_start = std::time::Instant::now();
 
         match self.decoder.decode(data) {
-            Ok(Some(frame)) => {
+            Ok(Some(mut frame)) => {
                 self.frames_decoded += 1;
                 self.consecutive_failures = 0;
+                frame.timestamp_us = crate::utils::instant_to_us(receive_time);
                 self.shared_frame.write(frame);
 
                 // Measure decode time from when we started pushing data
@@ -0,0 +1,224 @@
+//! Crash Reports
+//!
+//! Panic hook that writes a crash report (panic message, backtrace, system/GPU info
+//! and the log tail) next to the log file when the main thread panics and the app
+//! goes down. A marker file points the next launch at the report so it can be shown
+//! to the user. Panics in background threads and tasks are recovered, so they are
+//! only logged.
+
+use std::backtrace::Backtrace;
+use std::panic::PanicHookInfo;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// GPU adapter description for reports (set once the renderer is up)
+static CRASH_GPU: OnceLock<String> = OnceLock::new();
+
+/// Marker naming the report written by the last crash (removed once shown)
+const PENDING_MARKER: &str = "crash_pending.txt";
+
+/// Crash reports kept in the data directory (older ones are deleted)
+const MAX_CRASH_REPORTS: usize = 5;
+
+/// Crash report left by a previous run
+#[derive(Debug, Clone)]
+pub struct CrashReport {
+    /// Report file
+    pub path: PathBuf,
+    /// Panic message
+    pub message: String,
+}
+
+/// Record the GPU adapter for crash reports
+pub fn set_crash_gpu(description: String) {
+    let _ = CRASH_GPU.set(description);
+}
+
+/// Write a crash report when the main thread panics, then run the default hook (stderr trace)
+pub fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        // Tokio and worker threads recover from their panics; only the main thread ends the app
+        if std::thread::current().name() != Some("main") {
+            log::error!(
+                "Panic in thread {}: {} at {}",
+                std::thread::current().name().unwrap_or("unnamed"),
+                panic_message(info),
+                info.location()
+                    .map_or_else(|| "unknown".to_string(), |l| l.to_string())
+            );
+            default_hook(info);
+            return;
+        }
+        match write_crash_report(info) {
+            Ok(path) => {
+                log::error!("Crash report written to {}", path.display());
+                show_crash_dialog(&path);
+            }
+            Err(e) => log::error!("Failed to write crash report: {}", e),
+        }
+        default_hook(info);
+    }));
+}
+
+/// Report written by the previous run's crash, if it hasn't been shown yet
+pub fn take_pending_crash_report() -> Option<CrashReport> {
+    prune_crash_reports(&super::get_app_data_dir(), MAX_CRASH_REPORTS);
+    let marker = super::get_app_data_dir().join(PENDING_MARKER);
+    let content = std::fs::read_to_string(&marker).ok()?;
+    let _ = std::fs::remove_file(&marker);
+
+    let (path, message) = content.split_once('\n').unwrap_or((content.as_str(), ""));
+    let path = PathBuf::from(path);
+    path.exists().then(|| CrashReport {
+        path,
+        message: message.trim().to_string(),
+    })
+}
+
+fn write_crash_report(info: &PanicHookInfo) -> std::io::Result<PathBuf> {
+    let message = panic_message(info);
+    let location = info
+        .location()
+        .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()))
+        .unwrap_or_else(|| "unknown".to_string());
+    let thread = std::thread::current();
+
+    let report = format!(
+        "OpenNOW crash report\n\
+         ====================\n\
+         Time: {}\n\
+         Version: v{}\n\
+         System: {} / {}\n\
+         GPU: {}\n\
+         Thread: {}\n\
+         Panic: {}\n\
+         Location: {}\n\n\
+         Backtrace:\n{}\n\n\
+         Recent log:\n{}\n",
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
+        env!("CARGO_PKG_VERSION"),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        CRASH_GPU.get().map_or("unknown", |gpu| gpu.as_str()),
+        thread.name().unwrap_or("unnamed"),
+        message,
+        location,
+        Backtrace::force_capture(),
+        super::issue_report::read_log_tail(),
+    );
+
+    let dir = super::get_app_data_dir();
+    super::ensure_dir(&dir)?;
+    let path = dir.join(format!(
+        "crash-{}.txt",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    std::fs::write(&path, report)?;
+    std::fs::write(
+        dir.join(PENDING_MARKER),
+        format!("{}\n{}", path.display(), message),
+    )?;
+    Ok(path)
+}
+
+/// Delete all but the newest `keep` crash reports in `dir`
+fn prune_crash_reports(dir: &std::path::Path, keep: usize) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    // Names are crash-YYYYmmdd-HHMMSS.txt, so they sort by time
+    let mut reports: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("crash-") && name.ends_with(".txt"))
+        })
+        .collect();
+    reports.sort();
+    let excess = reports.len().saturating_sub(keep);
+    for path in &reports[..excess] {
+        if let Err(e) = std::fs::remove_file(path) {
+            log::warn!(
+                "Failed to remove old crash report {}: {}",
+                path.display(),
+                e
+            );
+        }
+    }
+}
+
+fn panic_message(info: &PanicHookInfo) -> String {
+    let payload = info.payload();
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Unknown panic".to_string())
+}
+
+/// Tell the user where the report went (the window is gone, so use a native message box)
+#[cfg(windows)]
+fn show_crash_dialog(path: &std::path::Path) {
+    use windows::core::HSTRING;
+    use windows::Win32::UI::WindowsAndMessaging::{MessageBoxW, MB_ICONERROR, MB_OK};
+
+    let text = format!(
+        "OpenNOW crashed. A crash report was saved to:\n\n{}\n\nPlease attach it when reporting the issue.",
+        path.display()
+    );
+    unsafe {
+        MessageBoxW(
+            None,
+            &HSTRING::from(text),
+            &HSTRING::from("OpenNOW crashed"),
+            MB_OK | MB_ICONERROR,
+        );
+    }
+}
+
+/// Tell the user where the report went (shown in the app again on next launch)
+#[cfg(not(windows))]
+fn show_crash_dialog(path: &std::path::Path) {
+    super::console_print(&format!(
+        "[CRASH] OpenNOW crashed. Report saved to {}",
+        path.display()
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prune_keeps_newest_reports() {
+        let dir = std::env::temp_dir().join(format!("opennow-crash-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in [
+            "crash-20260101-100000.txt",
+            "crash-20260102-100000.txt",
+            "crash-20260103-100000.txt",
+            "opennow.log",
+        ] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+
+        prune_crash_reports(&dir, 2);
+
+        let mut left: Vec<String> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        left.sort();
+        let _ = std::fs::remove_dir_all(&dir);
+        assert_eq!(
+            left,
+            [
+                "crash-20260102-100000.txt",
+                "crash-20260103-100000.txt",
+                "opennow.log"
+            ]
+        );
+    }
+}
@@ -0,0 +1,387 @@
+//! Deep Links
+//!
+//! `opennow://launch/<app_id>` (or `opennow://launch?id=<app_id>`) URLs let websites
+//! and shortcuts start a game without going through the menu. The URL arrives as a
+//! command line argument (Windows, Linux) or an Apple Event (macOS) and waits here
+//! until the app is ready to launch. Links clicked while OpenNOW is already running
+//! start a second copy, which hands the link to the running one over localhost and exits.
+//!
+//! The running copy writes a random token to the config dir (readable only by the
+//! user) and only accepts links that carry it, replying so the second copy knows it
+//! reached OpenNOW before exiting. Forwarded links still ask before launching.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use log::{debug, info, warn};
+use parking_lot::Mutex;
+
+/// Custom URL scheme registered with the OS
+pub const URL_SCHEME: &str = "opennow";
+
+/// Where the running instance listens for links opened by later instances
+const FORWARD_ADDR: ([u8; 4], u16) = ([127, 0, 0, 1], 47321);
+
+/// Reply that tells a later instance the link reached OpenNOW
+const FORWARD_ACK: &str = "OPENNOW";
+
+/// Launch requested by a deep link
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingLaunch {
+    pub app_id: String,
+    /// Handed over by another process rather than the OS, so confirm before launching
+    pub forwarded: bool,
+}
+
+/// Launch from a deep link, waiting for the game list to be ready
+static PENDING_LAUNCH: Mutex<Option<PendingLaunch>> = Mutex::new(None);
+
+/// Wakes the event loop when a link arrives while the menus are idle
+static WAKER: Mutex<Option<Box<dyn Fn() + Send>>> = Mutex::new(None);
+
+/// App ID from an `opennow://launch/<app_id>` or `opennow://launch?id=<app_id>` URL
+pub fn parse_launch_url(url: &str) -> Option<String> {
+    let rest = url.trim().strip_prefix(URL_SCHEME)?.strip_prefix("://")?;
+    let rest = rest.split('#').next().unwrap_or_default();
+    let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let app_id = match path.trim_end_matches('/').strip_prefix("launch")? {
+        "" => query
+            .split('&')
+            .find_map(|param| param.strip_prefix("id="))?,
+        id => id.strip_prefix('/')?,
+    };
+    let valid = !app_id.is_empty()
+        && app_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    valid.then(|| app_id.to_string())
+}
+
+/// Queue a launch from a deep link URL (ignored if it isn't one)
+pub fn handle_deep_link(url: &str) {
+    queue_launch(url, false);
+}
+
+fn queue_launch(url: &str, forwarded: bool) {
+    match parse_launch_url(url) {
+        Some(app_id) => {
+            info!(
+                "Deep link: launch app {} (forwarded: {})",
+                app_id, forwarded
+            );
+            *PENDING_LAUNCH.lock() = Some(PendingLaunch { app_id, forwarded });
+            if let Some(wake) = WAKER.lock().as_ref() {
+                wake();
+            }
+        }
+        None => warn!("Ignoring unsupported deep link: {}", url),
+    }
+}
+
+/// Deep link passed on the command line, if any
+/// (`--launch-game <app_id>`, used by game shortcuts, counts as a launch link)
+pub fn deep_link_arg() -> Option<String> {
+    let prefix = format!("{}://", URL_SCHEME);
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--launch-game" {
+            return args
+                .next()
+                .map(|app_id| format!("{}://launch/{}", URL_SCHEME, app_id));
+        }
+        if arg.starts_with(&prefix) {
+            return Some(arg);
+        }
+    }
+    None
+}
+
+/// Set how to wake the event loop when a link arrives
+pub fn set_deep_link_waker(wake: impl Fn() + Send + 'static) {
+    *WAKER.lock() = Some(Box::new(wake));
+}
+
+/// Token the running instance expects with forwarded links
+fn forward_token_path() -> PathBuf {
+    super::get_app_data_dir().join("deep_link.token")
+}
+
+/// Write a fresh token only this user can read
+fn write_forward_token(token: &str) -> std::io::Result<()> {
+    let path = forward_token_path();
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)?.write_all(token.as_bytes())
+}
+
+/// Hand a deep link to an already running OpenNOW; true only if it confirmed
+pub fn forward_to_running_instance(url: &str) -> bool {
+    let Ok(token) = std::fs::read_to_string(forward_token_path()) else {
+        return false;
+    };
+    let addr = SocketAddr::from(FORWARD_ADDR);
+    let Ok(mut stream) = TcpStream::connect_timeout(&addr, Duration::from_millis(300)) else {
+        return false;
+    };
+    let _ = stream.set_read_timeout(Some(Duration::from_secs(2)));
+    if stream
+        .write_all(format!("{}\n{}\n", token.trim(), url).as_bytes())
+        .is_err()
+    {
+        return false;
+    }
+    let mut reply = String::new();
+    let acknowledged = BufReader::new(stream.take(64))
+        .read_line(&mut reply)
+        .is_ok()
+        && reply.trim() == FORWARD_ACK;
+    if !acknowledged {
+        warn!("Deep link port is held by something other than OpenNOW");
+    }
+    acknowledged
+}
+
+/// Accept deep links forwarded by later instances
+pub fn listen_for_forwarded_links() {
+    let listener = match TcpListener::bind(SocketAddr::from(FORWARD_ADDR)) {
+        Ok(listener) => listener,
+        Err(e) => {
+            // Another copy (or another app) has the port - links then open a new window
+            debug!("Not listening for forwarded deep links: {}", e);
+            return;
+        }
+    };
+    let token = uuid::Uuid::new_v4().simple().to_string();
+    if let Err(e) = write_forward_token(&token) {
+        warn!("Not listening for forwarded deep links: {}", e);
+        return;
+    }
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let _ = stream.set_read_timeout(Some(Duration::from_secs(2)));
+            let Ok(mut reply) = stream.try_clone() else {
+                continue;
+            };
+            let mut reader = BufReader::new(stream.take(1024));
+            let (mut sent_token, mut url) = (String::new(), String::new());
+            if reader.read_line(&mut sent_token).is_err() || reader.read_line(&mut url).is_err() {
+                continue;
+            }
+            if sent_token.trim() != token {
+                warn!("Rejected a forwarded deep link with the wrong token");
+                continue;
+            }
+            let _ = reply.write_all(format!("{}\n", FORWARD_ACK).as_bytes());
+            queue_launch(&url, true);
+        }
+    });
+}
+
+/// Launch waiting to be started since the last call
+pub fn take_pending_launch() -> Option<PendingLaunch> {
+    PENDING_LAUNCH.lock().take()
+}
+
+/// Register this executable as the `opennow://` handler if it isn't already (checked
+/// on every start so the handler follows the app when it's moved or updated).
+/// Debug builds leave the installed app's handler alone
+#[cfg(target_os = "windows")]
+pub fn register_url_scheme() {
+    use std::os::windows::process::CommandExt;
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    if cfg!(debug_assertions) {
+        return;
+    }
+    let Some(exe) = super::launcher_exe() else {
+        return;
+    };
+    let key = format!(r"HKCU\Software\Classes\{}", URL_SCHEME);
+    let command = format!("\"{}\" \"%1\"", exe.display());
+
+    let current = std::process::Command::new("reg")
+        .args(["query", &format!(r"{}\shell\open\command", key), "/ve"])
+        .creation_flags(CREATE_NO_WINDOW)
+        .output();
+    if current
+        .is_ok_and(|o| o.status.success() && String::from_utf8_lossy(&o.stdout).contains(&command))
+    {
+        return;
+    }
+
+    let entries: [(String, &str, &str); 3] = [
+        (key.clone(), "", "URL:OpenNOW"),
+        (key.clone(), "URL Protocol", ""),
+        (format!(r"{}\shell\open\command", key), "", &command),
+    ];
+
+    for (key, name, value) in entries {
+        let mut reg = std::process::Command::new("reg");
+        reg.args(["add", key.as_str()]);
+        if name.is_empty() {
+            reg.arg("/ve");
+        } else {
+            reg.args(["/v", name]);
+        }
+        let output = reg
+            .args(["/d", value, "/f"])
+            .creation_flags(CREATE_NO_WINDOW)
+            .output();
+        if !output.is_ok_and(|o| o.status.success()) {
+            warn!("Failed to register {}:// URL handler", URL_SCHEME);
+            return;
+        }
+    }
+    info!("Registered {}:// URL handler", URL_SCHEME);
+}
+
+/// Register this executable as the `opennow://` handler if it isn't already (checked
+/// on every start so the handler follows the app when it's moved or updated).
+/// Debug builds leave the installed app's handler alone
+#[cfg(target_os = "linux")]
+pub fn register_url_scheme() {
+    const DESKTOP_FILE: &str = "opennow-url-handler.desktop";
+
+    if cfg!(debug_assertions) {
+        return;
+    }
+    // The Flatpak's exported desktop file already claims the scheme
+    if std::env::var_os("FLATPAK_ID").is_some() {
+        return;
+    }
+
+    let Some(exe) = super::launcher_exe() else {
+        return;
+    };
+    let Some(applications) = dirs::data_dir().map(|d| d.join("applications")) else {
+        return;
+    };
+    let entry = format!(
+        "[Desktop Entry]\nType=Application\nName=OpenNOW\nExec=\"{}\" %u\nNoDisplay=true\nMimeType=x-scheme-handler/{};\n",
+        exe.display(),
+        URL_SCHEME
+    );
+    let path = applications.join(DESKTOP_FILE);
+    let mime_type = format!("x-scheme-handler/{}", URL_SCHEME);
+    let is_default = || {
+        std::process::Command::new("xdg-mime")
+            .args(["query", "default", &mime_type])
+            .output()
+            .is_ok_and(|o| String::from_utf8_lossy(&o.stdout).trim() == DESKTOP_FILE)
+    };
+    if std::fs::read_to_string(&path).is_ok_and(|existing| existing == entry) && is_default() {
+        return;
+    }
+    if let Err(e) =
+        std::fs::create_dir_all(&applications).and_then(|_| std::fs::write(&path, entry))
+    {
+        warn!("Failed to write {}:// URL handler: {}", URL_SCHEME, e);
+        return;
+    }
+
+    let registered = std::process::Command::new("xdg-mime")
+        .args(["default", DESKTOP_FILE, &mime_type])
+        .output();
+    match registered {
+        Ok(output) if output.status.success() => {
+            info!("Registered {}:// URL handler", URL_SCHEME)
+        }
+        _ => warn!(
+            "xdg-mime failed - {}:// links may not open OpenNOW",
+            URL_SCHEME
+        ),
+    }
+}
+
+/// macOS registers the scheme from CFBundleURLTypes in Info.plist; the URL is
+/// delivered as a "get URL" Apple Event, so install a handler for it.
+/// Must run after the event loop is created and before it starts, so the URL the
+/// app was launched with isn't missed.
+#[cfg(target_os = "macos")]
+pub fn register_url_scheme() {
+    use cocoa::base::{id, nil};
+    use objc::declare::ClassDecl;
+    use objc::runtime::{Object, Sel};
+    use objc::{class, msg_send, sel, sel_impl};
+
+    /// 'GURL' - both the event class and ID of "open this URL"
+    const K_AE_GET_URL: u32 = 0x4755_524C;
+    /// '----' - the direct object (the URL string)
+    const KEY_DIRECT_OBJECT: u32 = 0x2D2D_2D2D;
+
+    extern "C" fn handle_get_url(_this: &Object, _cmd: Sel, event: id, _reply: id) {
+        unsafe {
+            let descriptor: id = msg_send![event, paramDescriptorForKeyword: KEY_DIRECT_OBJECT];
+            if descriptor == nil {
+                return;
+            }
+            let url: id = msg_send![descriptor, stringValue];
+            if url == nil {
+                return;
+            }
+            let utf8: *const std::os::raw::c_char = msg_send![url, UTF8String];
+            if !utf8.is_null() {
+                handle_deep_link(&std::ffi::CStr::from_ptr(utf8).to_string_lossy());
+            }
+        }
+    }
+
+    let Some(mut decl) = ClassDecl::new("OpenNowUrlHandler", class!(NSObject)) else {
+        return; // Already registered
+    };
+    unsafe {
+        decl.add_method(
+            sel!(handleGetURLEvent:withReplyEvent:),
+            handle_get_url as extern "C" fn(&Object, Sel, id, id),
+        );
+        let handler_class = decl.register();
+        let handler: id = msg_send![handler_class, new];
+        let manager: id = msg_send![class!(NSAppleEventManager), sharedAppleEventManager];
+        let _: () = msg_send![manager,
+            setEventHandler: handler
+            andSelector: sel!(handleGetURLEvent:withReplyEvent:)
+            forEventClass: K_AE_GET_URL
+            andEventID: K_AE_GET_URL];
+    }
+    info!("Listening for {}:// URLs", URL_SCHEME);
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux", target_os = "macos")))]
+pub fn register_url_scheme() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_launch_url() {
+        assert_eq!(
+            parse_launch_url("opennow://launch/100932911"),
+            Some("100932911".to_string())
+        );
+        assert_eq!(
+            parse_launch_url("opennow://launch/100932911/?source=web"),
+            Some("100932911".to_string())
+        );
+        assert_eq!(
+            parse_launch_url("opennow://launch?source=web&id=100932911"),
+            Some("100932911".to_string())
+        );
+        assert_eq!(parse_launch_url("opennow://launch/"), None);
+        assert_eq!(parse_launch_url("opennow://launch?id="), None);
+        assert_eq!(parse_launch_url("opennow://launcher/100932911"), None);
+        assert_eq!(parse_launch_url("opennow://settings"), None);
+        assert_eq!(parse_launch_url("opennow://launch/1;rm"), None);
+        assert_eq!(parse_launch_url("https://launch/100932911"), None);
+    }
+}
@@ -0,0 +1,133 @@
+//! Issue Reporting
+//!
+//! Builds a GitHub new-issue URL for the bug report form, pre-filled with the
+//! system details and log tail that reporters otherwise copy in by hand.
+
+use std::io::{Read, Seek, SeekFrom};
+
+/// Bug report issue form on GitHub
+const NEW_ISSUE_URL: &str = "https://github.com/zortos293/OpenNOW/issues/new";
+
+/// Log bytes included in the report. GitHub rejects URLs over ~8KB and
+/// URL-encoding roughly doubles log text, so keep well under half of that.
+const LOG_TAIL_BYTES: u64 = 3000;
+
+/// Details included in a pre-filled issue
+pub struct IssueReport {
+    /// Membership tier from the subscription API (e.g. "PRIORITY")
+    pub tier: Option<String>,
+    /// GPU adapter and wgpu backend
+    pub gpu: String,
+    /// Decoder backend from settings and the element actually in use
+    pub decoder: String,
+    /// Last error shown to the user
+    pub last_error: Option<String>,
+}
+
+/// GitHub new-issue URL with the bug report form filled from `report` and the log tail
+pub fn issue_report_url(report: &IssueReport) -> String {
+    let mut description =
+        String::from("<!-- Describe what happened and how to reproduce it -->\n\n");
+    description.push_str(&format!(
+        "**OpenNOW:** v{}\n**System:** {} / {}\n**GPU:** {}\n**Decoder:** {}\n",
+        env!("CARGO_PKG_VERSION"),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        report.gpu,
+        report.decoder
+    ));
+    if let Some(ref error) = report.last_error {
+        description.push_str(&format!("**Last error:** {}\n", error));
+    }
+
+    let mut params = vec![
+        ("template", "bug_report.yml".to_string()),
+        ("labels", "bug".to_string()),
+        ("description", description),
+        ("logs", read_log_tail()),
+    ];
+    if let Some(os) = issue_os() {
+        params.push(("os", os.to_string()));
+    }
+    if let Some(tier) = report.tier.as_deref().and_then(issue_tier) {
+        params.push(("tier", tier.to_string()));
+    }
+
+    let query = params
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, urlencoding::encode(value)))
+        .collect::<Vec<_>>()
+        .join("&");
+    format!("{}?{}", NEW_ISSUE_URL, query)
+}
+
+/// Operating System dropdown option in the issue form
+fn issue_os() -> Option<&'static str> {
+    match std::env::consts::OS {
+        "macos" => Some("macOS"),
+        "windows" => Some("Windows"),
+        "linux" => Some("Linux"),
+        _ => None,
+    }
+}
+
+/// GeForce NOW Tier dropdown option for a membership tier from the API
+fn issue_tier(tier: &str) -> Option<&'static str> {
+    match tier.to_uppercase().as_str() {
+        "FREE" => Some("Free"),
+        "PRIORITY" | "PERFORMANCE" => Some("Performance"),
+        "ULTIMATE" => Some("Ultimate"),
+        _ => None,
+    }
+}
+
+/// Last few KB of the log file, starting at a line boundary
+pub(super) fn read_log_tail() -> String {
+    let Ok(mut file) = std::fs::File::open(super::get_log_file_path()) else {
+        return String::new();
+    };
+    let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+    let start = len.saturating_sub(LOG_TAIL_BYTES);
+    if file.seek(SeekFrom::Start(start)).is_err() {
+        return String::new();
+    }
+    let mut bytes = Vec::new();
+    if file.read_to_end(&mut bytes).is_err() {
+        return String::new();
+    }
+
+    let text = String::from_utf8_lossy(&bytes);
+    // Drop the partial first line when the tail starts mid-file
+    let text = match text.find('\n') {
+        Some(newline) if start > 0 => &text[newline + 1..],
+        _ => &text[..],
+    };
+    text.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_url_prefills_form_fields() {
+        let url = issue_report_url(&IssueReport {
+            tier: Some("ULTIMATE".to_string()),
+            gpu: "Test GPU (Vulkan)".to_string(),
+            decoder: "Auto".to_string(),
+            last_error: Some("Session failed".to_string()),
+        });
+        assert!(url.starts_with(
+            "https://github.com/zortos293/OpenNOW/issues/new?template=bug_report.yml"
+        ));
+        assert!(url.contains("&tier=Ultimate"));
+        assert!(url.contains("Test%20GPU%20%28Vulkan%29"));
+        assert!(url.contains("Session%20failed"));
+    }
+
+    #[test]
+    fn test_unknown_tier_is_left_for_the_reporter() {
+        assert_eq!(issue_tier("priority"), Some("Performance"));
+        assert_eq!(issue_tier("SOMETHING_NEW"), None);
+    }
+}
@@ -2,10 +2,20 @@
 //!
 //! Common utilities used throughout the application.
 
+mod crash_report;
+mod deep_link;
+mod issue_report;
 mod logging;
+mod notification;
+mod shortcut;
 mod time;
 
+pub use crash_report::*;
+pub use deep_link::*;
+pub use issue_report::*;
 pub use logging::*;
+pub use notification::*;
+pub use shortcut::*;
 pub use time::*;
 
 use std::path::PathBuf;
@@ -17,6 +27,15 @@ pub fn get_app_data_dir() -> PathBuf {
         .join("opennow-streamer")
 }
 
+/// Executable that shortcuts and URL handlers should start
+/// (AppImages run from a temporary mount, so point at the image itself)
+pub fn launcher_exe() -> Option<PathBuf> {
+    if let Some(appimage) = std::env::var_os("APPIMAGE") {
+        return Some(PathBuf::from(appimage));
+    }
+    std::env::current_exe().ok()
+}
+
 /// Get the cache directory
 pub fn get_cache_dir() -> PathBuf {
     dirs::cache_dir()
@@ -0,0 +1,15 @@
+//! Desktop Notifications
+//!
+//! Native notifications for events worth seeing while OpenNOW is in the background.
+
+/// Show a desktop notification (failures are only logged)
+pub fn show_desktop_notification(summary: &str, body: &str) {
+    if let Err(e) = notify_rust::Notification::new()
+        .appname("OpenNOW")
+        .summary(summary)
+        .body(body)
+        .show()
+    {
+        log::warn!("Failed to show desktop notification: {}", e);
+    }
+}
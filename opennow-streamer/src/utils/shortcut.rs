@@ -0,0 +1,259 @@
+//! Game Shortcuts
+//!
+//! Native shortcuts that start a game with `--launch-game <app_id>`, so GeForce NOW
+//! games show up in the Start menu / app launcher next to installed ones. The game's
+//! box art (as loaded in the menu) becomes the shortcut icon.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use log::warn;
+
+/// Where shortcuts end up, for messages
+#[cfg(target_os = "windows")]
+pub const SHORTCUT_LOCATION: &str = "the Start menu";
+#[cfg(target_os = "macos")]
+pub const SHORTCUT_LOCATION: &str = "your Applications folder";
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+pub const SHORTCUT_LOCATION: &str = "your app launcher";
+
+/// Icon edge length in pixels
+const ICON_SIZE: u32 = 256;
+
+/// Create a shortcut that launches the game; returns the shortcut's path
+/// `box_art` is RGBA pixels with width and height
+pub fn create_game_shortcut(
+    app_id: &str,
+    title: &str,
+    box_art: Option<(&[u8], u32, u32)>,
+) -> Result<PathBuf> {
+    let exe = super::launcher_exe().context("Couldn't find the OpenNOW executable")?;
+    let name = shortcut_name(title, app_id);
+
+    // Icons live in the app data dir so the shortcut keeps its icon after the cache is cleared
+    let icon = match box_art.and_then(|(rgba, w, h)| icon_png(rgba, w, h)) {
+        Some(png) => {
+            let dir = super::get_app_data_dir().join("shortcut_icons");
+            super::ensure_dir(&dir)?;
+            let path = dir.join(format!("{}.png", app_id));
+            std::fs::write(&path, png)?;
+            Some(path)
+        }
+        None => {
+            warn!(
+                "No box art loaded for {} - shortcut gets the default icon",
+                title
+            );
+            None
+        }
+    };
+
+    write_shortcut(&exe, app_id, &name, icon.as_deref())
+}
+
+/// Title usable as a file name ("Game: Edition" -> "Game Edition")
+fn shortcut_name(title: &str, app_id: &str) -> String {
+    let name: String = title
+        .chars()
+        .filter(|c| !c.is_control() && !r#"<>:"/\|?*"#.contains(*c))
+        .collect();
+    let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+    let name = name.trim_matches('.').to_string();
+    if name.is_empty() {
+        format!("GeForce NOW {}", app_id)
+    } else {
+        name
+    }
+}
+
+/// Box art center-cropped to a square PNG icon
+fn icon_png(rgba: &[u8], width: u32, height: u32) -> Option<Vec<u8>> {
+    let image = image::RgbaImage::from_raw(width, height, rgba.to_vec())?;
+    let side = width.min(height);
+    let square =
+        image::imageops::crop_imm(&image, (width - side) / 2, (height - side) / 2, side, side)
+            .to_image();
+    let icon = image::imageops::resize(
+        &square,
+        ICON_SIZE,
+        ICON_SIZE,
+        image::imageops::FilterType::Triangle,
+    );
+    let mut png = Vec::new();
+    image::DynamicImage::ImageRgba8(icon)
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+        .ok()?;
+    Some(png)
+}
+
+/// Start menu shortcut (.lnk, created through the WScript.Shell COM object)
+#[cfg(target_os = "windows")]
+fn write_shortcut(exe: &Path, app_id: &str, name: &str, icon: Option<&Path>) -> Result<PathBuf> {
+    use std::os::windows::process::CommandExt;
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    let dir = dirs::data_dir()
+        .context("No AppData folder")?
+        .join(r"Microsoft\Windows\Start Menu\Programs\OpenNOW");
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("{}.lnk", name));
+
+    // Shortcuts take .ico icons; a single PNG-compressed image is enough
+    let icon_location = match icon {
+        Some(png_path) => {
+            let ico_path = png_path.with_extension("ico");
+            std::fs::write(&ico_path, ico_from_png(&std::fs::read(png_path)?))?;
+            ico_path
+        }
+        None => exe.to_path_buf(),
+    };
+
+    let quote = |s: &str| format!("'{}'", s.replace('\'', "''"));
+    let script = format!(
+        "$s = (New-Object -ComObject WScript.Shell).CreateShortcut({}); \
+         $s.TargetPath = {}; $s.Arguments = {}; $s.IconLocation = {}; \
+         $s.Description = 'Play on GeForce NOW with OpenNOW'; $s.Save()",
+        quote(&path.to_string_lossy()),
+        quote(&exe.to_string_lossy()),
+        quote(&format!("--launch-game {}", app_id)),
+        quote(&icon_location.to_string_lossy()),
+    );
+    let output = std::process::Command::new("powershell")
+        .args(["-NoProfile", "-NonInteractive", "-Command", &script])
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()
+        .context("Failed to run PowerShell")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "PowerShell couldn't create the shortcut: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(path)
+}
+
+/// .ico file holding one 256x256 PNG image
+#[cfg(target_os = "windows")]
+fn ico_from_png(png: &[u8]) -> Vec<u8> {
+    let mut ico = Vec::with_capacity(22 + png.len());
+    ico.extend_from_slice(&[0, 0, 1, 0, 1, 0]); // Reserved, type (icon), image count
+    ico.extend_from_slice(&[0, 0, 0, 0]); // 256x256 (stored as 0), no palette, reserved
+    ico.extend_from_slice(&1u16.to_le_bytes()); // Color planes
+    ico.extend_from_slice(&32u16.to_le_bytes()); // Bits per pixel
+    ico.extend_from_slice(&(png.len() as u32).to_le_bytes());
+    ico.extend_from_slice(&22u32.to_le_bytes()); // Image data offset
+    ico.extend_from_slice(png);
+    ico
+}
+
+/// Minimal app bundle in ~/Applications whose executable starts OpenNOW with the game
+#[cfg(target_os = "macos")]
+fn write_shortcut(exe: &Path, app_id: &str, name: &str, icon: Option<&Path>) -> Result<PathBuf> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = dirs::home_dir()
+        .context("No home folder")?
+        .join("Applications")
+        .join(format!("{}.app", name));
+    let macos_dir = path.join("Contents").join("MacOS");
+    std::fs::create_dir_all(&macos_dir)?;
+
+    let plist = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>CFBundleName</key>
+    <string>{}</string>
+    <key>CFBundleIdentifier</key>
+    <string>com.opennow.streamer.game.{}</string>
+    <key>CFBundleExecutable</key>
+    <string>launch</string>
+    <key>CFBundlePackageType</key>
+    <string>APPL</string>
+</dict>
+</plist>
+"#,
+        xml_escape(name),
+        app_id
+    );
+    std::fs::write(path.join("Contents").join("Info.plist"), plist)?;
+
+    let launcher = macos_dir.join("launch");
+    let quoted_exe = format!("'{}'", exe.to_string_lossy().replace('\'', r"'\''"));
+    std::fs::write(
+        &launcher,
+        format!("#!/bin/sh\nexec {} --launch-game {}\n", quoted_exe, app_id),
+    )?;
+    std::fs::set_permissions(&launcher, std::fs::Permissions::from_mode(0o755))?;
+
+    if let Some(icon) = icon {
+        set_finder_icon(&path, icon);
+    }
+    Ok(path)
+}
+
+#[cfg(target_os = "macos")]
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Give the bundle a custom Finder icon (bundles normally need an .icns)
+#[cfg(target_os = "macos")]
+fn set_finder_icon(bundle: &Path, icon: &Path) {
+    use cocoa::base::{id, nil};
+    use cocoa::foundation::NSString;
+    use objc::runtime::BOOL;
+    use objc::{class, msg_send, sel, sel_impl};
+
+    unsafe {
+        let icon_path = NSString::alloc(nil).init_str(&icon.to_string_lossy());
+        let image: id = msg_send![class!(NSImage), alloc];
+        let image: id = msg_send![image, initWithContentsOfFile: icon_path];
+        if image == nil {
+            warn!("Failed to load shortcut icon {}", icon.display());
+            return;
+        }
+        let bundle_path = NSString::alloc(nil).init_str(&bundle.to_string_lossy());
+        let workspace: id = msg_send![class!(NSWorkspace), sharedWorkspace];
+        let _: BOOL = msg_send![workspace, setIcon: image forFile: bundle_path options: 0usize];
+    }
+}
+
+/// Desktop entry in ~/.local/share/applications (shows up in the app launcher)
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn write_shortcut(exe: &Path, app_id: &str, name: &str, icon: Option<&Path>) -> Result<PathBuf> {
+    if std::env::var_os("FLATPAK_ID").is_some() {
+        anyhow::bail!("Shortcuts can't be created from the Flatpak version yet");
+    }
+    let dir = dirs::data_dir()
+        .context("No data folder")?
+        .join("applications");
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("opennow-game-{}.desktop", app_id));
+
+    let mut entry = format!(
+        "[Desktop Entry]\nType=Application\nName={}\nComment=Play on GeForce NOW with OpenNOW\nExec=\"{}\" --launch-game {}\nTerminal=false\nCategories=Game;\n",
+        name,
+        exe.display(),
+        app_id
+    );
+    if let Some(icon) = icon {
+        entry.push_str(&format!("Icon={}\n", icon.display()));
+    }
+    std::fs::write(&path, entry)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shortcut_name_is_file_safe() {
+        assert_eq!(shortcut_name("Game: The  Sequel?", "1"), "Game The Sequel");
+        assert_eq!(shortcut_name("...", "100932911"), "GeForce NOW 100932911");
+    }
+}
@@ -90,6 +90,21 @@ pub fn timestamp_us() -> u64 {
         .unwrap_or(0)
 }
 
+/// Reference point for monotonic microsecond timestamps
+static MONOTONIC_EPOCH: std::sync::OnceLock<Instant> = std::sync::OnceLock::new();
+
+/// Monotonic microseconds for an `Instant` (relative to the first call in this process)
+/// Used to stamp video frames with their packet receive time
+pub fn instant_to_us(instant: Instant) -> u64 {
+    let epoch = *MONOTONIC_EPOCH.get_or_init(Instant::now);
+    instant.saturating_duration_since(epoch).as_micros() as u64
+}
+
+/// Current monotonic time in microseconds (same clock as `instant_to_us`)
+pub fn monotonic_us() -> u64 {
+    instant_to_us(Instant::now())
+}
+
 /// Get current timestamp in milliseconds
 pub fn timestamp_ms() -> u64 {
     std::time::SystemTime::now()
@@ -0,0 +1,136 @@
+//! Adaptive Bitrate
+//!
+//! Lowers the bitrate requested from the server while packet loss stays high and
+//! restores it once the connection is clean again. Separate thresholds and hold
+//! times on each side keep the target from oscillating.
+
+/// Packet loss (percent) that counts as a bad second
+const HIGH_LOSS_PERCENT: f32 = 2.0;
+
+/// Packet loss (percent) that counts as a clean second
+const LOW_LOSS_PERCENT: f32 = 0.5;
+
+/// Consecutive bad seconds before stepping the bitrate down
+const DECREASE_AFTER_SECS: u32 = 3;
+
+/// Consecutive clean seconds before stepping the bitrate back up
+const INCREASE_AFTER_SECS: u32 = 10;
+
+/// Each step down keeps this fraction of the current target
+const DECREASE_FACTOR: f32 = 0.75;
+
+/// Each step up adds this fraction of the configured maximum
+const INCREASE_STEP: f32 = 0.1;
+
+/// Never request less than this fraction of the configured maximum
+const MIN_FRACTION: f32 = 0.25;
+
+/// Packet-loss-driven bitrate target (fed once per stats interval)
+pub struct AdaptiveBitrate {
+    max_kbps: u32,
+    target_kbps: u32,
+    bad_secs: u32,
+    clean_secs: u32,
+}
+
+impl AdaptiveBitrate {
+    pub fn new(max_kbps: u32) -> Self {
+        Self {
+            max_kbps,
+            target_kbps: max_kbps,
+            bad_secs: 0,
+            clean_secs: 0,
+        }
+    }
+
+    /// Bitrate currently requested for packet loss (kbps)
+    pub fn target_kbps(&self) -> u32 {
+        self.target_kbps
+    }
+
+    /// Change the maximum mid-session (bitrate picked in the stream overlay)
+    pub fn set_max(&mut self, max_kbps: u32) {
+        self.max_kbps = max_kbps;
+        self.target_kbps = max_kbps;
+        self.bad_secs = 0;
+        self.clean_secs = 0;
+    }
+
+    /// Record one second of packet loss; returns the new target (kbps) when it changes
+    pub fn update(&mut self, packet_loss_percent: f32) -> Option<u32> {
+        if packet_loss_percent >= HIGH_LOSS_PERCENT {
+            self.bad_secs += 1;
+            self.clean_secs = 0;
+        } else if packet_loss_percent <= LOW_LOSS_PERCENT {
+            self.clean_secs += 1;
+            self.bad_secs = 0;
+        } else {
+            // In between the thresholds: hold the current target
+            self.bad_secs = 0;
+            self.clean_secs = 0;
+        }
+
+        let min_kbps = (self.max_kbps as f32 * MIN_FRACTION) as u32;
+        let new_target = if self.bad_secs >= DECREASE_AFTER_SECS {
+            self.bad_secs = 0;
+            ((self.target_kbps as f32 * DECREASE_FACTOR) as u32).max(min_kbps)
+        } else if self.clean_secs >= INCREASE_AFTER_SECS {
+            self.clean_secs = 0;
+            let step = (self.max_kbps as f32 * INCREASE_STEP) as u32;
+            (self.target_kbps + step).min(self.max_kbps)
+        } else {
+            return None;
+        };
+
+        if new_target == self.target_kbps {
+            return None;
+        }
+        self.target_kbps = new_target;
+        Some(new_target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sustained_loss_lowers_and_clean_period_restores() {
+        let mut abr = AdaptiveBitrate::new(100_000);
+
+        // A single lossy second is ignored
+        assert_eq!(abr.update(5.0), None);
+        assert_eq!(abr.update(0.0), None);
+
+        // Sustained loss steps down once per hold period
+        assert_eq!(abr.update(5.0), None);
+        assert_eq!(abr.update(5.0), None);
+        assert_eq!(abr.update(5.0), Some(75_000));
+
+        // Moderate loss between the thresholds holds the target
+        for _ in 0..20 {
+            assert_eq!(abr.update(1.0), None);
+        }
+
+        // Clean seconds restore it in steps, capped at the maximum
+        let mut target = 75_000;
+        for _ in 0..40 {
+            if let Some(kbps) = abr.update(0.0) {
+                target = kbps;
+            }
+        }
+        assert_eq!(target, 100_000);
+    }
+
+    #[test]
+    fn test_never_drops_below_floor() {
+        let mut abr = AdaptiveBitrate::new(100_000);
+        let mut target = 100_000;
+        for _ in 0..100 {
+            if let Some(kbps) = abr.update(50.0) {
+                target = kbps;
+            }
+        }
+        assert_eq!(target, 25_000);
+    }
+}
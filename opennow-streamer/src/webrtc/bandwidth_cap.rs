@@ -0,0 +1,124 @@
+//! Bandwidth Cap
+//!
+//! Keeps the total received rate (video, audio, retransmits and RTP overhead, as
+//! counted on the ICE transport) under the user's cap, for shared connections.
+//! The max bitrate only limits the encoder, so the wire rate can run above it;
+//! this watches what actually arrives and lowers the requested bitrate until it fits.
+
+/// Seconds over the cap before lowering the bitrate
+const OVER_CAP_SECS: u32 = 3;
+
+/// Received rate (fraction of the cap) that counts as room to raise the bitrate again
+const HEADROOM_FRACTION: f32 = 0.75;
+
+/// Seconds with headroom before stepping the bitrate back up
+const RAISE_AFTER_SECS: u32 = 15;
+
+/// A step down aims for this percentage of the cap, since overhead scales with the bitrate
+const TARGET_PERCENT: u64 = 90;
+
+/// Each step up adds this fraction of the cap
+const RAISE_STEP: f32 = 0.1;
+
+/// Never request less than this (kbps), however far over the cap the stream is
+const MIN_KBPS: u32 = 2_000;
+
+/// Received-rate limit (fed once per stats interval)
+pub struct BandwidthCap {
+    cap_kbps: u32,
+    max_kbps: u32,
+    target_kbps: u32,
+    over_secs: u32,
+    headroom_secs: u32,
+}
+
+impl BandwidthCap {
+    pub fn new(cap_kbps: u32, max_kbps: u32) -> Self {
+        Self {
+            cap_kbps,
+            max_kbps,
+            target_kbps: max_kbps,
+            over_secs: 0,
+            headroom_secs: 0,
+        }
+    }
+
+    /// Bitrate currently allowed by the cap (kbps)
+    pub fn target_kbps(&self) -> u32 {
+        self.target_kbps
+    }
+
+    /// Change the maximum mid-session (bitrate picked in the stream overlay)
+    pub fn set_max(&mut self, max_kbps: u32) {
+        self.max_kbps = max_kbps;
+        self.target_kbps = self.target_kbps.min(max_kbps);
+    }
+
+    /// Record one second of received traffic; returns the new target (kbps) when it changes
+    pub fn update(&mut self, received_kbps: u32) -> Option<u32> {
+        if received_kbps > self.cap_kbps {
+            self.over_secs += 1;
+            self.headroom_secs = 0;
+        } else if (received_kbps as f32) < self.cap_kbps as f32 * HEADROOM_FRACTION {
+            self.headroom_secs += 1;
+            self.over_secs = 0;
+        } else {
+            self.over_secs = 0;
+            self.headroom_secs = 0;
+        }
+
+        let new_target = if self.over_secs >= OVER_CAP_SECS {
+            self.over_secs = 0;
+            // Scale by how far over the cap the stream is, so one step usually fits
+            let scaled = self.target_kbps as u64 * self.cap_kbps as u64 * TARGET_PERCENT
+                / 100
+                / received_kbps as u64;
+            (scaled as u32).max(MIN_KBPS)
+        } else if self.headroom_secs >= RAISE_AFTER_SECS && self.target_kbps < self.max_kbps {
+            self.headroom_secs = 0;
+            let step = (self.cap_kbps as f32 * RAISE_STEP) as u32;
+            (self.target_kbps + step).min(self.max_kbps)
+        } else {
+            return None;
+        };
+
+        if new_target == self.target_kbps {
+            return None;
+        }
+        self.target_kbps = new_target;
+        Some(new_target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sustained_overuse_lowers_and_headroom_restores() {
+        let mut cap = BandwidthCap::new(20_000, 50_000);
+
+        // A short burst over the cap is ignored
+        assert_eq!(cap.update(30_000), None);
+        assert_eq!(cap.update(15_000), None);
+
+        // Sustained overuse scales the target down to fit under the cap
+        assert_eq!(cap.update(36_000), None);
+        assert_eq!(cap.update(36_000), None);
+        assert_eq!(cap.update(36_000), Some(25_000));
+
+        // Close to the cap holds the target
+        for _ in 0..30 {
+            assert_eq!(cap.update(17_000), None);
+        }
+
+        // Plenty of headroom raises it in steps, never above the maximum
+        let mut target = cap.target_kbps();
+        for _ in 0..300 {
+            if let Some(kbps) = cap.update(5_000) {
+                target = kbps;
+            }
+        }
+        assert_eq!(target, 50_000);
+    }
+}
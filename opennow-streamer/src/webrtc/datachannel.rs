@@ -56,8 +56,13 @@ pub enum InputEvent {
     MouseButtonDown { button: u8, timestamp_us: u64 },
     /// Mouse button released
     MouseButtonUp { button: u8, timestamp_us: u64 },
-    /// Mouse wheel scrolled
-    MouseWheel { delta: i16, timestamp_us: u64 },
+    /// Mouse wheel scrolled, in WHEEL_DELTA units (120 per notch)
+    /// Positive horizontal = scroll right, positive vertical = scroll up
+    MouseWheel {
+        horizontal: i16,
+        vertical: i16,
+        timestamp_us: u64,
+    },
     /// Heartbeat (keep-alive)
     Heartbeat,
     /// Gamepad state update
@@ -179,14 +184,15 @@ impl InputEncoder {
             }
 
             InputEvent::MouseWheel {
-                delta,
+                horizontal,
+                vertical,
                 timestamp_us,
             } => {
                 // Type 10 (Mouse Wheel): 22 bytes
                 // [type 4B LE][horiz 2B BE][vert 2B BE][reserved 6B][timestamp 8B BE]
                 self.buffer.put_u32_le(INPUT_MOUSE_WHEEL);
-                self.buffer.put_i16(0); // Horizontal (unused)
-                self.buffer.put_i16(*delta); // Vertical (positive = scroll up)
+                self.buffer.put_i16(*horizontal); // Horizontal (positive = scroll right)
+                self.buffer.put_i16(*vertical); // Vertical (positive = scroll up)
                 self.buffer.put_u16(0); // Reserved
                 self.buffer.put_u32(0); // Reserved
                 self.buffer.put_u64(*timestamp_us);
@@ -585,6 +591,22 @@ mod tests {
         assert_eq!(&encoded[0..4], &[0x07, 0x00, 0x00, 0x00]);
     }
 
+    #[test]
+    fn test_mouse_wheel_encodes_both_axes() {
+        let mut encoder = InputEncoder::new();
+        let event = InputEvent::MouseWheel {
+            horizontal: -120,
+            vertical: 40,
+            timestamp_us: 12345,
+        };
+        let encoded = encoder.encode(&event);
+
+        assert_eq!(encoded.len(), 22);
+        assert_eq!(&encoded[0..4], &[0x0A, 0x00, 0x00, 0x00]);
+        assert_eq!(&encoded[4..6], &(-120i16).to_be_bytes());
+        assert_eq!(&encoded[6..8], &40i16.to_be_bytes());
+    }
+
     #[test]
     fn test_heartbeat_encoding() {
         let mut encoder = InputEncoder::new();
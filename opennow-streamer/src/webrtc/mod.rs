@@ -2,13 +2,18 @@
 //!
 //! WebRTC peer connection, signaling, and data channels for GFN streaming.
 
+mod adaptive_bitrate;
+mod bandwidth_cap;
 mod datachannel;
 mod peer;
 mod sdp;
 mod signaling;
 
 pub use datachannel::*;
-pub use peer::{request_keyframe, NetworkStats, WebRtcEvent, WebRtcPeer};
+pub use peer::{
+    connection_failure_reason, connection_stage, request_keyframe, request_max_bitrate,
+    set_connection_stage, ConnectionStage, NetworkStats, WebRtcEvent, WebRtcPeer,
+};
 pub use sdp::*;
 pub use signaling::{GfnSignaling, IceCandidate, SignalingEvent};
 // StreamingResult is defined in this module and exported automatically
@@ -18,6 +23,9 @@ use tokio::sync::mpsc;
 use webrtc::ice_transport::ice_server::RTCIceServer;
 
 use crate::app::{SessionInfo, Settings, SharedFrame, VideoCodec};
+use crate::app::config::{VideoDecoderBackend, MAX_ICE_GATHER_TIMEOUT_MS, MIN_ICE_GATHER_TIMEOUT_MS};
+use adaptive_bitrate::AdaptiveBitrate;
+use bandwidth_cap::BandwidthCap;
 
 /// Result of a streaming session - indicates why the stream ended
 #[derive(Debug, Clone)]
@@ -31,10 +39,28 @@ pub enum StreamingResult {
     SsrcChangeDetected { stall_duration_ms: u64 },
 }
 use crate::input::{ControllerManager, FfbEffectType, G29FfbManager, InputHandler, WheelManager};
+use crate::media::hevc_parser::{chroma_format_label, HevcParser};
 use crate::media::{
     AudioDecoder, AudioPlayer, DepacketizerCodec, RtpDepacketizer, StreamStats, UnifiedVideoDecoder,
 };
 
+/// Startup bitrate as a percentage of the configured maximum
+/// Starting high avoids the blocky first seconds while bandwidth estimation ramps up;
+/// the server's estimator settles to the sustainable rate from there
+const STARTUP_BITRATE_PERCENT: u32 = 90;
+
+/// Keyframe requests after the first decoded frame (ms). The first recovers quickly from
+/// a damaged initial IDR, the second replaces the low-bitrate startup picture once ramped
+const STARTUP_KEYFRAME_DELAYS_MS: [u64; 2] = [500, 2500];
+
+/// Bitrate (kbps) requested from the stream overlay, picked up by the streaming loop (0 = none)
+static SESSION_BITRATE_KBPS: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+
+/// Change the video bitrate of the running session (sent to the server within a second)
+pub fn set_session_bitrate(bitrate_kbps: u32) {
+    SESSION_BITRATE_KBPS.store(bitrate_kbps, std::sync::atomic::Ordering::Relaxed);
+}
+
 /// Active streaming session
 pub struct StreamingSession {
     pub signaling: Option<GfnSignaling>,
@@ -74,7 +100,7 @@ fn build_nvst_sdp(
     max_bitrate_kbps: u32,
 ) -> String {
     let min_bitrate_kbps = std::cmp::min(10000, max_bitrate_kbps / 10);
-    let initial_bitrate_kbps = max_bitrate_kbps / 2;
+    let initial_bitrate_kbps = max_bitrate_kbps * STARTUP_BITRATE_PERCENT / 100;
 
     let is_high_fps = fps >= 120;
     let is_120_fps = fps == 120;
@@ -189,7 +215,7 @@ fn build_nvst_sdp(
         format!("a=video.maxFPS:{}", fps),
         // Bitrate - critical for achieving high bitrates
         // Initial bitrate should be high to avoid slow ramp-up
-        format!("a=video.initialBitrateKbps:{}", max_bitrate_kbps * 3 / 4), // Start at 75% of max
+        format!("a=video.initialBitrateKbps:{}", initial_bitrate_kbps),
         format!("a=video.initialPeakBitrateKbps:{}", max_bitrate_kbps),
         format!("a=vqos.bw.maximumBitrateKbps:{}", max_bitrate_kbps),
         format!("a=vqos.bw.minimumBitrateKbps:{}", min_bitrate_kbps),
@@ -269,6 +295,64 @@ fn extract_public_ip(input: &str) -> Option<String> {
     None
 }
 
+/// RTP depacketizer mode for a video codec
+fn depacketizer_codec_for(codec: VideoCodec) -> DepacketizerCodec {
+    match codec {
+        VideoCodec::H264 => DepacketizerCodec::H264,
+        VideoCodec::H265 => DepacketizerCodec::H265,
+        VideoCodec::AV1 => DepacketizerCodec::AV1,
+    }
+}
+
+/// Human-readable codec list for errors (e.g. "AV1, H265, H264")
+fn codec_list(codecs: &[VideoCodec]) -> String {
+    codecs
+        .iter()
+        .map(|c| c.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Create a decoder for the first codec in `codecs` this system can decode
+fn first_decodable_codec(
+    codecs: &[VideoCodec],
+    settings: &Settings,
+    shared_frame: &Arc<SharedFrame>,
+) -> Option<(
+    VideoCodec,
+    (UnifiedVideoDecoder, mpsc::Receiver<crate::media::DecodeStats>),
+)> {
+    for &codec in codecs {
+        match UnifiedVideoDecoder::new_async(codec, settings.decoder_backend, shared_frame.clone())
+        {
+            Ok(decoder) => {
+                if codec != settings.codec {
+                    warn!(
+                        "Using fallback codec {} instead of {}",
+                        codec.as_str(),
+                        settings.codec.as_str()
+                    );
+                }
+                // The selected backend may have fallen back (e.g. hardware to software)
+                let hardware = decoder.0.is_hw_accelerated();
+                info!(
+                    "Video decoder: {} via {} ({}), selected backend: {}",
+                    codec.as_str(),
+                    decoder.0.backend_name(),
+                    if hardware { "hardware" } else { "software" },
+                    settings.decoder_backend.as_str()
+                );
+                if !hardware && settings.decoder_backend != VideoDecoderBackend::Software {
+                    warn!("No hardware decoder for {} - decoding on the CPU", codec.as_str());
+                }
+                return Some((codec, decoder));
+            }
+            Err(e) => warn!("Cannot decode {}: {}", codec.as_str(), e),
+        }
+    }
+    None
+}
+
 /// Run the streaming session
 /// Returns a `StreamingResult` indicating how/why the session ended
 pub async fn run_streaming(
@@ -283,11 +367,13 @@ pub async fn run_streaming(
         session_info.server_ip, session_info.session_id
     );
 
+    peer::reset_connection_stage();
+    set_connection_stage(ConnectionStage::Signaling);
+
     let (width, height) = settings.resolution_tuple();
     let fps = settings.fps;
     let max_bitrate = settings.max_bitrate_kbps();
-    let codec = settings.codec;
-    let _codec_str = codec.as_str().to_string();
+    let codec_preference = settings.codec_preference();
 
     // Create signaling client
     let (sig_event_tx, mut sig_event_rx) = mpsc::channel::<SignalingEvent>(64);
@@ -306,6 +392,7 @@ pub async fn run_streaming(
 
     // Connect to signaling
     if let Err(e) = signaling.connect().await {
+        peer::fail_connection_stage("signaling connection failed");
         return StreamingResult::Error(format!("Failed to connect signaling: {}", e));
     }
     info!("Signaling connected");
@@ -317,22 +404,21 @@ pub async fn run_streaming(
     // Video decoder - use async mode for non-blocking decode
     // Decoded frames are written directly to SharedFrame by the decoder thread
     // Uses UnifiedVideoDecoder to support both FFmpeg and native DXVA backends
-    let (mut video_decoder, mut decode_stats_rx) =
-        match UnifiedVideoDecoder::new_async(codec, settings.decoder_backend, shared_frame.clone())
-        {
-            Ok(decoder) => decoder,
-            Err(e) => {
-                return StreamingResult::Error(format!("Failed to create video decoder: {}", e))
-            }
-        };
+    // Walks the codec fallback chain until a decoder can be created
+    let Some((mut codec, (mut video_decoder, mut decode_stats_rx))) =
+        first_decodable_codec(&codec_preference, &settings, &shared_frame)
+    else {
+        return StreamingResult::Error(format!(
+            "Failed to create a video decoder for {}",
+            codec_list(&codec_preference)
+        ));
+    };
 
     // Create RTP depacketizer with correct codec
-    let depacketizer_codec = match codec {
-        VideoCodec::H264 => DepacketizerCodec::H264,
-        VideoCodec::H265 => DepacketizerCodec::H265,
-        VideoCodec::AV1 => DepacketizerCodec::AV1,
-    };
+    let mut depacketizer_codec = depacketizer_codec_for(codec);
     let mut rtp_depacketizer = RtpDepacketizer::with_codec(depacketizer_codec);
+    // Reads HEVC SPS to verify the chroma format the server actually sends
+    let mut hevc_sps_parser = HevcParser::new();
     info!("RTP depacketizer using {:?} mode", depacketizer_codec);
 
     let mut audio_decoder = match AudioDecoder::new(48000, 2) {
@@ -345,8 +431,9 @@ pub async fn run_streaming(
 
     // Audio player thread - receives decoded samples and plays them
     // Uses larger jitter buffer (150ms) to handle network timing variations
+    let resampler_quality = settings.audio_resampler_quality;
     std::thread::spawn(move || {
-        if let Ok(audio_player) = AudioPlayer::new(48000, 2) {
+        if let Ok(audio_player) = AudioPlayer::new(48000, 2, resampler_quality) {
             info!("Audio player thread started (async mode with jitter buffer)");
             if let Some(mut rx) = audio_sample_rx {
                 let mut total_samples: u64 = 0;
@@ -385,6 +472,21 @@ pub async fn run_streaming(
     let frames_dropped: u64 = 0;
     let mut bytes_received: u64 = 0;
     let mut last_frames_decoded: u64 = 0; // For actual FPS calculation
+    let mut first_frame_time: Option<std::time::Instant> = None;
+    let mut startup_keyframes_sent: usize = 0;
+
+    // Packet loss tracking (RTP sequence counters at the previous stats tick)
+    let mut last_video_packets: u64 = 0;
+    let mut last_video_packets_lost: u64 = 0;
+    let mut adaptive_bitrate = settings
+        .adaptive_bitrate
+        .then(|| AdaptiveBitrate::new(max_bitrate));
+    let mut bandwidth_cap = (settings.bandwidth_cap_mbps > 0)
+        .then(|| BandwidthCap::new(settings.bandwidth_cap_mbps * 1000, max_bitrate));
+    // Transport bytes at the previous stats tick (for the received rate)
+    let mut last_transport_bytes: u64 = 0;
+    // Drop a change left over from a previous session
+    SESSION_BITRATE_KBPS.store(0, std::sync::atomic::Ordering::Relaxed);
 
     // Pipeline latency tracking (receive to decode complete)
     let mut pipeline_latency_sum: f64 = 0.0;
@@ -406,8 +508,8 @@ pub async fn run_streaming(
     let (input_event_tx, input_event_rx) = mpsc::channel::<InputEvent>(32);
     input_handler.set_event_sender(input_event_tx.clone());
 
-    // Also set raw input sender for direct mouse events (Windows/macOS)
-    #[cfg(any(target_os = "windows", target_os = "macos"))]
+    // Also set raw input sender for direct mouse events (Windows/macOS/Linux)
+    #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
     crate::input::set_raw_input_sender(input_event_tx.clone());
 
     info!("Input handler connected to streaming loop");
@@ -463,14 +565,32 @@ pub async fn run_streaming(
     let input_ready_flag_clone = input_ready_flag.clone();
     let input_protocol_version_shared = Arc::new(std::sync::atomic::AtomicU8::new(0));
     let input_protocol_version_clone = input_protocol_version_shared.clone();
+    let flush_input_handler = input_handler.clone();
 
     tokio::spawn(async move {
         let mut input_encoder = InputEncoder::new();
         let mut input_event_rx = input_event_rx;
+        // Deadline for sending mouse motion still held by the coalescers after the
+        // last move, so the tail of a movement isn't stuck until the next event
+        let mut mouse_flush_at: Option<tokio::time::Instant> = None;
 
         loop {
-            match input_event_rx.recv().await {
+            let flush_deadline = mouse_flush_at.unwrap_or_else(tokio::time::Instant::now);
+            let next_event = tokio::select! {
+                event = input_event_rx.recv() => event,
+                _ = tokio::time::sleep_until(flush_deadline), if mouse_flush_at.is_some() => {
+                    mouse_flush_at = None;
+                    flush_input_handler.flush_mouse_events();
+                    #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
+                    crate::input::flush_pending_mouse_events();
+                    continue;
+                }
+            };
+            match next_event {
                 Some(event) => {
+                    // Any input counts as activity for the idle timeout
+                    crate::input::mark_input_activity();
+
                     // Only process if input is ready (handshake complete)
                     if !input_ready_flag_clone.load(std::sync::atomic::Ordering::Acquire) {
                         continue;
@@ -527,6 +647,14 @@ pub async fn run_streaming(
                             | InputEvent::MouseButtonUp { .. }
                             | InputEvent::MouseWheel { .. }
                     );
+                    if matches!(&event, InputEvent::MouseMove { .. }) {
+                        mouse_flush_at = Some(
+                            tokio::time::Instant::now()
+                                + std::time::Duration::from_micros(
+                                    crate::input::mouse_coalesce_interval_us(),
+                                ),
+                        );
+                    }
 
                     // Determine if this is a gamepad/controller event
                     let is_controller = matches!(&event, InputEvent::Gamepad { .. });
@@ -549,6 +677,9 @@ pub async fn run_streaming(
         debug!("Input processing task ended");
     });
 
+    // How the session ended (failures that break out of the loop set an error)
+    let mut result = StreamingResult::Normal;
+
     // Main event loop - no longer processes input directly
     loop {
         tokio::select! {
@@ -598,14 +729,36 @@ pub async fn run_streaming(
                     SignalingEvent::SdpOffer(sdp) => {
                         info!("Received SDP offer, length: {}", sdp.len());
 
-                        // Detect codec to use
-                        let codec = match settings.codec {
-                            VideoCodec::H264 => "H264",
-                            VideoCodec::H265 => "H265",
-                            VideoCodec::AV1 => "AV1",
-                        };
+                        // Fall back along the codec chain if the server doesn't offer ours
+                        if !sdp_offers_codec(&sdp, &codec) {
+                            let offered: Vec<VideoCodec> = codec_preference
+                                .iter()
+                                .copied()
+                                .filter(|c| *c != codec && sdp_offers_codec(&sdp, c))
+                                .collect();
+                            match first_decodable_codec(&offered, &settings, &shared_frame) {
+                                Some((fallback, (decoder, decoder_stats_rx))) => {
+                                    warn!(
+                                        "Server did not offer {} - falling back to {}",
+                                        codec.as_str(),
+                                        fallback.as_str()
+                                    );
+                                    codec = fallback;
+                                    video_decoder = decoder;
+                                    decode_stats_rx = decoder_stats_rx;
+                                    depacketizer_codec = depacketizer_codec_for(codec);
+                                    rtp_depacketizer = RtpDepacketizer::with_codec(depacketizer_codec);
+                                }
+                                None => warn!(
+                                    "Server did not offer {} and no fallback codec is available",
+                                    codec.as_str()
+                                ),
+                            }
+                        }
 
-                        info!("Preferred codec: {}", codec);
+                        // Codec name for stats
+                        let codec_name = codec.as_str();
+                        info!("Preferred codec: {}", codec_name);
 
                         // Use media_connection_info IP first, then server_ip
                         let public_ip = session_info.media_connection_info.as_ref()
@@ -626,7 +779,7 @@ pub async fn run_streaming(
                         let modified_sdp = inject_provisional_ssrcs(&modified_sdp);
 
                         // Prefer codec
-                        let modified_sdp = prefer_codec(&modified_sdp, &settings.codec);
+                        let modified_sdp = prefer_codec(&modified_sdp, &codec);
 
                         // CRITICAL: Create input channel BEFORE SDP negotiation (per GFN protocol)
                         info!("Creating input channel BEFORE SDP negotiation...");
@@ -635,8 +788,8 @@ pub async fn run_streaming(
                         // This corresponds to `iceServerConfiguration` in the CloudMatch response.
                         let mut ice_servers = Vec::new();
 
-                        // Convert SessionInfo ICE servers to RTCIceServer
-                        for server in &session_info.ice_servers {
+                        // Convert SessionInfo and user-configured ICE servers to RTCIceServer
+                        for server in session_info.ice_servers.iter().chain(&settings.turn_servers) {
                             let mut s = RTCIceServer {
                                 urls: server.urls.clone(),
                                 ..Default::default()
@@ -650,27 +803,53 @@ pub async fn run_streaming(
                             ice_servers.push(s);
                         }
 
-                        // Always add default STUN servers as fallback (Alliance robustness)
-                        ice_servers.push(RTCIceServer {
-                            urls: vec!["stun:s1.stun.gamestream.nvidia.com:19308".to_string()],
-                            ..Default::default()
-                        });
-                        ice_servers.push(RTCIceServer {
-                            urls: vec![
-                                "stun:stun.l.google.com:19302".to_string(),
-                                "stun:stun1.l.google.com:19302".to_string()
-                            ],
-                            ..Default::default()
-                        });
-
-                        if ice_servers.len() <= 2 {
-                             info!("Using default/fallback ICE servers only");
+                        if settings.force_relay {
+                            // Relay-only: STUN is useless, and without TURN there is nothing to gather
+                            let has_turn = ice_servers.iter().any(|s| {
+                                s.urls.iter().any(|u| u.starts_with("turn:") || u.starts_with("turns:"))
+                            });
+                            if !has_turn {
+                                error!("Relay-only mode enabled but no TURN servers are configured");
+                                peer::fail_connection_stage(
+                                    "Relay-only mode needs a TURN server - add one to turn_servers in settings.json",
+                                );
+                                result = StreamingResult::Error(
+                                    "Relay-only mode enabled but no TURN servers are configured".to_string(),
+                                );
+                                break;
+                            }
+                            info!("Relay-only mode: using {} ICE servers (TURN candidates only)", ice_servers.len());
                         } else {
-                            info!("Using {} ICE servers (session + fallback)", ice_servers.len());
+                            // Always add default STUN servers as fallback (Alliance robustness)
+                            ice_servers.push(RTCIceServer {
+                                urls: vec!["stun:s1.stun.gamestream.nvidia.com:19308".to_string()],
+                                ..Default::default()
+                            });
+                            ice_servers.push(RTCIceServer {
+                                urls: vec![
+                                    "stun:stun.l.google.com:19302".to_string(),
+                                    "stun:stun1.l.google.com:19302".to_string()
+                                ],
+                                ..Default::default()
+                            });
+
+                            if ice_servers.len() <= 2 {
+                                 info!("Using default/fallback ICE servers only");
+                            } else {
+                                info!("Using {} ICE servers (session + fallback)", ice_servers.len());
+                            }
                         }
 
                         // Handle offer and create answer
-                        match peer.handle_offer(&modified_sdp, ice_servers).await {
+                        let gather_timeout = std::time::Duration::from_millis(
+                            settings
+                                .ice_gather_timeout_ms
+                                .clamp(MIN_ICE_GATHER_TIMEOUT_MS, MAX_ICE_GATHER_TIMEOUT_MS) as u64,
+                        );
+                        match peer
+                            .handle_offer(&modified_sdp, ice_servers, gather_timeout, settings.force_relay)
+                            .await
+                        {
                             Ok(answer_sdp) => {
                                 // Create input channel
                                 if let Err(e) = peer.create_input_channel().await {
@@ -734,13 +913,19 @@ pub async fn run_streaming(
                                 }
 
                                 // Update stats with codec info
-                                stats.codec = codec.to_string();
+                                stats.codec = codec_name.to_string();
+                                stats.decoder = video_decoder.backend_name();
+                                stats.hw_decode = video_decoder.is_hw_accelerated();
                                 stats.resolution = format!("{}x{}", width, height);
                                 stats.target_fps = fps;
                             }
 
                             Err(e) => {
                                 error!("Failed to handle offer: {}", e);
+                                // Without an answer the server never connects - stop instead of hanging
+                                peer::fail_connection_stage("Couldn't set up the connection");
+                                result = StreamingResult::Error(format!("Failed to handle offer: {}", e));
+                                break;
                             }
                         }
                     }
@@ -775,7 +960,7 @@ pub async fn run_streaming(
                         stats.gpu_type = session_info.gpu_type.clone().unwrap_or_default();
                     }
                     WebRtcEvent::Disconnected => {
-                        warn!("WebRTC disconnected");
+                        warn!("WebRTC disconnected (stage: {:?})", connection_stage());
                         break;
                     }
                     WebRtcEvent::VideoFrame { payload, rtp_timestamp: _, marker } => {
@@ -817,6 +1002,19 @@ pub async fn run_streaming(
                                 // On marker bit, we have a complete Access Unit - send to decoder
                                 if marker {
                                     if let Some(frame_data) = rtp_depacketizer.take_nal_frame() {
+                                        if depacketizer_codec == DepacketizerCodec::H265 {
+                                            if let Some(idc) = hevc_sps_parser.sps_chroma_format(&frame_data) {
+                                                let label = chroma_format_label(idc);
+                                                if stats.chroma_format != label {
+                                                    info!(
+                                                        "Stream chroma format: {} (requested {})",
+                                                        label,
+                                                        settings.color_quality.display_name()
+                                                    );
+                                                    stats.chroma_format = label.to_string();
+                                                }
+                                            }
+                                        }
                                         if let Err(e) = video_decoder.decode_async(&frame_data, packet_receive_time) {
                                             warn!("Decode async failed: {}", e);
                                         }
@@ -966,7 +1164,7 @@ pub async fn run_streaming(
                         g29_ffb.stop();
 
                         // Clear raw input sender
-                        #[cfg(any(target_os = "windows", target_os = "macos"))]
+                        #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
                         crate::input::clear_raw_input_sender();
 
                         // Return SSRC change result to trigger reconnection
@@ -988,6 +1186,18 @@ pub async fn run_streaming(
                     // Log first decoded frame
                     if frames_decoded == 1 {
                         info!("First frame decoded (async) in {:.1}ms", decode_stat.decode_time_ms);
+                        first_frame_time = Some(std::time::Instant::now());
+                    }
+
+                    // Startup keyframe refresh while the bitrate ramps up
+                    if let (Some(first), Some(&delay_ms)) =
+                        (first_frame_time, STARTUP_KEYFRAME_DELAYS_MS.get(startup_keyframes_sent))
+                    {
+                        if first.elapsed() >= std::time::Duration::from_millis(delay_ms) {
+                            startup_keyframes_sent += 1;
+                            debug!("Startup keyframe refresh {} ({}ms after first frame)", startup_keyframes_sent, delay_ms);
+                            request_keyframe().await;
+                        }
                     }
                 }
 
@@ -1040,6 +1250,73 @@ pub async fn run_streaming(
                     stats.rtt_ms = net_stats.rtt_ms;
                 }
 
+                // Bitrate changed mid-session from the stream overlay
+                let session_bitrate = SESSION_BITRATE_KBPS.swap(0, std::sync::atomic::Ordering::Relaxed);
+                if session_bitrate != 0 {
+                    info!("Session bitrate changed to {} kbps", session_bitrate);
+                    if let Some(abr) = adaptive_bitrate.as_mut() {
+                        abr.set_max(session_bitrate);
+                    }
+                    if let Some(cap) = bandwidth_cap.as_mut() {
+                        cap.set_max(session_bitrate);
+                    }
+                    let capped_kbps = bandwidth_cap
+                        .as_ref()
+                        .map_or(session_bitrate, |cap| cap.target_kbps());
+                    request_max_bitrate(session_bitrate.min(capped_kbps)).await;
+                }
+
+                // Everything that arrived on the transport, not just video payload;
+                // the nominated pair isn't always reported (ice-lite), so fall back to video
+                let transport_bytes = net_stats.bytes_received.saturating_sub(last_transport_bytes);
+                last_transport_bytes = net_stats.bytes_received;
+                stats.received_mbps = if transport_bytes > 0 {
+                    ((transport_bytes as f64 * 8.0) / (elapsed * 1_000_000.0)) as f32
+                } else {
+                    stats.bitrate_mbps
+                };
+
+                // Bandwidth cap: lower the requested bitrate while the received rate stays over it
+                if let Some(cap_kbps) = bandwidth_cap
+                    .as_mut()
+                    .and_then(|cap| cap.update((stats.received_mbps * 1000.0) as u32))
+                {
+                    let abr_kbps = adaptive_bitrate
+                        .as_ref()
+                        .map_or(cap_kbps, |abr| abr.target_kbps());
+                    info!(
+                        "Bandwidth cap: receiving {:.1} Mbps, requesting {} kbps",
+                        stats.received_mbps,
+                        cap_kbps.min(abr_kbps)
+                    );
+                    request_max_bitrate(cap_kbps.min(abr_kbps)).await;
+                }
+
+                // Packet loss over the last interval from RTP sequence gaps
+                let packets = net_stats.video_packets_sequenced.saturating_sub(last_video_packets);
+                let lost = net_stats.video_packets_lost.saturating_sub(last_video_packets_lost);
+                last_video_packets = net_stats.video_packets_sequenced;
+                last_video_packets_lost = net_stats.video_packets_lost;
+                if packets + lost > 0 {
+                    stats.packet_loss = lost as f32 * 100.0 / (packets + lost) as f32;
+
+                    // Adaptive bitrate: lower the requested bitrate while loss stays high
+                    if let Some(target_kbps) = adaptive_bitrate
+                        .as_mut()
+                        .and_then(|abr| abr.update(stats.packet_loss))
+                    {
+                        // Never above what the bandwidth cap allows
+                        let target_kbps = bandwidth_cap
+                            .as_ref()
+                            .map_or(target_kbps, |cap| target_kbps.min(cap.target_kbps()));
+                        info!(
+                            "Adaptive bitrate: packet loss {:.1}%, requesting {} kbps",
+                            stats.packet_loss, target_kbps
+                        );
+                        request_max_bitrate(target_kbps).await;
+                    }
+                }
+
                 // Estimate end-to-end latency:
                 // E2E = network_rtt/2 (input to server) + server_processing (~16ms at 60fps)
                 //     + network_rtt/2 (video back) + decode_time + render_time
@@ -1091,9 +1368,9 @@ pub async fn run_streaming(
     g29_ffb.stop();
 
     // Clean up raw input sender
-    #[cfg(any(target_os = "windows", target_os = "macos"))]
+    #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
     crate::input::clear_raw_input_sender();
 
     info!("Streaming session ended");
-    StreamingResult::Normal
+    result
 }
@@ -13,6 +13,7 @@ use webrtc::data_channel::RTCDataChannel;
 use webrtc::dtls_transport::dtls_role::DTLSRole;
 use webrtc::ice_transport::ice_server::RTCIceServer;
 use webrtc::ice_transport::ice_gatherer_state::RTCIceGathererState;
+use webrtc::peer_connection::policy::ice_transport_policy::RTCIceTransportPolicy;
 use webrtc::interceptor::registry::Registry;
 use webrtc::peer_connection::RTCPeerConnection;
 use webrtc::peer_connection::configuration::RTCConfiguration;
@@ -21,6 +22,7 @@ use webrtc::rtp_transceiver::rtp_codec::{RTCRtpCodecCapability, RTCRtpCodecParam
 use webrtc::rtp_transceiver::rtp_transceiver_direction::RTCRtpTransceiverDirection;
 use webrtc::rtp_transceiver::rtp_codec::RTCRtpHeaderExtensionCapability;
 use webrtc::rtcp::payload_feedbacks::picture_loss_indication::PictureLossIndication;
+use webrtc::rtcp::payload_feedbacks::receiver_estimated_maximum_bitrate::ReceiverEstimatedMaximumBitrate;
 use anyhow::{Result, Context};
 use log::{info, debug, warn, error};
 use bytes::Bytes;
@@ -55,6 +57,82 @@ pub enum WebRtcEvent {
 static PEER_CONNECTION: Mutex<Option<Arc<RTCPeerConnection>>> = Mutex::new(None);
 /// Track SSRC for PLI
 static VIDEO_SSRC: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+/// Video RTP packets received and lost (gaps in sequence numbers) on the current track
+static VIDEO_PACKETS_RECEIVED: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+static VIDEO_PACKETS_LOST: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/// Connection setup stage, shown while waiting for the first video frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConnectionStage {
+    /// No connection in progress
+    Idle,
+    /// Connecting to the signaling server and exchanging SDP
+    Signaling,
+    /// Gathering local ICE candidates
+    GatheringCandidates,
+    /// ICE connectivity checks
+    Connecting,
+    /// ICE connected, DTLS/SRTP handshake in progress
+    Handshaking,
+    /// Peer connection established, waiting for video
+    Connected,
+}
+
+impl ConnectionStage {
+    /// Progress label for the UI
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConnectionStage::Idle => "Starting...",
+            ConnectionStage::Signaling => "Contacting server...",
+            ConnectionStage::GatheringCandidates => "Gathering candidates...",
+            ConnectionStage::Connecting => "Connecting...",
+            ConnectionStage::Handshaking => "Handshaking...",
+            ConnectionStage::Connected => "Waiting for video...",
+        }
+    }
+}
+
+/// Current connection stage and the stage at which setup failed (if it did)
+static CONNECTION_STAGE: Mutex<(ConnectionStage, Option<ConnectionStage>)> =
+    Mutex::new((ConnectionStage::Idle, None));
+
+/// Why connection setup failed (shown to the user)
+static CONNECTION_FAILURE_REASON: Mutex<Option<String>> = Mutex::new(None);
+
+/// Get the current connection stage and the stage setup failed at (if any)
+pub fn connection_stage() -> (ConnectionStage, Option<ConnectionStage>) {
+    *CONNECTION_STAGE.lock()
+}
+
+/// Get the reason connection setup failed (if it did)
+pub fn connection_failure_reason() -> Option<String> {
+    CONNECTION_FAILURE_REASON.lock().clone()
+}
+
+/// Reset stage tracking for a new connection attempt
+pub fn reset_connection_stage() {
+    *CONNECTION_STAGE.lock() = (ConnectionStage::Idle, None);
+    *CONNECTION_FAILURE_REASON.lock() = None;
+}
+
+/// Advance the connection stage (never moves backwards, logs each transition)
+pub fn set_connection_stage(stage: ConnectionStage) {
+    let mut current = CONNECTION_STAGE.lock();
+    if stage > current.0 && current.1.is_none() {
+        info!("Connection stage: {:?} -> {:?}", current.0, stage);
+        current.0 = stage;
+    }
+}
+
+/// Record that connection setup failed at the current stage
+pub(crate) fn fail_connection_stage(reason: &str) {
+    let mut current = CONNECTION_STAGE.lock();
+    if current.1.is_none() && current.0 != ConnectionStage::Connected {
+        error!("Connection setup failed during {:?}: {}", current.0, reason);
+        current.1 = Some(current.0);
+        *CONNECTION_FAILURE_REASON.lock() = Some(reason.to_string());
+    }
+}
 
 /// WebRTC peer for GFN streaming
 pub struct WebRtcPeer {
@@ -92,6 +170,27 @@ pub async fn request_keyframe() {
     }
 }
 
+/// Ask the server to cap its video bitrate (REMB - Receiver Estimated Maximum Bitrate)
+pub async fn request_max_bitrate(bitrate_kbps: u32) {
+    let pc = PEER_CONNECTION.lock().clone();
+    let ssrc = VIDEO_SSRC.load(std::sync::atomic::Ordering::Relaxed);
+
+    let (Some(pc), true) = (pc, ssrc != 0) else {
+        debug!("Cannot send REMB: no video stream yet");
+        return;
+    };
+
+    let remb = ReceiverEstimatedMaximumBitrate {
+        sender_ssrc: 0,
+        bitrate: bitrate_kbps as f32 * 1000.0,
+        ssrcs: vec![ssrc],
+    };
+    match pc.write_rtcp(&[Box::new(remb)]).await {
+        Ok(_) => info!("Requested max bitrate {} kbps for SSRC {}", bitrate_kbps, ssrc),
+        Err(e) => warn!("Failed to send REMB: {:?}", e),
+    }
+}
+
 impl WebRtcPeer {
     pub fn new(event_tx: mpsc::Sender<WebRtcEvent>) -> Self {
         Self {
@@ -105,7 +204,16 @@ impl WebRtcPeer {
     }
 
     /// Create peer connection and set remote SDP offer
-    pub async fn handle_offer(&mut self, sdp_offer: &str, ice_servers: Vec<RTCIceServer>) -> Result<String> {
+    /// `gather_timeout` bounds ICE gathering; on timeout the answer uses the candidates
+    /// gathered so far, or fails if there are none (e.g. blocked by a firewall)
+    /// `relay_only` restricts ICE to TURN candidates (for networks that block UDP)
+    pub async fn handle_offer(
+        &mut self,
+        sdp_offer: &str,
+        ice_servers: Vec<RTCIceServer>,
+        gather_timeout: std::time::Duration,
+        relay_only: bool,
+    ) -> Result<String> {
         info!("Setting up WebRTC peer connection");
 
         // Detect ice-lite BEFORE creating peer connection - this affects DTLS role
@@ -224,6 +332,12 @@ impl WebRtcPeer {
         // Create RTCConfiguration
         let config = RTCConfiguration {
             ice_servers,
+            ice_transport_policy: if relay_only {
+                info!("ICE transport policy: relay only");
+                RTCIceTransportPolicy::Relay
+            } else {
+                RTCIceTransportPolicy::All
+            },
             ..Default::default()
         };
 
@@ -260,11 +374,16 @@ impl WebRtcPeer {
             info!("ICE connection state: {:?}", state);
             Box::pin(async move {
                 match state {
+                    webrtc::ice_transport::ice_connection_state::RTCIceConnectionState::Checking => {
+                        set_connection_stage(ConnectionStage::Connecting);
+                    }
                     webrtc::ice_transport::ice_connection_state::RTCIceConnectionState::Connected => {
+                        set_connection_stage(ConnectionStage::Handshaking);
                         let _ = tx.send(WebRtcEvent::Connected).await;
                     }
                     webrtc::ice_transport::ice_connection_state::RTCIceConnectionState::Disconnected |
                     webrtc::ice_transport::ice_connection_state::RTCIceConnectionState::Failed => {
+                        fail_connection_stage(&format!("ICE {:?}", state));
                         let _ = tx.send(WebRtcEvent::Disconnected).await;
                     }
                     _ => {}
@@ -272,6 +391,12 @@ impl WebRtcPeer {
             })
         }));
 
+        // On signaling state change (offer/answer exchange)
+        peer_connection.on_signaling_state_change(Box::new(move |state| {
+            info!("Signaling state: {:?}", state);
+            Box::pin(async {})
+        }));
+
         // On peer connection state change (includes DTLS state)
         let _pc_for_state = peer_connection.clone();
         peer_connection.on_peer_connection_state_change(Box::new(move |state| {
@@ -280,9 +405,11 @@ impl WebRtcPeer {
                 match state {
                     webrtc::peer_connection::peer_connection_state::RTCPeerConnectionState::Connected => {
                         info!("=== DTLS HANDSHAKE COMPLETE - FULLY CONNECTED ===");
+                        set_connection_stage(ConnectionStage::Connected);
                     }
                     webrtc::peer_connection::peer_connection_state::RTCPeerConnectionState::Failed => {
                         warn!("Peer connection FAILED (likely DTLS handshake failure)");
+                        fail_connection_stage("peer connection failed");
                     }
                     _ => {}
                 }
@@ -308,6 +435,7 @@ impl WebRtcPeer {
                 let mut packet_count: u64 = 0;
                 let mut last_packet_time = std::time::Instant::now();
                 let mut stall_warning_sent = false;
+                let mut last_sequence: Option<u16> = None;
                 const STALL_TIMEOUT_MS: u64 = 2000; // 2 seconds without packets = stall
 
                 info!("=== Starting track read loop for {} ({}) ===",
@@ -330,6 +458,8 @@ impl WebRtcPeer {
 
                                 if track_kind == webrtc::rtp_transceiver::rtp_codec::RTPCodecType::Video {
                                     VIDEO_SSRC.store(rtp_packet.header.ssrc, std::sync::atomic::Ordering::Relaxed);
+                                    VIDEO_PACKETS_RECEIVED.store(0, std::sync::atomic::Ordering::Relaxed);
+                                    VIDEO_PACKETS_LOST.store(0, std::sync::atomic::Ordering::Relaxed);
 
                                     // Request keyframe immediately when video track starts
                                     // This ensures we get an IDR frame to begin decoding
@@ -350,6 +480,24 @@ impl WebRtcPeer {
                             }
 
                             if track_kind == webrtc::rtp_transceiver::rtp_codec::RTPCodecType::Video {
+                                // Count sequence gaps as lost; a late (reordered) packet cancels one
+                                let sequence = rtp_packet.header.sequence_number;
+                                VIDEO_PACKETS_RECEIVED.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                                match last_sequence.map(|last| sequence.wrapping_sub(last) as i16) {
+                                    Some(gap) if gap > 1 => {
+                                        VIDEO_PACKETS_LOST.fetch_add(gap as u64 - 1, std::sync::atomic::Ordering::Relaxed);
+                                        last_sequence = Some(sequence);
+                                    }
+                                    Some(gap) if gap < 0 => {
+                                        let _ = VIDEO_PACKETS_LOST.fetch_update(
+                                            std::sync::atomic::Ordering::Relaxed,
+                                            std::sync::atomic::Ordering::Relaxed,
+                                            |lost| lost.checked_sub(1),
+                                        );
+                                    }
+                                    _ => last_sequence = Some(sequence),
+                                }
+
                                 if let Err(e) = tx_clone.send(WebRtcEvent::VideoFrame {
                                     payload: rtp_packet.payload.to_vec(),
                                     rtp_timestamp: rtp_packet.header.timestamp,
@@ -479,6 +627,9 @@ impl WebRtcPeer {
             let gather_tx = gather_tx.clone();
             move |state| {
                 info!("ICE gathering state: {:?}", state);
+                if state == RTCIceGathererState::Gathering {
+                    set_connection_stage(ConnectionStage::GatheringCandidates);
+                }
                 if state == RTCIceGathererState::Complete {
                     if let Some(tx) = gather_tx.lock().unwrap().take() {
                         let _ = tx.send(());
@@ -494,21 +645,36 @@ impl WebRtcPeer {
         info!("Local description set, waiting for ICE gathering...");
 
         // Wait for ICE gathering (with timeout)
-        let gather_result = tokio::time::timeout(
-            std::time::Duration::from_secs(5),
-            gather_rx
-        ).await;
-
-        match gather_result {
-            Ok(_) => info!("ICE gathering complete"),
-            Err(_) => warn!("ICE gathering timeout - proceeding"),
-        }
+        let gather_result = tokio::time::timeout(gather_timeout, gather_rx).await;
 
         // Get final SDP (already has DTLS setup fixed if ice-lite)
         let final_sdp = peer_connection.local_description().await
             .map(|d| d.sdp)
             .unwrap_or_else(|| answer.sdp.clone());
 
+        match gather_result {
+            Ok(_) => info!("ICE gathering complete"),
+            Err(_) => {
+                let gathered = final_sdp.lines().filter(|l| l.starts_with("a=candidate:")).count();
+                if gathered == 0 {
+                    // Nothing to offer the server - connecting would hang forever
+                    let _ = peer_connection.close().await;
+                    fail_connection_stage(
+                        "Couldn't establish connection - check your firewall/NAT settings",
+                    );
+                    return Err(anyhow::anyhow!(
+                        "ICE gathering timed out after {}ms with no candidates",
+                        gather_timeout.as_millis()
+                    ));
+                }
+                warn!(
+                    "ICE gathering timed out after {}ms - proceeding with {} candidate(s)",
+                    gather_timeout.as_millis(),
+                    gathered
+                );
+            }
+        }
+
         info!("Final SDP length: {}", final_sdp.len());
 
         // Log SDP content for debugging
@@ -718,6 +884,9 @@ impl WebRtcPeer {
             }
         }
 
+        stats.video_packets_lost = VIDEO_PACKETS_LOST.load(std::sync::atomic::Ordering::Relaxed);
+        stats.video_packets_sequenced = VIDEO_PACKETS_RECEIVED.load(std::sync::atomic::Ordering::Relaxed);
+
         stats
     }
 }
@@ -728,6 +897,10 @@ pub struct NetworkStats {
     pub rtt_ms: f32,
     pub packets_received: u64,
     pub video_packets_received: u64,
+    /// Video packets counted by the RTP sequence tracker (for packet loss)
+    pub video_packets_sequenced: u64,
+    /// Video packets missing from the RTP sequence
+    pub video_packets_lost: u64,
     pub bytes_received: u64,
     pub bytes_sent: u64,
 }
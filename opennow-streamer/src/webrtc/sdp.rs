@@ -26,6 +26,34 @@ fn normalize_codec_name(name: &str) -> String {
     }
 }
 
+/// Check whether the video section of an SDP offer includes `codec`
+pub fn sdp_offers_codec(sdp: &str, codec: &VideoCodec) -> bool {
+    let mut in_video = false;
+
+    for line in sdp.lines() {
+        if line.starts_with("m=video") {
+            in_video = true;
+        } else if line.starts_with("m=") && in_video {
+            break;
+        }
+
+        if in_video {
+            if let Some(rtpmap) = line.strip_prefix("a=rtpmap:") {
+                let raw_codec = rtpmap
+                    .split_whitespace()
+                    .nth(1)
+                    .and_then(|c| c.split('/').next())
+                    .unwrap_or("");
+                if normalize_codec_name(raw_codec) == codec.as_str() {
+                    return true;
+                }
+            }
+        }
+    }
+
+    false
+}
+
 /// Force a specific video codec in SDP
 pub fn prefer_codec(sdp: &str, codec: &VideoCodec) -> String {
     let codec_name = match codec {
@@ -412,6 +440,19 @@ mod tests {
         assert!(!fixed.contains("a=candidate:"));
     }
 
+    #[test]
+    fn test_sdp_offers_codec() {
+        let sdp = "m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n\
+            a=rtpmap:111 opus/48000/2\r\n\
+            m=video 9 UDP/TLS/RTP/SAVPF 96 98\r\n\
+            a=rtpmap:96 H264/90000\r\n\
+            a=rtpmap:98 HEVC/90000\r\n";
+
+        assert!(sdp_offers_codec(sdp, &VideoCodec::H264));
+        assert!(sdp_offers_codec(sdp, &VideoCodec::H265));
+        assert!(!sdp_offers_codec(sdp, &VideoCodec::AV1));
+    }
+
     #[test]
     fn test_inject_provisional_ssrcs_with_existing() {
         // SDP with existing SSRC 1